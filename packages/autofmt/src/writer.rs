@@ -62,6 +62,7 @@ impl<'a> Writer<'a> {
             BodyNode::RawExpr(expr) => self.write_expr_node(expr),
             BodyNode::ForLoop(forloop) => self.write_for_loop(forloop),
             BodyNode::IfChain(ifchain) => self.write_if_chain(ifchain),
+            BodyNode::Match(match_expr) => self.write_match(match_expr),
         }?;
 
         let span = Self::final_span_of_node(node);
@@ -204,6 +205,58 @@ impl<'a> Writer<'a> {
         Ok(())
     }
 
+    fn write_match(&mut self, match_expr: &MatchExpr) -> std::fmt::Result {
+        write!(self.out, "match ")?;
+
+        self.write_inline_expr(&match_expr.expr)?;
+        self.out.new_line()?;
+
+        self.out.indent_level += 1;
+        let mut arms = match_expr.arms.iter().peekable();
+        while let Some(arm) = arms.next() {
+            let start = arm.pat.span().start();
+            if self.current_span_is_primary(start) {
+                self.write_comments(start)?;
+            }
+            self.out.tab()?;
+
+            let pat = self.unparse_pat(&arm.pat);
+            write!(self.out, "{pat}")?;
+            if let Some((_, guard)) = &arm.guard {
+                let guard = self.unparse_expr(guard);
+                write!(self.out, " if {guard}")?;
+            }
+            write!(self.out, " => {{")?;
+
+            if !arm.body.is_empty() {
+                self.out.new_line()?;
+                self.write_body_indented(&arm.body.roots)?;
+                self.out.tabbed_line()?;
+            }
+            write!(self.out, "}}")?;
+
+            if arms.peek().is_some() {
+                self.out.new_line()?;
+            }
+        }
+        self.out.indent_level -= 1;
+
+        self.out.tabbed_line()?;
+        write!(self.out, "}}")?;
+
+        Ok(())
+    }
+
+    /// Patterns can't be unparsed on their own, so unparse them as a let expression and strip it back off
+    fn unparse_pat(&mut self, pat: &syn::Pat) -> String {
+        let unparsed = self.unparse_expr(&syn::parse_quote! { let #pat = () });
+        unparsed
+            .strip_prefix("let ")
+            .and_then(|pat| pat.strip_suffix(" = ()"))
+            .map(ToString::to_string)
+            .unwrap_or_else(|| pat.to_token_stream().to_string())
+    }
+
     /// An expression within a for or if block that might need to be spread out across several lines
     fn write_inline_expr(&mut self, expr: &Expr) -> std::fmt::Result {
         let unparsed = self.unparse_expr(expr);
@@ -952,6 +1005,7 @@ impl<'a> Writer<'a> {
                 Some(b) => b.span.span(),
                 None => i.then_brace.span.span(),
             },
+            BodyNode::Match(m) => m.brace.span.span(),
         };
         span
     }
@@ -42,6 +42,7 @@ twoway![
     long,
     manual_props,
     many_exprs,
+    match_arms,
     messy_indent,
     misplaced,
     multirsx,
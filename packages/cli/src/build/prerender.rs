@@ -2,6 +2,7 @@ use anyhow::Context;
 use dioxus_cli_config::{server_ip, server_port};
 use futures_util::stream::FuturesUnordered;
 use futures_util::StreamExt;
+use serde::Serialize;
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
     path::Path,
@@ -80,7 +81,8 @@ pub(crate) async fn pre_render_static_routes(server_exe: &Path) -> anyhow::Resul
                 .get(format!("http://{address}:{port}{route}"))
                 .header("Accept", "text/html")
                 .send()
-                .await?;
+                .await
+                .map_err(|err| (route.clone(), err))?;
             // If it takes longer than 30 seconds to resolve the route, log a warning
             let warning_task = tokio::spawn({
                 let route = route.clone();
@@ -92,25 +94,50 @@ pub(crate) async fn pre_render_static_routes(server_exe: &Path) -> anyhow::Resul
             // Wait for the streaming response to completely finish before continuing. We don't use the html it returns directly
             // because it may contain artifacts of intermediate streaming steps while the page is loading. The SSG app should write
             // the final clean HTML to the disk automatically after the request completes.
-            let _html = request.text().await?;
+            let _html = request.text().await.map_err(|err| (route.clone(), err))?;
 
             // Cancel the warning task if it hasn't already run
             warning_task.abort();
 
-            Ok::<_, reqwest::Error>(route)
+            Ok::<_, (String, reqwest::Error)>(route)
         })
         .collect::<FuturesUnordered<_>>();
 
+    let mut manifest = SsgManifest::default();
     while let Some(route) = resolved_routes.next().await {
         match route {
-            Ok(route) => tracing::debug!("ssg success: {route:?}"),
-            Err(err) => tracing::error!("ssg error: {err:?}"),
+            Ok(route) => {
+                tracing::debug!("ssg success: {route:?}");
+                manifest.routes.push(route);
+            }
+            Err((route, err)) => {
+                tracing::error!("ssg error: {err:?}");
+                manifest.failed.push(route);
+            }
         }
     }
 
+    // Write a manifest of the rendered routes next to the html files so deploy scripts can check what was generated
+    manifest.routes.sort();
+    manifest.failed.sort();
+    if let Some(exe_dir) = server_exe.parent() {
+        let manifest_path = exe_dir.join("public").join("ssg-manifest.json");
+        std::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)
+            .with_context(|| format!("Failed to write the SSG manifest to {manifest_path:?}"))?;
+    }
+
     tracing::info!("SSG complete");
 
     drop(_child);
 
     Ok(())
 }
+
+/// The routes that were rendered during SSG
+#[derive(Debug, Default, Serialize)]
+struct SsgManifest {
+    /// The routes that were rendered to html files
+    routes: Vec<String>,
+    /// The routes that failed to render
+    failed: Vec<String>,
+}
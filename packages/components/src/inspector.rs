@@ -0,0 +1,177 @@
+use dioxus_lib::prelude::dioxus_core::{ComponentSnapshot, RenderEvent, RenderReason};
+use dioxus_lib::prelude::*;
+use std::time::Duration;
+
+/// The properties for a [`DevtoolsOverlay`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DevtoolsOverlayProps {
+    /// If the overlay starts expanded. A collapsed overlay only shows its header.
+    #[props(default = true)]
+    pub open: bool,
+
+    /// The class attribute for the overlay.
+    pub class: Option<String>,
+
+    /// The style attribute for the overlay. The default pins the overlay to the bottom right corner of the window.
+    #[props(
+        default = "position: fixed; right: 8px; bottom: 8px; z-index: 2147483647; max-height: 50vh; overflow: auto; background: white; color: black; font: 12px monospace; border: 1px solid #888;".to_string(),
+        into
+    )]
+    pub style: String,
+}
+
+/// An overlay that shows the component tree and highlights the components that rendered in the last update.
+///
+/// The overlay turns on profiling in the runtime while it is mounted. Every component is a row with its render count,
+/// how long its last render took, and why it rendered last. Rows of components that rendered in the last update are
+/// marked with `data-rendered` and list the reason they rendered. The overlay leaves its own components out of the
+/// tree and ignores their renders.
+///
+/// Renders are only recorded after the overlay mounts, so put it before the rest of the app to see the first render of
+/// every component. Only mount the overlay while debugging: profiling adds a small amount of work to every render.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus::components::DevtoolsOverlay;
+///
+/// fn app() -> Element {
+///     rsx! {
+///         if cfg!(debug_assertions) {
+///             DevtoolsOverlay {}
+///         }
+///         Counter {}
+///     }
+/// }
+///
+/// #[component]
+/// fn Counter() -> Element {
+///     let mut count = use_signal(|| 0);
+///     rsx! { button { onclick: move |_| count += 1, "{count}" } }
+/// }
+/// ```
+pub fn DevtoolsOverlay(props: DevtoolsOverlayProps) -> Element {
+    let mut open = use_signal(|| props.open);
+    let mut rendered = use_signal(Vec::<RenderEvent>::new);
+
+    use_hook_with_cleanup(
+        move || {
+            let overlay = current_scope_id().unwrap();
+            let runtime = Runtime::current().unwrap();
+            let was_profiling = runtime.is_profiling();
+            runtime.set_profiling(true);
+            let listener = runtime.add_render_listener(move |events| {
+                // Rendering the overlay records more renders. Ignore them so the overlay doesn't rerender forever
+                let events: Vec<_> = events
+                    .iter()
+                    .filter(|event| !is_overlay(event.scope, overlay))
+                    .cloned()
+                    .collect();
+                if !events.is_empty() {
+                    rendered.set(events);
+                }
+            });
+            (runtime, listener, was_profiling)
+        },
+        |(runtime, listener, was_profiling)| {
+            runtime.remove_render_listener(listener);
+            runtime.set_profiling(was_profiling);
+        },
+    );
+
+    let overlay = current_scope_id().unwrap();
+    let rendered = rendered.read();
+    let components: Vec<ComponentSnapshot> = match open() {
+        true => Runtime::current()
+            .unwrap()
+            .component_tree()
+            .into_iter()
+            .filter(|component| !is_overlay(component.id, overlay))
+            .collect(),
+        false => Vec::new(),
+    };
+
+    rsx! {
+        div {
+            class: props.class,
+            style: props.style,
+            role: "region",
+            aria_label: "Component inspector",
+            button {
+                aria_expanded: open(),
+                onclick: move |_| open.toggle(),
+                "Components ({rendered.len()} rendered)"
+            }
+            if open() {
+                ol {
+                    for component in components {
+                        ComponentRow {
+                            key: "{component.id:?}",
+                            rendered: rendered
+                                .iter()
+                                .find(|event| event.scope == component.id)
+                                .map(|event| event.reason),
+                            component,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ComponentRow(component: ComponentSnapshot, rendered: Option<RenderReason>) -> Element {
+    let name = short_name(component.name);
+    let indent = component.height * 12;
+    let hooks = component.hooks.join(", ");
+    let timing = component.profile.map(|profile| {
+        format!(
+            "{} in {} ({} total)",
+            profile.last_reason,
+            format_duration(profile.last_duration),
+            format_duration(profile.total_duration)
+        )
+    });
+
+    rsx! {
+        li {
+            style: "padding-left: {indent}px;",
+            title: "props: {component.props}\nhooks: {hooks}",
+            "data-rendered": rendered.map(|reason| reason.to_string()),
+            "{name} ×{component.render_count}"
+            if let Some(timing) = timing {
+                " {timing}"
+            }
+        }
+    }
+}
+
+/// Check if a scope is the overlay or one of its components
+fn is_overlay(scope: ScopeId, overlay: ScopeId) -> bool {
+    scope == overlay || scope.is_descendant_of(overlay)
+}
+
+/// Components are named after the path of their function. Only the last segment is useful in a tree.
+fn short_name(name: &str) -> &str {
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn format_duration(duration: Duration) -> String {
+    match duration.as_micros() {
+        micros @ 0..=999 => format!("{micros}µs"),
+        micros => format!("{:.1}ms", micros as f64 / 1000.0),
+    }
+}
+
+#[test]
+fn names_and_durations_are_short() {
+    assert_eq!(short_name("app::components::Counter"), "Counter");
+    assert_eq!(
+        short_name("dioxus_core::SuspenseBoundary"),
+        "SuspenseBoundary"
+    );
+    assert_eq!(short_name("Table<alloc::string::String>"), "Table");
+    assert_eq!(format_duration(Duration::from_micros(12)), "12µs");
+    assert_eq!(format_duration(Duration::from_micros(2500)), "2.5ms");
+}
@@ -0,0 +1,28 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+// cannot use forbid, because props derive macro generates #[allow(missing_docs)]
+#![deny(missing_docs)]
+#![allow(non_snake_case)]
+
+#[cfg(feature = "inspector")]
+mod inspector;
+#[cfg(feature = "inspector")]
+pub use inspector::*;
+
+mod shortcuts;
+pub use shortcuts::*;
+
+mod virtual_list;
+pub use virtual_list::*;
+
+/// A collection of useful items most applications might need.
+pub mod prelude {
+    #[cfg(feature = "inspector")]
+    pub use crate::inspector::{DevtoolsOverlay, DevtoolsOverlayProps};
+    pub use crate::shortcuts::{
+        use_shortcut, use_shortcut_registry, KeyCombo, ShortcutHelp, ShortcutHelpProps, Shortcuts,
+        ShortcutsProps,
+    };
+    pub use crate::virtual_list::{VirtualList, VirtualListProps};
+}
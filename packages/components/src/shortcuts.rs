@@ -0,0 +1,697 @@
+use dioxus_lib::document;
+use dioxus_lib::prelude::*;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver};
+use futures_util::future::{select, Either};
+use futures_util::StreamExt;
+use std::{fmt::Display, pin::pin, str::FromStr};
+
+/// Listens for key presses on the window and sends the id of every registered combination that was pressed. Matched
+/// key presses are handled synchronously, so the browser's default action like saving the page is prevented.
+const LISTENER_JS: &str = r#"
+let combos = new Set();
+const editable = (target) =>
+    target instanceof HTMLElement &&
+    (target.isContentEditable || ["INPUT", "TEXTAREA", "SELECT"].includes(target.tagName));
+const comboId = (event, shift, key) => [
+    event.ctrlKey && "ctrl",
+    event.altKey && "alt",
+    shift && "shift",
+    event.metaKey && "meta",
+    key.toLowerCase(),
+].filter(Boolean).join("+");
+window.addEventListener("keydown", (event) => {
+    if (event.isComposing) return;
+    if (["Control", "Alt", "Shift", "Meta"].includes(event.key)) return;
+    // Match the physical key because Alt on macOS and Shift change the character it types, like Alt+S typing ß
+    let id = event.code && comboId(event, event.shiftKey, event.code);
+    if (!combos.has(id)) {
+        // Characters that have no key in the US layout are matched by the character they type. Shift is part of
+        // the character for symbols, but not for letters
+        const key = event.key;
+        const shift = event.shiftKey && key.length === 1 && key.toLowerCase() !== key;
+        id = comboId(event, shift, key.length === 1 ? key.toUpperCase() : key);
+        if (!combos.has(id)) return;
+    }
+    // Let people type into inputs unless the shortcut uses a modifier
+    const modified = event.ctrlKey || event.altKey || event.metaKey;
+    if (editable(event.target) && !modified && event.key !== "Escape") return;
+    event.preventDefault();
+    dioxus.send(id);
+});
+while (true) {
+    combos = new Set(await dioxus.recv());
+}
+"#;
+
+/// A key combination like `Ctrl+Shift+K`, parsed from a string.
+///
+/// Modifiers are `Ctrl` (or `Control`), `Alt` (or `Option`), `Shift` and `Meta` (or `Cmd`, `Command` or `Super`). The
+/// key is a single character like `K` or `?`, or the name of a key like `Escape`, `ArrowUp`, `F1` or `Space`. Letters
+/// are case insensitive, and symbols like `?` don't need `Shift`.
+///
+/// Key presses are matched by the physical key, like
+/// [`KeyboardEvent.code`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/code), so `Alt+S` still works
+/// on macOS where Option changes the character the key types. Characters are mapped to the key that types them on a
+/// US keyboard, so `?` is `Shift+Slash` and `!` is `Shift+1`. Characters that are not on a US keyboard are matched by
+/// the character they type instead.
+///
+/// ```rust
+/// # use dioxus_components::KeyCombo;
+/// let combo: KeyCombo = "ctrl+shift+k".parse().unwrap();
+/// assert_eq!(combo.to_string(), "Ctrl+Shift+K");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+    key: String,
+    /// The physical key that types the key, and if it needs shift to type it
+    code: Option<(String, bool)>,
+}
+
+/// The symbols on a US keyboard, the physical key that types them and if they need shift
+const US_SYMBOLS: &[(char, &str, bool)] = &[
+    ('`', "Backquote", false),
+    ('~', "Backquote", true),
+    ('-', "Minus", false),
+    ('_', "Minus", true),
+    ('=', "Equal", false),
+    ('+', "Equal", true),
+    ('[', "BracketLeft", false),
+    ('{', "BracketLeft", true),
+    (']', "BracketRight", false),
+    ('}', "BracketRight", true),
+    ('\\', "Backslash", false),
+    ('|', "Backslash", true),
+    (';', "Semicolon", false),
+    (':', "Semicolon", true),
+    ('\'', "Quote", false),
+    ('"', "Quote", true),
+    (',', "Comma", false),
+    ('<', "Comma", true),
+    ('.', "Period", false),
+    ('>', "Period", true),
+    ('/', "Slash", false),
+    ('?', "Slash", true),
+    ('!', "Digit1", true),
+    ('@', "Digit2", true),
+    ('#', "Digit3", true),
+    ('$', "Digit4", true),
+    ('%', "Digit5", true),
+    ('^', "Digit6", true),
+    ('&', "Digit7", true),
+    ('*', "Digit8", true),
+    ('(', "Digit9", true),
+    (')', "Digit0", true),
+];
+
+/// Get the physical key that types a key on a US keyboard, and if it needs shift
+fn physical_key(key: &str) -> Option<(String, bool)> {
+    let mut chars = key.chars();
+    match (chars.next(), chars.next()) {
+        (Some(char), None) if char.is_ascii_alphabetic() => {
+            Some((format!("Key{}", char.to_ascii_uppercase()), false))
+        }
+        (Some(char), None) if char.is_ascii_digit() => Some((format!("Digit{char}"), false)),
+        (Some(char), None) => US_SYMBOLS
+            .iter()
+            .find(|(symbol, _, _)| *symbol == char)
+            .map(|(_, code, shift)| (code.to_string(), *shift)),
+        // Named keys like Escape or F1 have the same name as their physical key
+        _ => Some((key.to_string(), false)),
+    }
+}
+
+impl KeyCombo {
+    /// The id the key listener sends for this combination
+    fn id(&self) -> String {
+        let (key, shift) = match &self.code {
+            Some((code, shift)) => (code.as_str(), self.shift || *shift),
+            None => (self.key.as_str(), self.shift),
+        };
+        let modifiers = [
+            (self.ctrl, "ctrl"),
+            (self.alt, "alt"),
+            (shift, "shift"),
+            (self.meta, "meta"),
+        ];
+        let mut id = String::new();
+        for (_, name) in modifiers.iter().filter(|(pressed, _)| *pressed) {
+            id.push_str(name);
+            id.push('+');
+        }
+        id.push_str(&key.to_lowercase());
+        id
+    }
+}
+
+impl FromStr for KeyCombo {
+    type Err = ParseKeyComboError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseKeyComboError(s.to_string());
+        // The plus key is written as a trailing `+`, like `Ctrl++`
+        let (modifiers, key) = match s.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None if s == "+" => ("", "+"),
+            None => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+
+        let mut combo = KeyCombo {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+            key: String::new(),
+            code: None,
+        };
+        for modifier in modifiers.split('+').filter(|modifier| !modifier.is_empty()) {
+            let pressed = match modifier.trim().to_lowercase().as_str() {
+                "ctrl" | "control" => &mut combo.ctrl,
+                "alt" | "option" => &mut combo.alt,
+                "shift" => &mut combo.shift,
+                "meta" | "cmd" | "command" | "super" => &mut combo.meta,
+                _ => return Err(error()),
+            };
+            *pressed = true;
+        }
+
+        let key = key.trim();
+        let mut chars = key.chars();
+        combo.key = match (chars.next(), chars.next()) {
+            (None, _) => return Err(error()),
+            (Some(char), None) => {
+                // Shift is already part of symbols like `?`
+                if US_SYMBOLS
+                    .iter()
+                    .any(|(symbol, _, shift)| *symbol == char && *shift)
+                {
+                    combo.shift = false;
+                }
+                char.to_uppercase().collect()
+            }
+            _ => match key.to_lowercase().as_str() {
+                "esc" => "Escape".to_string(),
+                "return" => "Enter".to_string(),
+                "del" => "Delete".to_string(),
+                "up" => "ArrowUp".to_string(),
+                "down" => "ArrowDown".to_string(),
+                "left" => "ArrowLeft".to_string(),
+                "right" => "ArrowRight".to_string(),
+                "plus" => "+".to_string(),
+                "space" => "Space".to_string(),
+                _ => key.to_string(),
+            },
+        };
+        combo.code = physical_key(&combo.key);
+        Ok(combo)
+    }
+}
+
+impl Display for KeyCombo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let modifiers = [
+            (self.ctrl, "Ctrl"),
+            (self.alt, "Alt"),
+            (self.shift, "Shift"),
+            (self.meta, "Meta"),
+        ];
+        for (_, name) in modifiers.iter().filter(|(pressed, _)| *pressed) {
+            write!(f, "{name}+")?;
+        }
+        f.write_str(&self.key)
+    }
+}
+
+/// An error that occurs when a string is not a valid [`KeyCombo`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeyComboError(String);
+
+impl Display for ParseKeyComboError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` is not a valid key combination", self.0)
+    }
+}
+
+impl std::error::Error for ParseKeyComboError {}
+
+/// The scope a [`Shortcuts`] component declares
+#[derive(Debug, Clone, PartialEq)]
+struct ScopeInfo {
+    name: String,
+    priority: i32,
+    depth: usize,
+}
+
+#[derive(Clone)]
+struct Binding {
+    id: usize,
+    combo: KeyCombo,
+    description: String,
+    scope: Signal<ScopeInfo>,
+    action: Callback,
+}
+
+/// Two shortcuts in the same scope that are bound to the same key combination
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutConflict {
+    /// The key combination both shortcuts are bound to
+    pub combo: KeyCombo,
+    /// The scope the shortcuts are declared in
+    pub scope: String,
+    /// The descriptions of the shortcuts, in the order they were registered
+    pub descriptions: Vec<String>,
+}
+
+/// A shortcut in a [`ShortcutGroup`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutInfo {
+    /// The key combination of the shortcut
+    pub combo: KeyCombo,
+    /// What the shortcut does
+    pub description: String,
+    /// If a shortcut with a higher priority is bound to the same key combination, so this one never runs right now
+    pub shadowed: bool,
+}
+
+/// The shortcuts of one scope, for a help overlay
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutGroup {
+    /// The name of the scope
+    pub scope: String,
+    /// The shortcuts declared in the scope
+    pub shortcuts: Vec<ShortcutInfo>,
+}
+
+/// Every shortcut that is mounted under the outermost [`Shortcuts`] component. Get it with [`use_shortcut_registry`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct ShortcutRegistry {
+    bindings: Signal<Vec<Binding>>,
+    next_id: CopyValue<usize>,
+}
+
+impl ShortcutRegistry {
+    fn new() -> Self {
+        Self {
+            bindings: Signal::new(Vec::new()),
+            next_id: CopyValue::new(0),
+        }
+    }
+
+    fn register(
+        &self,
+        combo: KeyCombo,
+        description: String,
+        scope: Signal<ScopeInfo>,
+        action: Callback,
+    ) -> usize {
+        let mut next_id = self.next_id;
+        let id = *next_id.read();
+        *next_id.write() = id + 1;
+
+        let mut bindings = self.bindings;
+        let conflict = bindings
+            .peek()
+            .iter()
+            .find(|binding| binding.scope == scope && binding.combo == combo)
+            .map(|binding| binding.description.clone());
+        if let Some(other) = conflict {
+            tracing::warn!(
+                "The shortcut {combo} for \"{description}\" conflicts with \"{other}\" in the scope {}. Only the shortcut that was registered last runs",
+                scope.peek().name
+            );
+        }
+
+        bindings.write().push(Binding {
+            id,
+            combo,
+            description,
+            scope,
+            action,
+        });
+        id
+    }
+
+    fn unregister(&self, id: usize) {
+        // The registry may already be dropped if the whole tree is dropped
+        let mut bindings = self.bindings;
+        if let Ok(mut bindings) = bindings.try_write() {
+            bindings.retain(|binding| binding.id != id);
+        };
+    }
+
+    /// Run the shortcut bound to a key combination. If more than one shortcut is bound to the combination, the one
+    /// in the scope with the highest priority runs, then the one in the innermost scope, then the one registered
+    /// last. Returns `false` if no shortcut is bound to the combination.
+    ///
+    /// The registry already listens for key presses on the window. This is useful to run shortcuts from other places,
+    /// like a command palette.
+    pub fn trigger(&self, combo: &KeyCombo) -> bool {
+        let action = self
+            .bindings
+            .peek()
+            .iter()
+            .filter(|binding| &binding.combo == combo)
+            .max_by_key(|binding| binding.rank())
+            .map(|binding| binding.action);
+        match action {
+            Some(action) => {
+                action.call(());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the pairs of shortcuts that are bound to the same key combination in the same scope
+    pub fn conflicts(&self) -> Vec<ShortcutConflict> {
+        let bindings = self.bindings.read();
+        let mut conflicts: Vec<ShortcutConflict> = Vec::new();
+        for (index, binding) in bindings.iter().enumerate() {
+            let scope = binding.scope.read();
+            let earlier = bindings[..index]
+                .iter()
+                .any(|other| other.scope == binding.scope && other.combo == binding.combo);
+            if !earlier {
+                continue;
+            }
+            match conflicts
+                .iter_mut()
+                .find(|conflict| conflict.combo == binding.combo && conflict.scope == scope.name)
+            {
+                Some(conflict) => conflict.descriptions.push(binding.description.clone()),
+                None => conflicts.push(ShortcutConflict {
+                    combo: binding.combo.clone(),
+                    scope: scope.name.clone(),
+                    descriptions: bindings[..=index]
+                        .iter()
+                        .filter(|other| {
+                            other.scope == binding.scope && other.combo == binding.combo
+                        })
+                        .map(|other| other.description.clone())
+                        .collect(),
+                }),
+            }
+        }
+        conflicts
+    }
+
+    /// Get every shortcut grouped by scope, for a help overlay. Scopes with a higher priority come first. Reading
+    /// this subscribes the current component to shortcuts that are added or removed.
+    pub fn help(&self) -> Vec<ShortcutGroup> {
+        let bindings = self.bindings.read();
+        let mut ranked: Vec<_> = bindings.iter().collect();
+        ranked.sort_by_key(|binding| {
+            let scope = binding.scope.read();
+            (std::cmp::Reverse((scope.priority, scope.depth)), binding.id)
+        });
+
+        let mut groups: Vec<ShortcutGroup> = Vec::new();
+        for binding in ranked {
+            let shadowed = bindings
+                .iter()
+                .any(|other| other.combo == binding.combo && other.rank() > binding.rank());
+            let info = ShortcutInfo {
+                combo: binding.combo.clone(),
+                description: binding.description.clone(),
+                shadowed,
+            };
+            let scope = binding.scope.read().name.clone();
+            match groups.iter_mut().find(|group| group.scope == scope) {
+                Some(group) => group.shortcuts.push(info),
+                None => groups.push(ShortcutGroup {
+                    scope,
+                    shortcuts: vec![info],
+                }),
+            }
+        }
+        groups
+    }
+
+    /// The ids of every key combination with a shortcut, for the key listener
+    fn combo_ids(&self) -> Vec<String> {
+        let mut ids: Vec<_> = self
+            .bindings
+            .read()
+            .iter()
+            .map(|binding| binding.combo.id())
+            .collect();
+        ids.sort();
+        ids.dedup();
+        ids
+    }
+
+    /// Listen for key presses on the window until the outermost [`Shortcuts`] component is dropped
+    async fn listen(self, mut combos: UnboundedReceiver<Vec<String>>) {
+        let mut listener = document::eval(LISTENER_JS);
+        loop {
+            let event = {
+                let pressed = pin!(listener.recv::<String>());
+                match select(pressed, combos.next()).await {
+                    Either::Left((pressed, _)) => Either::Left(pressed),
+                    Either::Right((ids, _)) => Either::Right(ids),
+                }
+            };
+            let id = match event {
+                Either::Left(Ok(id)) => id,
+                // Platforms without javascript, like the server, never send key presses
+                Either::Left(Err(_)) | Either::Right(None) => return,
+                Either::Right(Some(ids)) => {
+                    _ = listener.send(ids);
+                    continue;
+                }
+            };
+            let combo = self
+                .bindings
+                .peek()
+                .iter()
+                .find(|binding| binding.combo.id() == id)
+                .map(|binding| binding.combo.clone());
+            if let Some(combo) = combo {
+                self.trigger(&combo);
+            }
+        }
+    }
+}
+
+impl Binding {
+    fn rank(&self) -> (i32, usize, usize) {
+        let scope = self.scope.peek();
+        (scope.priority, scope.depth, self.id)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ShortcutScope {
+    registry: ShortcutRegistry,
+    info: Signal<ScopeInfo>,
+}
+
+/// The properties for a [`Shortcuts`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ShortcutsProps {
+    /// The name of the scope, which groups the shortcuts in the help overlay.
+    #[props(default = "Global".to_string(), into)]
+    pub scope: String,
+
+    /// The priority of the shortcuts in this scope. If shortcuts in different scopes are bound to the same key
+    /// combination, the one with the highest priority runs. Nested scopes win over their parents if the priorities are
+    /// equal.
+    #[props(default)]
+    pub priority: i32,
+
+    /// The components that declare shortcuts with [`use_shortcut`].
+    pub children: Element,
+}
+
+/// A scope for keyboard shortcuts. Components under it declare shortcuts with [`use_shortcut`] that are active while
+/// the component is mounted.
+///
+/// The outermost [`Shortcuts`] component listens for key presses on the whole window, so shortcuts work no matter
+/// which element is focused. Plain keys without `Ctrl`, `Alt` or `Meta` are ignored while typing in an input. Nest
+/// [`Shortcuts`] components to group shortcuts for a part of the app, like an editor, and let them take precedence
+/// over global shortcuts while that part is mounted.
+///
+/// # Example
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut show_help = use_signal(|| false);
+///
+///     rsx! {
+///         Shortcuts {
+///             HelpToggle { show_help }
+///             if show_help() {
+///                 ShortcutHelp {}
+///             }
+///             Shortcuts { scope: "Editor", priority: 1, Editor {} }
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn HelpToggle(mut show_help: Signal<bool>) -> Element {
+///     use_shortcut("?", "Show keyboard shortcuts", move || show_help.toggle());
+///     rsx! {}
+/// }
+///
+/// #[component]
+/// fn Editor() -> Element {
+///     use_shortcut("Ctrl+S", "Save the document", move || println!("Saving..."));
+///     rsx! { textarea {} }
+/// }
+/// ```
+pub fn Shortcuts(props: ShortcutsProps) -> Element {
+    let ShortcutsProps {
+        scope,
+        priority,
+        children,
+    } = props;
+
+    let parent = use_hook(try_consume_context::<ShortcutScope>);
+    let new_info = ScopeInfo {
+        name: scope,
+        priority,
+        depth: parent.map_or(0, |parent| parent.info.peek().depth + 1),
+    };
+    let context = use_hook(|| {
+        let registry = parent.map_or_else(ShortcutRegistry::new, |parent| parent.registry);
+        provide_context(ShortcutScope {
+            registry,
+            info: Signal::new(new_info.clone()),
+        })
+    });
+    let mut info = context.info;
+    if *info.peek() != new_info {
+        info.set(new_info);
+    }
+
+    // Only the outermost scope listens for key presses
+    let listener = use_hook(|| {
+        parent.is_none().then(|| {
+            let (sender, receiver) = unbounded();
+            spawn(context.registry.listen(receiver));
+            CopyValue::new(sender)
+        })
+    });
+    use_effect(move || {
+        let ids = context.registry.combo_ids();
+        if let Some(listener) = listener {
+            _ = listener.read().unbounded_send(ids);
+        }
+    });
+
+    rsx! {
+        {children}
+    }
+}
+
+/// Declare a keyboard shortcut that is active as long as the current component is mounted. The shortcut belongs to the
+/// scope of the nearest [`Shortcuts`] component.
+///
+/// The combination is parsed with [`KeyCombo::from_str`]. The description is shown in the [`ShortcutHelp`] overlay.
+///
+/// # Panic
+/// - When the component is not nested within a [`Shortcuts`] component.
+/// - When the combination is not a valid [`KeyCombo`].
+#[track_caller]
+pub fn use_shortcut(combo: &str, description: impl ToString, mut action: impl FnMut() + 'static) {
+    let action = use_callback(move |_| action());
+    let context = use_hook(consume_context::<ShortcutScope>);
+    let description = description.to_string();
+    use_hook_with_cleanup(
+        || {
+            let combo = combo
+                .parse::<KeyCombo>()
+                .unwrap_or_else(|err| panic!("{err}"));
+            context
+                .registry
+                .register(combo, description, context.info, action)
+        },
+        move |id| context.registry.unregister(id),
+    );
+}
+
+/// Get the [`ShortcutRegistry`] of the nearest [`Shortcuts`] component.
+///
+/// # Panic
+/// - When the component is not nested within a [`Shortcuts`] component.
+pub fn use_shortcut_registry() -> ShortcutRegistry {
+    use_hook(|| consume_context::<ShortcutScope>().registry)
+}
+
+/// The properties for a [`ShortcutHelp`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ShortcutHelpProps {
+    /// The accessible label of the overlay.
+    #[props(default = "Keyboard shortcuts".to_string(), into)]
+    pub label: String,
+
+    /// The class attribute for the overlay.
+    pub class: Option<String>,
+}
+
+/// A list of every shortcut that is mounted, grouped by scope. Each scope is a `section` with a heading and a
+/// description list of key combinations. Shortcuts that are hidden by a shortcut with a higher priority are marked
+/// with `data-shadowed`.
+///
+/// # Panic
+/// - When the component is not nested within a [`Shortcuts`] component.
+pub fn ShortcutHelp(props: ShortcutHelpProps) -> Element {
+    let registry = use_shortcut_registry();
+
+    rsx! {
+        div { class: props.class, role: "region", aria_label: props.label,
+            for group in registry.help() {
+                section { key: "{group.scope}",
+                    h2 { "{group.scope}" }
+                    dl {
+                        for shortcut in group.shortcuts {
+                            div { "data-shadowed": shortcut.shadowed.then_some("true"),
+                                dt {
+                                    kbd { "{shortcut.combo}" }
+                                }
+                                dd { "{shortcut.description}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn key_combos_parse() {
+    let combo: KeyCombo = "ctrl+shift+k".parse().unwrap();
+    assert_eq!(combo.to_string(), "Ctrl+Shift+K");
+    assert_eq!(combo.id(), "ctrl+shift+keyk");
+
+    // Symbols already include shift, and they are matched by the key that types them
+    let combo: KeyCombo = "Shift+?".parse().unwrap();
+    assert_eq!(combo, "?".parse().unwrap());
+    assert_eq!(combo.to_string(), "?");
+    assert_eq!(combo.id(), "shift+slash");
+    assert_eq!("Shift+1".parse::<KeyCombo>().unwrap().id(), "shift+digit1");
+    assert_eq!("!".parse::<KeyCombo>().unwrap().id(), "shift+digit1");
+    assert_eq!("Alt+S".parse::<KeyCombo>().unwrap().id(), "alt+keys");
+    assert_eq!("Space".parse::<KeyCombo>().unwrap().id(), "space");
+
+    // Characters that are not on a US keyboard are matched by the character
+    assert_eq!("Ctrl+é".parse::<KeyCombo>().unwrap().id(), "ctrl+é");
+
+    let combo: KeyCombo = "Cmd+Plus".parse().unwrap();
+    assert_eq!(combo.to_string(), "Meta++");
+    assert_eq!(combo.id(), "shift+meta+equal");
+    assert_eq!("Ctrl++".parse::<KeyCombo>().unwrap().to_string(), "Ctrl++");
+    assert_eq!("esc".parse::<KeyCombo>().unwrap().to_string(), "Escape");
+    assert_eq!(
+        "Alt+ArrowUp".parse::<KeyCombo>().unwrap().id(),
+        "alt+arrowup"
+    );
+
+    assert!("Hyper+K".parse::<KeyCombo>().is_err());
+    assert!("Ctrl+".parse::<KeyCombo>().is_err());
+}
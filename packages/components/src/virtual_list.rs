@@ -0,0 +1,298 @@
+use dioxus_lib::prelude::*;
+use std::ops::Range;
+
+/// How tall the list is assumed to be before it is measured, like when it is rendered on the server
+const INITIAL_VIEWPORT_HEIGHT: f64 = 600.0;
+
+/// The properties for a [`VirtualList`].
+#[derive(Props, Clone, PartialEq)]
+pub struct VirtualListProps {
+    /// The number of rows in the list.
+    pub len: usize,
+
+    /// Render the row at an index. Only the rows that are visible are rendered.
+    pub row: Callback<usize, Element>,
+
+    /// The height in pixels of rows that were not rendered yet. Rows are measured once they are rendered, so this only
+    /// needs to be close to the real height to keep the scrollbar from jumping.
+    #[props(default = 32.0)]
+    pub estimated_row_height: f64,
+
+    /// How many rows to render above and below the visible rows, so fast scrolling doesn't show empty space.
+    #[props(default = 4)]
+    pub overscan: usize,
+
+    /// Called when the list is scrolled within `end_threshold` pixels of the end. It is called once for every length
+    /// of the list, so load more rows and increase `len` here for infinite scroll.
+    pub on_end_reached: Option<EventHandler>,
+
+    /// How close in pixels to the end of the list `on_end_reached` is called.
+    #[props(default = 200.0)]
+    pub end_threshold: f64,
+
+    /// The class attribute for the scroll container.
+    pub class: Option<String>,
+
+    /// The style attribute for the scroll container. The container needs a height for the list to scroll, either from
+    /// this style, the class or the layout of its parent.
+    #[props(default = "height: 100%;".to_string(), into)]
+    pub style: String,
+}
+
+/// A list that only renders the rows that are scrolled into view, so lists with hundreds of thousands of rows stay
+/// responsive.
+///
+/// The list is a scroll container with a spacer as tall as all rows together, and the visible rows are positioned in
+/// the spacer. Rows may have different heights: every rendered row is measured with `onresize` and the position of the
+/// rows after it is corrected.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn Table() -> Element {
+///     rsx! {
+///         VirtualList {
+///             style: "height: 400px;",
+///             len: 100_000,
+///             estimated_row_height: 20.0,
+///             row: move |index| rsx! { "Row {index}" },
+///         }
+///     }
+/// }
+/// #
+/// # let mut vdom = VirtualDom::new(Table);
+/// # vdom.rebuild_in_place();
+/// # let html = dioxus_ssr::render(&vdom);
+/// # assert!(html.contains("height: 2000000px;"));
+/// # assert!(html.contains("Row 34") && !html.contains("Row 35"));
+/// ```
+pub fn VirtualList(props: VirtualListProps) -> Element {
+    let VirtualListProps {
+        len,
+        row,
+        estimated_row_height,
+        overscan,
+        on_end_reached,
+        end_threshold,
+        class,
+        style,
+    } = props;
+
+    let container = use_node_ref();
+    let mut layout = use_hook(|| CopyValue::new(RowLayout::new(len, estimated_row_height)));
+    let mut measured = use_signal(|| 0usize);
+    let mut scroll_top = use_signal(|| 0.0);
+    let mut viewport_height = use_signal(|| INITIAL_VIEWPORT_HEIGHT);
+
+    // The layout only changes while rendering when the props change, so this doesn't need to rerun the component
+    layout.write().sync(len, estimated_row_height);
+    // Rerun when a row is measured with a new height
+    measured.read();
+
+    let layout_ref = layout.read();
+    let range = layout_ref.visible_range(scroll_top(), viewport_height(), overscan);
+    let total_height = layout_ref.total_height();
+    let offset = layout_ref.offset(range.start);
+    drop(layout_ref);
+
+    let end_reached = len > 0 && scroll_top() + viewport_height() >= total_height - end_threshold;
+    use_effect(use_reactive!(|end_reached, len| {
+        if end_reached && len > 0 {
+            if let Some(on_end_reached) = on_end_reached {
+                on_end_reached.call(());
+            }
+        }
+    }));
+
+    rsx! {
+        div {
+            class,
+            style: "overflow-y: auto; {style}",
+            onmounted: container,
+            onresize: move |event| {
+                if let Ok(size) = event.get_content_box_size() {
+                    viewport_height.set(size.height);
+                }
+            },
+            onscroll: move |_| async move {
+                let Some(container) = container.peek() else {
+                    return;
+                };
+                if let Ok(offset) = container.get_scroll_offset().await {
+                    scroll_top.set(offset.y);
+                }
+            },
+            div { style: "position: relative; height: {total_height}px;",
+                div { style: "position: absolute; top: 0; left: 0; right: 0; transform: translateY({offset}px);",
+                    for index in range {
+                        div {
+                            key: "{index}",
+                            onresize: move |event| {
+                                let Ok(size) = event.get_border_box_size() else {
+                                    return;
+                                };
+                                if layout.write().measure(index, size.height) {
+                                    measured += 1;
+                                }
+                            },
+                            {row.call(index)}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The heights of the rows of a list. Rows that were not measured yet use the estimated height.
+#[derive(Debug, Clone, PartialEq)]
+struct RowLayout {
+    estimated: f64,
+    heights: Vec<f64>,
+    measured: Vec<bool>,
+    // A Fenwick tree of the heights, so the offset of a row and the row at an offset are found in O(log n) for very
+    // long lists
+    tree: Vec<f64>,
+}
+
+impl RowLayout {
+    fn new(len: usize, estimated: f64) -> Self {
+        let mut layout = Self {
+            estimated,
+            heights: Vec::new(),
+            measured: Vec::new(),
+            tree: vec![0.0],
+        };
+        layout.sync(len, estimated);
+        layout
+    }
+
+    fn len(&self) -> usize {
+        self.heights.len()
+    }
+
+    /// Match the number of rows and the estimated height. Measured heights are kept
+    fn sync(&mut self, len: usize, estimated: f64) {
+        if len == self.len() && estimated == self.estimated {
+            return;
+        }
+        self.estimated = estimated;
+        self.heights.resize(len, estimated);
+        self.measured.resize(len, false);
+        for (height, measured) in self.heights.iter_mut().zip(&self.measured) {
+            if !measured {
+                *height = estimated;
+            }
+        }
+
+        // Build the tree in O(n) by adding every node to its parent
+        self.tree = std::iter::once(0.0)
+            .chain(self.heights.iter().copied())
+            .collect();
+        for index in 1..self.tree.len() {
+            let parent = index + lowest_bit(index);
+            if parent < self.tree.len() {
+                self.tree[parent] += self.tree[index];
+            }
+        }
+    }
+
+    /// Store the measured height of a row. Returns true if the height changed
+    fn measure(&mut self, index: usize, height: f64) -> bool {
+        let Some(current) = self.heights.get(index).copied() else {
+            return false;
+        };
+        self.measured[index] = true;
+        // Ignore sub pixel changes so rounding can't cause an endless loop of measurements
+        if (current - height).abs() < 0.5 {
+            return false;
+        }
+        self.heights[index] = height;
+        let mut node = index + 1;
+        while node < self.tree.len() {
+            self.tree[node] += height - current;
+            node += lowest_bit(node);
+        }
+        true
+    }
+
+    /// The distance from the top of the list to the top of a row
+    fn offset(&self, index: usize) -> f64 {
+        let mut node = index.min(self.len());
+        let mut offset = 0.0;
+        while node > 0 {
+            offset += self.tree[node];
+            node -= lowest_bit(node);
+        }
+        offset
+    }
+
+    fn total_height(&self) -> f64 {
+        self.offset(self.len())
+    }
+
+    /// The index of the row at a distance from the top of the list
+    fn row_at(&self, offset: f64) -> usize {
+        let len = self.len();
+        // Walk down the tree to find the number of rows that end above the offset
+        let mut rows_above = 0;
+        let mut remaining = offset;
+        let mut step = if len == 0 { 0 } else { 1 << len.ilog2() };
+        while step > 0 {
+            let next = rows_above + step;
+            if next <= len && self.tree[next] <= remaining {
+                rows_above = next;
+                remaining -= self.tree[next];
+            }
+            step >>= 1;
+        }
+        rows_above.min(len.saturating_sub(1))
+    }
+
+    /// The rows that are visible in the viewport, with extra rows above and below
+    fn visible_range(
+        &self,
+        scroll_top: f64,
+        viewport_height: f64,
+        overscan: usize,
+    ) -> Range<usize> {
+        if self.len() == 0 {
+            return 0..0;
+        }
+        let start = self.row_at(scroll_top).saturating_sub(overscan);
+        let end = (self.row_at(scroll_top + viewport_height) + 1 + overscan).min(self.len());
+        start..end
+    }
+}
+
+fn lowest_bit(index: usize) -> usize {
+    index & index.wrapping_neg()
+}
+
+#[test]
+fn row_layout_tracks_measured_rows() {
+    let mut layout = RowLayout::new(100, 10.0);
+    assert_eq!(layout.total_height(), 1000.0);
+    assert_eq!(layout.offset(5), 50.0);
+    assert_eq!(layout.row_at(55.0), 5);
+    assert_eq!(layout.visible_range(55.0, 30.0, 2), 3..11);
+
+    // A taller row moves every row after it
+    assert!(layout.measure(2, 40.0));
+    assert!(!layout.measure(2, 40.2));
+    assert_eq!(layout.offset(2), 20.0);
+    assert_eq!(layout.offset(3), 60.0);
+    assert_eq!(layout.row_at(59.0), 2);
+    assert_eq!(layout.row_at(60.0), 3);
+    assert_eq!(layout.total_height(), 1030.0);
+
+    // Measured rows keep their height when the list grows or the estimate changes
+    layout.sync(200, 20.0);
+    assert_eq!(layout.offset(3), 80.0);
+    assert_eq!(layout.total_height(), 199.0 * 20.0 + 40.0);
+    assert_eq!(layout.row_at(f64::MAX), 199);
+
+    layout.sync(0, 20.0);
+    assert_eq!(layout.visible_range(0.0, 100.0, 4), 0..0);
+}
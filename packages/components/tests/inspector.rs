@@ -0,0 +1,52 @@
+#![cfg(feature = "inspector")]
+use dioxus::components::DevtoolsOverlay;
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+
+fn app() -> Element {
+    rsx! {
+        DevtoolsOverlay {}
+        Counter {}
+    }
+}
+
+#[component]
+fn Counter() -> Element {
+    let count = use_context_provider(|| Signal::new(0));
+    rsx! { "{count}" }
+}
+
+fn counter_row(html: &str) -> &str {
+    let end = html.find("Counter ×").unwrap();
+    let start = html[..end].rfind("<li").unwrap();
+    &html[start..end]
+}
+
+#[test]
+fn overlay_highlights_components_that_rendered() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    // The overlay rerenders after it hears about the first render
+    dom.render_immediate(&mut NoOpMutations);
+
+    let html = dioxus_ssr::render(&dom);
+    assert!(html.contains("Counter ×1"));
+    assert!(counter_row(&html).contains(r#"data-rendered="mounted""#));
+    // The overlay leaves itself out of the tree
+    assert!(!html.contains("DevtoolsOverlay ×"));
+    assert!(!html.contains("ComponentRow ×"));
+
+    let counter = dom
+        .component_tree()
+        .into_iter()
+        .find(|component| component.name.ends_with("Counter"))
+        .unwrap();
+    let mut count = dom.in_runtime(|| counter.id.has_context::<Signal<i32>>().unwrap());
+    dom.in_runtime(|| count.set(1));
+    dom.render_immediate(&mut NoOpMutations);
+    dom.render_immediate(&mut NoOpMutations);
+
+    let html = dioxus_ssr::render(&dom);
+    assert!(html.contains("Counter ×2"));
+    assert!(counter_row(&html).contains(r#"data-rendered="state changed""#));
+}
@@ -0,0 +1,79 @@
+use dioxus::prelude::*;
+use dioxus_components::ShortcutRegistry;
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static REGISTRY: Cell<Option<ShortcutRegistry>> = const { Cell::new(None) };
+    static RAN: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+#[component]
+fn Bind(combo: &'static str, description: &'static str) -> Element {
+    use_shortcut(combo, description, move || {
+        RAN.with_borrow_mut(|ran| ran.push(description))
+    });
+    rsx! {}
+}
+
+#[component]
+fn Registry() -> Element {
+    REGISTRY.set(Some(use_shortcut_registry()));
+    rsx! {}
+}
+
+fn app() -> Element {
+    let show_editor = use_context_provider(|| Signal::new(true));
+
+    rsx! {
+        Shortcuts {
+            Registry {}
+            Bind { combo: "Ctrl+S", description: "Save everything" }
+            Bind { combo: "?", description: "Show help" }
+            if show_editor() {
+                Shortcuts { scope: "Editor", priority: 1,
+                    Bind { combo: "ctrl+s", description: "Save the document" }
+                }
+            }
+            ShortcutHelp {}
+        }
+    }
+}
+
+#[test]
+fn shortcuts_in_higher_priority_scopes_win() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let registry = REGISTRY.get().unwrap();
+
+    dom.in_runtime(|| {
+        assert!(registry.trigger(&"Ctrl+S".parse().unwrap()));
+        assert!(registry.trigger(&"Shift+?".parse().unwrap()));
+        assert!(!registry.trigger(&"Ctrl+K".parse().unwrap()));
+        assert!(registry.conflicts().is_empty());
+    });
+    assert_eq!(RAN.take(), vec!["Save the document", "Show help"]);
+
+    // Once the editor is unmounted, the global shortcut runs again
+    let mut show_editor = dom.in_runtime(|| ScopeId::APP.has_context::<Signal<bool>>().unwrap());
+    dom.in_runtime(|| show_editor.set(false));
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    dom.in_runtime(|| registry.trigger(&"ctrl+s".parse().unwrap()));
+    assert_eq!(RAN.take(), vec!["Save everything"]);
+}
+
+#[test]
+fn help_lists_shortcuts_by_scope() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        concat!(
+            r#"<div role="region" aria-label="Keyboard shortcuts">"#,
+            r#"<section><h2>Editor</h2><dl><div><dt><kbd>Ctrl+S</kbd></dt><dd>Save the document</dd></div></dl></section>"#,
+            r#"<section><h2>Global</h2><dl><div data-shadowed="true"><dt><kbd>Ctrl+S</kbd></dt><dd>Save everything</dd></div><div><dt><kbd>?</kbd></dt><dd>Show help</dd></div></dl></section>"#,
+            r#"</div>"#
+        )
+    );
+}
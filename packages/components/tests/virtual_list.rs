@@ -0,0 +1,58 @@
+use dioxus::prelude::*;
+use std::time::Duration;
+
+#[test]
+fn only_visible_rows_are_rendered() {
+    fn app() -> Element {
+        rsx! {
+            VirtualList {
+                len: 100_000,
+                overscan: 2,
+                row: move |index| rsx! { "[{index}]" },
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let html = dioxus_ssr::render(&dom);
+
+    // The spacer makes the scrollbar as long as the whole list
+    assert!(html.contains("height: 3200000px;"));
+    // 600 pixels of 32 pixel rows plus two rows of overscan
+    assert!(html.contains("[0]"));
+    assert!(html.contains("[20]"));
+    assert!(!html.contains("[21]"));
+}
+
+#[tokio::test]
+async fn end_reached_loads_more_rows() {
+    fn app() -> Element {
+        let mut len = use_signal(|| 5);
+        let mut loads = use_signal(|| 0);
+
+        rsx! {
+            "loads: {loads}"
+            VirtualList {
+                len: len(),
+                row: move |index| rsx! { "[{index}]" },
+                on_end_reached: move |_| {
+                    loads += 1;
+                    len += 100;
+                },
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    for _ in 0..3 {
+        _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    }
+
+    // The first five rows fit in the list, so more rows are loaded once. After that the end is out of view
+    let html = dioxus_ssr::render(&dom);
+    assert!(html.starts_with("loads: 1"));
+    assert!(html.contains("height: 3360px;"));
+}
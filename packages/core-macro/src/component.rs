@@ -228,8 +228,11 @@ impl ComponentBody {
         let struct_fields = inputs.iter().map(move |f| make_prop_struct_field(f, vis));
         let struct_ident = Ident::new(&format!("{ident}Props"), ident.span());
 
+        // Props with a custom comparison are memoized field by field, so they don't need to implement PartialEq
+        let partial_eq = (!inputs.iter().any(has_custom_compare)).then(|| quote! { PartialEq });
+
         parse_quote! {
-            #[derive(Props, Clone, PartialEq)]
+            #[derive(Props, Clone, #partial_eq)]
             #[allow(non_camel_case_types)]
             #vis struct #struct_ident #generics {
                 #(#struct_fields),*
@@ -493,6 +496,28 @@ fn make_prop_struct_field(f: &FnArg, vis: &Visibility) -> TokenStream {
     }
 }
 
+/// Check if a function argument has a `#[props(compare = ...)]` attribute
+fn has_custom_compare(f: &FnArg) -> bool {
+    let FnArg::Typed(pt) = f else {
+        return false;
+    };
+
+    pt.attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("props"))
+        .filter_map(|attr| {
+            attr.parse_args_with(punctuated::Punctuated::<Expr, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|expr| match expr {
+            Expr::Assign(assign) => {
+                matches!(&*assign.left, Expr::Path(path) if path.path.is_ident("compare"))
+            }
+            _ => false,
+        })
+}
+
 fn rebind_mutability(f: &FnArg) -> TokenStream {
     // There's no receivers (&self) allowed in the component body
     let FnArg::Typed(pt) = f else { unreachable!() };
@@ -282,6 +282,8 @@ mod field_info {
         pub strip_option: bool,
         pub ignore_option: bool,
         pub extends: Vec<Path>,
+        /// A custom function that checks if the old and new value of the field are equal when the props are memoized
+        pub compare: Option<syn::Expr>,
     }
 
     impl FieldBuilderAttr {
@@ -354,6 +356,10 @@ mod field_info {
                             self.default = Some(*assign.right);
                             Ok(())
                         }
+                        "compare" => {
+                            self.compare = Some(*assign.right);
+                            Ok(())
+                        }
                         "default_code" => {
                             if let syn::Expr::Lit(syn::ExprLit {
                                 lit: syn::Lit::Str(code),
@@ -612,7 +618,30 @@ mod struct_info {
             self.fields.iter().any(|f| child_owned_type(f.ty))
         }
 
+        /// Check if any field has a custom comparison. If one does, the props are compared field by field instead of
+        /// with the `PartialEq` implementation of the whole struct, so the struct doesn't need to implement `PartialEq`
+        pub fn has_custom_compare(&self) -> bool {
+            self.fields.iter().any(|f| f.builder_attr.compare.is_some())
+        }
+
+        /// The expression that checks if `self` and `new` are equal while memoizing
+        fn props_eq(&self) -> TokenStream {
+            if !self.has_custom_compare() {
+                return quote!(self == new);
+            }
+
+            let comparisons = self.fields.iter().map(|f| {
+                let name = f.name;
+                match &f.builder_attr.compare {
+                    Some(compare) => quote! { (#compare)(&self.#name, &new.#name) },
+                    None => quote! { self.#name == new.#name },
+                }
+            });
+            quote! { (true #(&& #comparisons)*) }
+        }
+
         fn memoize_impl(&self) -> Result<TokenStream, Error> {
+            let props_eq = self.props_eq();
             // First check if there are any ReadOnlySignal fields, if there are not, we can just use the partialEq impl
             let signal_fields: Vec<_> = self
                 .included_fields()
@@ -701,7 +730,7 @@ mod struct_info {
             if !signal_fields.is_empty() {
                 Ok(quote! {
                     // First check if the fields are equal. This will compare the signal fields by pointer
-                    let exactly_equal = self == new;
+                    let exactly_equal = #props_eq;
                     if exactly_equal {
                         // If they are return early, they can be memoized without any changes
                         return true;
@@ -715,7 +744,7 @@ mod struct_info {
 
                     // Then check if the fields are equal now that we know the signal fields are equal
                     // NOTE: we don't compare other fields individually because we want to let users opt-out of memoization for certain fields by implementing PartialEq themselves
-                    let non_signal_fields_equal = self == new;
+                    let non_signal_fields_equal = #props_eq;
 
                     // If they are not equal, we need to move over all the fields that are not event handlers or signals to self
                     if !non_signal_fields_equal {
@@ -733,7 +762,7 @@ mod struct_info {
                 })
             } else {
                 Ok(quote! {
-                    let equal = self == new;
+                    let equal = #props_eq;
                     // Move any signal and event fields into their old container.
                     #move_event_handlers
                     // If they are not equal, we need to move over all the fields that are not event handlers to self
@@ -1443,6 +1472,16 @@ Finally, call `.build()` to create the instance of `{name}`.
                 let vis = &self.vis;
                 let generics_with_bounds = &self.generics;
                 let where_clause = &self.generics.where_clause;
+                // Props with custom comparisons don't need to implement PartialEq, so the wrapper can't either
+                let partial_eq = (!self.has_custom_compare()).then(|| {
+                    quote! {
+                        impl #original_impl_generics PartialEq for #name #ty_generics #where_clause {
+                            fn eq(&self, other: &Self) -> bool {
+                                self.inner.eq(&other.inner)
+                            }
+                        }
+                    }
+                });
 
                 quote! {
                     #[doc(hidden)]
@@ -1453,11 +1492,7 @@ Finally, call `.build()` to create the instance of `{name}`.
                         owner: dioxus_core::internal::generational_box::Owner,
                     }
 
-                    impl #original_impl_generics PartialEq for #name #ty_generics #where_clause {
-                        fn eq(&self, other: &Self) -> bool {
-                            self.inner.eq(&other.inner)
-                        }
-                    }
+                    #partial_eq
 
                     impl #original_impl_generics #name #ty_generics #where_clause {
                         /// Create a component from the props.
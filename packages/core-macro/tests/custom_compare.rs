@@ -0,0 +1,45 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::cell::Cell;
+use std::rc::Rc;
+
+thread_local! {
+    static CHILD_RENDERS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[test]
+fn custom_compare_controls_memoization() {
+    fn app() -> Element {
+        let generation = generation();
+        let format =
+            use_hook(|| Rc::new(|value: usize| value.to_string()) as Rc<dyn Fn(usize) -> String>);
+
+        rsx! {
+            Child {
+                value: generation / 2,
+                format,
+                ignored: generation,
+            }
+        }
+    }
+
+    #[component]
+    fn Child(
+        value: usize,
+        #[props(compare = Rc::ptr_eq)] format: Rc<dyn Fn(usize) -> String>,
+        #[props(compare = |_, _| true)] ignored: usize,
+    ) -> Element {
+        CHILD_RENDERS.with(|renders| renders.set(renders.get() + 1));
+        rsx! { "{format(value)} {ignored}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    for _ in 0..3 {
+        dom.mark_dirty(ScopeId::APP);
+        dom.render_immediate(&mut NoOpMutations);
+    }
+
+    // The child only reruns when `value` changes. Changes to `ignored` are skipped
+    CHILD_RENDERS.with(|renders| assert_eq!(renders.get(), 2));
+}
@@ -92,7 +92,11 @@ pub fn event_bubbles(evt: &str) -> bool {
         "animationstart" => true,
         "animationend" => true,
         "animationiteration" => true,
+        "animationcancel" => true,
+        "transitionrun" => true,
+        "transitionstart" => true,
         "transitionend" => true,
+        "transitioncancel" => true,
         "toggle" => true,
         "mounted" => false,
         "visible" => false,
@@ -15,6 +15,9 @@ pub(crate) trait AnyProps: 'static {
     fn props_mut(&mut self) -> &mut dyn Any;
     /// Duplicate this component into a new boxed component.
     fn duplicate(&self) -> BoxedAnyProps;
+    /// Get the type name of the props for devtools.
+    #[cfg(feature = "inspector")]
+    fn props_type_name(&self) -> &'static str;
 }
 
 /// A component along with the props the component uses to render.
@@ -74,6 +77,11 @@ impl<F: ComponentFunction<P, M> + Clone, P: Clone + 'static, M: 'static> AnyProp
         &mut self.props
     }
 
+    #[cfg(feature = "inspector")]
+    fn props_type_name(&self) -> &'static str {
+        std::any::type_name::<P>()
+    }
+
     fn render(&self) -> Element {
         fn render_inner(name: &str, res: Result<Element, Box<dyn Any + Send>>) -> Element {
             match res {
@@ -0,0 +1,242 @@
+//! Checkpoints of the app state that can be restored on the next launch.
+//!
+//! Parts of the app register themselves under a unique key with a function that saves their state to a string and a
+//! function that restores it. A [`Checkpoint`] collects the saved state of every registered part into one blob.
+//! Restoring a checkpoint restores the parts that are already registered and keeps the rest until a part with the same
+//! key registers itself, so a checkpoint can be restored before the app is rendered.
+
+use std::{collections::BTreeMap, fmt::Display, rc::Rc, str::FromStr};
+
+use crate::{innerlude::VirtualDom, Runtime};
+
+/// The saved state of every part of the app that was registered with [`register_checkpoint`].
+///
+/// Convert the checkpoint to a string to store it and parse it again to restore it:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     use_hook(|| {
+///         register_checkpoint("greeting", || Some("hello".to_string()), |_| {});
+///     });
+///     rsx! {}
+/// }
+///
+/// let mut dom = VirtualDom::new(app);
+/// dom.rebuild_in_place();
+///
+/// let blob = dom.checkpoint().to_string();
+/// let checkpoint = blob.parse::<Checkpoint>().unwrap();
+/// assert_eq!(checkpoint.get("greeting"), Some("hello"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Checkpoint {
+    entries: BTreeMap<String, String>,
+}
+
+impl Checkpoint {
+    /// Create an empty checkpoint
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the saved state for a key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    /// Set the saved state for a key
+    pub fn insert(&mut self, key: impl ToString, value: impl ToString) {
+        self.entries.insert(key.to_string(), value.to_string());
+    }
+
+    /// Remove the saved state for a key
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.entries.remove(key)
+    }
+
+    /// Iterate over the keys and saved state in the checkpoint
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Check if the checkpoint is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+// Each key and value is written as its length in bytes, a colon and the string itself so any string can be stored
+impl Display for Checkpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (key, value) in &self.entries {
+            write!(f, "{}:{key}{}:{value}", key.len(), value.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// An error that occurs when a [`Checkpoint`] can't be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckpointParseError(String);
+
+impl Display for CheckpointParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse the checkpoint: {}", self.0)
+    }
+}
+
+impl std::error::Error for CheckpointParseError {}
+
+impl FromStr for Checkpoint {
+    type Err = CheckpointParseError;
+
+    fn from_str(mut s: &str) -> Result<Self, Self::Err> {
+        fn read_string<'a>(s: &mut &'a str) -> Result<&'a str, CheckpointParseError> {
+            let (len, rest) = s
+                .split_once(':')
+                .ok_or_else(|| CheckpointParseError("expected a length".to_string()))?;
+            let len = len
+                .parse::<usize>()
+                .map_err(|_| CheckpointParseError(format!("invalid length {len:?}")))?;
+            let string = rest.get(..len).ok_or_else(|| {
+                CheckpointParseError(format!("expected {len} bytes, found {}", rest.len()))
+            })?;
+            *s = &rest[len..];
+            Ok(string)
+        }
+
+        let mut checkpoint = Self::new();
+        while !s.is_empty() {
+            let key = read_string(&mut s)?;
+            let value = read_string(&mut s)?;
+            checkpoint.insert(key, value);
+        }
+        Ok(checkpoint)
+    }
+}
+
+type SaveFn = Rc<dyn Fn() -> Option<String>>;
+type RestoreFn = Rc<dyn Fn(&str)>;
+
+/// Every part of the app that is registered to be saved in a checkpoint, and the state that was restored for parts
+/// that are not registered yet
+#[derive(Default)]
+pub(crate) struct Checkpoints {
+    entries: BTreeMap<String, (SaveFn, RestoreFn)>,
+    pending: Checkpoint,
+}
+
+impl Runtime {
+    pub(crate) fn register_checkpoint(&self, key: String, save: SaveFn, restore: RestoreFn) {
+        let pending = {
+            let mut checkpoints = self.checkpoints.borrow_mut();
+            checkpoints
+                .entries
+                .insert(key.clone(), (save, restore.clone()));
+            checkpoints.pending.remove(&key)
+        };
+        if let Some(value) = pending {
+            restore(&value);
+        }
+    }
+
+    pub(crate) fn unregister_checkpoint(&self, key: &str) {
+        self.checkpoints.borrow_mut().entries.remove(key);
+    }
+
+    pub(crate) fn take_checkpoint(&self) -> Checkpoint {
+        let (mut checkpoint, saves) = {
+            let checkpoints = self.checkpoints.borrow();
+            let saves = checkpoints
+                .entries
+                .iter()
+                .map(|(key, (save, _))| (key.clone(), save.clone()))
+                .collect::<Vec<_>>();
+            (checkpoints.pending.clone(), saves)
+        };
+        for (key, save) in saves {
+            if let Some(value) = save() {
+                checkpoint.insert(key, value);
+            } else {
+                checkpoint.remove(&key);
+            }
+        }
+        checkpoint
+    }
+
+    pub(crate) fn restore_checkpoint(&self, checkpoint: Checkpoint) {
+        for (key, value) in checkpoint.entries {
+            let restore = {
+                let mut checkpoints = self.checkpoints.borrow_mut();
+                match checkpoints.entries.get(&key) {
+                    Some((_, restore)) => Some(restore.clone()),
+                    None => {
+                        checkpoints.pending.insert(key, &value);
+                        None
+                    }
+                }
+            };
+            if let Some(restore) = restore {
+                restore(&value);
+            }
+        }
+    }
+}
+
+impl VirtualDom {
+    /// Save the state of every part of the app that is registered with [`register_checkpoint`]
+    pub fn checkpoint(&self) -> Checkpoint {
+        self.in_runtime(|| self.runtime.take_checkpoint())
+    }
+
+    /// Restore a checkpoint. Parts of the app that are already registered are restored immediately and the rest are
+    /// restored when they register themselves.
+    pub fn restore_checkpoint(&self, checkpoint: Checkpoint) {
+        self.in_runtime(|| self.runtime.restore_checkpoint(checkpoint))
+    }
+
+    /// Build the virtual dom with a checkpoint that is restored as the app renders
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # fn app() -> Element { rsx! {} }
+    /// let saved = std::fs::read_to_string("session").unwrap_or_default();
+    /// let dom = VirtualDom::new(app).with_checkpoint(saved.parse().unwrap_or_default());
+    /// ```
+    pub fn with_checkpoint(self, checkpoint: Checkpoint) -> Self {
+        self.restore_checkpoint(checkpoint);
+        self
+    }
+}
+
+/// Register part of the app to be saved in a [`Checkpoint`] under a unique key. If a checkpoint with state for the key
+/// was restored, `restore` is called with it immediately.
+///
+/// Returning `None` from `save` leaves the key out of the checkpoint.
+pub fn register_checkpoint(
+    key: impl ToString,
+    save: impl Fn() -> Option<String> + 'static,
+    restore: impl Fn(&str) + 'static,
+) {
+    Runtime::with(|rt| rt.register_checkpoint(key.to_string(), Rc::new(save), Rc::new(restore)))
+        .expect("Runtime to exist");
+}
+
+/// Stop saving the part of the app registered under a key in checkpoints
+pub fn unregister_checkpoint(key: &str) {
+    // The runtime may already be dropped if this is called while the app is shutting down
+    _ = Runtime::with(|rt| rt.unregister_checkpoint(key));
+}
+
+/// Save the state of every part of the app that is registered with [`register_checkpoint`]
+pub fn take_checkpoint() -> Checkpoint {
+    Runtime::with(|rt| rt.take_checkpoint()).expect("Runtime to exist")
+}
+
+/// Restore a checkpoint from inside the app. See [`VirtualDom::restore_checkpoint`].
+pub fn restore_checkpoint(checkpoint: Checkpoint) {
+    Runtime::with(|rt| rt.restore_checkpoint(checkpoint)).expect("Runtime to exist");
+}
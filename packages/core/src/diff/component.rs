@@ -153,6 +153,11 @@ impl VNode {
         }
 
         // Now diff the scope
+        #[cfg(feature = "inspector")]
+        dom.runtime
+            .profiler
+            .borrow_mut()
+            .mark(scope_id, crate::innerlude::RenderReason::PropsChanged);
         dom.run_and_diff_scope(to, scope_id);
 
         let height = dom.runtime.get_state(scope_id).unwrap().height;
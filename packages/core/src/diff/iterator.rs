@@ -1,10 +1,11 @@
 use crate::{
     innerlude::{ElementRef, WriteMutations},
     nodes::VNode,
+    recycle::KeyedScratch,
     DynamicNode, ScopeId, VirtualDom,
 };
 
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 
 impl VirtualDom {
     pub(crate) fn diff_non_empty_fragment(
@@ -235,13 +236,27 @@ impl VirtualDom {
     // This function will load the appropriate nodes onto the stack and do diffing in place.
     //
     // Upon exit from this function, it will be restored to that same self.
-    #[allow(clippy::too_many_lines)]
     fn diff_keyed_middle(
+        &mut self,
+        to: Option<&mut impl WriteMutations>,
+        old: &[VNode],
+        new: &[VNode],
+        parent: Option<ElementRef>,
+    ) {
+        // Large lists diff through here on every render, so the scratch space is reused instead of reallocated
+        let mut scratch = self.runtime.recycler.borrow_mut().take_scratch();
+        self.diff_keyed_middle_with(to, old, new, parent, &mut scratch);
+        self.runtime.recycler.borrow_mut().recycle_scratch(scratch);
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn diff_keyed_middle_with(
         &mut self,
         mut to: Option<&mut impl WriteMutations>,
         old: &[VNode],
         new: &[VNode],
         parent: Option<ElementRef>,
+        scratch: &mut KeyedScratch,
     ) {
         /*
         1. Map the old keys into a numerical ordering based on indices.
@@ -278,25 +293,28 @@ impl VirtualDom {
             .map(|(i, o)| (o.key.as_ref().unwrap().as_str(), i))
             .collect::<FxHashMap<_, _>>();
 
-        let mut shared_keys = FxHashSet::default();
+        let KeyedScratch {
+            new_index_to_old_index,
+            old_is_shared,
+            lis_sequence,
+            lis_allocation,
+        } = scratch;
+        old_is_shared.resize(old.len(), false);
 
         // 3. Map each new key to the old key, carrying over the old index.
-        let new_index_to_old_index = new
-            .iter()
-            .map(|node| {
-                let key = node.key.as_ref().unwrap();
-                if let Some(&index) = old_key_to_old_index.get(key.as_str()) {
-                    shared_keys.insert(key);
-                    index
-                } else {
-                    usize::MAX
-                }
-            })
-            .collect::<Box<[_]>>();
+        new_index_to_old_index.extend(new.iter().map(|node| {
+            let key = node.key.as_ref().unwrap();
+            if let Some(&index) = old_key_to_old_index.get(key.as_str()) {
+                old_is_shared[index] = true;
+                index
+            } else {
+                usize::MAX
+            }
+        }));
 
         // If none of the old keys are reused by the new children, then we remove all the remaining old children and
         // create the new children afresh.
-        if shared_keys.is_empty() {
+        if !old_is_shared.contains(&true) {
             debug_assert!(
                 !old.is_empty(),
                 "we should never be appending - just creating N"
@@ -309,22 +327,23 @@ impl VirtualDom {
         }
 
         // remove any old children that are not shared
-        for child_to_remove in old
+        for (child_to_remove, _) in old
             .iter()
-            .filter(|child| !shared_keys.contains(child.key.as_ref().unwrap()))
+            .zip(old_is_shared.iter())
+            .filter(|(_, shared)| !**shared)
         {
             child_to_remove.remove_node(self, to.as_deref_mut(), None);
         }
 
         // 4. Compute the LIS of this list
-        let mut lis_sequence = Vec::with_capacity(new_index_to_old_index.len());
+        lis_sequence.reserve(new_index_to_old_index.len());
 
-        let mut allocation = vec![0; new_index_to_old_index.len() * 2];
-        let (predecessors, starts) = allocation.split_at_mut(new_index_to_old_index.len());
+        lis_allocation.resize(new_index_to_old_index.len() * 2, 0);
+        let (predecessors, starts) = lis_allocation.split_at_mut(new_index_to_old_index.len());
 
         longest_increasing_subsequence::lis_with(
-            &new_index_to_old_index,
-            &mut lis_sequence,
+            new_index_to_old_index.as_slice(),
+            lis_sequence,
             |a, b| a < b,
             predecessors,
             starts,
@@ -336,7 +355,7 @@ impl VirtualDom {
         }
 
         // Diff each nod in the LIS
-        for idx in &lis_sequence {
+        for idx in lis_sequence.iter() {
             old[new_index_to_old_index[*idx]].diff_node(&new[*idx], self, to.as_deref_mut());
         }
 
@@ -383,7 +402,7 @@ impl VirtualDom {
                 old,
                 to.as_deref_mut(),
                 parent,
-                &new_index_to_old_index,
+                new_index_to_old_index,
                 (last + 1)..new.len(),
             );
 
@@ -403,7 +422,7 @@ impl VirtualDom {
                     old,
                     to.as_deref_mut(),
                     parent,
-                    &new_index_to_old_index,
+                    new_index_to_old_index,
                     (next + 1)..last,
                 );
 
@@ -421,7 +440,7 @@ impl VirtualDom {
                 old,
                 to.as_deref_mut(),
                 parent,
-                &new_index_to_old_index,
+                new_index_to_old_index,
                 0..first_lis,
             );
 
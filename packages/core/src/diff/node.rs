@@ -1,4 +1,5 @@
 use crate::innerlude::MountId;
+use crate::recycle::MountBuffers;
 use crate::{Attribute, AttributeValue, DynamicNode::*};
 use crate::{VNode, VirtualDom, WriteMutations};
 use core::iter::Peekable;
@@ -293,8 +294,16 @@ impl VNode {
 
         if destroy_component_state {
             let mount = self.mount.take();
-            // Remove the mount information
-            dom.runtime.mounts.borrow_mut().remove(mount.0);
+            // Remove the mount information and keep its buffers for the next block with the same shape
+            let removed = dom.runtime.mounts.borrow_mut().remove(mount.0);
+            dom.runtime
+                .recycler
+                .borrow_mut()
+                .recycle_mount(MountBuffers {
+                    root_ids: removed.root_ids,
+                    mounted_attributes: removed.mounted_attributes,
+                    mounted_dynamic_nodes: removed.mounted_dynamic_nodes,
+                });
         }
     }
 
@@ -547,6 +556,11 @@ impl VNode {
 
         // Initialize the mount information for this vnode if it isn't already mounted
         if !self.mount.get().mounted() {
+            let buffers = dom.runtime.recycler.borrow_mut().take_mount(
+                template.roots.len(),
+                template.attr_paths.len(),
+                template.node_paths.len(),
+            );
             let mut mounts = dom.runtime.mounts.borrow_mut();
             let entry = mounts.vacant_entry();
             let mount = MountId(entry.key());
@@ -555,11 +569,9 @@ impl VNode {
             entry.insert(VNodeMount {
                 node: self.clone(),
                 parent,
-                root_ids: vec![ElementId(0); template.roots.len()].into_boxed_slice(),
-                mounted_attributes: vec![ElementId(0); template.attr_paths.len()]
-                    .into_boxed_slice(),
-                mounted_dynamic_nodes: vec![usize::MAX; template.node_paths.len()]
-                    .into_boxed_slice(),
+                root_ids: buffers.root_ids,
+                mounted_attributes: buffers.mounted_attributes,
+                mounted_dynamic_nodes: buffers.mounted_dynamic_nodes,
             });
         }
 
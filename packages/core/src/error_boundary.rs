@@ -3,7 +3,7 @@ use crate::{
     Properties, ScopeId, Template, TemplateAttribute, TemplateNode, VNode,
 };
 use std::{
-    any::{Any, TypeId},
+    any::Any,
     backtrace::Backtrace,
     cell::{Ref, RefCell},
     error::Error,
@@ -493,11 +493,7 @@ impl Display for CapturedError {
 impl CapturedError {
     /// Downcast the error type into a concrete error type
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
-        if TypeId::of::<T>() == (*self.error).type_id() {
-            self.error.as_any().downcast_ref::<T>()
-        } else {
-            None
-        }
+        self.error.as_any().downcast_ref::<T>()
     }
 }
 
@@ -522,7 +518,7 @@ impl<F: Fn(ErrorContext) -> Element + 'static> From<F> for ErrorHandler {
     }
 }
 
-fn default_handler(errors: ErrorContext) -> Element {
+pub(crate) fn default_handler(errors: ErrorContext) -> Element {
     static TEMPLATE: Template = Template {
         roots: &[TemplateNode::Element {
             tag: "div",
@@ -566,6 +562,23 @@ fn default_handler(errors: ErrorContext) -> Element {
     ))
 }
 
+/// Render errors that are not caught by any other error boundary in the app. Provide this as a root context, like with
+/// `LaunchBuilder::with_error_handler`, to replace the default error message the root error boundary renders.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus::dioxus_core::CapturedError;
+/// fn app() -> Element {
+///     Err(CapturedError::from_display("Something went wrong"))?
+/// }
+///
+/// let dom = VirtualDom::new(app).with_root_context(RootErrorHandler(|errors| {
+///     rsx! { "The app crashed: {errors:?}" }
+/// }));
+/// ```
+#[derive(Clone, Copy)]
+pub struct RootErrorHandler(pub fn(ErrorContext) -> Element);
+
 #[derive(Clone)]
 pub struct ErrorBoundaryProps {
     children: Element,
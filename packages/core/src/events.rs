@@ -29,6 +29,7 @@ pub struct Event<T: 'static + ?Sized> {
 pub(crate) struct EventMetadata {
     pub(crate) propagates: bool,
     pub(crate) prevent_default: bool,
+    pub(crate) at_target: bool,
 }
 
 impl<T: ?Sized + 'static> Event<T> {
@@ -39,6 +40,7 @@ impl<T: ?Sized + 'static> Event<T> {
             metadata: Rc::new(RefCell::new(EventMetadata {
                 propagates,
                 prevent_default: false,
+                at_target: true,
             })),
         }
     }
@@ -92,6 +94,29 @@ impl<T: ?Sized> Event<T> {
         self.metadata.borrow().propagates
     }
 
+    /// Check if the listener handling the event is attached to the element the event was dispatched to. This is false
+    /// while the event bubbles through the listeners on parent elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// rsx! {
+    ///     div {
+    ///         // Only react to clicks on the div itself, not the button inside of it
+    ///         onclick: move |evt: Event<MouseData>| {
+    ///             if evt.is_at_target() {
+    ///                 println!("clicked the div");
+    ///             }
+    ///         },
+    ///         button { onclick: move |_| {}, "Click me" }
+    ///     }
+    /// };
+    /// ```
+    pub fn is_at_target(&self) -> bool {
+        self.metadata.borrow().at_target
+    }
+
     /// Prevent this event from continuing to bubble up the tree to parent elements.
     ///
     /// # Example
@@ -1,5 +1,7 @@
 use crate::runtime::RuntimeError;
-use crate::{innerlude::SuspendedFuture, runtime::Runtime, CapturedError, Element, ScopeId, Task};
+use crate::{
+    innerlude::SuspendedFuture, runtime::Runtime, CapturedError, Element, Priority, ScopeId, Task,
+};
 use std::future::Future;
 use std::sync::Arc;
 
@@ -146,6 +148,72 @@ pub fn spawn(fut: impl Future<Output = ()> + 'static) -> Task {
     Runtime::with_current_scope(|cx| cx.spawn(fut)).unwrap()
 }
 
+/// Spawn a future that only lives as long as the current component and call `on_complete` with its output.
+///
+/// When the component unmounts, the future is dropped wherever it is waiting and `on_complete` is never called. This
+/// makes it safe to write to the component's signals when the future finishes, even if the user navigated away.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// # async fn fetch_profile() -> String { String::new() }
+/// fn Profile() -> Element {
+///     let mut profile = use_signal(|| None);
+///
+///     use_hook(|| {
+///         spawn_local_scoped(fetch_profile(), move |fetched| profile.set(Some(fetched)));
+///     });
+///
+///     rsx! { "{profile:?}" }
+/// }
+/// ```
+///
+#[doc = include_str!("../docs/common_spawn_errors.md")]
+pub fn spawn_local_scoped<T: 'static>(
+    fut: impl Future<Output = T> + 'static,
+    on_complete: impl FnOnce(T) + 'static,
+) -> Task {
+    Runtime::with_current_scope(|cx| cx.spawn_local_scoped(fut, on_complete)).unwrap()
+}
+
+/// Spawn a future in a [`Priority`] lane. Tasks in lower priority lanes are only polled once every dirty component,
+/// user input task and effect has been handled, so they don't delay the response to user input. This task will
+/// automatically be canceled when the component is dropped.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// fn App() -> Element {
+///     let mut frame = use_signal(|| 0);
+///
+///     use_hook(|| {
+///         spawn_with_priority(Priority::Animation, async move {
+///             loop {
+///                 tokio::time::sleep(std::time::Duration::from_millis(16)).await;
+///                 frame += 1;
+///             }
+///         });
+///     });
+///
+///     rsx! { "Frame {frame}" }
+/// }
+/// ```
+///
+#[doc = include_str!("../docs/common_spawn_errors.md")]
+pub fn spawn_with_priority(priority: Priority, fut: impl Future<Output = ()> + 'static) -> Task {
+    Runtime::with_current_scope(|cx| cx.spawn_with_priority(priority, fut)).unwrap()
+}
+
+/// Spawn a future in the [`Priority::Idle`] lane. It is only polled once there is nothing else to do. This task will
+/// automatically be canceled when the component is dropped.
+///
+#[doc = include_str!("../docs/common_spawn_errors.md")]
+pub fn spawn_idle(fut: impl Future<Output = ()> + 'static) -> Task {
+    spawn_with_priority(Priority::Idle, fut)
+}
+
 /// Queue an effect to run after the next render. You generally shouldn't need to interact with this function directly. [use_effect](https://docs.rs/dioxus-hooks/latest/dioxus_hooks/fn.use_effect.html) will call this function for you.
 pub fn queue_effect(f: impl FnOnce() + 'static) {
     Runtime::with_current_scope(|cx| cx.queue_effect(f)).unwrap()
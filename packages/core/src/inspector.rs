@@ -0,0 +1,284 @@
+//! Instrumentation for devtools that inspect the component tree and profile renders.
+//!
+//! The component tree can be inspected at any time with [`Runtime::component_tree`]. Render timings and the reason each
+//! component rerendered are only recorded while profiling is enabled with [`Runtime::set_profiling`], so apps that
+//! don't use devtools don't pay for the bookkeeping.
+//!
+//! Everything in this module is only compiled with the `inspector` feature.
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use rustc_hash::FxHashMap;
+use slab::Slab;
+
+use crate::{innerlude::VirtualDom, Runtime, ScopeId};
+
+/// Why a component rendered
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RenderReason {
+    /// The component rendered for the first time
+    Mounted,
+    /// The parent rerendered and passed props that are not equal to the last props
+    PropsChanged,
+    /// The component was marked dirty. This happens when a signal the component read was written to or the component
+    /// called [`needs_update`](crate::prelude::needs_update)
+    Updated,
+}
+
+impl std::fmt::Display for RenderReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderReason::Mounted => write!(f, "mounted"),
+            RenderReason::PropsChanged => write!(f, "props changed"),
+            RenderReason::Updated => write!(f, "state changed"),
+        }
+    }
+}
+
+/// A single render of a component that was recorded while profiling
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderEvent {
+    /// The scope that rendered
+    pub scope: ScopeId,
+    /// The name of the component that rendered
+    pub name: &'static str,
+    /// Why the component rendered
+    pub reason: RenderReason,
+    /// How long the component function took to run. This does not include the time it took to render the children
+    pub duration: Duration,
+}
+
+/// The renders of a component that were recorded while profiling
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderProfile {
+    /// The number of renders that were recorded
+    pub renders: usize,
+    /// The time the last render took
+    pub last_duration: Duration,
+    /// The time all recorded renders took together
+    pub total_duration: Duration,
+    /// Why the component rendered last
+    pub last_reason: RenderReason,
+}
+
+/// A snapshot of one component in the tree
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentSnapshot {
+    /// The scope of the component
+    pub id: ScopeId,
+    /// The name of the component. For components declared with a function, this is the path of the function
+    pub name: &'static str,
+    /// The scope of the component that rendered this component
+    pub parent: Option<ScopeId>,
+    /// The scopes of the components this component rendered, ordered by scope id
+    pub children: Vec<ScopeId>,
+    /// The depth of the component in the tree
+    pub height: u32,
+    /// The type name of the props of the component
+    pub props: &'static str,
+    /// The type name of the state of every hook of the component, in the order the hooks are called
+    pub hooks: Vec<&'static str>,
+    /// The number of times the component rendered successfully, including renders while profiling was disabled
+    pub render_count: usize,
+    /// The renders that were recorded while profiling, if the component rendered since profiling was enabled
+    pub profile: Option<RenderProfile>,
+}
+
+/// A listener registered with [`Runtime::add_render_listener`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RenderListenerId(usize);
+
+type RenderListener = Rc<RefCell<dyn FnMut(&[RenderEvent])>>;
+
+/// The renders recorded while profiling
+#[derive(Default)]
+pub(crate) struct Profiler {
+    enabled: bool,
+    reasons: FxHashMap<ScopeId, RenderReason>,
+    profiles: FxHashMap<ScopeId, RenderProfile>,
+    events: Vec<RenderEvent>,
+    listeners: Slab<RenderListener>,
+}
+
+impl Profiler {
+    pub(crate) fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Remember why a scope will rerender. The first reason wins if the scope is marked more than once.
+    pub(crate) fn mark(&mut self, scope: ScopeId, reason: RenderReason) {
+        if self.enabled {
+            self.reasons.entry(scope).or_insert(reason);
+        }
+    }
+
+    pub(crate) fn record(&mut self, scope: ScopeId, name: &'static str, duration: Duration) {
+        let reason = self.reasons.remove(&scope);
+        let profile = self.profiles.get_mut(&scope);
+        let reason = match (reason, &profile) {
+            (Some(reason), _) => reason,
+            (None, None) => RenderReason::Mounted,
+            (None, Some(_)) => RenderReason::Updated,
+        };
+        match profile {
+            Some(profile) => {
+                profile.renders += 1;
+                profile.last_duration = duration;
+                profile.total_duration += duration;
+                profile.last_reason = reason;
+            }
+            None => {
+                self.profiles.insert(
+                    scope,
+                    RenderProfile {
+                        renders: 1,
+                        last_duration: duration,
+                        total_duration: duration,
+                        last_reason: reason,
+                    },
+                );
+            }
+        }
+        if !self.listeners.is_empty() {
+            self.events.push(RenderEvent {
+                scope,
+                name,
+                reason,
+                duration,
+            });
+        }
+    }
+
+    /// Forget a scope that was removed. Scope ids are reused, so the next component with this id starts fresh.
+    pub(crate) fn remove_scope(&mut self, scope: ScopeId) {
+        self.reasons.remove(&scope);
+        self.profiles.remove(&scope);
+    }
+}
+
+impl Runtime {
+    /// Start or stop recording render timings and the reason every component rendered. Stopping discards everything
+    /// that was recorded.
+    pub fn set_profiling(&self, enabled: bool) {
+        let mut profiler = self.profiler.borrow_mut();
+        profiler.enabled = enabled;
+        if !enabled {
+            profiler.reasons.clear();
+            profiler.profiles.clear();
+            profiler.events.clear();
+        }
+    }
+
+    /// Check if renders are being profiled
+    pub fn is_profiling(&self) -> bool {
+        self.profiler.borrow().enabled
+    }
+
+    /// Take a snapshot of every component that is currently mounted, in depth first order starting from the root
+    pub fn component_tree(&self) -> Vec<ComponentSnapshot> {
+        let scopes = self.scope_states.borrow();
+        let profiler = self.profiler.borrow();
+
+        let mut children: FxHashMap<ScopeId, Vec<ScopeId>> = FxHashMap::default();
+        let mut roots = Vec::new();
+        for scope in scopes.iter().flatten() {
+            match scope.parent_id {
+                Some(parent) => children.entry(parent).or_default().push(scope.id),
+                None => roots.push(scope.id),
+            }
+        }
+
+        let mut snapshots = Vec::new();
+        let mut stack: Vec<ScopeId> = roots.into_iter().rev().collect();
+        while let Some(id) = stack.pop() {
+            let Some(scope) = scopes.get(id.0).and_then(Option::as_ref) else {
+                continue;
+            };
+            let scope_children = children.remove(&id).unwrap_or_default();
+            stack.extend(scope_children.iter().rev());
+            snapshots.push(ComponentSnapshot {
+                id,
+                name: scope.name,
+                parent: scope.parent_id,
+                children: scope_children,
+                height: scope.height,
+                props: scope.props_type,
+                hooks: scope.hook_types.borrow().clone(),
+                render_count: scope.render_count.get(),
+                profile: profiler.profiles.get(&id).copied(),
+            });
+        }
+        snapshots
+    }
+
+    /// Call a listener with every render that was recorded while profiling. The listener is called once after each
+    /// render of the virtual dom with all of the components that rendered.
+    ///
+    /// The listener may write to signals to display the renders. Components that render because of the listener cause
+    /// another call to the listener, so a listener that displays renders should ignore its own components.
+    pub fn add_render_listener(
+        &self,
+        listener: impl FnMut(&[RenderEvent]) + 'static,
+    ) -> RenderListenerId {
+        let listener: RenderListener = Rc::new(RefCell::new(listener));
+        RenderListenerId(self.profiler.borrow_mut().listeners.insert(listener))
+    }
+
+    /// Stop calling a listener added with [`Runtime::add_render_listener`]
+    pub fn remove_render_listener(&self, id: RenderListenerId) {
+        self.profiler.borrow_mut().listeners.try_remove(id.0);
+    }
+
+    /// Send the renders that were recorded since the last flush to the render listeners
+    pub(crate) fn flush_render_events(&self) {
+        let (events, listeners): (Vec<_>, Vec<_>) = {
+            let mut profiler = self.profiler.borrow_mut();
+            if profiler.events.is_empty() {
+                return;
+            }
+            let events = std::mem::take(&mut profiler.events);
+            let listeners = profiler.listeners.iter().map(|(_, l)| l.clone()).collect();
+            (events, listeners)
+        };
+        // The profiler is not borrowed while the listeners run so they can inspect the runtime
+        for listener in listeners {
+            if let Ok(mut listener) = listener.try_borrow_mut() {
+                listener(&events);
+            }
+        }
+    }
+}
+
+impl VirtualDom {
+    /// Start or stop profiling renders. See [`Runtime::set_profiling`].
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn app() -> Element {
+    ///     let count = use_signal(|| 0);
+    ///     rsx! { Counter { count } }
+    /// }
+    ///
+    /// #[component]
+    /// fn Counter(count: Signal<i32>) -> Element {
+    ///     rsx! { "{count}" }
+    /// }
+    ///
+    /// let mut dom = VirtualDom::new(app);
+    /// dom.set_profiling(true);
+    /// dom.rebuild_in_place();
+    ///
+    /// // Component names are the path of the component function
+    /// let tree = dom.component_tree();
+    /// let counter = tree.iter().find(|component| component.name.ends_with("Counter")).unwrap();
+    /// assert_eq!(counter.profile.unwrap().last_reason, dioxus_core::RenderReason::Mounted);
+    /// ```
+    pub fn set_profiling(&mut self, enabled: bool) {
+        self.runtime.set_profiling(enabled);
+    }
+
+    /// Take a snapshot of every component that is currently mounted. See [`Runtime::component_tree`].
+    pub fn component_tree(&self) -> Vec<ComponentSnapshot> {
+        self.runtime.component_tree()
+    }
+}
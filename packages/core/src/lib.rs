@@ -5,6 +5,7 @@
 
 mod any_props;
 mod arena;
+mod checkpoint;
 mod diff;
 mod effect;
 mod error_boundary;
@@ -12,11 +13,15 @@ mod events;
 mod fragment;
 mod generational_box;
 mod global_context;
+#[cfg(feature = "inspector")]
+mod inspector;
 mod launch;
 mod mutations;
 mod nodes;
+mod portal;
 mod properties;
 mod reactive_context;
+mod recycle;
 mod render_error;
 mod root_wrapper;
 mod runtime;
@@ -50,17 +55,22 @@ pub mod internal {
 pub(crate) mod innerlude {
     pub(crate) use crate::any_props::*;
     pub use crate::arena::*;
+    pub use crate::checkpoint::*;
     pub(crate) use crate::effect::*;
     pub use crate::error_boundary::*;
     pub use crate::events::*;
     pub use crate::fragment::*;
     pub use crate::generational_box::*;
     pub use crate::global_context::*;
+    #[cfg(feature = "inspector")]
+    pub use crate::inspector::*;
     pub use crate::launch::*;
     pub use crate::mutations::*;
     pub use crate::nodes::*;
+    pub use crate::portal::*;
     pub use crate::properties::*;
     pub use crate::reactive_context::*;
+    pub use crate::recycle::*;
     pub use crate::render_error::*;
     pub use crate::runtime::{Runtime, RuntimeGuard};
     pub use crate::scheduler::*;
@@ -80,11 +90,17 @@ pub(crate) mod innerlude {
 
 pub use crate::innerlude::{
     fc_to_builder, generation, schedule_update, schedule_update_any, use_hook, vdom_is_rendering,
-    AnyValue, Attribute, AttributeValue, CapturedError, Component, ComponentFunction, DynamicNode,
-    Element, ElementId, Event, Fragment, HasAttributes, IntoDynNode, LaunchConfig, MarkerWrapper,
-    Mutation, Mutations, NoOpMutations, Ok, Properties, Result, Runtime, ScopeId, ScopeState,
-    SpawnIfAsync, Task, Template, TemplateAttribute, TemplateNode, VComponent, VNode, VNodeInner,
-    VPlaceholder, VText, VirtualDom, WriteMutations,
+    AllocationStats, AnyValue, Attribute, AttributeValue, CapturedError, Checkpoint,
+    CheckpointParseError, Component, ComponentFunction, DynamicNode, Element, ElementId, Event,
+    Fragment, HasAttributes, IntoDynNode, LaunchConfig, MarkerWrapper, Mutation, Mutations,
+    NoOpMutations, Ok, Portal, PortalProps, PortalTarget, Priority, Properties, Result, Runtime,
+    ScopeId, ScopeState, SpawnIfAsync, Task, Template, TemplateAttribute, TemplateNode, VComponent,
+    VNode, VNodeInner, VPlaceholder, VText, VirtualDom, WriteMutations, PORTAL_ATTRIBUTE,
+};
+
+#[cfg(feature = "inspector")]
+pub use crate::innerlude::{
+    ComponentSnapshot, RenderEvent, RenderListenerId, RenderProfile, RenderReason,
 };
 
 /// The purpose of this module is to alleviate imports of many common types
@@ -92,15 +108,18 @@ pub use crate::innerlude::{
 /// This includes types like [`Element`], and [`Component`].
 pub mod prelude {
     pub use crate::innerlude::{
-        consume_context, consume_context_from_scope, current_owner, current_scope_id,
-        fc_to_builder, generation, has_context, needs_update, needs_update_any, parent_scope,
-        provide_context, provide_error_boundary, provide_root_context, queue_effect, remove_future,
-        schedule_update, schedule_update_any, spawn, spawn_forever, spawn_isomorphic, suspend,
-        throw_error, try_consume_context, use_after_render, use_before_render, use_drop, use_hook,
-        use_hook_with_cleanup, with_owner, AnyValue, Attribute, Callback, Component,
-        ComponentFunction, Context, Element, ErrorBoundary, ErrorContext, Event, EventHandler,
-        Fragment, HasAttributes, IntoAttributeValue, IntoDynNode, OptionStringFromMarker,
-        Properties, ReactiveContext, RenderError, Runtime, RuntimeGuard, ScopeId, ScopeState,
+        after_render, before_render, consume_context, consume_context_from_scope, current_owner,
+        current_scope_id, fc_to_builder, generation, has_context, needs_update, needs_update_any,
+        parent_scope, provide_context, provide_error_boundary, provide_root_context, queue_effect,
+        register_checkpoint, remove_future, restore_checkpoint, schedule_update,
+        schedule_update_any, spawn, spawn_forever, spawn_idle, spawn_isomorphic,
+        spawn_local_scoped, spawn_with_priority, suspend, take_checkpoint, throw_error,
+        try_consume_context, unregister_checkpoint, use_after_render, use_before_render, use_drop,
+        use_hook, use_hook_with_cleanup, with_owner, AnyValue, Attribute, Callback, Checkpoint,
+        Component, ComponentFunction, Context, Element, ErrorBoundary, ErrorContext, Event,
+        EventHandler, Fragment, HasAttributes, IntoAttributeValue, IntoDynNode,
+        OptionStringFromMarker, Portal, PortalProps, PortalTarget, Priority, Properties,
+        ReactiveContext, RenderError, RootErrorHandler, Runtime, RuntimeGuard, ScopeId, ScopeState,
         SuperFrom, SuperInto, SuspendedFuture, SuspenseBoundary, SuspenseBoundaryProps,
         SuspenseContext, SuspenseExtension, Task, Template, TemplateAttribute, TemplateNode, VNode,
         VNodeInner, VirtualDom,
@@ -1,5 +1,11 @@
 use crate::{arena::ElementId, AttributeValue, Template};
 
+#[cfg(feature = "serialize")]
+use crate::nodes::{deserialize_leaky, deserialize_option_leaky, deserialize_string_leaky};
+
+type StaticStr = &'static str;
+type StaticPath = &'static [u8];
+
 /// Something that can handle the mutations that are generated by the diffing process and apply them to the Real DOM
 ///
 /// This object provides a bunch of important information for a renderer to use patch the Real Dom with the state of the
@@ -120,7 +126,10 @@ pub trait WriteMutations {
 /// A `Mutation` represents a single instruction for the renderer to use to modify the UI tree to match the state
 /// of the Dioxus VirtualDom.
 ///
-/// These edits can be serialized and sent over the network or through any interface
+/// These edits can be serialized and sent over the network or through any interface. With the `serialize` feature,
+/// mutations implement serde's `Serialize` and `Deserialize` traits. Each mutation is encoded as an externally tagged
+/// enum, so the edits work with self-describing formats like json as well as compact binary formats like cbor.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq)]
 pub enum Mutation {
     /// Add these m children to the target element
@@ -141,7 +150,11 @@ pub enum Mutation {
         ///
         /// A path of `[]` represents the topmost node. A path of `[0]` represents the first child.
         /// `[0,1,2]` represents 1st child's 2nd child's 3rd child.
-        path: &'static [u8],
+        #[cfg_attr(
+            feature = "serialize",
+            serde(deserialize_with = "deserialize_leaky", bound = "")
+        )]
+        path: StaticPath,
 
         /// The ID we're assigning to this element/placeholder.
         ///
@@ -201,7 +214,11 @@ pub enum Mutation {
         ///
         /// A path of `[]` represents the topmost node. A path of `[0]` represents the first child.
         /// `[0,1,2]` represents 1st child's 2nd child's 3rd child.
-        path: &'static [u8],
+        #[cfg_attr(
+            feature = "serialize",
+            serde(deserialize_with = "deserialize_leaky", bound = "")
+        )]
+        path: StaticPath,
 
         /// The number of nodes on the stack to replace the target element with
         m: usize,
@@ -228,11 +245,19 @@ pub enum Mutation {
     /// Set the value of a node's attribute.
     SetAttribute {
         /// The name of the attribute to set.
-        name: &'static str,
+        #[cfg_attr(
+            feature = "serialize",
+            serde(deserialize_with = "deserialize_string_leaky", bound = "")
+        )]
+        name: StaticStr,
 
         /// The (optional) namespace of the attribute.
         /// For instance, "style" is in the "style" namespace.
-        ns: Option<&'static str>,
+        #[cfg_attr(
+            feature = "serialize",
+            serde(deserialize_with = "deserialize_option_leaky", bound = "")
+        )]
+        ns: Option<StaticStr>,
 
         /// The value of the attribute.
         value: AttributeValue,
@@ -282,12 +307,36 @@ pub enum Mutation {
 }
 
 /// A static list of mutations that can be applied to the DOM. Note: this list does not contain any `Any` attribute values
-#[derive(Debug, PartialEq, Default)]
+///
+/// With the `serialize` feature, the list can be sent to a renderer in another process. The templates that the
+/// [`Mutation::LoadTemplate`] edits load from are included in `Mutations::templates` so the renderer can cache them.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default)]
 pub struct Mutations {
+    /// Any templates that were loaded while collecting the mutations. Each template is only included once
+    #[cfg(feature = "serialize")]
+    #[serde(default)]
+    pub templates: Vec<Template>,
+
+    /// The templates that are already in `templates`
+    #[cfg(feature = "serialize")]
+    #[serde(skip)]
+    included_templates: rustc_hash::FxHashSet<Template>,
+
     /// Any mutations required to patch the renderer to match the layout of the VirtualDom
     pub edits: Vec<Mutation>,
 }
 
+impl PartialEq for Mutations {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "serialize")]
+        if self.templates != other.templates {
+            return false;
+        }
+        self.edits == other.edits
+    }
+}
+
 impl WriteMutations for Mutations {
     fn append_children(&mut self, id: ElementId, m: usize) {
         self.edits.push(Mutation::AppendChildren { id, m })
@@ -309,6 +358,10 @@ impl WriteMutations for Mutations {
     }
 
     fn load_template(&mut self, _template: Template, index: usize, id: ElementId) {
+        #[cfg(feature = "serialize")]
+        if self.included_templates.insert(_template) {
+            self.templates.push(_template);
+        }
         self.edits.push(Mutation::LoadTemplate { index, id })
     }
 
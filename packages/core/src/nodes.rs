@@ -352,7 +352,10 @@ pub struct Template {
     /// The list of template nodes that make up the template
     ///
     /// Unlike react, calls to `rsx!` can have multiple roots. This list supports that paradigm.
-    #[cfg_attr(feature = "serialize", serde(deserialize_with = "deserialize_leaky"))]
+    #[cfg_attr(
+        feature = "serialize",
+        serde(deserialize_with = "deserialize_leaky", bound = "")
+    )]
     pub roots: StaticTemplateArray,
 
     /// The paths of each node relative to the root of the template.
@@ -361,7 +364,7 @@ pub struct Template {
     /// topmost element, not the `roots` field.
     #[cfg_attr(
         feature = "serialize",
-        serde(deserialize_with = "deserialize_bytes_leaky")
+        serde(deserialize_with = "deserialize_bytes_leaky", bound = "")
     )]
     pub node_paths: StaticPathArray,
 
@@ -419,6 +422,38 @@ impl PartialEq for Template {
     }
 }
 
+/// Deserialized templates and mutations borrow their strings and paths for `'static`, so the values have to be
+/// leaked. Each distinct value is only leaked once and reused for every equal value after it. A renderer that keeps
+/// deserializing the same templates, attribute names and paths doesn't grow without bound.
+#[cfg(feature = "serialize")]
+fn intern<T>(value: Box<T>) -> &'static T
+where
+    T: ?Sized + std::hash::Hash + Eq + Send + Sync + 'static,
+{
+    use std::any::{Any, TypeId};
+    use std::collections::{HashMap, HashSet};
+    use std::sync::{Mutex, OnceLock};
+
+    static INTERNED: OnceLock<Mutex<HashMap<TypeId, Box<dyn Any + Send>>>> = OnceLock::new();
+
+    let mut interned = INTERNED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let values = interned
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::new(HashSet::<&'static T>::new()))
+        .downcast_mut::<HashSet<&'static T>>()
+        .expect("interned values are stored by their type id");
+
+    if let Some(value) = values.get(&*value) {
+        return value;
+    }
+    let value: &'static T = Box::leak(value);
+    values.insert(value);
+    value
+}
+
 #[cfg(feature = "serialize")]
 pub(crate) fn deserialize_string_leaky<'a, 'de, D>(
     deserializer: D,
@@ -429,7 +464,7 @@ where
     use serde::Deserialize;
 
     let deserialized = String::deserialize(deserializer)?;
-    Ok(&*Box::leak(deserialized.into_boxed_str()))
+    Ok(intern(deserialized.into_boxed_str()))
 }
 
 #[cfg(feature = "serialize")]
@@ -444,21 +479,21 @@ where
     let deserialized = Vec::<Vec<u8>>::deserialize(deserializer)?;
     let deserialized = deserialized
         .into_iter()
-        .map(|v| &*Box::leak(v.into_boxed_slice()))
+        .map(|v| intern(v.into_boxed_slice()))
         .collect::<Vec<_>>();
-    Ok(&*Box::leak(deserialized.into_boxed_slice()))
+    Ok(intern(deserialized.into_boxed_slice()))
 }
 
 #[cfg(feature = "serialize")]
 pub(crate) fn deserialize_leaky<'a, 'de, T, D>(deserializer: D) -> Result<&'static [T], D::Error>
 where
-    T: serde::Deserialize<'de>,
+    T: serde::Deserialize<'de> + std::hash::Hash + Eq + Send + Sync + 'static,
     D: serde::Deserializer<'de>,
 {
     use serde::Deserialize;
 
     let deserialized = Box::<[T]>::deserialize(deserializer)?;
-    Ok(&*Box::leak(deserialized))
+    Ok(intern(deserialized))
 }
 
 #[cfg(feature = "serialize")]
@@ -471,7 +506,7 @@ where
     use serde::Deserialize;
 
     let deserialized = Option::<String>::deserialize(deserializer)?;
-    Ok(deserialized.map(|deserialized| &*Box::leak(deserialized.into_boxed_str())))
+    Ok(deserialized.map(|deserialized| intern(deserialized.into_boxed_str())))
 }
 
 impl Template {
@@ -503,7 +538,7 @@ pub enum TemplateNode {
         /// IE for a div, it would be the string "div"
         #[cfg_attr(
             feature = "serialize",
-            serde(deserialize_with = "deserialize_string_leaky")
+            serde(deserialize_with = "deserialize_string_leaky", bound = "")
         )]
         tag: StaticStr,
 
@@ -513,7 +548,7 @@ pub enum TemplateNode {
         /// SVG is an example of this namespace
         #[cfg_attr(
             feature = "serialize",
-            serde(deserialize_with = "deserialize_option_leaky")
+            serde(deserialize_with = "deserialize_option_leaky", bound = "")
         )]
         namespace: Option<StaticStr>,
 
@@ -527,7 +562,10 @@ pub enum TemplateNode {
         attrs: StaticTemplateAttributeArray,
 
         /// A list of template nodes that define another set of template nodes
-        #[cfg_attr(feature = "serialize", serde(deserialize_with = "deserialize_leaky"))]
+        #[cfg_attr(
+            feature = "serialize",
+            serde(deserialize_with = "deserialize_leaky", bound = "")
+        )]
         children: StaticTemplateArray,
     },
 
@@ -890,6 +928,60 @@ impl std::fmt::Debug for AttributeValue {
     }
 }
 
+/// Only the text, float, int, bool and none values can be serialized. Serializing a listener or an `Any` value
+/// returns an error.
+#[cfg(feature = "serialize")]
+impl serde::Serialize for AttributeValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(serde::Serialize)]
+        #[serde(rename = "AttributeValue")]
+        enum SerializedAttributeValue<'a> {
+            Text(&'a str),
+            Float(f64),
+            Int(i64),
+            Bool(bool),
+            None,
+        }
+
+        let value = match self {
+            Self::Text(text) => SerializedAttributeValue::Text(text),
+            Self::Float(float) => SerializedAttributeValue::Float(*float),
+            Self::Int(int) => SerializedAttributeValue::Int(*int),
+            Self::Bool(bool) => SerializedAttributeValue::Bool(*bool),
+            Self::None => SerializedAttributeValue::None,
+            Self::Listener(_) | Self::Any(_) => {
+                return Err(serde::ser::Error::custom(
+                    "listeners and `Any` attribute values cannot be serialized",
+                ))
+            }
+        };
+        value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for AttributeValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "AttributeValue")]
+        enum SerializedAttributeValue {
+            Text(String),
+            Float(f64),
+            Int(i64),
+            Bool(bool),
+            None,
+        }
+
+        Ok(match SerializedAttributeValue::deserialize(deserializer)? {
+            SerializedAttributeValue::Text(text) => Self::Text(text),
+            SerializedAttributeValue::Float(float) => Self::Float(float),
+            SerializedAttributeValue::Int(int) => Self::Int(int),
+            SerializedAttributeValue::Bool(bool) => Self::Bool(bool),
+            SerializedAttributeValue::None => Self::None,
+        })
+    }
+}
+
 impl PartialEq for AttributeValue {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
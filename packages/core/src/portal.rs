@@ -0,0 +1,175 @@
+use std::rc::Rc;
+
+use crate::innerlude::*;
+
+/// The attribute a [`Portal`] sets on its wrapper element with the target the children should be moved into.
+/// Renderers that support portals look for this attribute.
+pub const PORTAL_ATTRIBUTE: &str = "data-dioxus-portal";
+
+/// The container a [`Portal`] renders its children into.
+#[derive(Clone)]
+pub enum PortalTarget {
+    /// The element with the given id
+    Id(String),
+    /// A handle to an element from the renderer, like a `web_sys::Element` on the web
+    Element(Rc<dyn AnyValue>),
+}
+
+impl PortalTarget {
+    /// Target an element handle from the renderer. Renderers ignore handles of types they don't know.
+    pub fn element(handle: impl AnyValue) -> Self {
+        Self::Element(Rc::new(handle))
+    }
+
+    fn attribute_value(&self) -> AttributeValue {
+        match self {
+            PortalTarget::Id(id) => AttributeValue::Text(id.clone()),
+            PortalTarget::Element(element) => AttributeValue::Any(element.clone()),
+        }
+    }
+}
+
+impl PartialEq for PortalTarget {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PortalTarget::Id(a), PortalTarget::Id(b)) => a == b,
+            (PortalTarget::Element(a), PortalTarget::Element(b)) => a.any_cmp(b.as_ref()),
+            _ => false,
+        }
+    }
+}
+
+impl From<&str> for PortalTarget {
+    fn from(id: &str) -> Self {
+        Self::Id(id.to_string())
+    }
+}
+
+impl From<String> for PortalTarget {
+    fn from(id: String) -> Self {
+        Self::Id(id)
+    }
+}
+
+static PORTAL_ROOTS: &[TemplateNode] = &[TemplateNode::Element {
+    tag: "div",
+    namespace: None,
+    attrs: &[
+        TemplateAttribute::Static {
+            name: "style",
+            value: "display:contents;",
+            namespace: None,
+        },
+        TemplateAttribute::Dynamic { id: 0 },
+    ],
+    children: &[TemplateNode::Dynamic { id: 0 }],
+}];
+
+static PORTAL_TEMPLATE: Template = Template {
+    roots: PORTAL_ROOTS,
+    node_paths: &[&[0, 0]],
+    attr_paths: &[&[0]],
+};
+
+/// Render the children into a container outside of the parent's subtree. This is useful for modals, tooltips, and
+/// toasts that need to escape the overflow and stacking context of their parent.
+///
+/// The children stay in the component tree: they can consume contexts from their parents, and events bubble to the
+/// parent components like they would without the portal.
+///
+/// The children are wrapped in a `div` with `display: contents` and the [`PORTAL_ATTRIBUTE`]. The web renderer moves
+/// the wrapper into the target once it is mounted. Renderers without portal support, like server side rendering,
+/// render the children in place.
+///
+/// ## Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn Modal() -> Element {
+///     rsx! {
+///         Portal { target: "modal-root",
+///             div { class: "modal", "I render into the modal-root element" }
+///         }
+///     }
+/// }
+/// #
+/// # let mut dom = VirtualDom::new(Modal);
+/// # dom.rebuild_in_place();
+/// # assert_eq!(
+/// #     dioxus_ssr::render(&dom),
+/// #     r#"<div style="display:contents;" data-dioxus-portal="modal-root"><div class="modal">I render into the modal-root element</div></div>"#
+/// # );
+/// ```
+#[allow(non_snake_case)]
+pub fn Portal(props: PortalProps) -> Element {
+    Element::Ok(VNode::new(
+        None,
+        PORTAL_TEMPLATE,
+        Box::new([props.children.into_dyn_node()]),
+        Box::new([Box::new([Attribute::new(
+            PORTAL_ATTRIBUTE,
+            props.target.attribute_value(),
+            None,
+            false,
+        )])]),
+    ))
+}
+
+/// The props for the [`Portal`] component
+#[derive(Clone, PartialEq)]
+pub struct PortalProps {
+    /// The container to render the children into. Use an id or [`PortalTarget::element`]
+    pub target: PortalTarget,
+    /// The children to render into the target
+    pub children: Element,
+}
+
+/// The builder for [`PortalProps`]. The target must be set before the props can be built.
+pub struct PortalPropsBuilder<const TARGET: bool> {
+    target: Option<PortalTarget>,
+    children: Element,
+}
+
+impl<const TARGET: bool> PortalPropsBuilder<TARGET> {
+    /// Set the container to render the children into
+    pub fn target(self, target: impl Into<PortalTarget>) -> PortalPropsBuilder<true> {
+        PortalPropsBuilder {
+            target: Some(target.into()),
+            children: self.children,
+        }
+    }
+
+    /// Set the children to render into the target
+    pub fn children(self, children: Element) -> Self {
+        Self { children, ..self }
+    }
+}
+
+impl PortalPropsBuilder<true> {
+    /// Build the props
+    pub fn build(self) -> PortalProps {
+        PortalProps {
+            target: self.target.expect("the target is always set"),
+            children: self.children,
+        }
+    }
+}
+
+impl Properties for PortalProps {
+    type Builder = PortalPropsBuilder<false>;
+
+    fn builder() -> Self::Builder {
+        PortalPropsBuilder {
+            target: None,
+            children: VNode::empty(),
+        }
+    }
+
+    fn memoize(&mut self, new: &Self) -> bool {
+        let equal = self == new;
+        if !equal {
+            *self = new.clone();
+        }
+        equal
+    }
+}
@@ -0,0 +1,139 @@
+//! Buffers that are recycled across renders.
+//!
+//! Every mounted rsx block owns a few small slices that track where its roots, attributes and dynamic nodes are
+//! mounted, and every keyed diff needs scratch space to find the nodes that moved. When a large list re-renders, most of
+//! the frame is spent allocating and freeing these buffers. Instead of freeing them, the runtime keeps them around and
+//! hands them to the next block with the same shape or the next keyed diff.
+
+use rustc_hash::FxHashMap;
+
+use crate::innerlude::ElementId;
+
+/// The most buffers of one shape that are kept after their block is removed. Removing a huge list shouldn't keep all of
+/// its memory alive forever.
+const MAX_RECYCLED_MOUNTS: usize = 256;
+
+/// Counts of how many buffers the runtime allocated and how many it reused from earlier renders.
+///
+/// Read the current counts with [`VirtualDom::allocation_stats`](crate::VirtualDom::allocation_stats). The counts are
+/// cumulative from when the virtual dom was created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// The number of mount buffers that were freshly allocated
+    pub mounts_allocated: usize,
+
+    /// The number of mount buffers that were reused from a removed block with the same shape
+    pub mounts_reused: usize,
+
+    /// The number of keyed diffs that had to allocate new scratch space
+    pub scratch_allocated: usize,
+
+    /// The number of keyed diffs that reused scratch space from an earlier diff
+    pub scratch_reused: usize,
+}
+
+/// The buffers that track where a block is mounted
+pub(crate) struct MountBuffers {
+    pub(crate) root_ids: Box<[ElementId]>,
+    pub(crate) mounted_attributes: Box<[ElementId]>,
+    pub(crate) mounted_dynamic_nodes: Box<[usize]>,
+}
+
+impl MountBuffers {
+    fn shape(&self) -> (usize, usize, usize) {
+        (
+            self.root_ids.len(),
+            self.mounted_attributes.len(),
+            self.mounted_dynamic_nodes.len(),
+        )
+    }
+}
+
+/// Scratch space for the middle of a keyed diff
+#[derive(Default)]
+pub(crate) struct KeyedScratch {
+    pub(crate) new_index_to_old_index: Vec<usize>,
+    pub(crate) old_is_shared: Vec<bool>,
+    pub(crate) lis_sequence: Vec<usize>,
+    pub(crate) lis_allocation: Vec<usize>,
+}
+
+#[derive(Default)]
+pub(crate) struct Recycler {
+    mounts: FxHashMap<(usize, usize, usize), Vec<MountBuffers>>,
+    // Keyed diffs are nested when a keyed child contains another keyed list, so there may be more than one in use
+    scratch: Vec<KeyedScratch>,
+    stats: AllocationStats,
+}
+
+impl Recycler {
+    /// Get mount buffers for a block with the given number of roots, dynamic attributes and dynamic nodes
+    pub(crate) fn take_mount(&mut self, roots: usize, attrs: usize, nodes: usize) -> MountBuffers {
+        match self
+            .mounts
+            .get_mut(&(roots, attrs, nodes))
+            .and_then(Vec::pop)
+        {
+            Some(mut buffers) => {
+                self.stats.mounts_reused += 1;
+                buffers.root_ids.fill(ElementId(0));
+                buffers.mounted_attributes.fill(ElementId(0));
+                buffers.mounted_dynamic_nodes.fill(usize::MAX);
+                buffers
+            }
+            None => {
+                self.stats.mounts_allocated += 1;
+                MountBuffers {
+                    root_ids: vec![ElementId(0); roots].into_boxed_slice(),
+                    mounted_attributes: vec![ElementId(0); attrs].into_boxed_slice(),
+                    mounted_dynamic_nodes: vec![usize::MAX; nodes].into_boxed_slice(),
+                }
+            }
+        }
+    }
+
+    /// Keep the mount buffers of a removed block for the next block with the same shape
+    pub(crate) fn recycle_mount(&mut self, buffers: MountBuffers) {
+        let pool = self.mounts.entry(buffers.shape()).or_default();
+        if pool.len() < MAX_RECYCLED_MOUNTS {
+            pool.push(buffers);
+        }
+    }
+
+    /// Get scratch space for a keyed diff. The buffers are empty, but may have capacity left over from earlier diffs
+    pub(crate) fn take_scratch(&mut self) -> KeyedScratch {
+        match self.scratch.pop() {
+            Some(scratch) => {
+                self.stats.scratch_reused += 1;
+                scratch
+            }
+            None => {
+                self.stats.scratch_allocated += 1;
+                KeyedScratch::default()
+            }
+        }
+    }
+
+    /// Return scratch space after a keyed diff is finished
+    pub(crate) fn recycle_scratch(&mut self, mut scratch: KeyedScratch) {
+        scratch.new_index_to_old_index.clear();
+        scratch.old_is_shared.clear();
+        scratch.lis_sequence.clear();
+        scratch.lis_allocation.clear();
+        self.scratch.push(scratch);
+    }
+
+    /// Keep the buffers another recycler holds. The counts of this recycler are not changed
+    pub(crate) fn absorb(&mut self, other: Recycler) {
+        for (shape, buffers) in other.mounts {
+            let pool = self.mounts.entry(shape).or_default();
+            let room = MAX_RECYCLED_MOUNTS.saturating_sub(pool.len());
+            pool.extend(buffers.into_iter().take(room));
+        }
+        self.scratch.extend(other.scratch);
+    }
+
+    pub(crate) fn stats(&self) -> AllocationStats {
+        self.stats
+    }
+}
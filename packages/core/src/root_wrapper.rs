@@ -1,9 +1,15 @@
-use crate::{prelude::*, properties::RootProps, DynamicNode, VComponent};
+use crate::{
+    innerlude::default_handler, prelude::*, properties::RootProps, DynamicNode, VComponent,
+};
 
 // We wrap the root scope in a component that renders it inside a default ErrorBoundary and SuspenseBoundary
 #[allow(non_snake_case)]
 #[allow(clippy::let_and_return)]
 pub(crate) fn RootScopeWrapper(props: RootProps<VComponent>) -> Element {
+    let handle_error = match ScopeId::ROOT.has_context::<RootErrorHandler>() {
+        Some(RootErrorHandler(handler)) => handler,
+        None => default_handler,
+    };
     static TEMPLATE: Template = Template {
         roots: &[TemplateNode::Dynamic { id: 0usize }],
         node_paths: &[&[0u8]],
@@ -20,6 +26,7 @@ pub(crate) fn RootScopeWrapper(props: RootProps<VComponent>) -> Element {
                     TEMPLATE,
                     Box::new([DynamicNode::Component({
                         fc_to_builder(ErrorBoundary)
+                            .handle_error(handle_error)
                             .children(Element::Ok(VNode::new(
                                 None,
                                 TEMPLATE,
@@ -1,6 +1,10 @@
 use crate::arena::ElementRef;
-use crate::innerlude::{DirtyTasks, Effect};
+use crate::checkpoint::Checkpoints;
+use crate::innerlude::{DeferredTasks, DirtyTasks, Effect};
+#[cfg(feature = "inspector")]
+use crate::inspector::Profiler;
 use crate::nodes::VNodeMount;
+use crate::recycle::Recycler;
 use crate::scheduler::ScopeOrder;
 use crate::scope_context::SuspenseLocation;
 use crate::{
@@ -9,7 +13,7 @@ use crate::{
     scopes::ScopeId,
     Task,
 };
-use crate::{AttributeValue, ElementId, Event};
+use crate::{AttributeValue, ElementId, Event, TemplateAttribute, TemplateNode};
 use slab::Slab;
 use slotmap::DefaultKey;
 use std::any::Any;
@@ -56,6 +60,9 @@ pub struct Runtime {
     // Tasks that are waiting to be polled
     pub(crate) dirty_tasks: RefCell<BTreeSet<DirtyTasks>>,
 
+    // Tasks in the animation and idle lanes that are waiting to be polled
+    pub(crate) deferred_tasks: RefCell<DeferredTasks>,
+
     // The element ids that are used in the renderer
     // These mark a specific place in a whole rsx block
     pub(crate) elements: RefCell<Slab<Option<ElementRef>>>,
@@ -64,6 +71,16 @@ pub struct Runtime {
     // We need to store this information on the virtual dom so that we know what nodes are mounted where when we bubble events
     // Each mount is associated with a whole rsx block. [`VirtualDom::elements`] link to a specific node in the block
     pub(crate) mounts: RefCell<Slab<VNodeMount>>,
+
+    // Buffers from removed mounts and finished keyed diffs that are reused by later renders
+    pub(crate) recycler: RefCell<Recycler>,
+
+    // The parts of the app that are saved in checkpoints
+    pub(crate) checkpoints: RefCell<Checkpoints>,
+
+    // The renders recorded for devtools while profiling is enabled
+    #[cfg(feature = "inspector")]
+    pub(crate) profiler: RefCell<Profiler>,
 }
 
 impl Runtime {
@@ -83,8 +100,13 @@ impl Runtime {
             suspended_tasks: Default::default(),
             pending_effects: Default::default(),
             dirty_tasks: Default::default(),
+            deferred_tasks: Default::default(),
             elements: RefCell::new(elements),
             mounts: Default::default(),
+            recycler: Default::default(),
+            checkpoints: Default::default(),
+            #[cfg(feature = "inspector")]
+            profiler: Default::default(),
         })
     }
 
@@ -154,6 +176,8 @@ impl Runtime {
             }
         }
         self.scope_states.borrow_mut()[id.0].take();
+        #[cfg(feature = "inspector")]
+        self.profiler.borrow_mut().remove_scope(id);
     }
 
     /// Get the current scope id
@@ -280,6 +304,8 @@ impl Runtime {
                 .unbounded_send(SchedulerMsg::EffectQueued)
                 .expect("Scheduler should exist");
         }
+        #[cfg(feature = "inspector")]
+        self.flush_render_events();
     }
 
     /// Check if we should render a scope
@@ -317,6 +343,67 @@ impl Runtime {
         }
     }
 
+    /// Find the value of an attribute on an element, or on the closest element it is nested inside of that sets the
+    /// attribute. Static and dynamic attributes are both checked, and the search continues through the parent
+    /// components of the element.
+    ///
+    /// Attributes that are set to [`AttributeValue::None`] are skipped since they are removed from the dom.
+    pub fn find_attribute(&self, element: ElementId, name: &str) -> Option<AttributeValue> {
+        let elements = self.elements.borrow();
+        let mounts = self.mounts.borrow();
+
+        let mut current = elements.get(element.0).copied().flatten();
+        while let Some(element_ref) = current {
+            let mount = mounts.get(element_ref.mount.0)?;
+            let vnode = &mount.node;
+
+            // Collect the nodes of the template from the root down to the element
+            let mut path = Vec::with_capacity(element_ref.path.path.len());
+            let mut siblings = vnode.template.roots;
+            for index in element_ref.path.path {
+                let Some(node) = siblings.get(*index as usize) else {
+                    break;
+                };
+                path.push(node);
+                match node {
+                    TemplateNode::Element { children, .. } => siblings = children,
+                    _ => break,
+                }
+            }
+
+            // Then check the closest element first
+            for node in path.into_iter().rev() {
+                let TemplateNode::Element { attrs, .. } = node else {
+                    continue;
+                };
+                for attr in attrs.iter() {
+                    match attr {
+                        TemplateAttribute::Static {
+                            name: attr_name,
+                            value,
+                            ..
+                        } if *attr_name == name => {
+                            return Some(AttributeValue::Text(value.to_string()));
+                        }
+                        TemplateAttribute::Dynamic { id } => {
+                            let found = vnode.dynamic_attrs[*id].iter().find(|attr| {
+                                attr.name == name && !matches!(attr.value, AttributeValue::None)
+                            });
+                            if let Some(attr) = found {
+                                return Some(attr.value.clone());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            current = mount.parent;
+        }
+
+        None
+    }
+
     /*
     ------------------------
     The algorithm works by walking through the list of dynamic attributes, checking their paths, and breaking when
@@ -349,6 +436,9 @@ impl Runtime {
         // If the event bubbles, we traverse through the tree until we find the target element.
         // Loop through each dynamic attribute (in a depth first order) in this template before moving up to the template's parent.
         let mut parent = Some(parent);
+        // Only listeners on the element the event was dispatched to are at the target. Once we move up to the parent
+        // template, every listener is on an ancestor
+        let mut in_target_template = true;
         while let Some(path) = parent {
             let mut listeners = vec![];
 
@@ -367,7 +457,8 @@ impl Runtime {
                 for attr in attrs.iter() {
                     // Remove the "on" prefix if it exists, TODO, we should remove this and settle on one
                     if attr.name.get(2..) == Some(name) && target_path.is_descendant(this_path) {
-                        listeners.push(&attr.value);
+                        let at_target = in_target_template && target_path == this_path;
+                        listeners.push((&attr.value, at_target));
 
                         // Break if this is the exact target element.
                         // This means we won't call two listeners with the same name on the same element. This should be
@@ -386,8 +477,9 @@ impl Runtime {
                 "Calling {} listeners",
                 listeners.len()
             );
-            for listener in listeners.into_iter().rev() {
+            for (listener, at_target) in listeners.into_iter().rev() {
                 if let AttributeValue::Listener(listener) = listener {
+                    uievent.metadata.borrow_mut().at_target = at_target;
                     listener.call(uievent.clone());
                     let metadata = uievent.metadata.borrow();
 
@@ -397,6 +489,7 @@ impl Runtime {
                 }
             }
 
+            in_target_template = false;
             let mount = el_ref.mount.get().as_usize();
             parent = mount.and_then(|id| mounts.get(id).and_then(|el| el.parent));
         }
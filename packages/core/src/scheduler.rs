@@ -75,6 +75,16 @@
 //! 3. Effects:
 //!    Description: Effects should always run after all changes to the DOM have been applied.
 //!    Priority: These are the lowest priority tasks in the scheduler. They are run after all other dirty scopes and futures have been resolved. Other tasks may cause components to rerun, which would update the DOM. These effects should only run after the DOM has been updated.
+//!
+//! Tasks can also be spawned in a lower [`Priority`] lane with [`spawn_with_priority`](crate::prelude::spawn_with_priority). Tasks in the
+//! [`Priority::Animation`] and [`Priority::Idle`] lanes are only polled once every dirty scope, user input task and effect has been
+//! resolved. Animation tasks are polled before idle tasks. Because all of the work that could drop the scope has already run,
+//! these tasks are kept in the order they were woken instead of the order of their scopes.
+//!
+//! ## Time slicing
+//!
+//! [`VirtualDom::render_with_deadline`] checks a deadline between each scope it reruns. If the deadline has passed, it stops early
+//! and leaves the rest of the dirty scopes queued, so the renderer can handle input before it resumes the render.
 
 use crate::innerlude::Effect;
 use crate::ScopeId;
@@ -85,6 +95,82 @@ use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::hash::Hash;
 
+/// The lane a task is polled in. Every lane is polled after the lanes before it are empty.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut results = use_signal(Vec::new);
+///
+///     use_hook(|| {
+///         // Indexing the results can wait until the app has nothing more important to do
+///         spawn_idle(async move {
+///             results.set((0..10_000).collect::<Vec<i32>>());
+///         });
+///     });
+///
+///     rsx! { "{results.len()} results" }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Work that responds to the user, like event handlers. These tasks are polled in the same pass as the scopes
+    /// they may rerun. This is the lane [`spawn`](crate::prelude::spawn) uses.
+    #[default]
+    UserInput,
+
+    /// Work that should run every frame, but can wait for user input to be handled, like animations
+    Animation,
+
+    /// Work that can wait until there is nothing else to do, like prefetching or indexing
+    Idle,
+}
+
+/// Tasks in the lower priority lanes that are waiting to be polled, in the order they were woken
+#[derive(Default)]
+pub(crate) struct DeferredTasks {
+    animation: VecDeque<Task>,
+    idle: VecDeque<Task>,
+}
+
+impl DeferredTasks {
+    pub(crate) fn queue(&mut self, priority: Priority, task: Task) {
+        let lane = match priority {
+            Priority::Animation => &mut self.animation,
+            Priority::Idle => &mut self.idle,
+            Priority::UserInput => unreachable!("user input tasks are queued with their scope"),
+        };
+        if !lane.contains(&task) {
+            lane.push_back(task);
+        }
+    }
+
+    /// Take the next task from the highest priority lane that has one
+    pub(crate) fn pop(&mut self) -> Option<Task> {
+        self.animation.pop_front().or_else(|| self.idle.pop_front())
+    }
+
+    pub(crate) fn remove(&mut self, task: Task) {
+        self.animation.retain(|queued| *queued != task);
+        self.idle.retain(|queued| *queued != task);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.animation.len() + self.idle.len()
+    }
+
+    /// The highest priority lane that has a task waiting
+    pub(crate) fn next_priority(&self) -> Option<Priority> {
+        if !self.animation.is_empty() {
+            Some(Priority::Animation)
+        } else if !self.idle.is_empty() {
+            Some(Priority::Idle)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq)]
 pub struct ScopeOrder {
     pub(crate) height: u32,
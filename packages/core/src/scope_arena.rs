@@ -30,6 +30,8 @@ impl VirtualDom {
         let id = ScopeId(entry.key());
 
         let scope_runtime = Scope::new(name, id, parent_id, height, suspense_boundary);
+        #[cfg(feature = "inspector")]
+        let scope_runtime = scope_runtime.with_props_type(props.props_type_name());
         let reactive_context = ReactiveContext::new_for_scope(&scope_runtime, &self.runtime);
 
         let scope = entry.insert(ScopeState {
@@ -67,8 +69,17 @@ impl VirtualDom {
 
                 let props: &dyn AnyProps = &*scope.props;
 
+                // Only read the clock while profiling. Devtools show how long the component function took to run
+                #[cfg(feature = "inspector")]
+                let started = self
+                    .runtime
+                    .profiler
+                    .borrow()
+                    .enabled()
+                    .then(web_time::Instant::now);
+
                 let span = tracing::trace_span!("render", scope = %scope.state().name);
-                span.in_scope(|| {
+                let output = span.in_scope(|| {
                     scope.reactive_context.reset_and_run_in(|| {
                         let render_return = props.render();
                         // After the component is run, we need to do a deep clone of the VNode. This
@@ -85,7 +96,17 @@ impl VirtualDom {
                         self.handle_element_return(&mut render_return, scope_id, &scope.state());
                         render_return
                     })
-                })
+                });
+
+                #[cfg(feature = "inspector")]
+                if let Some(started) = started {
+                    self.runtime.profiler.borrow_mut().record(
+                        scope_id,
+                        scope_state.name,
+                        started.elapsed(),
+                    );
+                }
+                output
             };
 
             let scope_state = scope.state();
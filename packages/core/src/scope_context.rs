@@ -1,5 +1,5 @@
 use crate::runtime::RuntimeError;
-use crate::{innerlude::SchedulerMsg, Runtime, ScopeId, Task};
+use crate::{innerlude::SchedulerMsg, Priority, Runtime, ScopeId, Task};
 use crate::{
     innerlude::{throw_into, CapturedError},
     prelude::SuspenseContext,
@@ -50,10 +50,15 @@ pub(crate) struct Scope {
     pub(crate) parent_id: Option<ScopeId>,
     pub(crate) height: u32,
     pub(crate) render_count: Cell<usize>,
+    #[cfg(feature = "inspector")]
+    pub(crate) props_type: &'static str,
 
     // Note: the order of the hook and context fields is important. The hooks field must be dropped before the contexts field in case a hook drop implementation tries to access a context.
     pub(crate) hooks: RefCell<Vec<Box<dyn Any>>>,
     pub(crate) hook_index: Cell<usize>,
+    // The type names of the hooks for devtools
+    #[cfg(feature = "inspector")]
+    pub(crate) hook_types: RefCell<Vec<&'static str>>,
     pub(crate) shared_contexts: RefCell<Vec<Box<dyn Any>>>,
     pub(crate) spawned_tasks: RefCell<FxHashSet<Task>>,
     pub(crate) before_render: RefCell<Vec<Box<dyn FnMut()>>>,
@@ -75,6 +80,8 @@ impl Scope {
     ) -> Self {
         Self {
             name,
+            #[cfg(feature = "inspector")]
+            props_type: "",
             id,
             parent_id,
             height,
@@ -83,6 +90,8 @@ impl Scope {
             spawned_tasks: RefCell::new(FxHashSet::default()),
             hooks: RefCell::new(vec![]),
             hook_index: Cell::new(0),
+            #[cfg(feature = "inspector")]
+            hook_types: RefCell::new(vec![]),
             before_render: RefCell::new(vec![]),
             after_render: RefCell::new(vec![]),
             status: RefCell::new(ScopeStatus::Unmounted {
@@ -92,6 +101,13 @@ impl Scope {
         }
     }
 
+    /// Set the type name of the props devtools show for this scope
+    #[cfg(feature = "inspector")]
+    pub(crate) fn with_props_type(mut self, props_type: &'static str) -> Self {
+        self.props_type = props_type;
+        self
+    }
+
     pub fn parent_id(&self) -> Option<ScopeId> {
         self.parent_id
     }
@@ -354,6 +370,34 @@ impl Scope {
         id
     }
 
+    /// Spawns the future in a [`Priority`] lane and returns the [`Task`]
+    pub fn spawn_with_priority(
+        &self,
+        priority: Priority,
+        fut: impl Future<Output = ()> + 'static,
+    ) -> Task {
+        let id = Runtime::with(|rt| rt.spawn_with_priority(self.id, priority, fut))
+            .expect("Runtime to exist");
+        self.spawned_tasks.borrow_mut().insert(id);
+        id
+    }
+
+    /// Spawns a future that only lives as long as this scope and calls `on_complete` with its output.
+    ///
+    /// If the component unmounts before the future finishes, the future is dropped at the await point it is waiting on
+    /// and `on_complete` is never called, so it can safely write to the component's state.
+    pub fn spawn_local_scoped<T: 'static>(
+        &self,
+        fut: impl Future<Output = T> + 'static,
+        on_complete: impl FnOnce(T) + 'static,
+    ) -> Task {
+        // The task is removed with the scope, so it never resumes after the component unmounts
+        self.spawn(async move {
+            let output = fut.await;
+            on_complete(output);
+        })
+    }
+
     /// Queue an effect to run after the next render
     pub fn queue_effect(&self, f: impl FnOnce() + 'static) {
         Runtime::with(|rt| rt.queue_effect(self.id, f)).expect("Runtime to exist");
@@ -448,6 +492,10 @@ impl Scope {
 
         if cur_hook >= hooks.len() {
             hooks.push(Box::new(initializer()));
+            #[cfg(feature = "inspector")]
+            self.hook_types
+                .borrow_mut()
+                .push(std::any::type_name::<State>());
         }
 
         self.use_hook_inner::<State>(hooks, cur_hook)
@@ -549,6 +597,15 @@ impl ScopeId {
         Runtime::with_scope(self, |cx| cx.spawn(fut)).unwrap();
     }
 
+    /// Spawns a future that only lives as long as this scope. See [`Scope::spawn_local_scoped`].
+    pub fn spawn_local_scoped<T: 'static>(
+        self,
+        fut: impl Future<Output = T> + 'static,
+        on_complete: impl FnOnce(T) + 'static,
+    ) -> Option<Task> {
+        Runtime::with_scope(self, |cx| cx.spawn_local_scoped(fut, on_complete)).ok()
+    }
+
     /// Get the current render since the inception of this component
     ///
     /// This can be used as a helpful diagnostic when debugging hooks/renders, etc
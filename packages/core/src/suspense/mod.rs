@@ -165,7 +165,9 @@ impl SuspenseContext {
             .suspended_tasks
             .borrow_mut()
             .retain(|t| t.task != task);
-        self.inner.id.get().needs_update();
+        // Tasks spawned above the boundary can outlive it while the virtual dom is dropped. There is nothing left to
+        // rerender in that case
+        let _ = Runtime::with_scope(self.inner.id.get(), |cx| cx.needs_update());
     }
 
     /// Get all suspended tasks
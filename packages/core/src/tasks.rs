@@ -1,4 +1,5 @@
 use crate::innerlude::Effect;
+use crate::innerlude::Priority;
 use crate::innerlude::ScopeOrder;
 use crate::innerlude::{remove_future, spawn, Runtime};
 use crate::scope_context::ScopeStatus;
@@ -76,6 +77,25 @@ impl Task {
         .unwrap_or_default()
     }
 
+    /// Get the [`Priority`] lane the task is polled in
+    pub fn priority(&self) -> Priority {
+        Runtime::with(|rt| rt.task_priority(*self))
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Move the task to a different [`Priority`] lane. The new lane is used the next time the task is woken
+    #[track_caller]
+    pub fn set_priority(&self, priority: Priority) {
+        Runtime::with(|rt| {
+            if let Some(task) = rt.tasks.borrow().get(self.id) {
+                task.priority.set(priority);
+            }
+        })
+        .unwrap_or_else(|e| panic!("{}", e))
+    }
+
     /// Wake the task.
     #[track_caller]
     pub fn wake(&self) {
@@ -141,7 +161,7 @@ impl Runtime {
         scope: ScopeId,
         task: impl Future<Output = ()> + 'static,
     ) -> Task {
-        self.spawn_task_of_type(scope, task, TaskType::Isomorphic)
+        self.spawn_task_of_type(scope, task, TaskType::Isomorphic, Priority::UserInput)
     }
 
     /// Start a new future on the same thread as the rest of the VirtualDom.
@@ -154,7 +174,20 @@ impl Runtime {
     /// Spawning a future onto the root scope will cause it to be dropped when the root component is dropped - which
     /// will only occur when the VirtualDom itself has been dropped.
     pub fn spawn(&self, scope: ScopeId, task: impl Future<Output = ()> + 'static) -> Task {
-        self.spawn_task_of_type(scope, task, TaskType::ClientOnly)
+        self.spawn_task_of_type(scope, task, TaskType::ClientOnly, Priority::UserInput)
+    }
+
+    /// Start a new future in a [`Priority`] lane. Tasks in lower priority lanes are only polled once there is no
+    /// higher priority work left.
+    ///
+    /// Whenever the component that owns this future is dropped, the future will be dropped as well.
+    pub fn spawn_with_priority(
+        &self,
+        scope: ScopeId,
+        priority: Priority,
+        task: impl Future<Output = ()> + 'static,
+    ) -> Task {
+        self.spawn_task_of_type(scope, task, TaskType::ClientOnly, priority)
     }
 
     fn spawn_task_of_type(
@@ -162,8 +195,9 @@ impl Runtime {
         scope: ScopeId,
         task: impl Future<Output = ()> + 'static,
         ty: TaskType,
+        priority: Priority,
     ) -> Task {
-        self.spawn_task_of_type_inner(scope, Box::pin(task), ty)
+        self.spawn_task_of_type_inner(scope, Box::pin(task), ty, priority)
     }
 
     // a non-momorphic version of spawn_task_of_type, helps with binari sizes
@@ -172,6 +206,7 @@ impl Runtime {
         scope: ScopeId,
         pinned_task: Pin<Box<dyn Future<Output = ()>>>,
         ty: TaskType,
+        priority: Priority,
     ) -> Task {
         // Insert the task, temporarily holding a borrow on the tasks map
         let (task, task_id) = {
@@ -192,6 +227,7 @@ impl Runtime {
                         tx: self.sender.clone(),
                     })),
                     ty: RefCell::new(ty),
+                    priority: Cell::new(priority),
                 });
 
                 local_task = Some(new_task.clone());
@@ -261,6 +297,10 @@ impl Runtime {
         self.tasks.borrow().get(task.id).map(|t| t.scope)
     }
 
+    pub(crate) fn task_priority(&self, task: Task) -> Option<Priority> {
+        self.tasks.borrow().get(task.id).map(|t| t.priority.get())
+    }
+
     #[track_caller]
     pub(crate) fn handle_task_wakeup(&self, id: Task) -> Poll<()> {
         #[cfg(debug_assertions)]
@@ -281,6 +321,13 @@ impl Runtime {
             return Poll::Pending;
         }
 
+        // Tasks spawned with `Runtime::spawn` are not dropped with their scope. Drop them instead of polling them in a
+        // component that no longer exists
+        if self.get_state(task.scope).is_none() {
+            self.remove_task(id);
+            return Poll::Ready(());
+        }
+
         let mut cx = std::task::Context::from_waker(&task.waker);
 
         // poll the future with the scope on the stack
@@ -291,11 +338,9 @@ impl Runtime {
 
             if poll_result.is_ready() {
                 // Remove it from the scope so we dont try to double drop it when the scope dropes
-                self.get_state(task.scope)
-                    .unwrap()
-                    .spawned_tasks
-                    .borrow_mut()
-                    .remove(&id);
+                if let Some(scope) = self.get_state(task.scope) {
+                    scope.spawned_tasks.borrow_mut().remove(&id);
+                }
 
                 self.remove_task(id);
             }
@@ -330,6 +375,7 @@ impl Runtime {
                     dirty_tasks.remove(id);
                 }
             }
+            self.deferred_tasks.borrow_mut().remove(id);
         }
 
         task
@@ -350,6 +396,7 @@ pub(crate) struct LocalTask {
     task: RefCell<Pin<Box<dyn Future<Output = ()> + 'static>>>,
     waker: Waker,
     ty: RefCell<TaskType>,
+    priority: Cell<Priority>,
     active: Cell<bool>,
 }
 
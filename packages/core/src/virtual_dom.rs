@@ -4,19 +4,23 @@
 
 use crate::innerlude::Work;
 use crate::properties::RootProps;
+use crate::recycle::MountBuffers;
 use crate::root_wrapper::RootScopeWrapper;
 use crate::{
     arena::ElementId,
-    innerlude::{NoOpMutations, SchedulerMsg, ScopeOrder, ScopeState, VProps, WriteMutations},
+    innerlude::{
+        AllocationStats, NoOpMutations, SchedulerMsg, ScopeOrder, ScopeState, VProps,
+        WriteMutations,
+    },
     runtime::{Runtime, RuntimeGuard},
     scopes::ScopeId,
     ComponentFunction, Element, Mutations,
 };
-use crate::{Task, VComponent};
+use crate::{Priority, Task, VComponent};
 use futures_util::StreamExt;
 use slab::Slab;
 use std::collections::BTreeSet;
-use std::{any::Any, rc::Rc};
+use std::{any::Any, future::Future, pin::Pin, rc::Rc};
 use tracing::instrument;
 
 /// A virtual node system that progresses user events and diffs UI trees.
@@ -213,8 +217,13 @@ pub struct VirtualDom {
     pub(crate) resolved_scopes: Vec<ScopeId>,
 
     rx: futures_channel::mpsc::UnboundedReceiver<SchedulerMsg>,
+
+    // How to give the platform a chance to run before polling tasks in a lower priority lane
+    deferred_pause: Option<DeferredPause>,
 }
 
+type DeferredPause = Box<dyn Fn(Priority) -> Pin<Box<dyn Future<Output = ()>>>>;
+
 impl VirtualDom {
     /// Create a new VirtualDom with a component that does not have special props.
     ///
@@ -321,6 +330,7 @@ impl VirtualDom {
             scopes: Default::default(),
             dirty_scopes: Default::default(),
             resolved_scopes: Default::default(),
+            deferred_pause: None,
         };
 
         let root = VProps::new(
@@ -375,6 +385,19 @@ impl VirtualDom {
         self.base_scope().state().provide_any_context(context);
     }
 
+    /// Set how [`VirtualDom::wait_for_work`] pauses before it polls tasks in the [`Priority::Animation`] and
+    /// [`Priority::Idle`] lanes. The future should resolve once the platform had a chance to handle input and paint,
+    /// for example on the next animation frame or idle callback.
+    ///
+    /// Without a pause, the virtual dom only yields to the async executor once, which is enough for executors that
+    /// run other work between polls but not for the browser's event loop.
+    pub fn set_deferred_pause(
+        &mut self,
+        pause: impl Fn(Priority) -> Pin<Box<dyn Future<Output = ()>>> + 'static,
+    ) {
+        self.deferred_pause = Some(Box::new(pause));
+    }
+
     /// Manually mark a scope as requiring a re-render
     ///
     /// Whenever the Runtime "works", it will re-render this scope
@@ -386,6 +409,11 @@ impl VirtualDom {
         tracing::event!(tracing::Level::TRACE, "Marking scope {:?} as dirty", id);
         let order = ScopeOrder::new(scope.height(), id);
         drop(scope);
+        #[cfg(feature = "inspector")]
+        self.runtime
+            .profiler
+            .borrow_mut()
+            .mark(id, crate::innerlude::RenderReason::Updated);
         self.queue_scope(order);
     }
 
@@ -407,7 +435,14 @@ impl VirtualDom {
 
         let order = ScopeOrder::new(scope.height(), scope.id);
         drop(scope);
-        self.queue_task(task, order);
+        match self.runtime.task_priority(task).unwrap_or_default() {
+            Priority::UserInput => self.queue_task(task, order),
+            priority => self
+                .runtime
+                .deferred_tasks
+                .borrow_mut()
+                .queue(priority, task),
+        }
     }
 
     /// Wait for the scheduler to have any work.
@@ -432,25 +467,71 @@ impl VirtualDom {
         loop {
             // Process all events - Scopes are marked dirty, etc
             // Sometimes when wakers fire we get a slew of updates at once, so its important that we drain this completely
-            self.process_events();
+            self.queue_events();
 
             // Now that we have collected all queued work, we should check if we have any dirty scopes. If there are not, then we can poll any queued futures
             if self.has_dirty_scopes() {
                 return;
             }
 
+            self.poll_tasks();
+            if self.has_dirty_scopes() {
+                return;
+            }
+
             // Make sure we set the runtime since we're running user code
             let _runtime = RuntimeGuard::new(self.runtime.clone());
 
+            // Tasks in the lower priority lanes are only polled after the platform had a chance to run, so a task that
+            // keeps waking itself can't block the event loop
+            let next_priority = self.runtime.deferred_tasks.borrow().next_priority();
+            if let Some(priority) = next_priority {
+                self.wait_for_deferred_work(priority).await;
+                if !self.has_dirty_scopes() && !self.has_user_input_work() {
+                    self.poll_deferred_tasks();
+                }
+                continue;
+            }
+
             // There isn't any more work we can do synchronously. Wait for any new work to be ready
             self.wait_for_event().await;
         }
     }
 
+    /// Wait for the platform to be ready for work in a lower priority lane. Events that queue more important work end
+    /// the wait early, but tasks in the lower priority lanes that wake up keep waiting for the pause
+    async fn wait_for_deferred_work(&mut self, priority: Priority) {
+        use futures_util::future::{select, Either};
+
+        let mut pause = match &self.deferred_pause {
+            Some(pause) => pause(priority),
+            None => Box::pin(yield_now()),
+        };
+        loop {
+            let (msg, rest) = match select(pause, self.rx.next()).await {
+                Either::Left(_) => return,
+                Either::Right(next) => next,
+            };
+            let Some(msg) = msg else {
+                return;
+            };
+            self.handle_scheduler_msg(msg);
+            if self.has_dirty_scopes() || self.has_user_input_work() {
+                return;
+            }
+            pause = rest;
+        }
+    }
+
     /// Wait for the next event to trigger and add it to the queue
     #[instrument(skip(self), level = "trace", name = "VirtualDom::wait_for_event")]
     async fn wait_for_event(&mut self) {
-        match self.rx.next().await.expect("channel should never close") {
+        let msg = self.rx.next().await.expect("channel should never close");
+        self.handle_scheduler_msg(msg);
+    }
+
+    fn handle_scheduler_msg(&mut self, msg: SchedulerMsg) {
+        match msg {
             SchedulerMsg::Immediate(id) => self.mark_dirty(id),
             SchedulerMsg::TaskNotified(id) => {
                 // Instead of running the task immediately, we insert it into the runtime's task queue.
@@ -483,10 +564,21 @@ impl VirtualDom {
             return;
         }
 
-        self.poll_tasks()
+        loop {
+            self.poll_tasks();
+            if self.has_dirty_scopes() {
+                return;
+            }
+
+            // Finally, poll the tasks in the lower priority lanes. If they queue more important work, handle it first
+            self.poll_deferred_tasks();
+            if self.has_dirty_scopes() || !self.has_user_input_work() {
+                return;
+            }
+        }
     }
 
-    /// Poll any queued tasks
+    /// Poll any queued user input tasks and effects
     #[instrument(skip(self), level = "trace", name = "VirtualDom::poll_tasks")]
     fn poll_tasks(&mut self) {
         // Make sure we set the runtime since we're running user code
@@ -494,9 +586,7 @@ impl VirtualDom {
 
         // Keep polling tasks until there are no more effects or tasks to run
         // Or until we have no more dirty scopes
-        while !self.runtime.dirty_tasks.borrow().is_empty()
-            || !self.runtime.pending_effects.borrow().is_empty()
-        {
+        loop {
             // Next, run any queued tasks
             // We choose not to poll the deadline since we complete pretty quickly anyways
             while let Some(task) = self.pop_task() {
@@ -518,9 +608,41 @@ impl VirtualDom {
                     return;
                 }
             }
+
+            if !self.has_user_input_work() {
+                return;
+            }
         }
     }
 
+    /// Poll the tasks in the lower priority lanes. Tasks that wake themselves up are queued again, so only the tasks
+    /// that were waiting when we started are polled. Any tasks left over are polled the next time the scheduler looks
+    /// for work
+    fn poll_deferred_tasks(&mut self) {
+        // Make sure we set the runtime since we're running user code
+        let _runtime = RuntimeGuard::new(self.runtime.clone());
+
+        let waiting = self.runtime.deferred_tasks.borrow().len();
+        for _ in 0..waiting {
+            let Some(task) = self.runtime.deferred_tasks.borrow_mut().pop() else {
+                break;
+            };
+            let _ = self.runtime.handle_task_wakeup(task);
+
+            // A lower priority task may queue more important work. Handle that work first
+            self.queue_events();
+            if self.has_dirty_scopes() || self.has_user_input_work() {
+                return;
+            }
+        }
+    }
+
+    /// Check if there are any user input tasks or effects waiting to run
+    fn has_user_input_work(&self) -> bool {
+        !self.runtime.dirty_tasks.borrow().is_empty()
+            || !self.runtime.pending_effects.borrow().is_empty()
+    }
+
     /// Rebuild the virtualdom without handling any of the mutations
     ///
     /// This is useful for testing purposes and in cases where you render the output of the virtualdom without
@@ -562,31 +684,69 @@ impl VirtualDom {
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::rebuild")]
     pub fn rebuild(&mut self, to: &mut impl WriteMutations) {
         let _runtime = RuntimeGuard::new(self.runtime.clone());
-        let new_nodes = self
-            .runtime
-            .clone()
-            .while_rendering(|| self.run_scope(ScopeId::ROOT));
+        // The children of the root are run while their nodes are created, so they are rendering too
+        self.runtime.clone().while_rendering(|| {
+            let new_nodes = self.run_scope(ScopeId::ROOT);
 
-        self.scopes[ScopeId::ROOT.0].last_rendered_node = Some(new_nodes.clone());
+            self.scopes[ScopeId::ROOT.0].last_rendered_node = Some(new_nodes.clone());
 
-        // Rebuilding implies we append the created elements to the root
-        let m = self.create_scope(Some(to), ScopeId::ROOT, new_nodes, None);
+            // Rebuilding implies we append the created elements to the root
+            let m = self.create_scope(Some(to), ScopeId::ROOT, new_nodes, None);
 
-        to.append_children(ElementId(0), m);
+            to.append_children(ElementId(0), m);
+        });
+        #[cfg(feature = "inspector")]
+        self.runtime.flush_render_events();
     }
 
     /// Render whatever the VirtualDom has ready as fast as possible without requiring an executor to progress
     /// suspended subtrees.
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::render_immediate")]
     pub fn render_immediate(&mut self, to: &mut impl WriteMutations) {
+        self.render_with_deadline(to, || false);
+    }
+
+    /// Render whatever the VirtualDom has ready until there is no work left or the deadline has passed. The deadline
+    /// is checked after each scope is rerun or task is polled. If it returns `true`, the rest of the work stays queued
+    /// and is resumed the next time the VirtualDom renders.
+    ///
+    /// Returns `true` if all of the work was finished. If a large rerender is cut off, the renderer should apply the
+    /// mutations that were written so far, handle any input from the user, and call this again to resume.
+    ///
+    /// Stopping early means the DOM may show some components in their new state and others in their old state until the
+    /// render is resumed. Effects are not run until every dirty scope has been rerun.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # use std::time::{Duration, Instant};
+    /// # fn app() -> Element { rsx! { div {} } }
+    /// let mut dom = VirtualDom::new(app);
+    /// dom.rebuild_in_place();
+    ///
+    /// // Render for at most 5 milliseconds at a time
+    /// let start = Instant::now();
+    /// let finished = dom.render_with_deadline(&mut dioxus_core::NoOpMutations, || {
+    ///     start.elapsed() > Duration::from_millis(5)
+    /// });
+    /// ```
+    #[instrument(
+        skip(self, to, deadline),
+        level = "trace",
+        name = "VirtualDom::render_with_deadline"
+    )]
+    pub fn render_with_deadline(
+        &mut self,
+        to: &mut impl WriteMutations,
+        mut deadline: impl FnMut() -> bool,
+    ) -> bool {
         // Process any events that might be pending in the queue
         // Signals marked with .write() need a chance to be handled by the effect driver
         // This also processes futures which might progress into immediately rerunning a scope
         self.process_events();
 
         // Next, diff any dirty scopes
-        // We choose not to poll the deadline since we complete pretty quickly anyways
         let _runtime = RuntimeGuard::new(self.runtime.clone());
+        let mut finished = true;
         while let Some(work) = self.pop_work() {
             match work {
                 Work::PollTask(task) => {
@@ -601,9 +761,15 @@ impl VirtualDom {
                     });
                 }
             }
+
+            if deadline() {
+                finished = !self.has_dirty_scopes() && self.runtime.dirty_tasks.borrow().is_empty();
+                break;
+            }
         }
 
         self.runtime.finish_render();
+        finished
     }
 
     /// [`Self::render_immediate`] to a vector of mutations for testing purposes
@@ -738,6 +904,62 @@ impl VirtualDom {
         self.runtime.clone()
     }
 
+    /// Get counts of the buffers the virtual dom allocated while diffing and how many of them were reused from earlier
+    /// renders. This is useful to check the allocator pressure of large lists while profiling.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx! { div { "Hello, world!" } }
+    /// }
+    ///
+    /// let mut dom = VirtualDom::new(app);
+    /// dom.rebuild_in_place();
+    /// assert!(dom.allocation_stats().mounts_allocated > 0);
+    /// ```
+    pub fn allocation_stats(&self) -> AllocationStats {
+        self.runtime.recycler.borrow().stats()
+    }
+
+    /// Drop a virtual dom that is no longer needed and keep the buffers it recycled between renders for this virtual
+    /// dom. Nothing else is shared, so this virtual dom still starts without any state from the old one.
+    ///
+    /// Servers that render a new virtual dom for every request can use this to skip allocating the same buffers for
+    /// every request:
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx! {
+    ///         for i in 0..10 {
+    ///             div { key: "{i}", "{i}" }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut previous = VirtualDom::new(app);
+    /// previous.rebuild_in_place();
+    ///
+    /// let mut dom = VirtualDom::new(app);
+    /// dom.reuse_buffers_from(previous);
+    /// dom.rebuild_in_place();
+    /// assert!(dom.allocation_stats().mounts_reused > 0);
+    /// ```
+    pub fn reuse_buffers_from(&mut self, previous: VirtualDom) {
+        let runtime = previous.runtime();
+        drop(previous);
+        let mut buffers = runtime.recycler.take();
+        // Blocks that were still mounted when the old virtual dom was dropped never returned their buffers
+        for (_, mount) in runtime.mounts.take() {
+            buffers.recycle_mount(MountBuffers {
+                root_ids: mount.root_ids,
+                mounted_attributes: mount.mounted_attributes,
+                mounted_dynamic_nodes: mount.mounted_dynamic_nodes,
+            });
+        }
+        self.runtime.recycler.borrow_mut().absorb(buffers);
+    }
+
     /// Handle an event with the Virtual Dom. This method is deprecated in favor of [VirtualDom::runtime().handle_event] and will be removed in a future release.
     #[deprecated = "Use [VirtualDom::runtime().handle_event] instead"]
     pub fn handle_event(&self, name: &str, event: Rc<dyn Any>, element: ElementId, bubbling: bool) {
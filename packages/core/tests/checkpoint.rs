@@ -0,0 +1,53 @@
+//! Verify that checkpoints save and restore the registered parts of the app
+
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::prelude::*;
+
+#[test]
+fn checkpoint_round_trips() {
+    let mut checkpoint = Checkpoint::new();
+    checkpoint.insert("empty", "");
+    checkpoint.insert("with:colons", "12:34");
+    checkpoint.insert("unicode", "héllo 🌍\nworld");
+
+    let blob = checkpoint.to_string();
+    assert_eq!(blob.parse::<Checkpoint>().unwrap(), checkpoint);
+
+    assert!("3:abc".parse::<Checkpoint>().is_err());
+    assert!("10:abc1:d".parse::<Checkpoint>().is_err());
+}
+
+#[test]
+fn restored_before_registration() {
+    thread_local! {
+        static RESTORED: RefCell<Option<String>> = const { RefCell::new(None) };
+    }
+
+    fn app() -> Element {
+        let state = use_hook(|| Rc::new(RefCell::new(String::from("initial"))));
+        use_hook(|| {
+            let save = state.clone();
+            register_checkpoint(
+                "state",
+                move || Some(save.borrow().clone()),
+                move |saved| RESTORED.with(|restored| *restored.borrow_mut() = Some(saved.into())),
+            );
+        });
+        rsx! {}
+    }
+
+    let mut checkpoint = Checkpoint::new();
+    checkpoint.insert("state", "saved");
+    checkpoint.insert("unmounted", "kept");
+
+    let mut dom = VirtualDom::new(app).with_checkpoint(checkpoint);
+    dom.rebuild_in_place();
+
+    RESTORED.with(|restored| assert_eq!(restored.borrow().as_deref(), Some("saved")));
+
+    // Keys that were never registered are kept in the next checkpoint
+    let next = dom.checkpoint();
+    assert_eq!(next.get("state"), Some("initial"));
+    assert_eq!(next.get("unmounted"), Some("kept"));
+}
@@ -77,3 +77,56 @@ fn problematic_child() -> Element {
             } }
     }
 }
+
+#[test]
+fn only_the_dispatched_element_is_at_target() {
+    thread_local! {
+        static CALLS: std::cell::RefCell<Vec<(&'static str, bool)>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    fn record(name: &'static str, evt: &MouseEvent) {
+        CALLS.with(|calls| calls.borrow_mut().push((name, evt.is_at_target())));
+    }
+
+    fn app() -> Element {
+        rsx! {
+            div { onclick: move |evt| record("outer", &evt),
+                div { onclick: move |evt| record("inner", &evt) }
+                Child {}
+            }
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        rsx! {
+            button { onclick: move |evt| record("button", &evt) }
+        }
+    }
+
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let click = || {
+        Event::new(
+            Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+            true,
+        )
+    };
+
+    // Clicking the inner div bubbles to the outer div in the same template
+    dom.runtime().handle_event("click", click(), ElementId(2));
+    assert_eq!(
+        CALLS.with(|calls| calls.take()),
+        [("inner", true), ("outer", false)]
+    );
+
+    // Clicking the button bubbles out of the child component
+    dom.runtime().handle_event("click", click(), ElementId(3));
+    assert_eq!(
+        CALLS.with(|calls| calls.take()),
+        [("button", true), ("outer", false)]
+    );
+}
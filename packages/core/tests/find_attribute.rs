@@ -0,0 +1,62 @@
+use dioxus::dioxus_core::Mutation;
+use dioxus::prelude::*;
+use dioxus_core::{AttributeValue, ElementId};
+
+fn listener_id(edits: &[Mutation], event: &str) -> ElementId {
+    edits
+        .iter()
+        .find_map(|edit| match edit {
+            Mutation::NewEventListener { name, id } if name == event => Some(*id),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn finds_attributes_on_ancestors() {
+    fn app() -> Element {
+        let private = true;
+        rsx! {
+            form { "data-private": "true",
+                input { oninput: |_| {} }
+            }
+            div { "data-private": private,
+                Child {}
+            }
+            button { onclick: |_| {} }
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        rsx! {
+            input { "type": "password", onkeydown: |_| {} }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let edits = dom.rebuild_to_vec().edits;
+    let runtime = dom.runtime();
+
+    // Static attributes on a parent element in the same template
+    assert_eq!(
+        runtime.find_attribute(listener_id(&edits, "input"), "data-private"),
+        Some(AttributeValue::Text("true".to_string()))
+    );
+
+    // Dynamic attributes on a parent element in another component
+    let password = listener_id(&edits, "keydown");
+    assert_eq!(
+        runtime.find_attribute(password, "data-private"),
+        Some(AttributeValue::Bool(true))
+    );
+    assert_eq!(
+        runtime.find_attribute(password, "type"),
+        Some(AttributeValue::Text("password".to_string()))
+    );
+
+    assert_eq!(
+        runtime.find_attribute(listener_id(&edits, "click"), "data-private"),
+        None
+    );
+}
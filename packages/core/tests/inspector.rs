@@ -0,0 +1,127 @@
+#![cfg(feature = "inspector")]
+//! Verify the component tree snapshots and render profiles devtools read
+
+use dioxus::prelude::*;
+use dioxus_core::{NoOpMutations, RenderEvent, RenderReason};
+use std::{cell::RefCell, rc::Rc};
+
+fn app() -> Element {
+    let count = use_context_provider(|| Signal::new(0));
+    rsx! {
+        Half { value: count() / 2 }
+        Static {}
+    }
+}
+
+#[component]
+fn Half(value: i32) -> Element {
+    let label = use_hook(|| String::from("half"));
+    rsx! { "{label}: {value}" }
+}
+
+#[component]
+fn Static() -> Element {
+    rsx! { "static" }
+}
+
+/// Component names are the full path of the component function
+fn short_name(name: &'static str) -> &'static str {
+    name.rsplit("::").next().unwrap()
+}
+
+fn renders(events: &[RenderEvent]) -> Vec<(&'static str, RenderReason)> {
+    events
+        .iter()
+        .map(|event| (short_name(event.name), event.reason))
+        .collect()
+}
+
+#[test]
+fn component_tree_lists_components_in_order() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let tree = dom.component_tree();
+    let names: Vec<_> = tree
+        .iter()
+        .map(|component| short_name(component.name))
+        .collect();
+    let app_index = names.iter().position(|name| *name == "root").unwrap();
+    assert_eq!(names[app_index + 1..], ["Half", "Static"]);
+    assert_eq!(tree[0].id, ScopeId::ROOT);
+    assert_eq!(tree[0].parent, None);
+
+    let app = &tree[app_index];
+    assert_eq!(
+        app.children,
+        [tree[app_index + 1].id, tree[app_index + 2].id]
+    );
+    assert_eq!(app.hooks, [std::any::type_name::<Signal<i32>>()]);
+    assert_eq!(app.render_count, 1);
+    // Render profiles are only recorded while profiling
+    assert_eq!(app.profile, None);
+
+    let half = &tree[app_index + 1];
+    assert_eq!(half.parent, Some(app.id));
+    assert_eq!(half.height, app.height + 1);
+    assert_eq!(half.props, std::any::type_name::<HalfProps>());
+    assert_eq!(half.hooks, [std::any::type_name::<String>()]);
+}
+
+#[test]
+fn profiler_records_why_components_rendered() {
+    let mut dom = VirtualDom::new(app);
+    dom.set_profiling(true);
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    dom.in_runtime(|| {
+        let events = events.clone();
+        Runtime::current()
+            .unwrap()
+            .add_render_listener(move |new| events.borrow_mut().extend_from_slice(new))
+    });
+
+    dom.rebuild_in_place();
+    let mounted = renders(&events.take());
+    assert!(mounted.ends_with(&[
+        ("root", RenderReason::Mounted),
+        ("Half", RenderReason::Mounted),
+        ("Static", RenderReason::Mounted),
+    ]));
+
+    // Half gets the same props, so only the app rerenders
+    let mut count = dom.in_runtime(|| ScopeId::APP.has_context::<Signal<i32>>().unwrap());
+    dom.in_runtime(|| count.set(1));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(renders(&events.take()), [("root", RenderReason::Updated)]);
+
+    dom.in_runtime(|| count.set(2));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(
+        renders(&events.take()),
+        [
+            ("root", RenderReason::Updated),
+            ("Half", RenderReason::PropsChanged)
+        ]
+    );
+
+    let tree = dom.component_tree();
+    let half = tree
+        .iter()
+        .find(|component| short_name(component.name) == "Half")
+        .unwrap();
+    let profile = half.profile.unwrap();
+    assert_eq!(profile.renders, 2);
+    assert_eq!(profile.last_reason, RenderReason::PropsChanged);
+    assert!(profile.total_duration >= profile.last_duration);
+
+    // Stopping the profiler discards what it recorded
+    dom.set_profiling(false);
+    dom.in_runtime(|| count.set(3));
+    dom.render_immediate(&mut NoOpMutations);
+    assert!(events.take().is_empty());
+    assert!(dom
+        .component_tree()
+        .iter()
+        .all(|component| component.profile.is_none()));
+}
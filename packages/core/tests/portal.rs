@@ -0,0 +1,121 @@
+//! Portals keep their children in the component tree while renderers move them into another container
+
+use dioxus::dioxus_core::{
+    AttributeValue, ElementId, Mutation, Mutations, PortalTarget, PORTAL_ATTRIBUTE,
+};
+use dioxus::prelude::*;
+use std::{any::Any, cell::Cell, rc::Rc};
+
+/// The elements that got a click listener
+fn click_listeners(mutations: &Mutations) -> Vec<ElementId> {
+    mutations
+        .edits
+        .iter()
+        .filter_map(|mutation| match mutation {
+            Mutation::NewEventListener { name, id } if name == "click" => Some(*id),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn portals_render_in_place_on_the_server() {
+    fn app() -> Element {
+        use_context_provider(|| String::from("from the parent"));
+        rsx! {
+            main {
+                Portal { target: "modal-root", Child {} }
+            }
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        let message = use_context::<String>();
+        rsx! { "{message}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<main><div style="display:contents;" data-dioxus-portal="modal-root">from the parent</div></main>"#
+    );
+}
+
+#[test]
+fn renderers_receive_the_target() {
+    fn app() -> Element {
+        let mut target = use_signal(|| "first");
+        rsx! {
+            button { onclick: move |_| target.set("second") }
+            Portal { target: target(), "content" }
+        }
+    }
+
+    let targets =
+        |mutations: &[Mutation]| {
+            mutations
+                .iter()
+                .filter_map(|mutation| match mutation {
+                    Mutation::SetAttribute {
+                        name, value: AttributeValue::Text(target), ..
+                    } if *name == PORTAL_ATTRIBUTE => Some(target.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+    assert_eq!(targets(&mutations.edits), ["first"]);
+
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+    let event = Event::new(
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+        true,
+    );
+    dom.runtime()
+        .handle_event("click", event, click_listeners(&mutations)[0]);
+    assert_eq!(targets(&dom.render_immediate_to_vec().edits), ["second"]);
+}
+
+#[test]
+fn events_bubble_out_of_portals() {
+    thread_local! {
+        static CLICKS: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            div {
+                onclick: move |_| CLICKS.with(|clicks| clicks.set((clicks.get().0 + 1, clicks.get().1))),
+                Portal { target: "modal-root",
+                    button {
+                        onclick: move |_| CLICKS.with(|clicks| clicks.set((clicks.get().0, clicks.get().1 + 1))),
+                    }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+    // The button inside of the portal is created after the div around it
+    let button = *click_listeners(&mutations).iter().max().unwrap();
+
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+    let event = Event::new(
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+        true,
+    );
+    dom.runtime().handle_event("click", event, button);
+    assert_eq!(CLICKS.with(Cell::get), (1, 1));
+}
+
+#[test]
+fn element_targets_compare_their_handles() {
+    assert!(PortalTarget::element(1) == PortalTarget::element(1));
+    assert!(PortalTarget::element(1) != PortalTarget::element(2));
+    assert!(PortalTarget::element("modal-root") != PortalTarget::from("modal-root"));
+}
@@ -0,0 +1,115 @@
+//! Verify that tasks in lower priority lanes are polled after more important work, and that renders can be sliced
+
+use std::cell::{Cell, RefCell};
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+#[test]
+fn lower_priority_lanes_poll_last() {
+    thread_local! {
+        static POLLED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn app() -> Element {
+        use_hook(|| {
+            let poll = |name| POLLED.with(|polled| polled.borrow_mut().push(name));
+            spawn_idle(async move { poll("idle") });
+            spawn_with_priority(Priority::Animation, async move { poll("animation") });
+            spawn(async move { poll("user input") });
+        });
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.process_events();
+
+    POLLED.with(|polled| assert_eq!(*polled.borrow(), ["user input", "animation", "idle"]));
+}
+
+#[test]
+fn render_with_deadline_resumes() {
+    thread_local! {
+        static RENDERS: Cell<usize> = const { Cell::new(0) };
+        static CHILDREN: RefCell<Vec<ScopeId>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            for i in 0..10 {
+                Child { key: "{i}" }
+            }
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        use_hook(|| {
+            CHILDREN.with(|children| children.borrow_mut().push(current_scope_id().unwrap()))
+        });
+        RENDERS.with(|renders| renders.set(renders.get() + 1));
+        rsx! { div {} }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    RENDERS.with(|renders| renders.set(0));
+
+    let children = CHILDREN.with(|children| children.borrow().clone());
+    for child in &children {
+        dom.mark_dirty(*child);
+    }
+
+    // Stop after three scopes have rerun
+    let mut checks = 0;
+    let finished = dom.render_with_deadline(&mut NoOpMutations, || {
+        checks += 1;
+        checks == 3
+    });
+    assert!(!finished);
+    RENDERS.with(|renders| assert_eq!(renders.get(), 3));
+
+    // The rest of the scopes are rerun when the render resumes
+    assert!(dom.render_with_deadline(&mut NoOpMutations, || false));
+    RENDERS.with(|renders| assert_eq!(renders.get(), 10));
+}
+
+#[tokio::test]
+async fn self_waking_deferred_tasks_yield_to_the_platform() {
+    thread_local! {
+        static PAUSES: RefCell<Vec<Priority>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn app() -> Element {
+        use_hook(|| {
+            spawn_with_priority(Priority::Animation, async move {
+                loop {
+                    tokio::task::yield_now().await;
+                }
+            })
+        });
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.set_deferred_pause(|priority| {
+        PAUSES.with(|pauses| pauses.borrow_mut().push(priority));
+        Box::pin(tokio::time::sleep(std::time::Duration::from_millis(5)))
+    });
+    dom.rebuild_in_place();
+
+    // The animation task never finishes, but waiting for work still gives other futures a chance to run
+    tokio::select! {
+        _ = dom.wait_for_work() => panic!("the animation task doesn't queue any work"),
+        _ = tokio::time::sleep(std::time::Duration::from_millis(50)) => {}
+    }
+
+    PAUSES.with(|pauses| {
+        let pauses = pauses.borrow();
+        assert!(!pauses.is_empty());
+        assert!(pauses
+            .iter()
+            .all(|priority| *priority == Priority::Animation));
+    });
+}
@@ -0,0 +1,81 @@
+//! Verify that buffers from earlier renders are reused
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+#[test]
+fn removed_mounts_are_reused() {
+    fn app() -> Element {
+        let show = generation().is_multiple_of(2);
+        rsx! {
+            if show {
+                for i in 0..10 {
+                    div { key: "{i}", "{i}" }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let first = dom.allocation_stats();
+    assert_eq!(first.mounts_reused, 0);
+
+    // Remove the list, then render it again
+    for _ in 0..2 {
+        dom.mark_dirty(ScopeId::APP);
+        dom.render_immediate(&mut NoOpMutations);
+    }
+
+    assert!(dom.allocation_stats().mounts_reused >= 10);
+}
+
+#[test]
+fn keyed_scratch_is_reused() {
+    fn app() -> Element {
+        let mut items: Vec<usize> = (0..10).collect();
+        items.rotate_left(generation() % 10);
+        items.swap(2, 7);
+        rsx! {
+            for item in items {
+                div { key: "{item}", "{item}" }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    for _ in 0..4 {
+        dom.mark_dirty(ScopeId::APP);
+        dom.render_immediate(&mut NoOpMutations);
+    }
+
+    let stats = dom.allocation_stats();
+    assert_eq!(stats.scratch_allocated, 1);
+    assert!(stats.scratch_reused >= 3);
+}
+
+#[test]
+fn buffers_move_to_a_new_virtual_dom() {
+    fn app() -> Element {
+        rsx! {
+            for i in 0..10 {
+                div { key: "{i}", "{i}" }
+            }
+        }
+    }
+
+    let mut previous = VirtualDom::new(app);
+    previous.rebuild_in_place();
+    let allocated = previous.allocation_stats().mounts_allocated;
+
+    let mut dom = VirtualDom::new(app);
+    dom.reuse_buffers_from(previous);
+    dom.rebuild_in_place();
+
+    // The new virtual dom renders the same blocks, so every buffer it needs was already allocated
+    let stats = dom.allocation_stats();
+    assert_eq!(stats.mounts_allocated, 0);
+    assert_eq!(stats.mounts_reused, allocated);
+}
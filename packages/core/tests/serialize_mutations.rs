@@ -0,0 +1,110 @@
+//! Mutations can be serialized so they can be sent to a renderer in another process
+
+use dioxus::dioxus_core::{AttributeValue, Mutation, Mutations};
+use dioxus::prelude::*;
+
+fn app() -> Element {
+    let class = "hello";
+    rsx! {
+        div { class: "{class}", "data-count": 1, hidden: false,
+            "text"
+            for i in 0..2 {
+                p { "{i}" }
+            }
+        }
+    }
+}
+
+// Templates may be compared by pointer, so compare the nodes of the deserialized templates by value
+fn assert_same_mutations(deserialized: &Mutations, mutations: &Mutations) {
+    assert_eq!(deserialized.edits, mutations.edits);
+    assert_eq!(deserialized.templates.len(), mutations.templates.len());
+    for (deserialized, template) in deserialized.templates.iter().zip(&mutations.templates) {
+        assert_eq!(deserialized.roots, template.roots);
+        assert_eq!(deserialized.node_paths, template.node_paths);
+        assert_eq!(deserialized.attr_paths, template.attr_paths);
+    }
+}
+
+#[test]
+fn mutations_round_trip_through_json() {
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+    assert!(!mutations.templates.is_empty());
+    assert!(mutations.edits.iter().any(|edit| matches!(
+        edit,
+        Mutation::SetAttribute { value: AttributeValue::Text(_), .. }
+    )));
+
+    let json = serde_json::to_string(&mutations).unwrap();
+    let deserialized: Mutations = serde_json::from_str(&json).unwrap();
+    assert_same_mutations(&deserialized, &mutations);
+}
+
+#[test]
+fn mutations_round_trip_through_cbor() {
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&mutations, &mut bytes).unwrap();
+    let deserialized: Mutations = ciborium::from_reader(bytes.as_slice()).unwrap();
+    assert_same_mutations(&deserialized, &mutations);
+}
+
+#[test]
+fn templates_are_only_included_once() {
+    fn app() -> Element {
+        rsx! {
+            for i in 0..3 {
+                p { "{i}" }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+    let loads = mutations
+        .edits
+        .iter()
+        .filter(|edit| matches!(edit, Mutation::LoadTemplate { .. }))
+        .count();
+    assert!(loads > mutations.templates.len());
+}
+
+#[test]
+fn listeners_cannot_be_serialized() {
+    // Listeners are owned by a scope, so they can only be created inside the runtime
+    let dom = VirtualDom::new(app);
+    dom.in_runtime(|| {
+        dom.runtime().on_scope(ScopeId::ROOT, || {
+            let listener = AttributeValue::listener(|_: Event<()>| {});
+            assert!(serde_json::to_string(&listener).is_err());
+        })
+    });
+}
+
+#[test]
+fn deserializing_the_same_mutations_reuses_their_strings() {
+    let mut dom = VirtualDom::new(app);
+    let json = serde_json::to_string(&dom.rebuild_to_vec()).unwrap();
+    let first: Mutations = serde_json::from_str(&json).unwrap();
+    let second: Mutations = serde_json::from_str(&json).unwrap();
+
+    let names = |mutations: &Mutations| {
+        mutations
+            .edits
+            .iter()
+            .filter_map(|edit| match edit {
+                Mutation::SetAttribute { name, .. } => Some(*name as *const str),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    assert!(!names(&first).is_empty());
+    assert_eq!(names(&first), names(&second));
+    assert!(std::ptr::eq(
+        first.templates[0].roots,
+        second.templates[0].roots
+    ));
+}
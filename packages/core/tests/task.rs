@@ -126,3 +126,77 @@ async fn yield_now_works() {
 
     SEQUENCE.with(|s| assert_eq!(s.borrow().len(), 20));
 }
+
+/// Prove that scoped tasks are dropped with their component and never call back into it
+#[tokio::test]
+async fn spawn_local_scoped_ignores_unmounted_components() {
+    use futures_channel::oneshot;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static SENDERS: RefCell<Vec<oneshot::Sender<usize>>> = const { RefCell::new(Vec::new()) };
+        static COMPLETED: RefCell<Vec<(usize, usize)>> = const { RefCell::new(Vec::new()) };
+        static DROPPED: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    }
+
+    struct DropGuard(usize);
+
+    impl Drop for DropGuard {
+        fn drop(&mut self) {
+            DROPPED.with_borrow_mut(|dropped| dropped.push(self.0));
+        }
+    }
+
+    fn app() -> Element {
+        let show_first = use_context_provider(|| Signal::new(true));
+        rsx! {
+            if show_first() {
+                Child { id: 0 }
+            }
+            Child { id: 1 }
+        }
+    }
+
+    #[component]
+    fn Child(id: usize) -> Element {
+        let mut value = use_signal(|| 0);
+        use_hook(|| {
+            let (tx, rx) = oneshot::channel();
+            SENDERS.with_borrow_mut(|senders| senders.push(tx));
+            let guard = DropGuard(id);
+            spawn_local_scoped(
+                async move {
+                    let _guard = guard;
+                    rx.await.unwrap()
+                },
+                move |received| {
+                    value.set(received);
+                    COMPLETED.with_borrow_mut(|completed| completed.push((id, received)));
+                },
+            );
+        });
+        rsx! { "{value}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    // Unmounting the first child drops its pending task
+    let mut show_first = dom.in_runtime(|| ScopeId::APP.has_context::<Signal<bool>>().unwrap());
+    dom.in_runtime(|| show_first.set(false));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(DROPPED.take(), [0]);
+
+    for (index, sender) in SENDERS.take().into_iter().enumerate() {
+        // The receiver of the first child was dropped with its task
+        assert_eq!(sender.send(index + 10).is_ok(), index == 1);
+    }
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(COMPLETED.take(), [(1, 11)]);
+    assert_eq!(DROPPED.take(), [1]);
+}
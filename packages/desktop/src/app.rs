@@ -7,7 +7,9 @@ use crate::{
     shortcut::ShortcutRegistry,
     webview::WebviewInstance,
 };
-use dioxus_core::{ElementId, VirtualDom};
+use dioxus_core::{ElementId, ScopeId, VirtualDom};
+use dioxus_history::History;
+use dioxus_hooks::{FileStorage, MemoryStorage, PersistentStorage};
 use dioxus_html::PlatformEventData;
 use std::{
     any::Any,
@@ -51,6 +53,8 @@ pub(crate) struct SharedContext {
     pub(crate) shortcut_manager: ShortcutRegistry,
     pub(crate) proxy: EventLoopProxy<UserWindowEvent>,
     pub(crate) target: EventLoopWindowTarget<UserWindowEvent>,
+    /// The storage persistent state is saved in. Every window shares it so they see each other's changes
+    pub(crate) storage: PersistentStorage,
 }
 
 impl App {
@@ -60,6 +64,11 @@ impl App {
             .take()
             .unwrap_or_else(|| EventLoopBuilder::<UserWindowEvent>::with_user_event().build());
 
+        let storage = PersistentStorage::new(
+            FileStorage::new(cfg.storage_dir()),
+            MemoryStorage::default(),
+        );
+
         let app = Self {
             window_behavior: cfg.last_window_close_behavior,
             is_visible_before_start: true,
@@ -75,6 +84,7 @@ impl App {
                 shortcut_manager: ShortcutRegistry::new(),
                 proxy: event_loop.create_proxy(),
                 target: event_loop.clone(),
+                storage,
             }),
         };
 
@@ -483,12 +493,20 @@ impl App {
                 return;
             };
 
+            // Save the route so the app doesn't jump back to the index every time it is rebuilt
+            let route = webview.dom.in_runtime(|| {
+                ScopeId::ROOT
+                    .consume_context::<Rc<dyn History>>()
+                    .map(|history| history.current_route())
+            });
+
             let state = PreservedWindowState {
                 x,
                 y,
                 width: size.width.max(200),
                 height: size.height.saturating_sub(adjustment).max(200),
                 monitor: monitor_name.to_string(),
+                route,
             };
 
             // Yes... I know... we're loading a file that might not be ours... but it's a debug feature
@@ -532,6 +550,15 @@ impl App {
                 if explicit_inner_size.is_none() {
                     window.set_inner_size(tao::dpi::PhysicalSize::new(size.0, size.1));
                 }
+
+                // The app hasn't rendered yet, so the router starts at the restored route
+                if let Some(route) = state.route {
+                    webview.dom.in_runtime(|| {
+                        if let Some(history) = ScopeId::ROOT.consume_context::<Rc<dyn History>>() {
+                            history.replace(route);
+                        }
+                    });
+                }
             }
         }
     }
@@ -570,6 +597,8 @@ struct PreservedWindowState {
     width: u32,
     height: u32,
     monitor: String,
+    #[serde(default)]
+    route: Option<String>,
 }
 
 /// Hide the last window when using LastWindowHides.
@@ -127,6 +127,21 @@ impl Config {
         self
     }
 
+    /// Get the directory persistent state is saved in. This is the `storage` folder in the data directory, or in a
+    /// folder named after the executable in the platform's local data directory if no data directory is set.
+    pub(crate) fn storage_dir(&self) -> PathBuf {
+        let data_dir = self.data_dir.clone().unwrap_or_else(|| {
+            let name = std::env::current_exe()
+                .ok()
+                .and_then(|exe| exe.file_stem().map(|stem| stem.to_os_string()))
+                .unwrap_or_else(|| "dioxus".into());
+            dirs::data_local_dir()
+                .unwrap_or_else(std::env::temp_dir)
+                .join(name)
+        });
+        data_dir.join("storage")
+    }
+
     /// Set whether or not the right-click context menu should be disabled.
     pub fn with_disable_context_menu(mut self, disable: bool) -> Self {
         self.disable_context_menu = disable;
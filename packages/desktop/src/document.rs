@@ -1,8 +1,8 @@
 use crate::{query::Query, DesktopContext, WeakDesktopContext};
 use dioxus_core::prelude::queue_effect;
 use dioxus_document::{
-    create_element_in_head, Document, Eval, EvalError, Evaluator, LinkProps, MetaProps,
-    ScriptProps, StyleProps,
+    create_element_in_head, Capabilities, Document, Eval, EvalError, Evaluator, LinkProps,
+    MetaProps, ScriptProps, StyleProps,
 };
 
 use generational_box::{AnyStorage, GenerationalBox, UnsyncStorage};
@@ -78,6 +78,15 @@ impl Document for DesktopDocument {
             myself.eval(create_element_in_head("link", &props.attributes(), None));
         });
     }
+
+    fn capabilities(&self) -> Capabilities {
+        let mut capabilities = Capabilities::default();
+        // The webview exposes the clipboard api and the app has direct access to the file system
+        capabilities.clipboard = true;
+        capabilities.file_system = true;
+        capabilities.touch = cfg!(any(target_os = "android", target_os = "ios"));
+        capabilities
+    }
 }
 
 /// Represents a desktop-target's JavaScript evaluator.
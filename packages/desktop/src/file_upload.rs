@@ -164,15 +164,36 @@ impl HasFormData for DesktopFileUploadForm {
     }
 }
 
+type FileDropListener = Box<dyn FnMut(&DragDropEvent)>;
+
 #[derive(Default, Clone)]
 pub struct NativeFileHover {
     event: Rc<RefCell<Option<DragDropEvent>>>,
+    listeners: Rc<RefCell<Vec<(usize, FileDropListener)>>>,
+    next_listener: Rc<Cell<usize>>,
 }
 impl NativeFileHover {
     pub fn set(&self, event: DragDropEvent) {
+        for (_, listener) in self.listeners.borrow_mut().iter_mut() {
+            listener(&event);
+        }
         self.event.borrow_mut().replace(event);
     }
 
+    /// Add a listener that is called with every drag and drop event from the OS
+    pub(crate) fn add_listener(&self, listener: FileDropListener) -> usize {
+        let id = self.next_listener.get();
+        self.next_listener.set(id + 1);
+        self.listeners.borrow_mut().push((id, listener));
+        id
+    }
+
+    pub(crate) fn remove_listener(&self, id: usize) {
+        self.listeners
+            .borrow_mut()
+            .retain(|(listener, _)| *listener != id);
+    }
+
     pub fn current(&self) -> Option<DragDropEvent> {
         self.event.borrow_mut().clone()
     }
@@ -11,7 +11,7 @@ use dioxus_core::{
 
 use dioxus_hooks::use_callback;
 use tao::{event::Event, event_loop::EventLoopWindowTarget};
-use wry::RequestAsyncResponder;
+use wry::{DragDropEvent, RequestAsyncResponder};
 
 /// Get an imperative handle to the current window
 pub fn use_window() -> DesktopContext {
@@ -88,6 +88,48 @@ pub fn use_tray_icon_event_handler(
     })
 }
 
+/// Register an event handler that runs when files are dragged over or dropped on the window.
+///
+/// Unlike the `ondrop` event of an element, the handler receives the paths of the files from the OS and runs for
+/// drops anywhere in the window.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::desktop::{use_file_drop_handler, wry::DragDropEvent};
+///
+/// fn App() -> Element {
+///     let mut dropped = use_signal(Vec::new);
+///     use_file_drop_handler(move |event| {
+///         if let DragDropEvent::Drop { paths, .. } = event {
+///             dropped.set(paths.clone());
+///         }
+///     });
+///     rsx! {
+///         for path in dropped.iter() {
+///             p { "{path.display()}" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_file_drop_handler(mut handler: impl FnMut(&DragDropEvent) + 'static) {
+    use dioxus_core::prelude::current_scope_id;
+
+    // Capture the current runtime and scope ID.
+    let runtime = Runtime::current().unwrap();
+    let scope_id = current_scope_id().unwrap();
+
+    use_hook_with_cleanup(
+        move || {
+            let file_hover = window().file_hover.clone();
+            let id = file_hover.add_listener(Box::new(move |event| {
+                runtime.on_scope(scope_id, || handler(event))
+            }));
+            (file_hover, id)
+        },
+        move |(file_hover, id)| file_hover.remove_listener(id),
+    );
+}
+
 /// Provide a callback to handle asset loading yourself.
 ///
 /// The callback takes a path as requested by the web view, and it should return `Some(response)`
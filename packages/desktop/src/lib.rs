@@ -41,6 +41,18 @@ pub use wry;
 #[cfg(not(any(target_os = "ios", target_os = "android")))]
 pub use muda;
 
+// Reexport rfd for native file dialogs on the platforms it supports
+#[cfg(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+pub use rfd;
+
 // Tray icon
 #[cfg(any(target_os = "windows", target_os = "linux", target_os = "macos"))]
 pub mod trayicon;
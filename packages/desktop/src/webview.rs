@@ -13,11 +13,11 @@ use crate::{
 };
 use crate::{document::DesktopDocument, WeakDesktopContext};
 use base64::prelude::BASE64_STANDARD;
-use dioxus_core::{Runtime, ScopeId, VirtualDom};
+use dioxus_core::{prelude::has_context, Runtime, ScopeId, VirtualDom};
 use dioxus_document::Document;
 use dioxus_history::{History, MemoryHistory};
 use dioxus_hooks::to_owned;
-use dioxus_html::{HasFileData, HtmlEvent, PlatformEventData};
+use dioxus_html::{EventRecorder, HasFileData, HtmlEvent, PlatformEventData};
 use futures_util::{pin_mut, FutureExt};
 use std::cell::OnceCell;
 use std::sync::Arc;
@@ -102,6 +102,14 @@ impl WebviewEdits {
     }
 
     pub fn handle_html_event(&self, event: HtmlEvent) -> SynchronousEventResponse {
+        // Record the event if the app opted into recording. This runs inside the runtime so the recorder can check
+        // if the target element is private
+        self.runtime.on_scope(ScopeId::APP, || {
+            if let Some(recorder) = has_context::<EventRecorder>() {
+                recorder.record_event(&event);
+            }
+        });
+
         let HtmlEvent {
             element,
             name,
@@ -425,6 +433,7 @@ impl WebviewInstance {
             ScopeId::ROOT.provide_context(desktop_context.clone());
             ScopeId::ROOT.provide_context(provider);
             ScopeId::ROOT.provide_context(history_provider);
+            ScopeId::ROOT.provide_context(shared.storage.clone());
         });
 
         WebviewInstance {
@@ -465,10 +474,24 @@ impl WebviewInstance {
             // lock the hack-ed in lock sync wry has some thread-safety issues with event handlers
             let _lock = crate::android_sync_lock::android_runtime_lock();
 
-            self.edits
-                .wry_queue
-                .with_mutation_state_mut(|f| self.dom.render_immediate(f));
-            self.edits.wry_queue.send_edits();
+            // Attach the current route to the render spans so slow renders can be attributed to a route
+            let diff = tracing::info_span!("dioxus::diff", route = tracing::field::Empty);
+            let commit = tracing::info_span!("dioxus::commit", route = tracing::field::Empty);
+            if !diff.is_disabled() || !commit.is_disabled() {
+                let route = self
+                    .dom
+                    .runtime()
+                    .on_scope(ScopeId::ROOT, || dioxus_history::history().current_route());
+                diff.record("route", route.as_str());
+                commit.record("route", route.as_str());
+            }
+
+            diff.in_scope(|| {
+                self.edits
+                    .wry_queue
+                    .with_mutation_state_mut(|f| self.dom.render_immediate(f))
+            });
+            commit.in_scope(|| self.edits.wry_queue.send_edits());
         }
     }
 
@@ -81,6 +81,17 @@ impl LaunchBuilder {
         }
     }
 
+    /// Launch your liveview application.
+    #[cfg(all(feature = "liveview", feature = "server"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "liveview", feature = "server"))))]
+    pub fn liveview() -> LaunchBuilder {
+        LaunchBuilder {
+            launch_fn: |root, contexts, cfg| dioxus_liveview::launch::launch(root, contexts, cfg),
+            contexts: Vec::new(),
+            configs: Vec::new(),
+        }
+    }
+
     /// Launch your fullstack application.
     #[cfg(feature = "mobile")]
     #[cfg_attr(docsrs, doc(cfg(feature = "mobile")))]
@@ -186,6 +197,27 @@ impl LaunchBuilder {
             .push(Box::new(move || Box::new(state.clone())));
         self
     }
+
+    /// Render errors that are not caught by any error boundary in the app with a custom handler instead of the
+    /// default error message. This works the same way on every platform.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     rsx! {
+    ///         div { "Hello, world!" }
+    ///     }
+    /// }
+    ///
+    /// dioxus::LaunchBuilder::new()
+    ///     .with_error_handler(|errors| rsx! { "Something went wrong: {errors:?}" })
+    ///     .launch(app);
+    /// ```
+    pub fn with_error_handler(self, handler: fn(ErrorContext) -> Element) -> Self {
+        self.with_context(RootErrorHandler(handler))
+    }
 }
 
 impl LaunchBuilder {
@@ -9,7 +9,11 @@
 //! - `html`: (default) exports `dioxus-html` as the default elements to use in rsx
 //! - `hooks`: (default) re-exports `dioxus-hooks`
 //! - `hot-reload`: (default) enables hot rsx reloading in all renderers that support it
+//! - `graphql`: enables the GraphQL client hooks in `dioxus-hooks`
+//! - `realtime`: enables the WebSocket and server-sent event hooks in `dioxus-hooks`
 //! - `router`: exports the [router](https://dioxuslabs.com/learn/0.6/router) and enables any router features for the current platform
+//! - `components`: exports reusable components like [`VirtualList`](dioxus_components::VirtualList) from `dioxus-components`
+//! - `inspector`: exports the `DevtoolsOverlay` component that inspects the component tree and profiles renders
 //! - `third-party-renderer`: Just disables warnings about no active platform when no renderers are enabled
 //! - `logger`: Enable the default tracing subscriber for Dioxus apps
 //!
@@ -58,6 +62,10 @@ pub use dioxus_document as document;
 #[cfg_attr(docsrs, doc(cfg(feature = "document")))]
 pub use dioxus_history as history;
 
+#[cfg(feature = "i18n")]
+#[cfg_attr(docsrs, doc(cfg(feature = "i18n")))]
+pub use dioxus_i18n as i18n;
+
 #[cfg(feature = "html")]
 #[cfg_attr(docsrs, doc(cfg(feature = "html")))]
 pub use dioxus_html as html;
@@ -87,6 +95,10 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "document")))]
     pub use dioxus_history::{history, History};
 
+    #[cfg(feature = "i18n")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "i18n")))]
+    pub use dioxus_i18n::{t, use_i18n, use_init_i18n};
+
     #[cfg(feature = "launch")]
     #[cfg_attr(docsrs, doc(cfg(feature = "launch")))]
     pub use crate::launch::*;
@@ -139,6 +151,10 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "router")))]
     pub use dioxus_router::prelude::*;
 
+    #[cfg(feature = "components")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "components")))]
+    pub use dioxus_components::prelude::*;
+
     #[cfg(feature = "asset")]
     #[cfg_attr(docsrs, doc(cfg(feature = "asset")))]
     pub use manganis::{self, *};
@@ -156,6 +172,10 @@ pub use dioxus_web as web;
 #[cfg_attr(docsrs, doc(cfg(feature = "router")))]
 pub use dioxus_router as router;
 
+#[cfg(feature = "components")]
+#[cfg_attr(docsrs, doc(cfg(feature = "components")))]
+pub use dioxus_components as components;
+
 #[cfg(feature = "fullstack")]
 #[cfg_attr(docsrs, doc(cfg(feature = "fullstack")))]
 pub use dioxus_fullstack as fullstack;
@@ -0,0 +1,56 @@
+use dioxus_core::prelude::use_hook;
+
+/// The platform features the current renderer supports. Each renderer fills this in when it provides its [`Document`](crate::Document).
+///
+/// Renderers only report a capability if they know it is available, so every capability is `false` on renderers that
+/// can't detect it, like the server renderer.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    /// Text can be read from and written to the system clipboard
+    pub clipboard: bool,
+    /// System notifications can be shown
+    pub notifications: bool,
+    /// Files can be read from and written to the file system, either directly or with a file picker
+    pub file_system: bool,
+    /// The [view transitions](https://developer.mozilla.org/en-US/docs/Web/API/View_Transition_API) api is available
+    pub view_transitions: bool,
+    /// The device has a touch screen
+    pub touch: bool,
+}
+
+/// Get the [`Capabilities`] of the current renderer so a component can adapt to the platform it is running on without `cfg` attributes or user agent sniffing.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn CopyButton(text: String) -> Element {
+///     let capabilities = document::use_capabilities();
+///
+///     if !capabilities.clipboard {
+///         return rsx! { code { "{text}" } };
+///     }
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 document::eval(&format!("navigator.clipboard.writeText({text:?})"));
+///             },
+///             "Copy"
+///         }
+///     }
+/// }
+/// ```
+///
+/// <div class="warning">
+///
+/// The server renderer doesn't report any capabilities. In fullstack apps, branching on the capabilities during the first
+/// render can cause the html from the server to differ from the client while hydrating. Read them in an effect or after
+/// an interaction instead.
+///
+/// </div>
+pub fn use_capabilities() -> Capabilities {
+    use_hook(|| crate::document().capabilities())
+}
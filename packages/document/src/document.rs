@@ -5,7 +5,7 @@ use super::*;
 /// A context for the document
 pub type DocumentContext = Arc<dyn Document>;
 
-fn format_string_for_js(s: &str) -> String {
+pub(crate) fn format_string_for_js(s: &str) -> String {
     let escaped = s
         .replace('\\', "\\\\")
         .replace('\n', "\\n")
@@ -70,6 +70,11 @@ pub trait Document: 'static {
         self.eval(format!("document.title = {title:?};"));
     }
 
+    /// Set the `lang` and `dir` attributes on the root element of the document
+    fn set_language(&self, language: DocumentLanguage) {
+        self.eval(language.update_script());
+    }
+
     /// Create a new element in the head
     fn create_head_element(
         &self,
@@ -106,6 +111,11 @@ pub trait Document: 'static {
         self.create_head_element("link", &attributes, None);
     }
 
+    /// Get the platform features this document supports. By default, no capabilities are reported
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
     /// Check if we should create a new head component at all. If it returns false, the head component will be skipped.
     ///
     /// This runs once per head component and is used to hydrate head components in fullstack.
@@ -145,6 +155,7 @@ impl Document for NoOpDocument {
     }
 
     fn set_title(&self, _: String) {}
+    fn set_language(&self, _: DocumentLanguage) {}
     fn create_meta(&self, _: MetaProps) {}
     fn create_script(&self, _: ScriptProps) {}
     fn create_style(&self, _: StyleProps) {}
@@ -0,0 +1,251 @@
+use super::*;
+use crate::document;
+use crate::document::format_string_for_js;
+
+/// The direction text is written in, set with the [`dir`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/dir) attribute
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TextDirection {
+    /// Left to right, like English
+    #[default]
+    Ltr,
+    /// Right to left, like Arabic or Hebrew
+    Rtl,
+    /// Let the browser pick the direction from the contents of the element
+    Auto,
+}
+
+impl TextDirection {
+    /// Get the value of the `dir` attribute for this direction
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextDirection::Ltr => "ltr",
+            TextDirection::Rtl => "rtl",
+            TextDirection::Auto => "auto",
+        }
+    }
+
+    /// Guess the direction of a [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag like `ar-EG` or `en-US`.
+    ///
+    /// A script subtag takes precedence over the language, so `az-Arab` is right to left while `az` is left to right.
+    ///
+    /// ```rust
+    /// use dioxus::document::TextDirection;
+    ///
+    /// assert_eq!(TextDirection::for_language("he"), TextDirection::Rtl);
+    /// assert_eq!(TextDirection::for_language("ar_EG"), TextDirection::Rtl);
+    /// assert_eq!(TextDirection::for_language("az-Arab"), TextDirection::Rtl);
+    /// assert_eq!(TextDirection::for_language("en-US"), TextDirection::Ltr);
+    /// ```
+    pub fn for_language(lang: &str) -> Self {
+        const RTL_LANGUAGES: &[&str] = &[
+            "ar", "arc", "ckb", "dv", "fa", "ha", "he", "iw", "khw", "ks", "ku", "ps", "sd", "ug",
+            "ur", "yi",
+        ];
+        const RTL_SCRIPTS: &[&str] = &[
+            "adlm", "arab", "hebr", "mand", "mend", "nkoo", "rohg", "samr", "syrc", "thaa", "yezi",
+        ];
+
+        let mut subtags = lang.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+        if let Some(script) = subtags.next().filter(|subtag| subtag.len() == 4) {
+            return match RTL_SCRIPTS.contains(&script.to_ascii_lowercase().as_str()) {
+                true => TextDirection::Rtl,
+                false => TextDirection::Ltr,
+            };
+        }
+        match RTL_LANGUAGES.contains(&language.as_str()) {
+            true => TextDirection::Rtl,
+            false => TextDirection::Ltr,
+        }
+    }
+}
+
+impl std::fmt::Display for TextDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The language and text direction of the document, set on the root `<html>` element
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentLanguage {
+    /// The [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag, like `en-US`
+    pub lang: String,
+    /// The direction text is written in
+    pub dir: TextDirection,
+}
+
+impl DocumentLanguage {
+    /// Create a language with the direction guessed by [`TextDirection::for_language`]
+    pub fn new(lang: impl Into<String>) -> Self {
+        let lang = lang.into();
+        let dir = TextDirection::for_language(&lang);
+        Self { lang, dir }
+    }
+
+    /// Set the direction of the language
+    pub fn with_dir(mut self, dir: TextDirection) -> Self {
+        self.dir = dir;
+        self
+    }
+
+    /// Get the javascript that sets the language on the root element and dispatches a `dioxus:languagechange` event
+    /// on the document with the language and direction in the event detail
+    pub fn update_script(&self) -> String {
+        let lang = format_string_for_js(&self.lang);
+        let dir = format_string_for_js(self.dir.as_str());
+        format!(
+            "const root = document.documentElement;\
+            if (root.lang !== {lang} || root.dir !== {dir}) {{\
+                root.lang = {lang};\
+                root.dir = {dir};\
+                document.dispatchEvent(new CustomEvent(\"dioxus:languagechange\", {{ detail: {{ lang: {lang}, dir: {dir} }} }}));\
+            }}"
+        )
+    }
+}
+
+/// The latest language set by a [`Language`] component and the scopes that read it
+#[derive(Default, Clone)]
+struct LanguageContext(Rc<RefCell<LanguageContextInner>>);
+
+#[derive(Default)]
+struct LanguageContextInner {
+    language: Option<DocumentLanguage>,
+    subscribers: HashSet<ScopeId>,
+}
+
+impl LanguageContext {
+    fn set(&self, language: DocumentLanguage) {
+        let subscribers = {
+            let mut inner = self.0.borrow_mut();
+            if inner.language.as_ref() == Some(&language) {
+                return;
+            }
+            inner.language = Some(language);
+            inner.subscribers.clone()
+        };
+        for scope in subscribers {
+            scope.needs_update();
+        }
+    }
+}
+
+/// Get the language set by the latest [`Language`] component, or `None` if no language has been set. The component
+/// that calls this hook reruns whenever the language changes, which makes it a single place to update formatters or
+/// styles that depend on the language.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::document::TextDirection;
+///
+/// fn Sidebar() -> Element {
+///     let language = document::use_document_language();
+///     let side = match language.map(|language| language.dir) {
+///         Some(TextDirection::Rtl) => "right",
+///         _ => "left",
+///     };
+///
+///     rsx! {
+///         nav { style: "float: {side};" }
+///     }
+/// }
+/// ```
+pub fn use_document_language() -> Option<DocumentLanguage> {
+    let (context, scope) = use_hook(|| {
+        let context = get_or_insert_root_context::<LanguageContext>();
+        let scope = current_scope_id().ok();
+        if let Some(scope) = scope {
+            context.0.borrow_mut().subscribers.insert(scope);
+        }
+        (context, scope)
+    });
+    let remove = context.clone();
+    use_drop(move || {
+        if let Some(scope) = scope {
+            remove.0.borrow_mut().subscribers.remove(&scope);
+        }
+    });
+    let language = context.0.borrow().language.clone();
+    language
+}
+
+/// Set the language of the document outside of a [`Language`] component, like from an event handler or a library
+/// that manages the locale. The `lang` and `dir` attributes on the root element are updated and every component that
+/// reads [`use_document_language`] reruns if the language changed.
+pub fn set_document_language(language: DocumentLanguage) {
+    let context = get_or_insert_root_context::<LanguageContext>();
+    if context.0.borrow().language.as_ref() == Some(&language) {
+        return;
+    }
+    document().set_language(language.clone());
+    context.set(language);
+}
+
+#[non_exhaustive]
+/// Props for the [`Language`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct LanguageProps {
+    /// The [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag, like `en-US`
+    #[props(into)]
+    pub lang: String,
+    /// The direction text is written in. If this is not set, the direction is guessed from the language
+    pub dir: Option<TextDirection>,
+}
+
+impl LanguageProps {
+    /// Get the language these props describe
+    pub fn language(&self) -> DocumentLanguage {
+        let language = DocumentLanguage::new(self.lang.clone());
+        match self.dir {
+            Some(dir) => language.with_dir(dir),
+            None => language,
+        }
+    }
+}
+
+/// Set the `lang` and `dir` attributes on the root `<html>` element of the page.
+///
+/// Unlike most head components, the language can be changed after the first render. When the language changes, the
+/// attributes are updated in the live document, a `dioxus:languagechange` event is dispatched on the document, and
+/// every component that reads [`use_document_language`] reruns. Only the latest update is reflected if multiple
+/// language components are rendered. During server side rendering, the language is written into the `<html>` tag of
+/// the page.
+///
+/// # Example
+///
+/// Pass the locale of the page to switch the language when it changes, for example when the user navigates to a
+/// locale prefixed route:
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn Home(locale: String) -> Element {
+///     rsx! {
+///         // "ar" sets dir="rtl" on the root element
+///         document::Language { lang: locale }
+///         h1 { "Home" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Language(props: LanguageProps) -> Element {
+    let language = props.language();
+
+    // NOTE: We don't use use_effect here because the language needs to be set on the server
+    let last_language = use_hook(|| {
+        set_document_language(language.clone());
+        Rc::new(RefCell::new(language.clone()))
+    });
+
+    // If the language changes, update the root element
+    let mut last_language = last_language.borrow_mut();
+    if language != *last_language {
+        set_document_language(language.clone());
+        *last_language = language;
+    }
+
+    VNode::empty()
+}
@@ -127,7 +127,7 @@ pub fn Link(props: LinkProps) -> Element {
 #[derive(Default, Clone)]
 struct LinkContext(DeduplicationContext);
 
-fn should_insert_link(href: &str) -> bool {
+pub(super) fn should_insert_link(href: &str) -> bool {
     get_or_insert_root_context::<LinkContext>()
         .0
         .should_insert(href)
@@ -2,7 +2,7 @@
 
 use std::{cell::RefCell, collections::HashSet, rc::Rc};
 
-use dioxus_core::{prelude::*, DynamicNode};
+use dioxus_core::{prelude::*, DynamicNode, VText};
 use dioxus_core_macro::*;
 
 mod link;
@@ -17,6 +17,18 @@ mod style;
 pub use style::*;
 mod title;
 pub use title::*;
+mod open_graph;
+pub use open_graph::*;
+mod structured_data;
+pub use structured_data::*;
+mod preload;
+pub use preload::*;
+mod theme;
+pub use theme::*;
+mod language;
+pub use language::*;
+mod scoped_style;
+pub use scoped_style::*;
 
 /// Warn the user if they try to change props on a element that is injected into the head
 #[allow(unused)]
@@ -98,6 +110,21 @@ fn extract_single_text_node(children: &Element) -> Result<String, ExtractSingleT
     }
 }
 
+/// Create the children of a head element from a single text node, the same way `rsx! { "{text}" }` would
+fn text_node(text: String) -> Element {
+    static TEMPLATE: Template = Template {
+        roots: &[TemplateNode::Dynamic { id: 0 }],
+        node_paths: &[&[0]],
+        attr_paths: &[],
+    };
+    Element::Ok(VNode::new(
+        None,
+        TEMPLATE,
+        Box::new([DynamicNode::Text(VText::new(text))]),
+        Box::new([]),
+    ))
+}
+
 fn get_or_insert_root_context<T: Default + Clone + 'static>() -> T {
     match ScopeId::ROOT.has_context::<T>() {
         Some(context) => context,
@@ -0,0 +1,294 @@
+use super::*;
+use crate::document;
+
+/// The type of object described by an [`OpenGraph`] component. See <https://ogp.me/#types>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OpenGraphType {
+    /// A generic website. This is the default type
+    #[default]
+    Website,
+    /// An article such as a blog post or news story
+    Article,
+    /// A book
+    Book,
+    /// A person's profile
+    Profile,
+    /// A song
+    MusicSong,
+    /// A music album
+    MusicAlbum,
+    /// A movie
+    VideoMovie,
+    /// A single episode of a tv show
+    VideoEpisode,
+    /// Any other type of video
+    VideoOther,
+}
+
+impl OpenGraphType {
+    /// Get the value of the `og:type` meta tag for this type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OpenGraphType::Website => "website",
+            OpenGraphType::Article => "article",
+            OpenGraphType::Book => "book",
+            OpenGraphType::Profile => "profile",
+            OpenGraphType::MusicSong => "music.song",
+            OpenGraphType::MusicAlbum => "music.album",
+            OpenGraphType::VideoMovie => "video.movie",
+            OpenGraphType::VideoEpisode => "video.episode",
+            OpenGraphType::VideoOther => "video.other",
+        }
+    }
+}
+
+impl std::fmt::Display for OpenGraphType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[non_exhaustive]
+/// Props for the [`OpenGraph`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct OpenGraphProps {
+    /// The title of the object as it should appear within the graph
+    #[props(into)]
+    pub title: String,
+    /// The type of the object
+    #[props(default)]
+    pub r#type: OpenGraphType,
+    /// The canonical url of the object
+    #[props(into)]
+    pub url: Option<String>,
+    /// An image url which should represent the object
+    #[props(into)]
+    pub image: Option<String>,
+    /// A description of the image for screen readers
+    #[props(into)]
+    pub image_alt: Option<String>,
+    /// A one to two sentence description of the object
+    #[props(into)]
+    pub description: Option<String>,
+    /// The name of the overall site the object is a part of
+    #[props(into)]
+    pub site_name: Option<String>,
+    /// The locale the tags are marked up in. Defaults to `en_US` if not set
+    #[props(into)]
+    pub locale: Option<String>,
+}
+
+impl OpenGraphProps {
+    /// Get the `property` and `content` pairs of every meta tag this component renders
+    pub fn properties(&self) -> Vec<(&'static str, String)> {
+        let mut properties = vec![
+            ("og:title", self.title.clone()),
+            ("og:type", self.r#type.to_string()),
+        ];
+        if let Some(url) = &self.url {
+            properties.push(("og:url", url.clone()));
+        }
+        if let Some(image) = &self.image {
+            properties.push(("og:image", image.clone()));
+        }
+        if let Some(image_alt) = &self.image_alt {
+            properties.push(("og:image:alt", image_alt.clone()));
+        }
+        if let Some(description) = &self.description {
+            properties.push(("og:description", description.clone()));
+        }
+        if let Some(site_name) = &self.site_name {
+            properties.push(("og:site_name", site_name.clone()));
+        }
+        if let Some(locale) = &self.locale {
+            properties.push(("og:locale", locale.clone()));
+        }
+        properties
+    }
+}
+
+/// Render the [Open Graph](https://ogp.me/) meta tags for the current page into the head of the page.
+///
+/// The required `og:title` and `og:type` properties are always rendered, so the page is a valid object in the graph.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::document::OpenGraphType;
+///
+/// fn BlogPost() -> Element {
+///     rsx! {
+///         document::OpenGraph {
+///             title: "Dioxus 0.6",
+///             r#type: OpenGraphType::Article,
+///             url: "https://dioxuslabs.com/blog/release-060",
+///             image: "https://dioxuslabs.com/static/release-060.png",
+///         }
+///     }
+/// }
+/// ```
+///
+/// <div class="warning">
+///
+/// Any updates to the props after the first render will not be reflected in the head.
+///
+/// </div>
+#[component]
+pub fn OpenGraph(props: OpenGraphProps) -> Element {
+    use_update_warning(&props, "OpenGraph {}");
+
+    use_hook(|| {
+        let document = document();
+        if !document.create_head_component() {
+            return;
+        }
+
+        for (property, content) in props.properties() {
+            document.create_meta(MetaProps {
+                property: Some(property.to_string()),
+                name: None,
+                charset: None,
+                http_equiv: None,
+                content: Some(content),
+                additional_attributes: Vec::new(),
+            });
+        }
+    });
+
+    VNode::empty()
+}
+
+/// The type of card rendered by a [`TwitterCard`] component. See <https://developer.x.com/en/docs/x-for-websites/cards/overview/markup>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TwitterCardType {
+    /// A small card with a thumbnail image. This is the default type
+    #[default]
+    Summary,
+    /// A card with a large, prominently featured image
+    SummaryLargeImage,
+    /// A card with a direct download link to a mobile app
+    App,
+    /// A card that can play video or audio
+    Player,
+}
+
+impl TwitterCardType {
+    /// Get the value of the `twitter:card` meta tag for this type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TwitterCardType::Summary => "summary",
+            TwitterCardType::SummaryLargeImage => "summary_large_image",
+            TwitterCardType::App => "app",
+            TwitterCardType::Player => "player",
+        }
+    }
+}
+
+impl std::fmt::Display for TwitterCardType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[non_exhaustive]
+/// Props for the [`TwitterCard`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct TwitterCardProps {
+    /// The type of card to render
+    #[props(default)]
+    pub card: TwitterCardType,
+    /// The title of the content. If this is not set, crawlers fall back to `og:title`
+    #[props(into)]
+    pub title: Option<String>,
+    /// A description of the content. If this is not set, crawlers fall back to `og:description`
+    #[props(into)]
+    pub description: Option<String>,
+    /// An image url which should represent the content. If this is not set, crawlers fall back to `og:image`
+    #[props(into)]
+    pub image: Option<String>,
+    /// A description of the image for screen readers
+    #[props(into)]
+    pub image_alt: Option<String>,
+    /// The `@username` of the website
+    #[props(into)]
+    pub site: Option<String>,
+    /// The `@username` of the content creator
+    #[props(into)]
+    pub creator: Option<String>,
+}
+
+impl TwitterCardProps {
+    /// Get the `name` and `content` pairs of every meta tag this component renders
+    pub fn properties(&self) -> Vec<(&'static str, String)> {
+        let mut properties = vec![("twitter:card", self.card.to_string())];
+        if let Some(title) = &self.title {
+            properties.push(("twitter:title", title.clone()));
+        }
+        if let Some(description) = &self.description {
+            properties.push(("twitter:description", description.clone()));
+        }
+        if let Some(image) = &self.image {
+            properties.push(("twitter:image", image.clone()));
+        }
+        if let Some(image_alt) = &self.image_alt {
+            properties.push(("twitter:image:alt", image_alt.clone()));
+        }
+        if let Some(site) = &self.site {
+            properties.push(("twitter:site", site.clone()));
+        }
+        if let Some(creator) = &self.creator {
+            properties.push(("twitter:creator", creator.clone()));
+        }
+        properties
+    }
+}
+
+/// Render the [Twitter card](https://developer.x.com/en/docs/x-for-websites/cards/overview/abouts-cards) meta tags for the current page into the head of the page.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::document::TwitterCardType;
+///
+/// fn BlogPost() -> Element {
+///     rsx! {
+///         document::TwitterCard {
+///             card: TwitterCardType::SummaryLargeImage,
+///             title: "Dioxus 0.6",
+///             site: "@dioxuslabs",
+///         }
+///     }
+/// }
+/// ```
+///
+/// <div class="warning">
+///
+/// Any updates to the props after the first render will not be reflected in the head.
+///
+/// </div>
+#[component]
+pub fn TwitterCard(props: TwitterCardProps) -> Element {
+    use_update_warning(&props, "TwitterCard {}");
+
+    use_hook(|| {
+        let document = document();
+        if !document.create_head_component() {
+            return;
+        }
+
+        for (name, content) in props.properties() {
+            document.create_meta(MetaProps {
+                property: None,
+                name: Some(name.to_string()),
+                charset: None,
+                http_equiv: None,
+                content: Some(content),
+                additional_attributes: Vec::new(),
+            });
+        }
+    });
+
+    VNode::empty()
+}
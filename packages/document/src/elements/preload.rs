@@ -0,0 +1,222 @@
+use super::*;
+use crate::document;
+use futures_util::FutureExt;
+use std::{cell::Cell, collections::HashMap};
+
+/// Preload an image and suspend the component until the image is loaded and decoded.
+///
+/// The image is preloaded with a `<link rel="preload" as="image">` tag in the head, so server side rendering starts
+/// the download before the page is hydrated. On the client, the component suspends until the image is decoded, so
+/// it can be shown without popping in. Components that preload the same image share one preload.
+///
+/// The server doesn't wait for the image, so pages that are hydrated don't suspend either. The link the server
+/// rendered already started the download.
+///
+/// If the image fails to load, the component stops suspending and the error is logged.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Hero() -> Element {
+///     document::use_preloaded_image("/hero.png")?;
+///     rsx! { img { src: "/hero.png" } }
+/// }
+/// ```
+pub fn use_preloaded_image(src: impl ToString) -> Result<(), RenderError> {
+    let preload = use_hook(|| {
+        let src = src.to_string();
+        let script = format!(
+            "const image = new Image(); image.src = {src:?}; await image.decode(); return true;"
+        );
+        preload(src, "image", script)
+    });
+    preload.suspend()
+}
+
+/// Preload a font and suspend the component until the font is loaded and added to the document.
+///
+/// The font is preloaded with a `<link rel="preload" as="font">` tag in the head, so server side rendering starts
+/// the download before the page is hydrated. On the client, the component suspends until the font is loaded under
+/// the name `family`, so text doesn't flash with the fallback font. Components that preload the same font share one
+/// preload.
+///
+/// Like [`use_preloaded_image`], pages that are hydrated don't suspend because the server didn't wait for the font.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Title() -> Element {
+///     document::use_preloaded_font("Inter", "/inter.woff2")?;
+///     rsx! { h1 { font_family: "Inter", "Welcome" } }
+/// }
+/// ```
+pub fn use_preloaded_font(family: impl ToString, href: impl ToString) -> Result<(), RenderError> {
+    let preload = use_hook(|| {
+        let family = family.to_string();
+        let href = href.to_string();
+        let source = format!("url({href:?})");
+        let script = format!("const font = new FontFace({family:?}, {source:?}); await font.load(); document.fonts.add(font); return true;");
+        preload(href, "font", script)
+    });
+    preload.suspend()
+}
+
+/// The preloads that were started in the app, keyed by the url of the asset
+#[derive(Default, Clone)]
+struct PreloadContext(Rc<RefCell<HashMap<String, Preload>>>);
+
+#[derive(Clone)]
+struct Preload {
+    task: Option<Task>,
+    loaded: Rc<Cell<bool>>,
+}
+
+impl Preload {
+    fn suspend(&self) -> Result<(), RenderError> {
+        match self.task {
+            Some(task) if !self.loaded.get() => {
+                Err(RenderError::Suspended(SuspendedFuture::new(task)))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn preload(href: String, kind: &str, script: String) -> Preload {
+    let context = get_or_insert_root_context::<PreloadContext>();
+    if let Some(preload) = context.0.borrow().get(&href) {
+        return preload.clone();
+    }
+
+    let document = document();
+    // Head components the server already rendered are skipped while hydrating. The server didn't suspend, so
+    // suspending now would make the client render something different from the html it is hydrating
+    let hydrating = !document.create_head_component();
+    if !hydrating && should_insert_link(&href) {
+        document.create_link(LinkProps {
+            rel: Some("preload".into()),
+            media: None,
+            title: None,
+            disabled: None,
+            r#as: Some(kind.into()),
+            sizes: None,
+            href: Some(href.clone()),
+            // Fonts are always fetched in cors mode, so the preload must be too for the browser to reuse it
+            crossorigin: (kind == "font").then(|| "anonymous".into()),
+            referrerpolicy: None,
+            fetchpriority: None,
+            hreflang: None,
+            integrity: None,
+            r#type: None,
+            blocking: None,
+            additional_attributes: Vec::new(),
+        });
+    }
+
+    let loaded = Rc::new(Cell::new(hydrating));
+    if hydrating {
+        let preload = Preload { task: None, loaded };
+        context.0.borrow_mut().insert(href, preload.clone());
+        return preload;
+    }
+
+    let mut join = Box::pin(document.eval(script).join::<bool>());
+    // Renderers that can't run javascript, like the server, fail right away. There is nothing to wait for on those
+    // platforms, so the preload is done as soon as the link is inserted
+    let task = match (&mut join).now_or_never() {
+        Some(_) => {
+            loaded.set(true);
+            None
+        }
+        None => spawn_forever({
+            let loaded = loaded.clone();
+            let href = href.clone();
+            async move {
+                if let Err(err) = join.await {
+                    tracing::error!("Failed to preload {href}: {err}");
+                }
+                loaded.set(true);
+            }
+        }),
+    };
+
+    let preload = Preload { task, loaded };
+    context.0.borrow_mut().insert(href, preload.clone());
+    preload
+}
+
+#[cfg(test)]
+use crate::{Document, Eval, EvalError, Evaluator};
+
+/// A document whose evals never finish
+#[cfg(test)]
+struct PendingDocument {
+    hydrating: bool,
+    owner: generational_box::Owner,
+}
+
+#[cfg(test)]
+impl Document for PendingDocument {
+    fn eval(&self, _: String) -> Eval {
+        struct PendingEvaluator;
+        impl Evaluator for PendingEvaluator {
+            fn poll_join(
+                &mut self,
+                _: &mut std::task::Context<'_>,
+            ) -> std::task::Poll<Result<serde_json::Value, EvalError>> {
+                std::task::Poll::Pending
+            }
+
+            fn poll_recv(
+                &mut self,
+                _: &mut std::task::Context<'_>,
+            ) -> std::task::Poll<Result<serde_json::Value, EvalError>> {
+                std::task::Poll::Pending
+            }
+
+            fn send(&self, _data: serde_json::Value) -> Result<(), EvalError> {
+                Ok(())
+            }
+        }
+        Eval::new(self.owner.insert(Box::new(PendingEvaluator)))
+    }
+
+    fn create_head_component(&self) -> bool {
+        !self.hydrating
+    }
+}
+
+/// Render a component that preloads an image and return whether it suspended
+#[cfg(test)]
+fn preload_suspends(document: impl Document + 'static) -> bool {
+    thread_local! {
+        static SUSPENDED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    fn app() -> Element {
+        let result = use_preloaded_image("/hero.png");
+        SUSPENDED.with(|suspended| suspended.set(result.is_err()));
+        result?;
+        VNode::empty()
+    }
+
+    let mut dom = VirtualDom::new(app).with_root_context(Rc::new(document) as Rc<dyn Document>);
+    dom.rebuild_in_place();
+    SUSPENDED.with(Cell::get)
+}
+
+#[test]
+fn preloads_suspend_until_the_asset_loads() {
+    assert!(preload_suspends(PendingDocument {
+        hydrating: false,
+        owner: Default::default(),
+    }));
+}
+
+#[test]
+fn preloads_match_the_server_while_hydrating() {
+    // The server can't run javascript, so it never suspends
+    assert!(!preload_suspends(crate::NoOpDocument));
+    assert!(!preload_suspends(PendingDocument {
+        hydrating: true,
+        owner: Default::default(),
+    }));
+}
@@ -0,0 +1,248 @@
+use super::*;
+use crate::document;
+
+/// A stylesheet whose class names are scoped to the stylesheet, created with [`use_style`].
+///
+/// Every class selector in the css is renamed to `{class}-{hash}` where the hash is derived from the css itself,
+/// so two components can both style a `.button` class without their styles colliding.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus::document::ScopedStyle;
+///
+/// let style = ScopedStyle::new(".button { color: red; } .button:hover { color: blue; }");
+/// let button = style.class("button");
+/// assert!(button.starts_with("button-"));
+/// assert_eq!(
+///     style.css(),
+///     format!(".{button} {{ color: red; }} .{button}:hover {{ color: blue; }}")
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScopedStyle {
+    scope: String,
+    css: String,
+}
+
+impl ScopedStyle {
+    /// Scope the class names in a stylesheet. This does not insert the stylesheet into the page; use [`use_style`] for that
+    pub fn new(css: &str) -> Self {
+        let scope = format!("{:x}", fnv1a(css.as_bytes()));
+        let css = scope_classes(css, &scope);
+        Self { scope, css }
+    }
+
+    /// Get the scoped name of a class from the stylesheet
+    pub fn class(&self, name: &str) -> String {
+        format!("{name}-{}", self.scope)
+    }
+
+    /// Get the scoped names of several classes from the stylesheet, separated by spaces. This can be passed directly
+    /// to the `class` attribute
+    pub fn classes<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> String {
+        names
+            .into_iter()
+            .map(|name| self.class(name))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Get the hash that is appended to every class name in the stylesheet
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// Get the css with the scoped class names
+    pub fn css(&self) -> &str {
+        &self.css
+    }
+}
+
+/// Insert a stylesheet with scoped class names into the head of the page and return the scoped names.
+///
+/// The stylesheet is only inserted once, no matter how many components use it. It is also included in server rendered html.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Card() -> Element {
+///     let style = document::use_style(
+///         r#"
+///         .card { padding: 1rem; border-radius: 4px; }
+///         .card .title { font-weight: bold; }
+///         "#,
+///     );
+///
+///     rsx! {
+///         div { class: style.class("card"),
+///             h2 { class: style.class("title"), "Scoped styles" }
+///         }
+///     }
+/// }
+/// ```
+///
+/// <div class="warning">
+///
+/// The css is only read on the first render. Changing it after the first render will not update the stylesheet.
+///
+/// </div>
+pub fn use_style(css: &str) -> ScopedStyle {
+    use_hook(|| {
+        let style = ScopedStyle::new(css);
+        let document = document();
+        if document.create_head_component() && should_insert_scoped_style(style.scope()) {
+            let contents = style.css().replace("</", "<\\/");
+            document.create_style(StyleProps {
+                children: text_node(contents),
+                href: None,
+                media: None,
+                nonce: None,
+                title: None,
+                additional_attributes: Vec::new(),
+            });
+        }
+        style
+    })
+}
+
+#[derive(Default, Clone)]
+struct ScopedStyleContext(DeduplicationContext);
+
+fn should_insert_scoped_style(scope: &str) -> bool {
+    get_or_insert_root_context::<ScopedStyleContext>()
+        .0
+        .should_insert(scope)
+}
+
+/// A hash that is stable across platforms and compiler versions so the server and client agree on the class names
+fn fnv1a(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c9dc5;
+    for byte in bytes {
+        hash ^= *byte as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    hash
+}
+
+/// The contents of a block in the stylesheet
+#[derive(Clone, Copy, PartialEq)]
+enum Block {
+    /// A list of rules, like the top level of the stylesheet or the body of `@media`. Selectors are scoped here
+    Rules,
+    /// Declarations or anything else where class names cannot appear, like the body of `@keyframes`
+    Other,
+}
+
+/// Append the scope to every class selector in the css. Declarations, strings and comments are left unchanged
+fn scope_classes(css: &str, scope: &str) -> String {
+    let mut scoped = String::with_capacity(css.len());
+    let mut blocks = vec![Block::Rules];
+    // The text since the last rule or block started, used to tell at-rules that contain other rules apart
+    let mut prelude = String::new();
+    let mut chars = css.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let block = *blocks.last().unwrap_or(&Block::Rules);
+        match c {
+            '/' if chars.peek() == Some(&'*') => {
+                scoped.push(c);
+                scoped.extend(chars.next());
+                let mut last = '\0';
+                for c in chars.by_ref() {
+                    scoped.push(c);
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+            }
+            '"' | '\'' => {
+                scoped.push(c);
+                let mut escaped = false;
+                for next in chars.by_ref() {
+                    scoped.push(next);
+                    if escaped {
+                        escaped = false;
+                    } else if next == '\\' {
+                        escaped = true;
+                    } else if next == c {
+                        break;
+                    }
+                }
+            }
+            '{' => {
+                scoped.push(c);
+                let prelude = std::mem::take(&mut prelude);
+                let prelude = prelude.trim_start();
+                let nested_rules = block == Block::Rules
+                    && ["@media", "@supports", "@container", "@layer", "@document"]
+                        .iter()
+                        .any(|at_rule| prelude.starts_with(at_rule));
+                blocks.push(if nested_rules {
+                    Block::Rules
+                } else {
+                    Block::Other
+                });
+            }
+            '}' => {
+                scoped.push(c);
+                prelude.clear();
+                if blocks.len() > 1 {
+                    blocks.pop();
+                }
+            }
+            ';' => {
+                scoped.push(c);
+                prelude.clear();
+            }
+            '.' if block == Block::Rules && !prelude.trim_start().starts_with('@') => {
+                scoped.push(c);
+                prelude.push(c);
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next.is_alphanumeric() || next == '-' || next == '_' {
+                        name.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                scoped.push_str(&name);
+                prelude.push_str(&name);
+                if !name.is_empty() && !name.starts_with(|c: char| c.is_ascii_digit()) {
+                    scoped.push('-');
+                    scoped.push_str(scope);
+                }
+            }
+            _ => {
+                scoped.push(c);
+                prelude.push(c);
+            }
+        }
+    }
+
+    scoped
+}
+
+#[test]
+fn scopes_class_selectors() {
+    assert_eq!(
+        scope_classes(
+            r#"
+            /* .comment */
+            .a.b > div:not(.c) { content: ".d"; width: 0.5em; }
+            @media (max-width: 10.5px) { .a { color: red; } }
+            @keyframes spin { from { opacity: 0.5; } }
+            "#,
+            "x"
+        ),
+        r#"
+            /* .comment */
+            .a-x.b-x > div:not(.c-x) { content: ".d"; width: 0.5em; }
+            @media (max-width: 10.5px) { .a-x { color: red; } }
+            @keyframes spin { from { opacity: 0.5; } }
+            "#
+    );
+}
@@ -0,0 +1,140 @@
+use super::*;
+use crate::document;
+use serde::Serialize;
+
+/// A [schema.org](https://schema.org) object that can be rendered as [JSON-LD](https://json-ld.org/) with the [`JsonLd`] component.
+///
+/// The `@context` and `@type` keys are always set, so every object built with this type is a valid structured data node.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus::document::StructuredData;
+///
+/// let article = StructuredData::new("Article")
+///     .property("headline", "Dioxus 0.6")
+///     .property("author", StructuredData::new("Person").property("name", "Jonathan Kelley"));
+/// assert_eq!(article.to_json()["author"]["@type"], "Person");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct StructuredData {
+    object: serde_json::Map<String, serde_json::Value>,
+}
+
+impl StructuredData {
+    /// Create a new schema.org object with the given type. For example `"Article"`, `"Product"` or `"BreadcrumbList"`
+    pub fn new(r#type: impl Into<String>) -> Self {
+        let mut object = serde_json::Map::new();
+        object.insert(
+            "@context".to_string(),
+            serde_json::Value::String("https://schema.org".to_string()),
+        );
+        object.insert(
+            "@type".to_string(),
+            serde_json::Value::String(r#type.into()),
+        );
+        Self { object }
+    }
+
+    /// Set a property on the object. Any serializable value can be used, including nested [`StructuredData`] objects.
+    ///
+    /// If the value fails to serialize, an error is logged and the property is skipped.
+    pub fn property(mut self, name: impl Into<String>, value: impl Serialize) -> Self {
+        let name = name.into();
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.object.insert(name, value);
+            }
+            Err(err) => {
+                tracing::error!("Failed to serialize the structured data property {name}: {err}");
+            }
+        }
+        self
+    }
+
+    /// Get the object as a json value
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Object(self.object.clone())
+    }
+}
+
+impl Serialize for StructuredData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.object.serialize(serializer)
+    }
+}
+
+impl From<StructuredData> for serde_json::Value {
+    fn from(data: StructuredData) -> Self {
+        serde_json::Value::Object(data.object)
+    }
+}
+
+#[non_exhaustive]
+/// Props for the [`JsonLd`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct JsonLdProps {
+    /// The structured data to render. This is typically built with [`StructuredData`], but any json value is accepted
+    #[props(into)]
+    pub data: serde_json::Value,
+}
+
+impl JsonLdProps {
+    /// Get the contents of the script tag. Any `</` sequences are escaped so the data cannot close the script tag early
+    pub fn script_contents(&self) -> String {
+        self.data.to_string().replace("</", "<\\/")
+    }
+}
+
+/// Render [JSON-LD](https://json-ld.org/) structured data into a `<script type="application/ld+json">` tag in the head of the page.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::document::StructuredData;
+///
+/// fn Product() -> Element {
+///     rsx! {
+///         document::JsonLd {
+///             data: StructuredData::new("Product")
+///                 .property("name", "Dioxus hoodie")
+///                 .property("sku", "DX-001"),
+///         }
+///     }
+/// }
+/// ```
+///
+/// <div class="warning">
+///
+/// Any updates to the props after the first render will not be reflected in the head.
+///
+/// </div>
+#[component]
+pub fn JsonLd(props: JsonLdProps) -> Element {
+    use_update_warning(&props, "JsonLd {}");
+
+    use_hook(|| {
+        let document = document();
+        if !document.create_head_component() {
+            return;
+        }
+
+        let contents = props.script_contents();
+        document.create_script(ScriptProps {
+            children: text_node(contents),
+            src: None,
+            defer: None,
+            crossorigin: None,
+            fetchpriority: None,
+            integrity: None,
+            nomodule: None,
+            nonce: None,
+            referrerpolicy: None,
+            r#type: Some("application/ld+json".to_string()),
+            additional_attributes: Vec::new(),
+        });
+    });
+
+    VNode::empty()
+}
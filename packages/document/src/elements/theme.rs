@@ -0,0 +1,208 @@
+use super::*;
+use crate::document;
+use crate::document::format_string_for_js;
+
+/// A set of design tokens that are exposed to css as [custom properties](https://developer.mozilla.org/en-US/docs/Web/CSS/Using_CSS_custom_properties) with the [`Theme`] component.
+///
+/// Token names are written without the leading `--`. Tokens keep the order they were added in, and setting a token that already exists replaces its value.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus::document::ThemeTokens;
+///
+/// let tokens = ThemeTokens::new()
+///     .token("color-primary", "#29d")
+///     .token("radius", "4px");
+/// assert_eq!(tokens.get("radius"), Some("4px"));
+/// assert_eq!(ThemeTokens::var("color-primary"), "var(--color-primary)");
+/// assert_eq!(tokens.to_css(), ":root{--color-primary:#29d;--radius:4px;}");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThemeTokens {
+    tokens: Vec<(String, String)>,
+}
+
+impl ThemeTokens {
+    /// Create an empty set of tokens
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a token. The name may include the leading `--`, but it is not required
+    pub fn token(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    /// Set a token in place. The name may include the leading `--`, but it is not required
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        let name = name.trim_start_matches("--").to_string();
+        let value = value.into();
+        match self
+            .tokens
+            .iter_mut()
+            .find(|(existing, _)| *existing == name)
+        {
+            Some((_, existing)) => *existing = value,
+            None => self.tokens.push((name, value)),
+        }
+    }
+
+    /// Get the value of a token
+    pub fn get(&self, name: &str) -> Option<&str> {
+        let name = name.trim_start_matches("--");
+        self.tokens
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Iterate over the names and values of the tokens in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.tokens
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Get a css `var()` expression that reads a token, for use in inline styles or other css
+    pub fn var(name: &str) -> String {
+        format!("var(--{})", name.trim_start_matches("--"))
+    }
+
+    /// Get a css rule that sets every token as a custom property on the `:root` element.
+    ///
+    /// Any `</` sequences are escaped so a value cannot close the style tag early
+    pub fn to_css(&self) -> String {
+        let mut css = String::from(":root{");
+        for (name, value) in self.iter() {
+            css.push_str(&format!("--{name}:{value};"));
+        }
+        css.push('}');
+        css.replace("</", "<\\/")
+    }
+
+    /// Get the javascript that replaces the rule in the style tag of the theme with these tokens. If the tag is
+    /// missing, it is created in the head of the page
+    fn update_script(&self, theme: &str) -> String {
+        let selector = format_string_for_js(&format!("style[{THEME_ATTRIBUTE}=\"{theme}\"]"));
+        let attribute = format_string_for_js(THEME_ATTRIBUTE);
+        let theme = format_string_for_js(theme);
+        let css = format_string_for_js(&self.to_css());
+        format!(
+            "let style = document.head.querySelector({selector});\
+            if (!style) {{ style = document.createElement(\"style\"); style.setAttribute({attribute}, {theme}); document.head.appendChild(style); }}\
+            style.textContent = {css};"
+        )
+    }
+}
+
+impl<N: Into<String>, V: Into<String>> FromIterator<(N, V)> for ThemeTokens {
+    fn from_iter<T: IntoIterator<Item = (N, V)>>(iter: T) -> Self {
+        let mut tokens = Self::new();
+        for (name, value) in iter {
+            tokens.set(name, value);
+        }
+        tokens
+    }
+}
+
+/// The attribute that marks the style tag of a [`Theme`] so the tag can be updated when the tokens change
+const THEME_ATTRIBUTE: &str = "data-dioxus-theme";
+
+#[non_exhaustive]
+/// Props for the [`Theme`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct ThemeProps {
+    /// The tokens to expose as css custom properties
+    pub tokens: ThemeTokens,
+}
+
+/// Expose a set of [`ThemeTokens`] as css custom properties on the root element of the page.
+///
+/// The tokens are rendered into a style tag in the head of the page on the first render, so they are also present in server rendered html.
+/// Unlike the other head components, the theme does update after the first render. When the tokens change, the rule in the style tag is
+/// replaced, so tokens that were removed are no longer set. Keep the theme in a signal or context and read it where the `Theme` component
+/// is rendered to switch themes.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::document::ThemeTokens;
+///
+/// fn App() -> Element {
+///     let mut dark = use_signal(|| false);
+///     let tokens = if dark() {
+///         ThemeTokens::new().token("background", "#111").token("text", "#eee")
+///     } else {
+///         ThemeTokens::new().token("background", "#fff").token("text", "#111")
+///     };
+///
+///     // Plain css and inline styles share the same tokens
+///     let background = ThemeTokens::var("text");
+///     let color = ThemeTokens::var("background");
+///
+///     rsx! {
+///         document::Theme { tokens }
+///         button {
+///             style: "background: {background}; color: {color};",
+///             onclick: move |_| dark.toggle(),
+///             "Toggle theme"
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Theme(props: ThemeProps) -> Element {
+    let tokens = props.tokens;
+
+    // NOTE: We don't use use_effect here because the initial tokens need to be rendered on the server
+    let document = use_hook(document);
+    // The scope id is the same on the server and the client, so the client finds the style tag the server rendered
+    let theme = use_hook(|| {
+        current_scope_id()
+            .map(|scope| scope.0.to_string())
+            .unwrap_or_default()
+    });
+    let last_tokens = use_hook(|| {
+        if document.create_head_component() {
+            let contents = tokens.to_css();
+            document.create_style(StyleProps {
+                children: text_node(contents),
+                href: None,
+                media: None,
+                nonce: None,
+                title: None,
+                additional_attributes: vec![Attribute::new(
+                    THEME_ATTRIBUTE,
+                    theme.clone(),
+                    None,
+                    false,
+                )],
+            });
+        }
+        Rc::new(RefCell::new(tokens.clone()))
+    });
+
+    // If the tokens change, replace the rule in the style tag
+    let mut last_tokens = last_tokens.borrow_mut();
+    if tokens != *last_tokens {
+        document.eval(tokens.update_script(&theme));
+        *last_tokens = tokens;
+    }
+
+    VNode::empty()
+}
+
+#[test]
+fn update_script_replaces_the_rule() {
+    let next = ThemeTokens::new()
+        .token("--background", "#111")
+        .token("text", "#111");
+    assert_eq!(
+        next.update_script("3"),
+        r#"let style = document.head.querySelector("style[data-dioxus-theme=\"3\"]");if (!style) { style = document.createElement("style"); style.setAttribute("data-dioxus-theme", "3"); document.head.appendChild(style); }style.textContent = ":root{--background:#111;--text:#111;}";"#
+    );
+}
@@ -0,0 +1,357 @@
+use std::{cell::Cell, fmt::Display, rc::Rc, time::Duration};
+
+use dioxus_core::prelude::*;
+use dioxus_core_macro::*;
+use dioxus_html::{self as dioxus_elements, ImageData, MountedData};
+// The event handlers in the props are owned by the props builder
+use generational_box::Owner;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{document::format_string_for_js, Eval, EvalError};
+
+/// A restriction that can be lifted from a sandboxed [`Frame`]. See the
+/// [`sandbox` attribute](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/iframe#sandbox) for details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SandboxFlag {
+    /// `allow-downloads`
+    Downloads,
+    /// `allow-forms`
+    Forms,
+    /// `allow-modals`
+    Modals,
+    /// `allow-orientation-lock`
+    OrientationLock,
+    /// `allow-pointer-lock`
+    PointerLock,
+    /// `allow-popups`
+    Popups,
+    /// `allow-popups-to-escape-sandbox`
+    PopupsToEscapeSandbox,
+    /// `allow-presentation`
+    Presentation,
+    /// `allow-same-origin`
+    SameOrigin,
+    /// `allow-scripts`
+    Scripts,
+    /// `allow-storage-access-by-user-activation`
+    StorageAccessByUserActivation,
+    /// `allow-top-navigation`
+    TopNavigation,
+    /// `allow-top-navigation-by-user-activation`
+    TopNavigationByUserActivation,
+    /// `allow-top-navigation-to-custom-protocols`
+    TopNavigationToCustomProtocols,
+}
+
+impl SandboxFlag {
+    /// Get the token this flag adds to the `sandbox` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SandboxFlag::Downloads => "allow-downloads",
+            SandboxFlag::Forms => "allow-forms",
+            SandboxFlag::Modals => "allow-modals",
+            SandboxFlag::OrientationLock => "allow-orientation-lock",
+            SandboxFlag::PointerLock => "allow-pointer-lock",
+            SandboxFlag::Popups => "allow-popups",
+            SandboxFlag::PopupsToEscapeSandbox => "allow-popups-to-escape-sandbox",
+            SandboxFlag::Presentation => "allow-presentation",
+            SandboxFlag::SameOrigin => "allow-same-origin",
+            SandboxFlag::Scripts => "allow-scripts",
+            SandboxFlag::StorageAccessByUserActivation => "allow-storage-access-by-user-activation",
+            SandboxFlag::TopNavigation => "allow-top-navigation",
+            SandboxFlag::TopNavigationByUserActivation => "allow-top-navigation-by-user-activation",
+            SandboxFlag::TopNavigationToCustomProtocols => {
+                "allow-top-navigation-to-custom-protocols"
+            }
+        }
+    }
+}
+
+/// The restrictions of a sandboxed [`Frame`]. A new sandbox applies every restriction, and each [`SandboxFlag`] lifts one of them.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus::document::{Sandbox, SandboxFlag};
+///
+/// let sandbox = Sandbox::new()
+///     .allow(SandboxFlag::Scripts)
+///     .allow(SandboxFlag::Forms);
+/// assert_eq!(sandbox.to_string(), "allow-scripts allow-forms");
+/// assert_eq!(Sandbox::new().to_string(), "");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sandbox {
+    flags: Vec<SandboxFlag>,
+}
+
+impl Sandbox {
+    /// Create a sandbox with every restriction applied
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lift a restriction from the sandbox
+    pub fn allow(mut self, flag: SandboxFlag) -> Self {
+        if !self.allows(flag) {
+            self.flags.push(flag);
+        }
+        self
+    }
+
+    /// Check if a restriction was lifted
+    pub fn allows(&self, flag: SandboxFlag) -> bool {
+        self.flags.contains(&flag)
+    }
+}
+
+impl Display for Sandbox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, flag) in self.flags.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(flag.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<SandboxFlag> for Sandbox {
+    fn from_iter<T: IntoIterator<Item = SandboxFlag>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::new(), Self::allow)
+    }
+}
+
+#[non_exhaustive]
+/// Props for the [`Frame`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct FrameProps {
+    /// The url of the page to embed
+    #[props(into)]
+    pub src: String,
+    /// The id of the iframe. This is required to talk to the frame with a [`FrameChannel`]
+    #[props(into)]
+    pub id: Option<String>,
+    /// A description of the embedded content for assistive technology
+    #[props(into)]
+    pub title: Option<String>,
+    /// The restrictions applied to the embedded page. By default, every restriction is applied
+    #[props(default)]
+    pub sandbox: Sandbox,
+    /// Called when the embedded page finishes loading
+    pub onload: Option<EventHandler<()>>,
+    /// Called when the embedded page fails to load. Browsers don't report most failed loads of frames, so this is also
+    /// called if the page doesn't finish loading within `load_timeout`
+    pub onerror: Option<EventHandler<()>>,
+    /// How long the embedded page can take to load, in milliseconds, before `onerror` is called
+    #[props(default = 10_000)]
+    pub load_timeout: u64,
+    #[props(extends = iframe, extends = GlobalAttributes)]
+    pub additional_attributes: Vec<Attribute>,
+}
+
+/// Embed a page from another origin in a sandboxed [`iframe`](dioxus_html::elements::iframe).
+///
+/// Unlike a plain `iframe`, the frame is sandboxed unless you lift restrictions with [`SandboxFlag`]s. Use a [`FrameChannel`]
+/// to send and receive messages from the embedded page.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::document::{Sandbox, SandboxFlag};
+///
+/// fn Video() -> Element {
+///     let mut loaded = use_signal(|| false);
+///
+///     rsx! {
+///         document::Frame {
+///             src: "https://player.example.com/embed/123",
+///             title: "Video player",
+///             sandbox: Sandbox::new().allow(SandboxFlag::Scripts),
+///             onload: move |_| loaded.set(true),
+///         }
+///         if !loaded() {
+///             "Loading..."
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Frame(props: FrameProps) -> Element {
+    let FrameProps {
+        src,
+        id,
+        title,
+        sandbox,
+        onload,
+        onerror,
+        load_timeout,
+        additional_attributes,
+    } = props;
+
+    // Set once the page loaded or failed so only one of the handlers is called for each load
+    let settled = use_hook(|| Rc::new(Cell::new(false)));
+    let fail = {
+        let settled = settled.clone();
+        move || {
+            if !settled.replace(true) {
+                if let Some(onerror) = onerror {
+                    onerror.call(());
+                }
+            }
+        }
+    };
+
+    let mut attributes = vec![
+        Attribute::new("src", src, None, false),
+        Attribute::new("id", id, None, false),
+        Attribute::new("title", title, None, false),
+        Attribute::new("sandbox", sandbox.to_string(), None, false),
+        // Mounted events only fire on the client, so the server never times out
+        dioxus_html::onmounted({
+            let fail = fail.clone();
+            move |_: Event<MountedData>| {
+                let fail = fail.clone();
+                spawn(async move {
+                    sleep(Duration::from_millis(load_timeout)).await;
+                    fail();
+                });
+            }
+        }),
+        dioxus_html::onload({
+            let settled = settled.clone();
+            move |_: Event<ImageData>| {
+                if !settled.replace(true) {
+                    if let Some(onload) = onload {
+                        onload.call(());
+                    }
+                }
+            }
+        }),
+        dioxus_html::onerror(move |_: Event<ImageData>| fail()),
+    ];
+    attributes.extend(additional_attributes);
+
+    Element::Ok(VNode::new(
+        None,
+        FRAME_TEMPLATE,
+        Box::new([]),
+        Box::new([attributes.into_boxed_slice()]),
+    ))
+}
+
+static FRAME_TEMPLATE: Template = Template {
+    roots: &[TemplateNode::Element {
+        tag: "iframe",
+        namespace: None,
+        attrs: &[TemplateAttribute::Dynamic { id: 0 }],
+        children: &[],
+    }],
+    node_paths: &[],
+    attr_paths: &[&[0]],
+};
+
+/// Wait for a duration with a browser timer on the web and a background thread everywhere else, so the load timeout
+/// works without a specific async runtime.
+async fn sleep(duration: Duration) {
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let (wake, woken) = futures_channel::oneshot::channel();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            _ = wake.send(());
+        });
+        _ = woken.await;
+    }
+}
+
+/// A channel to send and receive [`postMessage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/postMessage)
+/// messages from the page inside a [`Frame`].
+///
+/// Messages are serialized as json. Only messages that come from the frame's window and the origin the channel was
+/// created with are received, and messages that are sent can only be read by that origin. Dropping the channel stops
+/// listening for messages.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::document::{FrameChannel, Sandbox, SandboxFlag};
+///
+/// fn Video() -> Element {
+///     use_future(|| async {
+///         let mut channel = FrameChannel::new("player", "https://player.example.com");
+///         _ = channel.send("play");
+///         while let Ok(message) = channel.recv::<String>().await {
+///             if message == "ended" {
+///                 println!("The video ended");
+///             }
+///         }
+///     });
+///
+///     rsx! {
+///         document::Frame {
+///             id: "player",
+///             src: "https://player.example.com/embed/123",
+///             sandbox: Sandbox::new().allow(SandboxFlag::Scripts),
+///         }
+///     }
+/// }
+/// ```
+pub struct FrameChannel {
+    eval: Eval,
+}
+
+impl FrameChannel {
+    /// Connect to the frame with the given id. `origin` is the origin of the embedded page, like `https://example.com`
+    ///
+    /// The frame does not need to be mounted yet. It is looked up every time a message is sent or received.
+    pub fn new(frame_id: &str, origin: &str) -> Self {
+        let frame_id = format_string_for_js(frame_id);
+        let origin = format_string_for_js(origin);
+        let eval = crate::eval(&format!(
+            r#"
+            const frame = () => document.getElementById({frame_id});
+            const listener = (event) => {{
+                const current = frame();
+                if (event.origin === {origin} && current && event.source === current.contentWindow) {{
+                    dioxus.send(event.data);
+                }}
+            }};
+            window.addEventListener("message", listener);
+            while (true) {{
+                const command = await dioxus.recv();
+                if (command === "close") {{
+                    window.removeEventListener("message", listener);
+                    return;
+                }}
+                frame()?.contentWindow?.postMessage(command.post, {origin});
+            }}
+            "#
+        ));
+        Self { eval }
+    }
+
+    /// Send a message to the embedded page
+    pub fn send(&self, message: impl Serialize) -> Result<(), EvalError> {
+        let message = serde_json::to_value(message).map_err(EvalError::Serialization)?;
+        self.eval.send(serde_json::json!({ "post": message }))
+    }
+
+    /// Wait for the next message from the embedded page
+    pub async fn recv<T: DeserializeOwned>(&mut self) -> Result<T, EvalError> {
+        self.eval.recv().await
+    }
+}
+
+impl Drop for FrameChannel {
+    fn drop(&mut self) {
+        // Remove the message listener from the window. If the script already finished, there is nothing to remove
+        _ = self.eval.send("close");
+    }
+}
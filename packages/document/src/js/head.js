@@ -1 +1 @@
-var createElementInHead=function(tag,attributes,children){const element=document.createElement(tag);for(let[key,value]of attributes)element.setAttribute(key,value);if(children)element.appendChild(document.createTextNode(children));document.head.appendChild(element)};window.createElementInHead=createElementInHead;
+var isArrayProperty=function(property){return ARRAY_PROPERTIES.some((array)=>property===array||property.startsWith(array+":"))},findDuplicateInHead=function(tag,attributes){const attribute=(name)=>attributes.find(([key])=>key===name)?.[1];let selector=null;if(tag==="meta")for(let key of["name","property","http-equiv","charset"]){const value=attribute(key);if(value!==void 0){if(key==="property"&&isArrayProperty(value))break;selector=key==="charset"?"meta[charset]":`meta[${key}="${CSS.escape(value)}"]`;break}}else if(tag==="link"&&attribute("rel")==="canonical")selector='link[rel="canonical"]';return selector?document.head.querySelector(selector):null},createElementInHead=function(tag,attributes,children){findDuplicateInHead(tag,attributes)?.remove();const element=document.createElement(tag);for(let[key,value]of attributes)element.setAttribute(key,value);if(children)element.appendChild(document.createTextNode(children));document.head.appendChild(element)};var ARRAY_PROPERTIES=["og:image","og:video","og:audio","og:locale:alternate","article:author","article:tag","book:author","book:tag","music:song","music:musician","music:creator","video:actor","video:director","video:writer","video:tag"];window.createElementInHead=createElementInHead;
@@ -1,14 +1,18 @@
 use std::rc::Rc;
 
+mod capabilities;
 mod document;
 mod elements;
 mod error;
 mod eval;
+mod frame;
 
+pub use capabilities::*;
 pub use document::*;
 pub use elements::*;
 pub use error::*;
 pub use eval::*;
+pub use frame::*;
 
 /// Get the document provider for the current platform or a no-op provider if the platform doesn't document functionality.
 pub fn document() -> Rc<dyn Document> {
@@ -1,10 +1,66 @@
 // Helper functions for working with the document head
 
+// Open Graph properties that are arrays, like several og:image tags for one page. The structured properties of an
+// array item, like og:image:width, repeat with the item
+const ARRAY_PROPERTIES = [
+  "og:image",
+  "og:video",
+  "og:audio",
+  "og:locale:alternate",
+  "article:author",
+  "article:tag",
+  "book:author",
+  "book:tag",
+  "music:song",
+  "music:musician",
+  "music:creator",
+  "video:actor",
+  "video:director",
+  "video:writer",
+  "video:tag",
+];
+
+function isArrayProperty(property: string): boolean {
+  return ARRAY_PROPERTIES.some(
+    (array) => property === array || property.startsWith(array + ":")
+  );
+}
+
+// Find an element in the head that describes the same thing as the new element. Only one of these elements can be
+// in the head at a time, so the last element that was created wins
+function findDuplicateInHead(
+  tag: string,
+  attributes: [string, string][]
+): Element | null {
+  const attribute = (name: string) =>
+    attributes.find(([key]) => key === name)?.[1];
+  let selector: string | null = null;
+  if (tag === "meta") {
+    for (const key of ["name", "property", "http-equiv", "charset"]) {
+      const value = attribute(key);
+      if (value !== undefined) {
+        if (key === "property" && isArrayProperty(value)) {
+          break;
+        }
+        selector =
+          key === "charset"
+            ? "meta[charset]"
+            : `meta[${key}="${CSS.escape(value)}"]`;
+        break;
+      }
+    }
+  } else if (tag === "link" && attribute("rel") === "canonical") {
+    selector = 'link[rel="canonical"]';
+  }
+  return selector ? document.head.querySelector(selector) : null;
+}
+
 function createElementInHead(
   tag: string,
   attributes: [string, string][],
   children: string | null
 ): void {
+  findDuplicateInHead(tag, attributes)?.remove();
   const element = document.createElement(tag);
   for (const [key, value] of attributes) {
     element.setAttribute(key, value);
@@ -0,0 +1,111 @@
+//! Content security policy nonces for server rendered pages.
+
+use crate::prelude::DioxusServerContext;
+
+/// A [content security policy nonce](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/nonce) for the current request.
+///
+/// Insert the nonce into the extensions of the request, typically in the same middleware that sets the
+/// `Content-Security-Policy` header. While the page is rendered on the server, every inline script and style that
+/// dioxus writes will carry the nonce. This includes the hydration scripts, the scripts and styles in the index.html
+/// template, and the head elements inserted with components like `document::Style` and `document::Script`.
+///
+/// The nonce is also provided as a root context, so components can read it with `try_use_context::<CspNonce>()`.
+///
+/// Pages rendered with a nonce are never read from or written to the incremental rendering cache because the nonce
+/// must be unique for every response.
+///
+/// ```rust, ignore
+/// # use dioxus_fullstack::prelude::*;
+/// use axum::{extract::Request, middleware::Next, response::Response};
+///
+/// async fn csp(mut request: Request, next: Next) -> Response {
+///     let nonce = CspNonce::new(random_nonce());
+///     let policy = format!("script-src 'nonce-{}' 'wasm-unsafe-eval'; style-src 'nonce-{}'", nonce.as_str(), nonce.as_str());
+///     request.extensions_mut().insert(nonce);
+///     let mut response = next.run(request).await;
+///     response
+///         .headers_mut()
+///         .insert("content-security-policy", policy.parse().unwrap());
+///     response
+/// }
+///
+/// // The nonce must come from a cryptographically secure random number generator, like the one in the `rand` crate
+/// fn random_nonce() -> String {
+///     use base64::Engine;
+///     let bytes: [u8; 16] = rand::random();
+///     base64::engine::general_purpose::STANDARD.encode(bytes)
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CspNonce(String);
+
+impl CspNonce {
+    /// Create a new nonce. The nonce should be random and unique for every response
+    pub fn new(nonce: impl Into<String>) -> Self {
+        Self(nonce.into())
+    }
+
+    /// Get the value of the nonce
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the nonce from the extensions of the request the server context is handling
+    pub(crate) fn from_server_context(server_context: &DioxusServerContext) -> Option<Self> {
+        server_context
+            .request_parts()
+            .extensions
+            .get::<CspNonce>()
+            .cloned()
+    }
+
+    /// Get the nonce as an html attribute with a leading space, like ` nonce="..."`
+    pub(crate) fn attribute(&self) -> String {
+        let escaped = self
+            .0
+            .replace('&', "&amp;")
+            .replace('"', "&quot;")
+            .replace('<', "&lt;");
+        format!(" nonce=\"{escaped}\"")
+    }
+
+    /// Add the nonce to every opening script and style tag in a chunk of html from the index.html template
+    pub(crate) fn apply_to_template(&self, html: &str) -> String {
+        let attribute = self.attribute();
+        let mut output = String::with_capacity(html.len());
+        let mut rest = html;
+        while let Some(index) = rest.find('<') {
+            output.push_str(&rest[..index]);
+            rest = &rest[index..];
+            let tag = ["<script", "<style"].into_iter().find(|tag| {
+                rest.get(..tag.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(tag))
+                    && rest[tag.len()..].starts_with(|c: char| c == '>' || c.is_whitespace())
+            });
+            match tag {
+                Some(tag) => {
+                    output.push_str(&rest[..tag.len()]);
+                    output.push_str(&attribute);
+                    rest = &rest[tag.len()..];
+                }
+                None => {
+                    output.push('<');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        output.push_str(rest);
+        output
+    }
+}
+
+#[test]
+fn nonce_is_added_to_template_scripts_and_styles() {
+    let nonce = CspNonce::new("abc");
+    assert_eq!(
+        nonce.apply_to_template(
+            r#"<script type="module">init()</script><link rel="stylesheet"><STYLE>a{}</STYLE><scripts>"#
+        ),
+        r#"<script nonce="abc" type="module">init()</script><link rel="stylesheet"><STYLE nonce="abc">a{}</STYLE><scripts>"#
+    );
+}
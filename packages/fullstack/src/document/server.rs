@@ -6,6 +6,8 @@ use std::cell::RefCell;
 
 use dioxus_lib::{document::*, prelude::*};
 use dioxus_ssr::Renderer;
+
+use crate::CspNonce;
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 
@@ -14,17 +16,104 @@ static RENDERER: Lazy<RwLock<Renderer>> = Lazy::new(|| RwLock::new(Renderer::new
 #[derive(Default)]
 struct ServerDocumentInner {
     streaming: bool,
+    nonce: Option<CspNonce>,
     title: Option<String>,
-    meta: Vec<Element>,
-    link: Vec<Element>,
+    language: Option<DocumentLanguage>,
+    meta: Vec<HeadElement>,
+    link: Vec<HeadElement>,
     script: Vec<Element>,
 }
 
+/// An element in the head. Elements with the same key describe the same thing, so only the last one is rendered.
+struct HeadElement {
+    key: Option<String>,
+    element: Element,
+}
+
+fn insert_head_element(elements: &mut Vec<HeadElement>, key: Option<String>, element: Element) {
+    if key.is_some() {
+        elements.retain(|existing| existing.key != key);
+    }
+    elements.push(HeadElement { key, element });
+}
+
+/// Open Graph properties that are arrays, like several `og:image` tags for one page. The structured properties of an
+/// array item, like `og:image:width`, repeat with the item.
+const ARRAY_PROPERTIES: &[&str] = &[
+    "og:image",
+    "og:video",
+    "og:audio",
+    "og:locale:alternate",
+    "article:author",
+    "article:tag",
+    "book:author",
+    "book:tag",
+    "music:song",
+    "music:musician",
+    "music:creator",
+    "video:actor",
+    "video:director",
+    "video:writer",
+    "video:tag",
+];
+
+fn is_array_property(property: &str) -> bool {
+    ARRAY_PROPERTIES.iter().any(|array| {
+        property
+            .strip_prefix(array)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(':'))
+    })
+}
+
+fn meta_key(props: &MetaProps) -> Option<String> {
+    if let Some(name) = &props.name {
+        Some(format!("name={name}"))
+    } else if let Some(property) = &props.property {
+        if is_array_property(property) {
+            return None;
+        }
+        Some(format!("property={property}"))
+    } else if let Some(http_equiv) = &props.http_equiv {
+        Some(format!("http-equiv={http_equiv}"))
+    } else {
+        props.charset.as_ref().map(|_| "charset".to_string())
+    }
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+fn link_key(props: &LinkProps) -> Option<String> {
+    (props.rel.as_deref() == Some("canonical")).then(|| "canonical".to_string())
+}
+
 /// A Document provider that collects all contents injected into the head for SSR rendering.
 #[derive(Default)]
 pub struct ServerDocument(RefCell<ServerDocumentInner>);
 
 impl ServerDocument {
+    /// Create a document that adds the nonce to any scripts and styles that don't set their own nonce
+    pub(crate) fn new(nonce: Option<CspNonce>) -> Self {
+        Self(RefCell::new(ServerDocumentInner {
+            nonce,
+            ..Default::default()
+        }))
+    }
+
+    fn nonce(&self, nonce: Option<String>) -> Option<String> {
+        nonce.or_else(|| {
+            self.0
+                .borrow()
+                .nonce
+                .as_ref()
+                .map(|nonce| nonce.as_str().to_string())
+        })
+    }
+
     pub(crate) fn title(&self) -> Option<String> {
         let myself = self.0.borrow();
         myself.title.as_ref().map(|title| {
@@ -34,11 +123,31 @@ impl ServerDocument {
         })
     }
 
+    /// Add the `lang` and `dir` attributes from the latest [`Language`] component to the `<html>` tag in a chunk of
+    /// the index.html template. Returns `None` if there is no language or the chunk doesn't contain the tag.
+    pub(crate) fn apply_language(&self, html: &str) -> Option<String> {
+        let myself = self.0.borrow();
+        let language = myself.language.as_ref()?;
+        let start = html.to_ascii_lowercase().find("<html")? + "<html".len();
+        if html[start..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '-') {
+            return None;
+        }
+        let lang = escape_attribute(&language.lang);
+        let dir = language.dir.as_str();
+        // Browsers use the first occurrence of an attribute, so these take precedence over any `lang` or `dir` that is
+        // already in the template
+        Some(format!(
+            "{} lang=\"{lang}\" dir=\"{dir}\"{}",
+            &html[..start],
+            &html[start..]
+        ))
+    }
+
     pub(crate) fn render(&self, to: &mut impl std::fmt::Write) -> std::fmt::Result {
         let myself = self.0.borrow();
         let element = rsx! {
-            {myself.meta.iter().map(|m| rsx! { {m} })}
-            {myself.link.iter().map(|l| rsx! { {l} })}
+            {myself.meta.iter().map(|m| rsx! { {&m.element} })}
+            {myself.link.iter().map(|l| rsx! { {&l.element} })}
             {myself.script.iter().map(|s| rsx! { {s} })}
         };
 
@@ -80,8 +189,14 @@ impl Document for ServerDocument {
         self.0.borrow_mut().title = Some(title);
     }
 
+    fn set_language(&self, language: DocumentLanguage) {
+        self.warn_if_streaming();
+        self.0.borrow_mut().language = Some(language);
+    }
+
     fn create_meta(&self, props: MetaProps) {
-        self.0.borrow_mut().meta.push(rsx! {
+        let key = meta_key(&props);
+        let element = rsx! {
             meta {
                 name: props.name,
                 charset: props.charset,
@@ -90,11 +205,19 @@ impl Document for ServerDocument {
                 property: props.property,
                 ..props.additional_attributes,
             }
-        });
+        };
+        insert_head_element(&mut self.0.borrow_mut().meta, key, element);
     }
 
     fn create_script(&self, props: ScriptProps) {
         let children = props.script_contents().ok();
+        // JSON-LD is data, not html, so it is written as it is. The contents are escaped to never close the script
+        // tag early. Other scripts keep their contents as text
+        let (inner_html, children) = match props.r#type.as_deref() {
+            Some("application/ld+json") => (children, None),
+            _ => (None, children),
+        };
+        let nonce = self.nonce(props.nonce);
         self.0.borrow_mut().script.push(rsx! {
             script {
                 src: props.src,
@@ -103,9 +226,10 @@ impl Document for ServerDocument {
                 fetchpriority: props.fetchpriority,
                 integrity: props.integrity,
                 nomodule: props.nomodule,
-                nonce: props.nonce,
+                nonce,
                 referrerpolicy: props.referrerpolicy,
                 r#type: props.r#type,
+                dangerous_inner_html: inner_html,
                 ..props.additional_attributes,
                 {children}
             }
@@ -114,10 +238,11 @@ impl Document for ServerDocument {
 
     fn create_style(&self, props: StyleProps) {
         let contents = props.style_contents().ok();
+        let nonce = self.nonce(props.nonce);
         self.0.borrow_mut().script.push(rsx! {
             style {
                 media: props.media,
-                nonce: props.nonce,
+                nonce,
                 title: props.title,
                 ..props.additional_attributes,
                 {contents}
@@ -126,7 +251,8 @@ impl Document for ServerDocument {
     }
 
     fn create_link(&self, props: LinkProps) {
-        self.0.borrow_mut().link.push(rsx! {
+        let key = link_key(&props);
+        let element = rsx! {
             link {
                 rel: props.rel,
                 media: props.media,
@@ -144,7 +270,8 @@ impl Document for ServerDocument {
                 blocking: props.blocking,
                 ..props.additional_attributes,
             }
-        })
+        };
+        insert_head_element(&mut self.0.borrow_mut().link, key, element);
     }
 
     fn create_head_component(&self) -> bool {
@@ -153,3 +280,99 @@ impl Document for ServerDocument {
         true
     }
 }
+
+#[test]
+fn language_is_added_to_the_html_tag() {
+    let document = ServerDocument::default();
+    assert_eq!(
+        document.apply_language(r#"<!DOCTYPE html><html lang="en">"#),
+        None
+    );
+
+    document.set_language(DocumentLanguage::new("ar-EG"));
+    assert_eq!(
+        document.apply_language(r#"<!DOCTYPE html><HTML lang="en"><head>"#),
+        Some(r#"<!DOCTYPE html><HTML lang="ar-EG" dir="rtl" lang="en"><head>"#.to_string())
+    );
+    assert_eq!(
+        document.apply_language("<head><meta charset=\"UTF-8\">"),
+        None
+    );
+}
+
+#[test]
+fn only_json_ld_scripts_are_written_unescaped() {
+    fn script(r#type: Option<&str>, contents: &str) -> ScriptProps {
+        ScriptProps {
+            children: rsx! { "{contents}" },
+            src: None,
+            defer: None,
+            crossorigin: None,
+            fetchpriority: None,
+            integrity: None,
+            nomodule: None,
+            nonce: None,
+            referrerpolicy: None,
+            r#type: r#type.map(str::to_string),
+            additional_attributes: Vec::new(),
+        }
+    }
+
+    let dom = VirtualDom::new(VNode::empty);
+    let head = dom.in_runtime(|| {
+        let document = ServerDocument::default();
+        document.create_script(script(Some("application/ld+json"), r#"{"name":"A & B"}"#));
+        document.create_script(script(None, "1 < 2"));
+        let mut head = String::new();
+        document.render(&mut head).unwrap();
+        head
+    });
+    assert_eq!(
+        head,
+        concat!(
+            r#"<script type="application/ld+json">{"name":"A & B"}</script>"#,
+            r#"<script>1 &lt; 2</script>"#,
+        )
+    );
+}
+
+#[test]
+fn array_properties_are_not_deduplicated() {
+    fn meta(name: Option<&str>, property: Option<&str>, content: &str) -> MetaProps {
+        MetaProps {
+            property: property.map(str::to_string),
+            name: name.map(str::to_string),
+            charset: None,
+            http_equiv: None,
+            content: Some(content.to_string()),
+            additional_attributes: Vec::new(),
+        }
+    }
+
+    let dom = VirtualDom::new(VNode::empty);
+    let head = dom.in_runtime(|| {
+        let document = ServerDocument::default();
+        document.create_meta(meta(Some("description"), None, "Layout"));
+        document.create_meta(meta(None, Some("og:title"), "Layout"));
+        document.create_meta(meta(None, Some("og:image"), "/a.png"));
+        document.create_meta(meta(None, Some("og:image:width"), "100"));
+        document.create_meta(meta(None, Some("og:image"), "/b.png"));
+        document.create_meta(meta(None, Some("og:image:width"), "200"));
+        document.create_meta(meta(Some("description"), None, "Page"));
+        document.create_meta(meta(None, Some("og:title"), "Page"));
+        let mut head = String::new();
+        document.render(&mut head).unwrap();
+        head
+    });
+    assert_eq!(
+        head,
+        concat!(
+            r#"<meta content="/a.png" property="og:image"/>"#,
+            r#"<meta content="100" property="og:image:width"/>"#,
+            r#"<meta content="/b.png" property="og:image"/>"#,
+            r#"<meta content="200" property="og:image:width"/>"#,
+            r#"<meta name="description" content="Page"/>"#,
+            r#"<meta content="Page" property="og:title"/>"#,
+        )
+    );
+}
@@ -25,6 +25,11 @@ impl Document for FullstackWebDocument {
         WebDocument.set_title(title);
     }
 
+    /// Set the language of the document
+    fn set_language(&self, language: DocumentLanguage) {
+        WebDocument.set_language(language);
+    }
+
     /// Create a new meta tag in the head
     fn create_meta(&self, props: MetaProps) {
         WebDocument.create_meta(props);
@@ -45,6 +50,10 @@ impl Document for FullstackWebDocument {
         WebDocument.create_link(props);
     }
 
+    fn capabilities(&self) -> Capabilities {
+        WebDocument.capabilities()
+    }
+
     fn create_head_component(&self) -> bool {
         !head_element_written_on_server()
     }
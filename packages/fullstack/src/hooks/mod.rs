@@ -1,2 +1,3 @@
 pub mod server_cached;
+pub mod server_form;
 pub mod server_future;
@@ -0,0 +1,383 @@
+use dioxus_lib::{html::FormValue, prelude::*};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use server_fn::{error::ServerFnError, ServerFn};
+use std::collections::{BTreeMap, HashMap};
+
+/// Validation errors for the fields of a form, keyed by the name of the field.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FormErrors(BTreeMap<String, String>);
+
+impl FormErrors {
+    /// Create an empty set of errors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an error for a field
+    pub fn add(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.0.insert(field.into(), message.into());
+    }
+
+    /// Get the error for a field, if there is one
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.0.get(field).map(|message| message.as_str())
+    }
+
+    /// Check if there are no errors
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the fields and their errors
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(field, message)| (field.as_str(), message.as_str()))
+    }
+
+    /// Return `Ok(())` if there are no errors, or the errors otherwise
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Get the form errors from a server function error returned by [`ValidateForm::validate_on_server`]
+    pub fn from_server_error<E>(error: &ServerFnError<E>) -> Option<Self> {
+        match error {
+            ServerFnError::ServerError(message) => serde_json::from_str(message).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FormErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (field, message)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{field}: {message}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FormErrors {}
+
+/// Validation that is shared between a server function and the form that submits to it.
+///
+/// Implement this trait on the arguments struct the `#[server]` macro generates for your server function. The client
+/// runs the validation before sending the request, and the server function can run the same validation with
+/// [`ValidateForm::validate_on_server`] for submissions that happen before the wasm bundle is loaded.
+pub trait ValidateForm {
+    /// Validate the fields of the form
+    fn validate(&self) -> Result<(), FormErrors>;
+
+    /// Validate the fields of the form inside of a server function. Any errors are returned as a [`ServerFnError`]
+    /// that [`FormErrors::from_server_error`] can turn back into form errors on the client.
+    fn validate_on_server<E>(&self) -> Result<(), ServerFnError<E>> {
+        self.validate().map_err(|errors| {
+            ServerFnError::ServerError(serde_json::to_string(&errors).unwrap_or_default())
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum FormField<'a> {
+    Single(&'a str),
+    Multiple(&'a [String]),
+}
+
+/// Parse the values of a submitted form with the same url encoding server functions use for form posts.
+pub fn parse_form<T: DeserializeOwned>(
+    values: &HashMap<String, FormValue>,
+) -> Result<T, serde_qs::Error> {
+    let fields: BTreeMap<&str, FormField> = values
+        .iter()
+        .map(|(name, value)| {
+            let field = match value.as_slice() {
+                [single] => FormField::Single(single),
+                many => FormField::Multiple(many),
+            };
+            (name.as_str(), field)
+        })
+        .collect();
+    let encoded = serde_qs::to_string(&fields)?;
+    serde_qs::from_str(&encoded)
+}
+
+type ServerFnResult<F> = Result<<F as ServerFn>::Output, ServerFnError<<F as ServerFn>::Error>>;
+
+/// A form that submits to a server function and keeps working before the wasm bundle is loaded.
+///
+/// Created with [`use_server_form`].
+pub struct ServerForm<F>
+where
+    F: ServerFn,
+    F::Output: 'static,
+    F::Error: 'static,
+{
+    result: Signal<Option<ServerFnResult<F>>>,
+    errors: Signal<FormErrors>,
+    pending: Signal<bool>,
+}
+
+impl<F> Clone for ServerForm<F>
+where
+    F: ServerFn,
+    F::Output: 'static,
+    F::Error: 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for ServerForm<F>
+where
+    F: ServerFn,
+    F::Output: 'static,
+    F::Error: 'static,
+{
+}
+
+impl<F> ServerForm<F>
+where
+    F: ServerFn + DeserializeOwned + ValidateForm + 'static,
+    F::Output: 'static,
+    F::Error: 'static,
+{
+    /// The url of the server function. Use this as the `action` of the form so the browser can post the form
+    /// directly to the server function before the wasm bundle is loaded.
+    pub fn action(&self) -> &'static str {
+        F::url()
+    }
+
+    /// Handle a submit event from the form. The values of the form are parsed into the server function arguments,
+    /// validated with [`ValidateForm::validate`], and sent to the server if they are valid.
+    pub fn submit(&self, event: FormEvent) {
+        let mut errors = self.errors;
+        let mut result = self.result;
+        let mut pending = self.pending;
+
+        let arguments = match parse_form::<F>(&event.values()) {
+            Ok(arguments) => arguments,
+            Err(err) => {
+                let mut parse_errors = FormErrors::new();
+                parse_errors.add("", err.to_string());
+                errors.set(parse_errors);
+                return;
+            }
+        };
+
+        if let Err(validation_errors) = arguments.validate() {
+            errors.set(validation_errors);
+            return;
+        }
+
+        errors.set(FormErrors::new());
+        pending.set(true);
+        spawn(async move {
+            let response = arguments.run_on_client().await;
+            if let Err(err) = &response {
+                if let Some(server_errors) = FormErrors::from_server_error(err) {
+                    errors.set(server_errors);
+                }
+            }
+            result.set(Some(response));
+            pending.set(false);
+        });
+    }
+
+    /// The validation errors from the last submission
+    pub fn errors(&self) -> ReadOnlySignal<FormErrors> {
+        self.errors.into()
+    }
+
+    /// The result of the last submission that was sent from the client
+    pub fn result(&self) -> ReadOnlySignal<Option<ServerFnResult<F>>> {
+        self.result.into()
+    }
+
+    /// Check if a submission is currently waiting for the server
+    pub fn pending(&self) -> bool {
+        (self.pending)()
+    }
+}
+
+/// Create a form that submits to the server function `F` with progressive enhancement.
+///
+/// Before the wasm bundle is loaded, the browser posts the form to the server function endpoint and is redirected
+/// back to the page. Once the page is hydrated, submissions are intercepted, validated on the client, and sent with
+/// the server function client instead.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::fullstack::prelude::{FormErrors, ValidateForm};
+///
+/// #[server]
+/// async fn add_todo(title: String) -> Result<(), ServerFnError> {
+///     // Submissions from forms that were not hydrated yet skip the client side validation
+///     AddTodo { title: title.clone() }.validate_on_server()?;
+///     Ok(())
+/// }
+///
+/// impl ValidateForm for AddTodo {
+///     fn validate(&self) -> Result<(), FormErrors> {
+///         let mut errors = FormErrors::new();
+///         if self.title.trim().is_empty() {
+///             errors.add("title", "The title can't be empty");
+///         }
+///         errors.into_result()
+///     }
+/// }
+///
+/// fn NewTodo() -> Element {
+///     let form = use_server_form::<AddTodo>();
+///     rsx! {
+///         form {
+///             action: form.action(),
+///             method: "post",
+///             onsubmit: move |event| form.submit(event),
+///             input { name: "title" }
+///             if let Some(error) = form.errors().read().get("title") {
+///                 p { "{error}" }
+///             }
+///             button { disabled: form.pending(), "Add" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_server_form<F>() -> ServerForm<F>
+where
+    F: ServerFn + DeserializeOwned + ValidateForm + 'static,
+    F::Output: 'static,
+    F::Error: 'static,
+{
+    let result = use_signal(|| None);
+    let errors = use_signal(FormErrors::new);
+    let pending = use_signal(|| false);
+    ServerForm {
+        result,
+        errors,
+        pending,
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug, PartialEq, Deserialize)]
+struct TestForm {
+    title: String,
+    priority: u32,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[cfg(test)]
+impl ValidateForm for TestForm {
+    fn validate(&self) -> Result<(), FormErrors> {
+        let mut errors = FormErrors::new();
+        if self.title.trim().is_empty() {
+            errors.add("title", "The title can't be empty");
+        }
+        if self.priority > 5 {
+            errors.add("priority", "The priority must be at most 5");
+        }
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+fn form_values(values: &[(&str, &[&str])]) -> HashMap<String, FormValue> {
+    values
+        .iter()
+        .map(|(name, values)| {
+            let values = values.iter().map(|value| value.to_string()).collect();
+            (name.to_string(), FormValue(values))
+        })
+        .collect()
+}
+
+#[test]
+fn parse_single_and_repeated_form_fields() {
+    let values = form_values(&[
+        ("title", &["Write tests"]),
+        ("priority", &["3"]),
+        ("tags", &["fullstack", "forms"]),
+    ]);
+    assert_eq!(
+        parse_form::<TestForm>(&values).unwrap(),
+        TestForm {
+            title: "Write tests".to_string(),
+            priority: 3,
+            tags: vec!["fullstack".to_string(), "forms".to_string()],
+        }
+    );
+
+    // Fields that are missing from the form use their serde defaults
+    let values = form_values(&[("title", &["Write tests"]), ("priority", &["3"])]);
+    assert!(parse_form::<TestForm>(&values).unwrap().tags.is_empty());
+
+    // Values that don't parse into the field type are an error
+    let values = form_values(&[("title", &["Write tests"]), ("priority", &["high"])]);
+    assert!(parse_form::<TestForm>(&values).is_err());
+}
+
+#[test]
+fn form_errors_are_ordered_by_field() {
+    let mut errors = FormErrors::new();
+    assert!(errors.clone().into_result().is_ok());
+
+    errors.add("title", "The title can't be empty");
+    errors.add("priority", "The priority must be at most 5");
+    // Adding a field again replaces its error
+    errors.add("title", "The title is too short");
+
+    assert_eq!(errors.get("title"), Some("The title is too short"));
+    assert_eq!(errors.get("tags"), None);
+    assert_eq!(
+        errors.iter().map(|(field, _)| field).collect::<Vec<_>>(),
+        ["priority", "title"]
+    );
+    assert_eq!(
+        errors.to_string(),
+        "priority: The priority must be at most 5; title: The title is too short"
+    );
+    assert_eq!(errors.clone().into_result(), Err(errors));
+}
+
+#[test]
+fn server_validation_errors_round_trip() {
+    use server_fn::error::NoCustomError;
+
+    let valid = TestForm {
+        title: "Write tests".to_string(),
+        priority: 3,
+        tags: Vec::new(),
+    };
+    assert!(valid.validate_on_server::<NoCustomError>().is_ok());
+
+    let invalid = TestForm {
+        title: " ".to_string(),
+        priority: 9,
+        tags: Vec::new(),
+    };
+    let error = invalid.validate_on_server::<NoCustomError>().unwrap_err();
+    assert_eq!(
+        FormErrors::from_server_error(&error),
+        Some(invalid.validate().unwrap_err())
+    );
+
+    // Other server errors are not form errors
+    let error = ServerFnError::<NoCustomError>::ServerError("database offline".to_string());
+    assert_eq!(FormErrors::from_server_error(&error), None);
+    let error = ServerFnError::<NoCustomError>::Request("timed out".to_string());
+    assert_eq!(FormErrors::from_server_error(&error), None);
+}
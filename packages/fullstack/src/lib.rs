@@ -19,6 +19,13 @@ pub mod document;
 mod render;
 #[cfg(feature = "server")]
 mod streaming;
+#[cfg(feature = "server")]
+mod virtual_dom_pool;
+
+#[cfg(feature = "server")]
+mod csp;
+#[cfg(feature = "server")]
+pub use csp::*;
 
 #[cfg(feature = "server")]
 mod serve_config;
@@ -31,7 +38,11 @@ mod server_context;
 /// A prelude of commonly used items in dioxus-fullstack.
 pub mod prelude {
     use crate::hooks;
-    pub use hooks::{server_cached::use_server_cached, server_future::use_server_future};
+    pub use hooks::{
+        server_cached::use_server_cached,
+        server_form::{parse_form, use_server_form, FormErrors, ServerForm, ValidateForm},
+        server_future::use_server_future,
+    };
 
     #[cfg(feature = "axum")]
     #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
@@ -41,10 +52,18 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
     pub use crate::render::{FullstackHTMLTemplate, SSRState};
 
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub use crate::virtual_dom_pool::{VirtualDomPool, VirtualDomPoolMetrics};
+
     #[cfg(feature = "server")]
     #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
     pub use crate::serve_config::{ServeConfig, ServeConfigBuilder};
 
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub use crate::csp::CspNonce;
+
     #[cfg(all(feature = "server", feature = "axum"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "server", feature = "axum"))))]
     pub use crate::server_context::Axum;
@@ -2,6 +2,7 @@
 use crate::document::ServerDocument;
 use crate::html_storage::serialize::SerializedHydrationData;
 use crate::streaming::{Mount, StreamingRenderer};
+use crate::virtual_dom_pool::{PooledVirtualDom, VirtualDomPool};
 use dioxus_cli_config::base_path;
 use dioxus_interpreter_js::INITIALIZE_STREAMING_JS;
 use dioxus_isrg::{CachedRender, IncrementalRendererError, RenderFreshness};
@@ -25,6 +26,16 @@ struct PendingSuspenseBoundary {
     children: Vec<ScopeId>,
 }
 
+/// Get the host the request was sent to. HTTP/2 requests send the host in the uri instead of the host header
+fn request_host(parts: &http::request::Parts) -> Option<String> {
+    parts
+        .headers
+        .get(http::header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .or_else(|| parts.uri.authority().map(|authority| authority.as_str()))
+        .map(|host| host.to_string())
+}
+
 /// Spawn a task in the background. If wasm is enabled, this will use the single threaded tokio runtime
 fn spawn_platform<Fut>(f: impl FnOnce() -> Fut + Send + 'static) -> JoinHandle<Fut::Output>
 where
@@ -105,7 +116,7 @@ impl SsrRendererPool {
         self: Arc<Self>,
         cfg: &ServeConfig,
         route: String,
-        virtual_dom_factory: impl FnOnce() -> VirtualDom + Send + Sync + 'static,
+        virtual_dom_factory: impl FnOnce() -> PooledVirtualDom + Send + Sync + 'static,
         server_context: &DioxusServerContext,
     ) -> Result<
         (
@@ -145,8 +156,15 @@ impl SsrRendererPool {
             Result<String, dioxus_isrg::IncrementalRendererError>,
         >(1000);
 
+        // Pages rendered with a nonce can't be cached because the nonce must be unique for every response
+        let nonce = CspNonce::from_server_context(server_context);
+
         // before we even spawn anything, we can check synchronously if we have the route cached
-        if let Some(freshness) = self.check_cached_route(&route, &mut into) {
+        if let Some(freshness) = nonce
+            .is_none()
+            .then(|| self.check_cached_route(&route, &mut into))
+            .flatten()
+        {
             return Ok((
                 freshness,
                 ReceiverWithDrop {
@@ -156,7 +174,10 @@ impl SsrRendererPool {
             ));
         }
 
-        let wrapper = FullstackHTMLTemplate { cfg: cfg.clone() };
+        let wrapper = FullstackHTMLTemplate {
+            cfg: cfg.clone(),
+            nonce: nonce.clone(),
+        };
 
         let server_context = server_context.clone();
         let mut renderer = self
@@ -169,13 +190,19 @@ impl SsrRendererPool {
         let myself = self.clone();
         let streaming_mode = cfg.streaming_mode;
 
+        let (initial_frame_tx, initial_frame_rx) = futures_channel::oneshot::channel();
+
         let join_handle = spawn_platform(move || async move {
             let mut virtual_dom = virtual_dom_factory();
-            let document = std::rc::Rc::new(crate::document::server::ServerDocument::default());
+            let document =
+                std::rc::Rc::new(crate::document::server::ServerDocument::new(nonce.clone()));
             virtual_dom.provide_root_context(document.clone());
+            if let Some(nonce) = &nonce {
+                virtual_dom.provide_root_context(nonce.clone());
+            }
             // If there is a base path, trim the base path from the route and add the base path formatting to the
             // history provider
-            let history;
+            let mut history;
             if let Some(base_path) = base_path() {
                 let base_path = base_path.trim_matches('/');
                 let base_path = format!("/{base_path}");
@@ -185,6 +212,10 @@ impl SsrRendererPool {
             } else {
                 history = dioxus_history::MemoryHistory::with_initial_path(&route);
             }
+            // Pass the host of the request on to host aware route matchers
+            if let Some(host) = request_host(&server_context.request_parts()) {
+                history = history.with_host(host);
+            }
             virtual_dom.provide_root_context(Rc::new(history) as Rc<dyn dioxus_history::History>);
             virtual_dom.provide_root_context(document.clone() as std::rc::Rc<dyn Document>);
 
@@ -198,7 +229,7 @@ impl SsrRendererPool {
                 return;
             }
 
-            let stream = Arc::new(StreamingRenderer::new(pre_body, into));
+            let stream = Arc::new(StreamingRenderer::new(pre_body, into, nonce.clone()));
             let scope_to_mount_mapping = Arc::new(RwLock::new(HashMap::new()));
 
             renderer.pre_render = true;
@@ -225,6 +256,26 @@ impl SsrRendererPool {
                     .await
             }
 
+            // Errors thrown while building the dom are only caught by their error boundary on the next render. Render
+            // the boundaries so the error pages are in the initial frame, and so the router knows which page it shows
+            with_server_context(server_context.clone(), || {
+                virtual_dom.render_immediate(&mut dioxus_lib::prelude::dioxus_core::NoOpMutations)
+            });
+
+            // If the router rendered an error page, use the status of that page for the response. Without streaming
+            // every suspense boundary is resolved at this point. With streaming, the status is sent with the initial
+            // frame, so pages that suspended boundaries render later can't change it
+            #[cfg(feature = "router")]
+            if let Some(status) = virtual_dom.in_runtime(|| {
+                dioxus_router::prelude::root_router().and_then(|router| router.error_status())
+            }) {
+                if let Ok(status) = http::StatusCode::from_u16(status.code()) {
+                    server_context.response_parts_mut().status = status;
+                }
+            }
+            // The response parts are final once the initial frame is rendered
+            _ = initial_frame_tx.send(());
+
             // Render the initial frame with loading placeholders
             let mut initial_frame = renderer.render(&virtual_dom);
 
@@ -300,7 +351,7 @@ impl SsrRendererPool {
             }
 
             // If incremental rendering is enabled, add the new render to the cache without the streaming bits
-            if let Some(incremental) = &self.incremental_cache {
+            if let Some(incremental) = self.incremental_cache.as_ref().filter(|_| nonce.is_none()) {
                 let mut cached_render = String::new();
                 if let Err(err) = wrapper.render_head(&mut cached_render, &virtual_dom) {
                     throw_error!(err);
@@ -325,6 +376,10 @@ impl SsrRendererPool {
             myself.renderers.write().unwrap().push(renderer);
         });
 
+        // Wait for the first frame so any response parts set while rendering it are applied to the response.
+        // If the render task fails before that, the error is reported through the stream instead
+        _ = initial_frame_rx.await;
+
         Ok((
             RenderFreshness::now(None),
             ReceiverWithDrop {
@@ -451,7 +506,34 @@ impl SSRState {
     > {
         self.renderers
             .clone()
-            .render_to(cfg, route, virtual_dom_factory, server_context)
+            .render_to(
+                cfg,
+                route,
+                move || PooledVirtualDom::unpooled(virtual_dom_factory()),
+                server_context,
+            )
+            .await
+    }
+
+    /// Render the application to HTML with a virtual dom from a [`VirtualDomPool`]. The virtual dom is returned to the
+    /// pool once the response is finished.
+    pub async fn render_pooled<'a>(
+        &'a self,
+        route: String,
+        cfg: &'a ServeConfig,
+        pool: &'a VirtualDomPool,
+        server_context: &'a DioxusServerContext,
+    ) -> Result<
+        (
+            RenderFreshness,
+            impl Stream<Item = Result<String, dioxus_isrg::IncrementalRendererError>>,
+        ),
+        dioxus_isrg::IncrementalRendererError,
+    > {
+        let pool = pool.clone();
+        self.renderers
+            .clone()
+            .render_to(cfg, route, move || pool.checkout(), server_context)
             .await
     }
 }
@@ -459,12 +541,38 @@ impl SSRState {
 /// The template that wraps the body of the HTML for a fullstack page. This template contains the data needed to hydrate server functions that were run on the server.
 pub struct FullstackHTMLTemplate {
     cfg: ServeConfig,
+    nonce: Option<CspNonce>,
 }
 
 impl FullstackHTMLTemplate {
     /// Create a new [`FullstackHTMLTemplate`].
     pub fn new(cfg: &ServeConfig) -> Self {
-        Self { cfg: cfg.clone() }
+        Self {
+            cfg: cfg.clone(),
+            nonce: None,
+        }
+    }
+
+    /// Add a [`CspNonce`] to every inline script and style the template renders
+    pub fn with_nonce(mut self, nonce: CspNonce) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Write a section of the index.html template, adding the nonce to any scripts and styles in it
+    fn write_template<R: std::fmt::Write>(&self, to: &mut R, html: &str) -> std::fmt::Result {
+        match &self.nonce {
+            Some(nonce) => to.write_str(&nonce.apply_to_template(html)),
+            None => to.write_str(html),
+        }
+    }
+
+    /// Get the nonce attribute for the scripts the template renders, or an empty string if there is no nonce
+    fn nonce_attribute(&self) -> String {
+        self.nonce
+            .as_ref()
+            .map(CspNonce::attribute)
+            .unwrap_or_default()
     }
 }
 
@@ -477,23 +585,38 @@ impl FullstackHTMLTemplate {
     ) -> Result<(), dioxus_isrg::IncrementalRendererError> {
         let ServeConfig { index, .. } = &self.cfg;
 
-        let title = {
-            let document: Option<std::rc::Rc<ServerDocument>> =
-                virtual_dom.in_runtime(|| ScopeId::ROOT.consume_context());
-            // Collect any head content from the document provider and inject that into the head
-            document.and_then(|document| document.title())
+        let document: Option<std::rc::Rc<ServerDocument>> =
+            virtual_dom.in_runtime(|| ScopeId::ROOT.consume_context());
+        // Collect any head content from the document provider and inject that into the head
+        let title = document.as_ref().and_then(|document| document.title());
+
+        // The `<html>` tag is in the part of the template before the title if the template has a title
+        let (head_before_title, head_after_title) = match &document {
+            Some(document) => match document.apply_language(&index.head_before_title) {
+                Some(head_before_title) => (Some(head_before_title), None),
+                None => (None, document.apply_language(&index.head_after_title)),
+            },
+            None => (None, None),
         };
 
-        to.write_str(&index.head_before_title)?;
+        self.write_template(
+            to,
+            head_before_title
+                .as_deref()
+                .unwrap_or(&index.head_before_title),
+        )?;
         if let Some(title) = title {
             to.write_str(&title)?;
         } else {
             to.write_str(&index.title)?;
         }
-        to.write_str(&index.head_after_title)?;
+        self.write_template(
+            to,
+            head_after_title
+                .as_deref()
+                .unwrap_or(&index.head_after_title),
+        )?;
 
-        let document: Option<std::rc::Rc<ServerDocument>> =
-            virtual_dom.in_runtime(|| ScopeId::ROOT.consume_context());
         if let Some(document) = document {
             // Collect any head content from the document provider and inject that into the head
             document.render(to)?;
@@ -514,9 +637,10 @@ impl FullstackHTMLTemplate {
     ) -> Result<(), dioxus_isrg::IncrementalRendererError> {
         let ServeConfig { index, .. } = &self.cfg;
 
-        to.write_str(&index.close_head)?;
+        self.write_template(to, &index.close_head)?;
 
-        write!(to, "<script>{INITIALIZE_STREAMING_JS}</script>")?;
+        let nonce = self.nonce_attribute();
+        write!(to, "<script{nonce}>{INITIALIZE_STREAMING_JS}</script>")?;
 
         Ok(())
     }
@@ -534,9 +658,10 @@ impl FullstackHTMLTemplate {
         let resolved_data = serialize_server_data(virtual_dom, ScopeId::ROOT);
         // We always send down the data required to hydrate components on the client
         let raw_data = resolved_data.data;
+        let nonce = self.nonce_attribute();
         write!(
             to,
-            r#"<script>window.initial_dioxus_hydration_data="{raw_data}";"#,
+            r#"<script{nonce}>window.initial_dioxus_hydration_data="{raw_data}";"#,
         )?;
         #[cfg(debug_assertions)]
         {
@@ -553,7 +678,7 @@ impl FullstackHTMLTemplate {
             )?;
         }
         write!(to, r#"</script>"#,)?;
-        to.write_str(&index.post_main)?;
+        self.write_template(to, &index.post_main)?;
 
         Ok(())
     }
@@ -565,7 +690,7 @@ impl FullstackHTMLTemplate {
     ) -> Result<(), dioxus_isrg::IncrementalRendererError> {
         let ServeConfig { index, .. } = &self.cfg;
 
-        to.write_str(&index.after_closing_body_tag)?;
+        self.write_template(to, &index.after_closing_body_tag)?;
 
         Ok(())
     }
@@ -18,6 +18,7 @@ pub struct ServeConfigBuilder {
     pub(crate) incremental: Option<dioxus_isrg::IncrementalRendererConfig>,
     pub(crate) context_providers: ContextProviders,
     pub(crate) streaming_mode: StreamingMode,
+    pub(crate) virtual_dom_pool_size: Option<usize>,
 }
 
 impl LaunchConfig for ServeConfigBuilder {}
@@ -32,6 +33,7 @@ impl ServeConfigBuilder {
             incremental: None,
             context_providers: Default::default(),
             streaming_mode: StreamingMode::default(),
+            virtual_dom_pool_size: None,
         }
     }
 
@@ -177,6 +179,27 @@ impl ServeConfigBuilder {
         self
     }
 
+    /// Set how many idle virtual doms are kept ready for the next request on each render thread. A request that finds
+    /// an idle virtual dom skips building one and reuses the buffers of an earlier render. Set it to zero to build a
+    /// new virtual dom for every request. (defaults to 4)
+    ///
+    /// The [`VirtualDomPoolMetrics`](crate::prelude::VirtualDomPoolMetrics) of the pool show whether the size fits the
+    /// load of the server.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # fn app() -> Element { todo!() }
+    /// dioxus::LaunchBuilder::new()
+    ///     .with_context(server_only! {
+    ///         dioxus::fullstack::ServeConfig::builder().virtual_dom_pool_size(16)
+    ///     })
+    ///     .launch(app);
+    /// ```
+    pub fn virtual_dom_pool_size(mut self, size: usize) -> Self {
+        self.virtual_dom_pool_size = Some(size);
+        self
+    }
+
     /// Build the ServeConfig. This may fail if the index.html file is not found.
     pub fn build(self) -> Result<ServeConfig, UnableToLoadIndex> {
         // The CLI always bundles static assets into the exe/public directory
@@ -201,6 +224,7 @@ impl ServeConfigBuilder {
             incremental: self.incremental,
             context_providers: self.context_providers,
             streaming_mode: self.streaming_mode,
+            virtual_dom_pool_size: self.virtual_dom_pool_size.unwrap_or(4),
         })
     }
 }
@@ -318,6 +342,7 @@ pub struct ServeConfig {
     pub(crate) incremental: Option<dioxus_isrg::IncrementalRendererConfig>,
     pub(crate) context_providers: ContextProviders,
     pub(crate) streaming_mode: StreamingMode,
+    pub(crate) virtual_dom_pool_size: usize,
 }
 
 impl LaunchConfig for ServeConfig {}
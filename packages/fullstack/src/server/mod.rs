@@ -299,6 +299,7 @@ pub struct RenderHandleState {
     config: ServeConfig,
     build_virtual_dom: Arc<dyn Fn() -> VirtualDom + Send + Sync>,
     ssr_state: once_cell::sync::OnceCell<SSRState>,
+    virtual_dom_pool: once_cell::sync::OnceCell<VirtualDomPool>,
 }
 
 impl RenderHandleState {
@@ -308,6 +309,7 @@ impl RenderHandleState {
             config,
             build_virtual_dom: Arc::new(move || VirtualDom::new(root)),
             ssr_state: Default::default(),
+            virtual_dom_pool: Default::default(),
         }
     }
 
@@ -320,12 +322,14 @@ impl RenderHandleState {
             config,
             build_virtual_dom: Arc::new(build_virtual_dom),
             ssr_state: Default::default(),
+            virtual_dom_pool: Default::default(),
         }
     }
 
     /// Set the [`ServeConfig`] for this [`RenderHandleState`]
     pub fn with_config(mut self, config: ServeConfig) -> Self {
         self.config = config;
+        self.virtual_dom_pool = Default::default();
         self
     }
 
@@ -341,6 +345,22 @@ impl RenderHandleState {
     fn ssr_state(&self) -> &SSRState {
         self.ssr_state.get_or_init(|| SSRState::new(&self.config))
     }
+
+    /// Get the pool of virtual doms requests are rendered with. Its [`VirtualDomPool::metrics`] can be used to tune
+    /// [`ServeConfigBuilder::virtual_dom_pool_size`].
+    pub fn virtual_dom_pool(&self) -> &VirtualDomPool {
+        self.virtual_dom_pool.get_or_init(|| {
+            let build_virtual_dom = self.build_virtual_dom.clone();
+            let context_providers = self.config.context_providers.clone();
+            VirtualDomPool::new(self.config.virtual_dom_pool_size, move || {
+                let mut vdom = build_virtual_dom();
+                for state in context_providers.as_slice() {
+                    vdom.insert_any_root_context(state());
+                }
+                vdom
+            })
+        })
+    }
 }
 
 /// SSR renderer handler for Axum with added context injection.
@@ -390,17 +410,7 @@ pub async fn render_handler(
 
     let cfg = &state.config;
     let ssr_state = state.ssr_state();
-    let build_virtual_dom = {
-        let build_virtual_dom = state.build_virtual_dom.clone();
-        let context_providers = state.config.context_providers.clone();
-        move || {
-            let mut vdom = build_virtual_dom();
-            for state in context_providers.as_slice() {
-                vdom.insert_any_root_context(state());
-            }
-            vdom
-        }
-    };
+    let virtual_dom_pool = state.virtual_dom_pool();
 
     let (parts, _) = request.into_parts();
     let url = parts
@@ -416,14 +426,15 @@ pub async fn render_handler(
     add_server_context(&server_context, &state.config.context_providers);
 
     match ssr_state
-        .render(url, cfg, build_virtual_dom, &server_context)
+        .render_pooled(url, cfg, virtual_dom_pool, &server_context)
         .await
     {
         Ok((freshness, rx)) => {
             let mut response = axum::response::Html::from(Body::from_stream(rx)).into_response();
             freshness.write(response.headers_mut());
-            let headers = server_context.response_parts().headers.clone();
-            apply_request_parts_to_response(headers, &mut response);
+            let parts = server_context.response_parts();
+            *response.status_mut() = parts.status;
+            apply_request_parts_to_response(parts.headers.clone(), &mut response);
             Ok(response)
         }
         Err(e) => {
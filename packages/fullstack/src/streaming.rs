@@ -34,6 +34,7 @@ use std::{
 };
 
 use crate::html_storage::serialize::SerializedHydrationData;
+use crate::CspNonce;
 
 /// Sections are identified by a unique id based on the suspense path. We only track the path of suspense boundaries because the client may render different components than the server.
 #[derive(Clone, Debug, Default)]
@@ -63,13 +64,16 @@ impl Display for MountPath {
 pub(crate) struct StreamingRenderer<E = std::convert::Infallible> {
     channel: RwLock<Sender<Result<String, E>>>,
     current_path: RwLock<MountPath>,
+    nonce: Option<CspNonce>,
 }
 
 impl<E> StreamingRenderer<E> {
-    /// Create a new streaming renderer with the given head that renders into a channel
+    /// Create a new streaming renderer with the given head that renders into a channel. If there is a nonce, it is added
+    /// to the scripts that hydrate resolved suspense boundaries
     pub(crate) fn new(
         before_body: impl Display,
         mut render_into: Sender<Result<String, E>>,
+        nonce: Option<CspNonce>,
     ) -> Self {
         let start_html = before_body.to_string();
         _ = render_into.start_send(Ok(start_html));
@@ -77,6 +81,7 @@ impl<E> StreamingRenderer<E> {
         Self {
             channel: render_into.into(),
             current_path: Default::default(),
+            nonce,
         }
     }
 
@@ -128,9 +133,14 @@ impl<E> StreamingRenderer<E> {
         // 3. (in debug mode) The type names of the serialized data
         // 4. (in debug mode) The locations of the serialized data
         let raw_data = resolved_data.data;
+        let nonce = self
+            .nonce
+            .as_ref()
+            .map(CspNonce::attribute)
+            .unwrap_or_default();
         write!(
             into,
-            r#"</div><script>window.dx_hydrate([{id}], "{raw_data}""#
+            r#"</div><script{nonce}>window.dx_hydrate([{id}], "{raw_data}""#
         )?;
         #[cfg(debug_assertions)]
         {
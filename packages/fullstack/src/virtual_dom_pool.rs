@@ -0,0 +1,218 @@
+//! A pool of virtual doms that are built before the request that renders them arrives.
+use dioxus_lib::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+thread_local! {
+    /// The idle virtual doms of every pool. Virtual doms can't move between threads, so every render thread keeps its
+    /// own virtual doms
+    static IDLE_VIRTUAL_DOMS: RefCell<HashMap<usize, Vec<VirtualDom>>> = RefCell::new(HashMap::new());
+}
+
+/// Counts of how a [`VirtualDomPool`] is used. This is useful to tune the size of the pool: if many requests create a
+/// new virtual dom, the pool is too small for the load, and if many returned virtual doms are discarded, it is larger
+/// than it needs to be.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VirtualDomPoolMetrics {
+    /// The number of virtual doms requests checked out of the pool
+    pub checkouts: usize,
+
+    /// The number of checkouts that got a virtual dom that was built before the request arrived
+    pub reused: usize,
+
+    /// The number of checkouts that had to build a new virtual dom because no idle virtual dom was ready on the thread
+    pub created: usize,
+
+    /// The number of virtual doms that are rendering a request right now
+    pub in_use: usize,
+
+    /// The number of virtual doms that are ready for the next request, across all render threads
+    pub idle: usize,
+
+    /// The number of virtual doms that were dropped instead of returned to the pool because the pool was full
+    pub discarded: usize,
+}
+
+#[derive(Default)]
+struct Counters {
+    checkouts: AtomicUsize,
+    reused: AtomicUsize,
+    created: AtomicUsize,
+    in_use: AtomicUsize,
+    idle: AtomicUsize,
+    discarded: AtomicUsize,
+}
+
+struct PoolInner {
+    id: usize,
+    size: usize,
+    build: Arc<dyn Fn() -> VirtualDom + Send + Sync>,
+    counters: Counters,
+}
+
+/// A pool of virtual doms for server side rendering.
+///
+/// Every request still renders a virtual dom that has never rendered before, so no state leaks between requests. When
+/// a request is done with its virtual dom, the pool builds the virtual dom for the next request right away and moves
+/// the buffers the old virtual dom allocated into it. The next request on that thread skips building the virtual dom
+/// and most of the allocations of the first render.
+///
+/// The pool keeps up to `size` idle virtual doms on each render thread.
+#[derive(Clone)]
+pub struct VirtualDomPool {
+    inner: Arc<PoolInner>,
+}
+
+impl VirtualDomPool {
+    /// Create a new pool that builds virtual doms with a factory and keeps up to `size` idle virtual doms on each render
+    /// thread. A pool with a size of zero builds a new virtual dom for every request.
+    pub fn new(size: usize, build: impl Fn() -> VirtualDom + Send + Sync + 'static) -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        Self {
+            inner: Arc::new(PoolInner {
+                id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+                size,
+                build: Arc::new(build),
+                counters: Counters::default(),
+            }),
+        }
+    }
+
+    /// Get the number of idle virtual doms the pool keeps on each render thread
+    pub fn size(&self) -> usize {
+        self.inner.size
+    }
+
+    /// Get a snapshot of how the pool has been used since it was created
+    pub fn metrics(&self) -> VirtualDomPoolMetrics {
+        let counters = &self.inner.counters;
+        VirtualDomPoolMetrics {
+            checkouts: counters.checkouts.load(Ordering::Relaxed),
+            reused: counters.reused.load(Ordering::Relaxed),
+            created: counters.created.load(Ordering::Relaxed),
+            in_use: counters.in_use.load(Ordering::Relaxed),
+            idle: counters.idle.load(Ordering::Relaxed),
+            discarded: counters.discarded.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Take an idle virtual dom from the current thread, or build a new one if there is none. The virtual dom is
+    /// returned to the pool when it is dropped.
+    pub(crate) fn checkout(&self) -> PooledVirtualDom {
+        let counters = &self.inner.counters;
+        counters.checkouts.fetch_add(1, Ordering::Relaxed);
+        counters.in_use.fetch_add(1, Ordering::Relaxed);
+
+        let idle = IDLE_VIRTUAL_DOMS
+            .try_with(|pools| pools.borrow_mut().get_mut(&self.inner.id)?.pop())
+            .ok()
+            .flatten();
+        let dom = match idle {
+            Some(dom) => {
+                counters.idle.fetch_sub(1, Ordering::Relaxed);
+                counters.reused.fetch_add(1, Ordering::Relaxed);
+                dom
+            }
+            None => {
+                counters.created.fetch_add(1, Ordering::Relaxed);
+                (self.inner.build)()
+            }
+        };
+
+        PooledVirtualDom {
+            dom: Some(dom),
+            pool: Some(self.clone()),
+        }
+    }
+
+    /// Build the virtual dom for the next request on this thread from a virtual dom a request is done with
+    fn check_in(&self, used: VirtualDom) {
+        let counters = &self.inner.counters;
+        counters.in_use.fetch_sub(1, Ordering::Relaxed);
+
+        let has_room = IDLE_VIRTUAL_DOMS
+            .try_with(|pools| {
+                let pools = pools.borrow();
+                let idle = pools.get(&self.inner.id).map_or(0, Vec::len);
+                idle < self.inner.size
+            })
+            .unwrap_or(false);
+        if !has_room {
+            counters.discarded.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        let mut fresh = (self.inner.build)();
+        fresh.reuse_buffers_from(used);
+        let returned = IDLE_VIRTUAL_DOMS.try_with(|pools| {
+            pools
+                .borrow_mut()
+                .entry(self.inner.id)
+                .or_default()
+                .push(fresh)
+        });
+        match returned {
+            Ok(()) => _ = counters.idle.fetch_add(1, Ordering::Relaxed),
+            Err(_) => _ = counters.discarded.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl std::fmt::Debug for VirtualDomPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VirtualDomPool")
+            .field("size", &self.inner.size)
+            .field("metrics", &self.metrics())
+            .finish()
+    }
+}
+
+/// A virtual dom that may be returned to a [`VirtualDomPool`] when it is dropped
+pub(crate) struct PooledVirtualDom {
+    dom: Option<VirtualDom>,
+    pool: Option<VirtualDomPool>,
+}
+
+impl PooledVirtualDom {
+    /// Wrap a virtual dom that doesn't belong to a pool
+    pub(crate) fn unpooled(dom: VirtualDom) -> Self {
+        Self {
+            dom: Some(dom),
+            pool: None,
+        }
+    }
+}
+
+impl std::ops::Deref for PooledVirtualDom {
+    type Target = VirtualDom;
+
+    fn deref(&self) -> &Self::Target {
+        self.dom.as_ref().unwrap()
+    }
+}
+
+impl std::ops::DerefMut for PooledVirtualDom {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.dom.as_mut().unwrap()
+    }
+}
+
+impl Drop for PooledVirtualDom {
+    fn drop(&mut self) {
+        let (Some(dom), Some(pool)) = (self.dom.take(), self.pool.take()) else {
+            return;
+        };
+        // Building the next virtual dom may panic in user code, which would abort if the thread is already panicking
+        if std::thread::panicking() {
+            pool.inner.counters.in_use.fetch_sub(1, Ordering::Relaxed);
+            pool.inner
+                .counters
+                .discarded
+                .fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        pool.check_in(dom);
+    }
+}
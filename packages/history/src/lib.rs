@@ -58,6 +58,14 @@ pub trait History {
         None
     }
 
+    /// Get the host of the current URL, like `admin.example.com`, if the [`HistoryProvider`] knows it.
+    ///
+    /// The host can include a port. It is used by the router to pick routes based on the domain or subdomain the app
+    /// is served from.
+    fn current_host(&self) -> Option<String> {
+        None
+    }
+
     /// Check whether there is a previous page to navigate back to.
     ///
     /// If a [`HistoryProvider`] cannot know this, it should return [`true`].
@@ -235,6 +243,40 @@ pub trait History {
     /// ```
     fn replace(&self, path: String);
 
+    /// Go to another page like [`History::push`] and save a state payload with the new history entry.
+    ///
+    /// The state stays with the entry when navigating back and forward, so it can remember things like the selected
+    /// tab of the page. If the route is already the current route, the state of the current entry is replaced instead.
+    /// A [`HistoryProvider`] that can't save state should navigate without it.
+    ///
+    /// ```rust
+    /// # use dioxus_history::{History, MemoryHistory};
+    /// let history = MemoryHistory::default();
+    /// history.push_with_state("/settings".to_string(), "tab=privacy".to_string());
+    /// history.push("/about".to_string());
+    /// assert_eq!(history.current_state(), None);
+    ///
+    /// history.go_back();
+    /// assert_eq!(history.current_state(), Some("tab=privacy".to_string()));
+    /// ```
+    #[allow(unused_variables)]
+    fn push_with_state(&self, route: String, state: String) {
+        self.push(route);
+    }
+
+    /// Replace the current page like [`History::replace`] and save a state payload with the history entry.
+    ///
+    /// A [`HistoryProvider`] that can't save state should navigate without it.
+    #[allow(unused_variables)]
+    fn replace_with_state(&self, route: String, state: String) {
+        self.replace(route);
+    }
+
+    /// Get the state payload that was saved with the current history entry, if any.
+    fn current_state(&self) -> Option<String> {
+        None
+    }
+
     /// Navigate to an external URL.
     ///
     /// This should navigate to an external URL, which isn't controlled by the router. If a
@@ -246,6 +288,23 @@ pub trait History {
         false
     }
 
+    /// Set the element whose scroll position is saved and restored when navigating, instead of the window.
+    ///
+    /// The selector is a css selector like `#content`. Passing [`None`] restores the scroll position of the window
+    /// again. A [`HistoryProvider`] that doesn't restore scroll positions can ignore this.
+    #[allow(unused_variables)]
+    fn set_scroll_container(&self, selector: Option<String>) {}
+
+    /// Get the current scroll position of the scroll container or the window, if the [`HistoryProvider`] knows it.
+    fn scroll_position(&self) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// Scroll the scroll container or the window to a position. A [`HistoryProvider`] that can't scroll can ignore
+    /// this.
+    #[allow(unused_variables)]
+    fn scroll_to(&self, x: f64, y: f64) {}
+
     /// Provide the [`HistoryProvider`] with an update callback.
     ///
     /// Some [`HistoryProvider`]s may receive URL updates from outside the router. When such
@@ -3,15 +3,30 @@ use std::cell::RefCell;
 use crate::History;
 
 struct MemoryHistoryState {
-    current: String,
-    history: Vec<String>,
-    future: Vec<String>,
+    current: Entry,
+    history: Vec<Entry>,
+    future: Vec<Entry>,
+}
+
+/// A route and the state payload saved with it
+struct Entry {
+    route: String,
+    state: Option<String>,
+}
+
+impl MemoryHistoryState {
+    fn push(&mut self, entry: Entry) {
+        let old = std::mem::replace(&mut self.current, entry);
+        self.history.push(old);
+        self.future.clear();
+    }
 }
 
 /// A [`History`] provider that stores all navigation information in memory.
 pub struct MemoryHistory {
     state: RefCell<MemoryHistoryState>,
     base_path: Option<String>,
+    host: Option<String>,
 }
 
 impl Default for MemoryHistory {
@@ -45,13 +60,17 @@ impl MemoryHistory {
     pub fn with_initial_path(path: impl ToString) -> Self {
         Self {
             state: MemoryHistoryState{
-                current: path.to_string().parse().unwrap_or_else(|err| {
-                    panic!("index route does not exist:\n{err}\n use MemoryHistory::with_initial_path to set a custom path")
-                }),
+                current: Entry {
+                    route: path.to_string().parse().unwrap_or_else(|err| {
+                        panic!("index route does not exist:\n{err}\n use MemoryHistory::with_initial_path to set a custom path")
+                    }),
+                    state: None,
+                },
                 history: Vec::new(),
                 future: Vec::new(),
             }.into(),
             base_path: None,
+            host: None,
         }
     }
 
@@ -68,6 +87,12 @@ impl MemoryHistory {
         self.base_path = Some(prefix.to_string());
         self
     }
+
+    /// Set the host that [`History::current_host`] returns, like the host of the request during server side rendering
+    pub fn with_host(mut self, host: impl ToString) -> Self {
+        self.host = Some(host.to_string());
+        self
+    }
 }
 
 impl History for MemoryHistory {
@@ -75,8 +100,12 @@ impl History for MemoryHistory {
         self.base_path.clone()
     }
 
+    fn current_host(&self) -> Option<String> {
+        self.host.clone()
+    }
+
     fn current_route(&self) -> String {
-        self.state.borrow().current.clone()
+        self.state.borrow().current.route.clone()
     }
 
     fn can_go_back(&self) -> bool {
@@ -106,16 +135,46 @@ impl History for MemoryHistory {
     fn push(&self, new: String) {
         let mut write = self.state.borrow_mut();
         // don't push the same route twice
-        if write.current == new {
+        if write.current.route == new {
             return;
         }
-        let old = std::mem::replace(&mut write.current, new);
-        write.history.push(old);
-        write.future.clear();
+        write.push(Entry {
+            route: new,
+            state: None,
+        });
     }
 
     fn replace(&self, path: String) {
         let mut write = self.state.borrow_mut();
-        write.current = path;
+        write.current = Entry {
+            route: path,
+            state: None,
+        };
+    }
+
+    fn push_with_state(&self, route: String, state: String) {
+        let mut write = self.state.borrow_mut();
+        let entry = Entry {
+            route,
+            state: Some(state),
+        };
+        // Pushing the current route again only updates its state
+        if write.current.route == entry.route {
+            write.current = entry;
+        } else {
+            write.push(entry);
+        }
+    }
+
+    fn replace_with_state(&self, route: String, state: String) {
+        let mut write = self.state.borrow_mut();
+        write.current = Entry {
+            route,
+            state: Some(state),
+        };
+    }
+
+    fn current_state(&self) -> Option<String> {
+        self.state.borrow().current.state.clone()
     }
 }
@@ -60,6 +60,9 @@ pub use use_callback::*;
 mod use_on_destroy;
 pub use use_on_destroy::*;
 
+mod use_container_query;
+pub use use_container_query::*;
+
 mod use_context;
 pub use use_context::*;
 
@@ -78,6 +81,40 @@ pub use use_reactive::*;
 mod use_resource;
 pub use use_resource::*;
 
+mod use_mutation;
+pub use use_mutation::*;
+
+#[cfg(feature = "graphql")]
+mod use_graphql;
+#[cfg(feature = "graphql")]
+pub use use_graphql::*;
+
+mod persistent_storage;
+pub use persistent_storage::*;
+
+#[cfg(feature = "persist")]
+mod use_persistent;
+#[cfg(feature = "persist")]
+pub use use_persistent::*;
+
+#[cfg(feature = "realtime")]
+mod realtime;
+#[cfg(feature = "realtime")]
+pub use realtime::{ConnectionState, RealtimeError, Reconnect};
+
+#[cfg(feature = "realtime")]
+mod use_ws;
+#[cfg(feature = "realtime")]
+pub use use_ws::*;
+
+#[cfg(feature = "realtime")]
+mod use_sse;
+#[cfg(feature = "realtime")]
+pub use use_sse::*;
+
+mod use_task_group;
+pub use use_task_group::*;
+
 mod use_effect;
 pub use use_effect::*;
 
@@ -0,0 +1,317 @@
+//! The storage [`use_persistent`](crate::use_persistent) saves state in. Each platform provides its own storage, or
+//! none if state should not be saved, like when rendering on the server.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+#[cfg(feature = "persist")]
+use dioxus_core::prelude::ScopeId;
+
+/// A callback that is called with the key and new value when a value in a [`StorageBackend`] changes
+pub type StorageListener = dyn Fn(&str, Option<&str>);
+
+/// A key value store that persistent state is saved in
+pub trait StorageBackend {
+    /// Get the value stored under a key
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Store a value under a key, or remove the key if the value is `None`
+    fn set(&self, key: &str, value: Option<&str>);
+
+    /// Call a listener when a value changes, including changes from other windows or tabs that share the storage. The
+    /// storage only keeps a weak reference to the listener, so it stops being called when it is dropped.
+    fn watch(&self, _listener: Weak<StorageListener>) {}
+}
+
+/// The storage for state that survives a restart of the app and state that only lives as long as the current session.
+///
+/// The platform provides this as a root context. Provide your own to save persistent state somewhere else:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus::hooks::{MemoryStorage, PersistentStorage};
+///
+/// fn app() -> Element {
+///     rsx! {}
+/// }
+///
+/// let dom = VirtualDom::new(app)
+///     .with_root_context(PersistentStorage::new(MemoryStorage::default(), MemoryStorage::default()));
+/// ```
+#[derive(Clone)]
+pub struct PersistentStorage {
+    local: Rc<dyn StorageBackend>,
+    session: Rc<dyn StorageBackend>,
+    load_after_hydration: bool,
+}
+
+impl PersistentStorage {
+    /// Create a new storage from the backend for state that survives a restart and the backend for session state
+    pub fn new(
+        local: impl StorageBackend + 'static,
+        session: impl StorageBackend + 'static,
+    ) -> Self {
+        Self {
+            local: Rc::new(local),
+            session: Rc::new(session),
+            load_after_hydration: false,
+        }
+    }
+
+    /// Load saved values after the first render instead of while the hooks are created. The html the server
+    /// rendered doesn't contain the saved values, so the first render has to use the initial values to match it
+    /// during hydration. On the web, this is turned on automatically when the page is hydrated.
+    pub fn load_after_hydration(mut self) -> Self {
+        self.load_after_hydration = true;
+        self
+    }
+
+    /// If saved values are loaded after the first render
+    pub fn loads_after_hydration(&self) -> bool {
+        self.load_after_hydration
+    }
+
+    /// The backend for state that survives a restart of the app
+    pub fn local(&self) -> Rc<dyn StorageBackend> {
+        self.local.clone()
+    }
+
+    /// The backend for state that only lives as long as the current session
+    pub fn session(&self) -> Rc<dyn StorageBackend> {
+        self.session.clone()
+    }
+
+    /// Get the storage provided by the platform, or `None` if state should not be saved
+    #[cfg(feature = "persist")]
+    pub(crate) fn current() -> Option<Self> {
+        if let Some(storage) = ScopeId::ROOT.has_context::<Self>() {
+            return Some(storage);
+        }
+        // The browser always has storage. Other platforms provide their own storage when they launch
+        #[cfg(target_arch = "wasm32")]
+        let default = Some({
+            let storage = Self::new(web::WebStorage::local(), web::WebStorage::session());
+            match web::is_hydrating() {
+                true => storage.load_after_hydration(),
+                false => storage,
+            }
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        let default: Option<Self> = None;
+        default.map(|storage| ScopeId::ROOT.provide_context(storage))
+    }
+}
+
+/// The listeners of a storage backend
+#[derive(Default)]
+struct Listeners(RefCell<Vec<Weak<StorageListener>>>);
+
+impl Listeners {
+    fn push(&self, listener: Weak<StorageListener>) {
+        self.0.borrow_mut().push(listener);
+    }
+
+    fn notify(&self, key: &str, value: Option<&str>) {
+        // Collect the listeners first because a listener may change the storage again
+        let listeners: Vec<_> = {
+            let mut listeners = self.0.borrow_mut();
+            listeners.retain(|listener| listener.strong_count() > 0);
+            listeners.iter().filter_map(Weak::upgrade).collect()
+        };
+        for listener in listeners {
+            listener(key, value);
+        }
+    }
+}
+
+/// A storage backend that keeps values in memory. Every window that shares the storage is notified of changes.
+#[derive(Default)]
+pub struct MemoryStorage {
+    values: RefCell<HashMap<String, String>>,
+    listeners: Listeners,
+}
+
+impl StorageBackend for MemoryStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.values.borrow().get(key).cloned()
+    }
+
+    fn set(&self, key: &str, value: Option<&str>) {
+        {
+            let mut values = self.values.borrow_mut();
+            if values.get(key).map(String::as_str) == value {
+                return;
+            }
+            match value {
+                Some(value) => values.insert(key.to_string(), value.to_string()),
+                None => values.remove(key),
+            };
+        }
+        self.listeners.notify(key, value);
+    }
+
+    fn watch(&self, listener: Weak<StorageListener>) {
+        self.listeners.push(listener);
+    }
+}
+
+/// A storage backend that saves every value in its own file in a directory. Every window that shares the storage is
+/// notified of changes.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStorage {
+    directory: std::path::PathBuf,
+    listeners: Listeners,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+    /// Create a storage that saves values in a directory. The directory is created when the first value is saved.
+    pub fn new(directory: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            listeners: Listeners::default(),
+        }
+    }
+
+    /// Get the path of the file a key is saved in. Characters that may not be valid in a file name are escaped.
+    fn path(&self, key: &str) -> std::path::PathBuf {
+        let mut file_name = String::with_capacity(key.len());
+        for byte in key.bytes() {
+            match byte {
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' => {
+                    file_name.push(byte as char)
+                }
+                _ => file_name.push_str(&format!("%{byte:02X}")),
+            }
+        }
+        self.directory.join(file_name)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for FileStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        std::fs::read_to_string(self.path(key)).ok()
+    }
+
+    fn set(&self, key: &str, value: Option<&str>) {
+        if self.get(key).as_deref() == value {
+            return;
+        }
+        let path = self.path(key);
+        let result = match value {
+            // Write to a temporary file first so a crash never leaves a partially written value
+            Some(value) => std::fs::create_dir_all(&self.directory).and_then(|_| {
+                let temporary = path.with_extension("tmp");
+                std::fs::write(&temporary, value)?;
+                std::fs::rename(&temporary, &path)
+            }),
+            None => std::fs::remove_file(&path),
+        };
+        if let Err(err) = result {
+            tracing::warn!("Failed to save {key} in {}: {err}", path.display());
+        }
+        self.listeners.notify(key, value);
+    }
+
+    fn watch(&self, listener: Weak<StorageListener>) {
+        self.listeners.push(listener);
+    }
+}
+
+#[cfg(all(feature = "persist", target_arch = "wasm32"))]
+mod web {
+    use super::{Listeners, StorageBackend, StorageListener};
+    use std::rc::{Rc, Weak};
+    use wasm_bindgen::{
+        prelude::{wasm_bindgen, Closure},
+        JsCast, JsValue,
+    };
+
+    #[wasm_bindgen(inline_js = r#"
+        export function is_hydrating() {
+            return window.initial_dioxus_hydration_data !== undefined;
+        }
+    "#)]
+    extern "C" {
+        /// If the page was rendered on the server and is being hydrated
+        pub(crate) fn is_hydrating() -> bool;
+    }
+
+    /// The browser's localStorage or sessionStorage
+    pub(crate) struct WebStorage {
+        storage: Option<web_sys::Storage>,
+        listeners: Rc<Listeners>,
+        _on_storage: Option<Closure<dyn FnMut(web_sys::StorageEvent)>>,
+    }
+
+    impl WebStorage {
+        pub(crate) fn local() -> Self {
+            Self::new(web_sys::window().and_then(|window| window.local_storage().ok().flatten()))
+        }
+
+        pub(crate) fn session() -> Self {
+            Self::new(web_sys::window().and_then(|window| window.session_storage().ok().flatten()))
+        }
+
+        fn new(storage: Option<web_sys::Storage>) -> Self {
+            let listeners = Rc::new(Listeners::default());
+            // The browser fires a storage event in every other tab when a value changes
+            let on_storage = storage
+                .clone()
+                .zip(web_sys::window())
+                .map(|(storage, window)| {
+                    let listeners = listeners.clone();
+                    let on_storage = Closure::new(move |event: web_sys::StorageEvent| {
+                        let same_area = event.storage_area().is_some_and(|area| {
+                            JsValue::from(area) == JsValue::from(storage.clone())
+                        });
+                        if let (true, Some(key)) = (same_area, event.key()) {
+                            listeners.notify(&key, event.new_value().as_deref());
+                        }
+                    });
+                    _ = window.add_event_listener_with_callback(
+                        "storage",
+                        on_storage.as_ref().unchecked_ref(),
+                    );
+                    on_storage
+                });
+            Self {
+                storage,
+                listeners,
+                _on_storage: on_storage,
+            }
+        }
+    }
+
+    impl StorageBackend for WebStorage {
+        fn get(&self, key: &str) -> Option<String> {
+            self.storage.as_ref()?.get_item(key).ok().flatten()
+        }
+
+        fn set(&self, key: &str, value: Option<&str>) {
+            let Some(storage) = &self.storage else {
+                return;
+            };
+            if self.get(key).as_deref() == value {
+                return;
+            }
+            let result = match value {
+                Some(value) => storage.set_item(key, value),
+                None => storage.remove_item(key),
+            };
+            if let Err(err) = result {
+                tracing::warn!("Failed to save {key} in the browser storage: {err:?}");
+            }
+            // The browser only fires storage events in other tabs, so notify the listeners in this tab here
+            self.listeners.notify(key, value);
+        }
+
+        fn watch(&self, listener: Weak<StorageListener>) {
+            self.listeners.push(listener);
+        }
+    }
+}
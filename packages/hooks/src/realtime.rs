@@ -0,0 +1,533 @@
+//! Shared pieces of [`use_ws`](crate::use_ws) and [`use_sse`](crate::use_sse), and the platform specific connections
+//! they run on.
+
+use dioxus_signals::{CopyValue, ReadOnlySignal, Readable, Signal, Writable};
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::future::{select, Either};
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+use std::{future::Future, pin::pin, time::Duration};
+
+/// The state of a realtime connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// The first connection is being opened
+    Connecting,
+    /// The connection is open and receiving messages
+    Open,
+    /// The connection was lost and will be opened again after a delay
+    Reconnecting,
+    /// The connection was closed and will not be opened again. This happens after [`Reconnect::max_attempts`] failed
+    /// attempts or when the connection is closed manually.
+    Closed,
+}
+
+/// How a realtime connection is opened again after it is lost.
+///
+/// The delay starts at `initial_delay` and doubles after every failed attempt until it reaches `max_delay`. Once a
+/// connection opens, the delay is reset.
+///
+/// ```rust
+/// # use dioxus_hooks::Reconnect;
+/// # use std::time::Duration;
+/// let reconnect = Reconnect {
+///     max_attempts: Some(5),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(reconnect.delay(0), Some(Duration::from_millis(500)));
+/// assert_eq!(reconnect.delay(2), Some(Duration::from_secs(2)));
+/// assert_eq!(reconnect.delay(5), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reconnect {
+    /// The delay before the first attempt to reconnect
+    pub initial_delay: Duration,
+    /// The longest delay between two attempts
+    pub max_delay: Duration,
+    /// The number of attempts in a row before giving up, or `None` to keep trying forever
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for Reconnect {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_attempts: None,
+        }
+    }
+}
+
+impl Reconnect {
+    /// Never reconnect. The connection is closed the first time it is lost.
+    pub fn never() -> Self {
+        Self {
+            max_attempts: Some(0),
+            ..Default::default()
+        }
+    }
+
+    /// Get the delay before an attempt to reconnect, starting at `0`, or `None` if there should be no more attempts
+    pub fn delay(&self, attempt: u32) -> Option<Duration> {
+        if self.max_attempts.is_some_and(|max| attempt >= max) {
+            return None;
+        }
+        let factor = 2u32.saturating_pow(attempt);
+        Some(
+            self.initial_delay
+                .saturating_mul(factor)
+                .min(self.max_delay),
+        )
+    }
+}
+
+/// An error that occurred in a realtime connection
+#[derive(Debug, Clone, PartialEq)]
+pub enum RealtimeError {
+    /// The connection could not be opened or was lost
+    Connection(String),
+    /// A message could not be serialized before it was sent
+    Serialize(String),
+    /// A message was received that did not match the message type
+    Deserialize(String),
+    /// A message was sent after the connection was closed
+    Closed,
+}
+
+impl std::fmt::Display for RealtimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RealtimeError::Connection(err) => write!(f, "The connection failed: {err}"),
+            RealtimeError::Serialize(err) => write!(f, "Failed to serialize a message: {err}"),
+            RealtimeError::Deserialize(err) => {
+                write!(f, "Failed to deserialize a message: {err}")
+            }
+            RealtimeError::Closed => write!(f, "The connection is closed"),
+        }
+    }
+}
+
+impl std::error::Error for RealtimeError {}
+
+type MessageHandler<T> = Box<dyn FnMut(T)>;
+
+/// The reactive state of a connection that is shared by the hook and the task that runs the connection
+pub(crate) struct Connection<T: 'static> {
+    message: Signal<Option<T>>,
+    state: Signal<ConnectionState>,
+    error: Signal<Option<RealtimeError>>,
+    /// Receives every message instead of `message` if the hook was given a handler
+    handler: CopyValue<Option<MessageHandler<T>>>,
+    url: CopyValue<String>,
+    /// Tells the task to connect again because the url changed
+    restart: CopyValue<UnboundedSender<()>>,
+}
+
+impl<T> Clone for Connection<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Connection<T> {}
+
+impl<T: DeserializeOwned + 'static> Connection<T> {
+    /// Create the state of a connection to a url and the receiver the task listens to for url changes
+    pub(crate) fn new(url: String) -> (Self, UnboundedReceiver<()>) {
+        let (restart, restarts) = unbounded();
+        let connection = Self {
+            message: Signal::new(None),
+            state: Signal::new(ConnectionState::Connecting),
+            error: Signal::new(None),
+            handler: CopyValue::new(None),
+            url: CopyValue::new(url),
+            restart: CopyValue::new(restart),
+        };
+        (connection, restarts)
+    }
+
+    /// The url the next connection is opened to
+    pub(crate) fn url(&self) -> String {
+        self.url.read().clone()
+    }
+
+    /// Connect to a new url if it changed since the last render
+    pub(crate) fn set_url(&self, url: String) {
+        if *self.url.read() == url {
+            return;
+        }
+        let mut current = self.url;
+        current.set(url);
+        _ = self.restart.read().unbounded_send(());
+    }
+
+    /// Pass every message to a handler instead of keeping only the latest in `message`
+    pub(crate) fn set_handler(&self, handler: impl FnMut(T) + 'static) {
+        let mut current = self.handler;
+        current.set(Some(Box::new(handler)));
+    }
+
+    pub(crate) fn message(&self) -> ReadOnlySignal<Option<T>> {
+        self.message.into()
+    }
+
+    pub(crate) fn state(&self) -> ConnectionState {
+        *self.state.read()
+    }
+
+    pub(crate) fn error(&self) -> Option<RealtimeError> {
+        self.error.read().clone()
+    }
+
+    pub(crate) fn set_state(&self, state: ConnectionState) {
+        let mut signal = self.state;
+        if *signal.peek() != state {
+            signal.set(state);
+        }
+    }
+
+    pub(crate) fn fail(&self, error: RealtimeError) {
+        tracing::warn!("{error}");
+        let mut signal = self.error;
+        signal.set(Some(error));
+    }
+
+    /// Deserialize a message and pass it to the handler, or make it the latest message
+    pub(crate) fn receive(&self, text: &str) {
+        let message = match serde_json::from_str(text) {
+            Ok(message) => message,
+            Err(err) => {
+                self.fail(RealtimeError::Deserialize(err.to_string()));
+                return;
+            }
+        };
+        let mut handler = self.handler;
+        match handler.write().as_mut() {
+            Some(handler) => handler(message),
+            None => {
+                let mut signal = self.message;
+                signal.set(Some(message));
+            }
+        };
+    }
+}
+
+/// Run a future until it finishes or the url of the connection changes. Returns `None` if the url changed.
+pub(crate) async fn until_restart<F: Future>(
+    restarts: &mut UnboundedReceiver<()>,
+    future: F,
+) -> Option<F::Output> {
+    match select(pin!(future), restarts.next()).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right((Some(()), _)) => None,
+        // The url can't change anymore, so just wait for the future
+        Either::Right((None, future)) => Some(future.await),
+    }
+}
+
+/// Wait before the next attempt to reconnect
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web::{event_source, websocket};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use native::{event_source, websocket};
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use super::RealtimeError;
+    use futures_channel::mpsc::{unbounded, UnboundedReceiver};
+    use futures_util::future::{select, Either};
+    use futures_util::StreamExt;
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+
+    enum SocketEvent {
+        Open,
+        Message(String),
+        Error,
+        Close,
+    }
+
+    fn js_error(err: JsValue) -> RealtimeError {
+        RealtimeError::Connection(err.as_string().unwrap_or_else(|| format!("{err:?}")))
+    }
+
+    /// Forward a kind of event from a socket into a channel
+    fn forward<E: JsCast>(
+        sender: &futures_channel::mpsc::UnboundedSender<SocketEvent>,
+        map: impl Fn(E) -> Option<SocketEvent> + 'static,
+    ) -> Closure<dyn FnMut(web_sys::Event)> {
+        let sender = sender.clone();
+        Closure::new(move |event: web_sys::Event| {
+            if let Some(event) = map(event.unchecked_into()) {
+                _ = sender.unbounded_send(event);
+            }
+        })
+    }
+
+    fn text_message(event: web_sys::MessageEvent) -> Option<SocketEvent> {
+        event.data().as_string().map(SocketEvent::Message)
+    }
+
+    /// Closes the socket and removes its handlers when the connection future is dropped
+    struct CloseOnDrop<F: Fn()>(F);
+
+    impl<F: Fn()> Drop for CloseOnDrop<F> {
+        fn drop(&mut self) {
+            (self.0)()
+        }
+    }
+
+    pub(crate) async fn websocket(
+        url: &str,
+        protocol: Option<&str>,
+        outgoing: &mut UnboundedReceiver<String>,
+        mut on_open: impl FnMut(),
+        mut on_message: impl FnMut(String),
+    ) -> Result<(), RealtimeError> {
+        let socket = match protocol {
+            Some(protocol) => web_sys::WebSocket::new_with_str(url, protocol),
+            None => web_sys::WebSocket::new(url),
+        }
+        .map_err(js_error)?;
+        let (sender, mut events) = unbounded();
+        let onopen = forward(&sender, |_: web_sys::Event| Some(SocketEvent::Open));
+        let onmessage = forward(&sender, text_message);
+        let onerror = forward(&sender, |_: web_sys::Event| Some(SocketEvent::Error));
+        let onclose = forward(&sender, |_: web_sys::CloseEvent| Some(SocketEvent::Close));
+        socket.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        socket.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+        // The guard is dropped before the closures, so the socket never calls a dropped closure
+        let _guard = CloseOnDrop(|| {
+            socket.set_onopen(None);
+            socket.set_onmessage(None);
+            socket.set_onerror(None);
+            socket.set_onclose(None);
+            _ = socket.close();
+        });
+
+        let mut open = false;
+        loop {
+            // Messages can only be sent once the socket is open
+            let event = match open {
+                true => match select(events.next(), outgoing.next()).await {
+                    Either::Left((event, _)) => Either::Left(event),
+                    Either::Right((text, _)) => Either::Right(text),
+                },
+                false => Either::Left(events.next().await),
+            };
+            match event {
+                Either::Left(Some(SocketEvent::Open)) => {
+                    open = true;
+                    on_open();
+                }
+                Either::Left(Some(SocketEvent::Message(text))) => on_message(text),
+                Either::Left(Some(SocketEvent::Error)) => {
+                    return Err(RealtimeError::Connection(format!(
+                        "The WebSocket connection to {url} failed"
+                    )))
+                }
+                Either::Left(Some(SocketEvent::Close) | None) | Either::Right(None) => {
+                    return Ok(())
+                }
+                Either::Right(Some(text)) => socket.send_with_str(&text).map_err(js_error)?,
+            }
+        }
+    }
+
+    pub(crate) async fn event_source(
+        url: &str,
+        mut on_open: impl FnMut(),
+        mut on_message: impl FnMut(String),
+    ) -> Result<(), RealtimeError> {
+        let source = web_sys::EventSource::new(url).map_err(js_error)?;
+        let (sender, mut events) = unbounded();
+        let onopen = forward(&sender, |_: web_sys::Event| Some(SocketEvent::Open));
+        let onmessage = forward(&sender, text_message);
+        let onerror = forward(&sender, |_: web_sys::Event| Some(SocketEvent::Error));
+        source.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+        source.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        source.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+        // The browser reconnects event sources by itself, but we close it on errors so every platform uses the same
+        // backoff
+        let _guard = CloseOnDrop(|| {
+            source.set_onopen(None);
+            source.set_onmessage(None);
+            source.set_onerror(None);
+            source.close();
+        });
+
+        loop {
+            match events.next().await {
+                Some(SocketEvent::Open) => on_open(),
+                Some(SocketEvent::Message(text)) => on_message(text),
+                Some(SocketEvent::Error) => {
+                    return Err(RealtimeError::Connection(format!(
+                        "The event stream from {url} failed"
+                    )))
+                }
+                Some(SocketEvent::Close) | None => return Ok(()),
+            }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use super::{RealtimeError, SseParser};
+    use futures_channel::mpsc::UnboundedReceiver;
+    use futures_util::future::{select, Either};
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::{client::IntoClientRequest, Message};
+
+    fn connection_error(err: impl std::fmt::Display) -> RealtimeError {
+        RealtimeError::Connection(err.to_string())
+    }
+
+    pub(crate) async fn websocket(
+        url: &str,
+        protocol: Option<&str>,
+        outgoing: &mut UnboundedReceiver<String>,
+        mut on_open: impl FnMut(),
+        mut on_message: impl FnMut(String),
+    ) -> Result<(), RealtimeError> {
+        let mut request = url.into_client_request().map_err(connection_error)?;
+        if let Some(protocol) = protocol {
+            let protocol = protocol.parse().map_err(connection_error)?;
+            request
+                .headers_mut()
+                .insert("Sec-WebSocket-Protocol", protocol);
+        }
+        let (mut socket, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(connection_error)?;
+        on_open();
+
+        loop {
+            let event = match select(socket.next(), outgoing.next()).await {
+                Either::Left((message, _)) => Either::Left(message),
+                Either::Right((text, _)) => Either::Right(text),
+            };
+            match event {
+                Either::Left(Some(Ok(Message::Text(text)))) => on_message(text),
+                Either::Left(Some(Ok(Message::Binary(bytes)))) => match String::from_utf8(bytes) {
+                    Ok(text) => on_message(text),
+                    Err(err) => tracing::warn!("Ignoring a binary WebSocket message: {err}"),
+                },
+                Either::Left(Some(Ok(Message::Close(_))) | None) | Either::Right(None) => {
+                    return Ok(())
+                }
+                Either::Left(Some(Ok(_))) => {}
+                Either::Left(Some(Err(err))) => return Err(connection_error(err)),
+                Either::Right(Some(text)) => socket
+                    .send(Message::Text(text))
+                    .await
+                    .map_err(connection_error)?,
+            }
+        }
+    }
+
+    pub(crate) async fn event_source(
+        url: &str,
+        mut on_open: impl FnMut(),
+        mut on_message: impl FnMut(String),
+    ) -> Result<(), RealtimeError> {
+        let response = reqwest::Client::new()
+            .get(url)
+            .header("Accept", "text/event-stream")
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(connection_error)?;
+        on_open();
+
+        let mut parser = SseParser::default();
+        let mut body = response.bytes_stream();
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(connection_error)?;
+            for event in parser.feed(&chunk) {
+                // Like the browser's onmessage, only unnamed events are received
+                if event.event == "message" {
+                    on_message(event.data);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An event parsed from a `text/event-stream` body
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+pub(crate) struct SseEvent {
+    pub(crate) event: String,
+    pub(crate) data: String,
+}
+
+/// An incremental parser for `text/event-stream` bodies. The body may be split into chunks at any byte.
+#[derive(Default)]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+pub(crate) struct SseParser {
+    buffer: Vec<u8>,
+    event: String,
+    data: Option<String>,
+}
+
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+impl SseParser {
+    /// Add a chunk of the body and get every event that is complete
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer.extend_from_slice(chunk);
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            // An empty line dispatches the event
+            if line.is_empty() {
+                let event = std::mem::take(&mut self.event);
+                if let Some(data) = self.data.take() {
+                    events.push(SseEvent {
+                        event: match event.is_empty() {
+                            true => "message".to_string(),
+                            false => event,
+                        },
+                        data,
+                    });
+                }
+                continue;
+            }
+            // Lines starting with a colon are comments, which servers use to keep the connection alive
+            if line.starts_with(':') {
+                continue;
+            }
+
+            let (field, value) = match line.split_once(':') {
+                Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+                None => (line, ""),
+            };
+            match field {
+                "event" => self.event = value.to_string(),
+                "data" => match &mut self.data {
+                    Some(data) => {
+                        data.push('\n');
+                        data.push_str(value);
+                    }
+                    None => self.data = Some(value.to_string()),
+                },
+                _ => {}
+            }
+        }
+        events
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{use_memo, use_signal};
+use dioxus_core::prelude::*;
+use dioxus_signals::*;
+
+/// Track which breakpoint the width of an element matches, for responsive logic that depends on the size of the
+/// component instead of the size of the viewport.
+///
+/// Each breakpoint is a minimum width and a value. The query matches the breakpoint with the largest minimum width
+/// that is less than or equal to the width of the element. Pass the width of the element to
+/// [`ContainerQuery::set_width`] from its `onresize` event, which is backed by a `ResizeObserver`.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Layout {
+///     Stacked,
+///     SideBySide,
+/// }
+///
+/// fn Card() -> Element {
+///     let mut query = use_container_query([(0.0, Layout::Stacked), (600.0, Layout::SideBySide)]);
+///     rsx! {
+///         div {
+///             onresize: move |event| {
+///                 if let Ok(size) = event.get_border_box_size() {
+///                     query.set_width(size.width);
+///                 }
+///             },
+///             match query.breakpoint() {
+///                 Some(Layout::SideBySide) => rsx! { "Side by side" },
+///                 _ => rsx! { "Stacked" },
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_container_query<B: Clone + PartialEq + 'static>(
+    breakpoints: impl IntoIterator<Item = (f64, B)>,
+) -> ContainerQuery<B> {
+    let breakpoints = use_hook(|| {
+        let mut breakpoints: Vec<_> = breakpoints.into_iter().collect();
+        breakpoints.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        CopyValue::new(breakpoints)
+    });
+    let width = use_signal(|| None);
+    let breakpoint = use_memo(move || {
+        let width = width()?;
+        breakpoints
+            .read()
+            .iter()
+            .rev()
+            .find(|(min_width, _)| *min_width <= width)
+            .map(|(_, breakpoint)| breakpoint.clone())
+    });
+
+    ContainerQuery { width, breakpoint }
+}
+
+/// A handle to a container query created with [`use_container_query`].
+pub struct ContainerQuery<B: 'static> {
+    width: Signal<Option<f64>>,
+    breakpoint: Memo<Option<B>>,
+}
+
+impl<B> Clone for ContainerQuery<B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B> Copy for ContainerQuery<B> {}
+
+impl<B: Clone + PartialEq + 'static> ContainerQuery<B> {
+    /// Set the current width of the element
+    pub fn set_width(&mut self, width: f64) {
+        if *self.width.peek() != Some(width) {
+            self.width.set(Some(width));
+        }
+    }
+
+    /// The width of the element, or `None` if the element hasn't been measured yet
+    pub fn width(&self) -> Option<f64> {
+        (self.width)()
+    }
+
+    /// The breakpoint the element currently matches. Components that call this only rerun when the breakpoint changes,
+    /// not every time the width changes.
+    pub fn breakpoint(&self) -> Option<B> {
+        (self.breakpoint)()
+    }
+}
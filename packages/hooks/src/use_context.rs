@@ -1,7 +1,9 @@
+use crate::use_memo;
 use dioxus_core::{
     prelude::{consume_context, provide_context, try_consume_context},
     use_hook,
 };
+use dioxus_signals::{Memo, Readable, Signal};
 
 /// Consume some context in the tree, providing a sharable handle to the value
 ///
@@ -65,3 +67,37 @@ pub fn use_context<T: 'static + Clone>() -> T {
 pub fn use_context_provider<T: 'static + Clone>(f: impl FnOnce() -> T) -> T {
     use_hook(|| provide_context(f()))
 }
+
+/// Select part of a [`Signal`] context. The component only reruns when the selected value changes, instead of
+/// every time any part of the context changes.
+///
+/// The selector reads from the `Signal<T>` provided as a context and the result is compared with `PartialEq`.
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Default)]
+/// struct AppState {
+///     user_name: String,
+///     unread_messages: usize,
+/// }
+///
+/// fn app() -> Element {
+///     use_context_provider(|| Signal::new(AppState::default()));
+///     rsx! { UserName {} }
+/// }
+///
+/// // Changing the unread messages doesn't rerun this component
+/// #[component]
+/// fn UserName() -> Element {
+///     let name = use_context_selector(|state: &AppState| state.user_name.clone());
+///     rsx! { "{name}" }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[doc = include_str!("../docs/moving_state_around.md")]
+#[track_caller]
+pub fn use_context_selector<T: 'static, O: PartialEq + 'static>(
+    mut selector: impl FnMut(&T) -> O + 'static,
+) -> Memo<O> {
+    let context = use_context::<Signal<T>>();
+    use_memo(move || selector(&context.read()))
+}
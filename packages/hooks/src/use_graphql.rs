@@ -0,0 +1,599 @@
+use crate::use_ws::use_ws_with_protocol;
+use crate::{
+    use_effect, use_mutation, use_resource, use_signal, ConnectionState, RealtimeError, Reconnect,
+    Resource, UseMutation, WebSocket,
+};
+use dioxus_core::prelude::*;
+use dioxus_signals::{CopyValue, ReadOnlySignal, Readable, Signal, Writable};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+/// A client that sends GraphQL requests to a single endpoint and caches the responses.
+///
+/// Provide the client as a context near the root of the app. [`use_graphql_query`] and [`use_graphql_mutation`] use
+/// the closest client to send their requests.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     use_context_provider(|| GraphQLClient::new("https://example.com/graphql"));
+///     rsx! { Profile {} }
+/// }
+/// # fn Profile() -> Element { VNode::empty() }
+/// ```
+#[derive(Clone)]
+pub struct GraphQLClient {
+    endpoint: String,
+    subscription_endpoint: Option<String>,
+    headers: Vec<(String, String)>,
+    http: reqwest::Client,
+    cache: Rc<RefCell<NormalizedCache>>,
+    /// Changes whenever cached objects are updated, so queries read the new values from the cache
+    revision: Signal<u64>,
+}
+
+impl GraphQLClient {
+    /// Create a new client for the GraphQL endpoint at the given url
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            subscription_endpoint: None,
+            headers: Vec::new(),
+            http: reqwest::Client::new(),
+            cache: Default::default(),
+            revision: Signal::new(0),
+        }
+    }
+
+    /// Add a header that is sent with every request. This is useful for authorization headers
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set the WebSocket url subscriptions connect to. By default, subscriptions connect to the endpoint of the
+    /// client with a `ws://` or `wss://` scheme.
+    pub fn with_subscription_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.subscription_endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// The WebSocket url subscriptions connect to
+    pub fn subscription_endpoint(&self) -> String {
+        if let Some(endpoint) = &self.subscription_endpoint {
+            return endpoint.clone();
+        }
+        if let Some(rest) = self.endpoint.strip_prefix("https://") {
+            return format!("wss://{rest}");
+        }
+        if let Some(rest) = self.endpoint.strip_prefix("http://") {
+            return format!("ws://{rest}");
+        }
+        self.endpoint.clone()
+    }
+
+    /// Send a query. If the same query was already sent with the same variables, the response is built from the
+    /// cache without sending another request.
+    ///
+    /// The cache is normalized: every object in a response with a `__typename` and an `id` is stored once, and
+    /// responses refer to it. When a later query, mutation or subscription returns a newer version of the object,
+    /// every cached response that contains the object sees the new fields.
+    pub async fn query<V: Serialize, R: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: &V,
+    ) -> Result<R, GraphQLError> {
+        // Subscribe to changes of the cache, so queries in a resource rerun when a cached object changes
+        self.revision.read();
+        let key = Self::cache_key(query, variables)?;
+        let cached = self.cache.borrow().read(&key);
+        let data = match cached {
+            Some(data) => data,
+            None => {
+                let data = self.execute(query, variables).await?;
+                if self.cache.borrow_mut().write(key, &data) {
+                    self.bump_revision();
+                }
+                data
+            }
+        };
+        serde_json::from_value(data).map_err(GraphQLError::Deserialize)
+    }
+
+    /// Send a mutation. Mutations are never cached. Objects in the response are written to the cache, so cached
+    /// queries that contain them see the changes. Call [`GraphQLClient::invalidate`] to reload queries that the
+    /// mutation changes in other ways, like a list that gained an item.
+    pub async fn mutate<V: Serialize, R: DeserializeOwned>(
+        &self,
+        mutation: &str,
+        variables: &V,
+    ) -> Result<R, GraphQLError> {
+        let data = self.execute(mutation, variables).await?;
+        self.merge(&data);
+        serde_json::from_value(data).map_err(GraphQLError::Deserialize)
+    }
+
+    /// Remove the cached responses for a query with any variables
+    pub fn invalidate(&self, query: &str) {
+        let prefix = format!("{query}\0");
+        self.cache
+            .borrow_mut()
+            .responses
+            .retain(|key, _| !key.starts_with(&prefix));
+        self.bump_revision();
+    }
+
+    /// Remove every cached response and object
+    pub fn invalidate_all(&self) {
+        *self.cache.borrow_mut() = NormalizedCache::default();
+        self.bump_revision();
+    }
+
+    /// Write the objects in some data to the cache and rerun the queries that read the cache
+    fn merge(&self, data: &serde_json::Value) {
+        if self.cache.borrow_mut().merge(data) {
+            self.bump_revision();
+        }
+    }
+
+    fn bump_revision(&self) {
+        let mut revision = self.revision;
+        if let Ok(mut revision) = revision.try_write() {
+            *revision += 1;
+        };
+    }
+
+    fn cache_key<V: Serialize>(query: &str, variables: &V) -> Result<String, GraphQLError> {
+        let variables = serde_json::to_string(variables).map_err(GraphQLError::Serialize)?;
+        Ok(format!("{query}\0{variables}"))
+    }
+
+    async fn execute<V: Serialize>(
+        &self,
+        query: &str,
+        variables: &V,
+    ) -> Result<serde_json::Value, GraphQLError> {
+        #[derive(Serialize)]
+        struct Request<'a, V> {
+            query: &'a str,
+            variables: &'a V,
+        }
+
+        #[derive(Deserialize)]
+        struct Response {
+            data: Option<serde_json::Value>,
+            #[serde(default)]
+            errors: Vec<GraphQLResponseError>,
+        }
+
+        let mut request = self.http.post(&self.endpoint);
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        let response: Response = request
+            .json(&Request { query, variables })
+            .send()
+            .await
+            .map_err(GraphQLError::Request)?
+            .json()
+            .await
+            .map_err(GraphQLError::Request)?;
+
+        if !response.errors.is_empty() {
+            return Err(GraphQLError::Response(response.errors));
+        }
+        Ok(response.data.unwrap_or_default())
+    }
+}
+
+/// Responses with every identifiable object replaced by a reference to a single shared copy of the object
+#[derive(Default)]
+struct NormalizedCache {
+    /// The objects with a `__typename` and an `id`, keyed by `Type:id`. Nested objects are references
+    objects: HashMap<String, serde_json::Map<String, serde_json::Value>>,
+    /// The responses to queries keyed by the query and its variables
+    responses: HashMap<String, serde_json::Value>,
+}
+
+/// The field a reference to a cached object is stored in
+const REFERENCE: &str = "__ref";
+
+impl NormalizedCache {
+    /// Build a cached response, or `None` if the response or one of the objects it refers to isn't cached
+    fn read(&self, key: &str) -> Option<serde_json::Value> {
+        self.denormalize(self.responses.get(key)?, 0)
+    }
+
+    /// Cache a response and the objects in it. Returns true if an object that was already cached changed
+    fn write(&mut self, key: String, data: &serde_json::Value) -> bool {
+        let mut changed = false;
+        let normalized = self.normalize(data, &mut changed);
+        self.responses.insert(key, normalized);
+        changed
+    }
+
+    /// Cache the objects in a response. Returns true if an object that was already cached changed
+    fn merge(&mut self, data: &serde_json::Value) -> bool {
+        let mut changed = false;
+        self.normalize(data, &mut changed);
+        changed
+    }
+
+    /// The key an object is cached under, if it can be identified
+    fn object_key(object: &serde_json::Map<String, serde_json::Value>) -> Option<String> {
+        let typename = object.get("__typename")?.as_str()?;
+        match object.get("id")? {
+            serde_json::Value::String(id) => Some(format!("{typename}:{id}")),
+            serde_json::Value::Number(id) => Some(format!("{typename}:{id}")),
+            _ => None,
+        }
+    }
+
+    fn normalize(&mut self, value: &serde_json::Value, changed: &mut bool) -> serde_json::Value {
+        match value {
+            serde_json::Value::Array(items) => serde_json::Value::Array(
+                items
+                    .iter()
+                    .map(|item| self.normalize(item, changed))
+                    .collect(),
+            ),
+            serde_json::Value::Object(object) => {
+                let normalized: serde_json::Map<_, _> = object
+                    .iter()
+                    .map(|(field, value)| (field.clone(), self.normalize(value, changed)))
+                    .collect();
+                let Some(key) = Self::object_key(object) else {
+                    return serde_json::Value::Object(normalized);
+                };
+                // Queries may select different fields of the same object, so the fields are merged
+                match self.objects.get_mut(&key) {
+                    Some(cached) => {
+                        for (field, value) in normalized {
+                            if cached.get(&field) != Some(&value) {
+                                *changed = true;
+                                cached.insert(field, value);
+                            }
+                        }
+                    }
+                    None => {
+                        self.objects.insert(key.clone(), normalized);
+                    }
+                }
+                serde_json::json!({ REFERENCE: key })
+            }
+            value => value.clone(),
+        }
+    }
+
+    fn denormalize(&self, value: &serde_json::Value, depth: usize) -> Option<serde_json::Value> {
+        // Objects can refer to each other in a cycle. Responses are never nested this deep
+        if depth > 64 {
+            return None;
+        }
+        match value {
+            serde_json::Value::Array(items) => items
+                .iter()
+                .map(|item| self.denormalize(item, depth + 1))
+                .collect::<Option<Vec<_>>>()
+                .map(serde_json::Value::Array),
+            serde_json::Value::Object(object) => {
+                let object = match object.get(REFERENCE).and_then(|key| key.as_str()) {
+                    Some(key) => self.objects.get(key)?,
+                    None => object,
+                };
+                object
+                    .iter()
+                    .map(|(field, value)| {
+                        Some((field.clone(), self.denormalize(value, depth + 1)?))
+                    })
+                    .collect::<Option<serde_json::Map<_, _>>>()
+                    .map(serde_json::Value::Object)
+            }
+            value => Some(value.clone()),
+        }
+    }
+}
+
+/// An error returned by the GraphQL server
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct GraphQLResponseError {
+    /// A description of the error
+    pub message: String,
+    /// The path of the field that caused the error
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+}
+
+/// An error that occurred while sending a GraphQL request
+#[derive(Debug)]
+pub enum GraphQLError {
+    /// The variables could not be serialized
+    Serialize(serde_json::Error),
+    /// The request failed or the response was not valid json
+    Request(reqwest::Error),
+    /// The server responded with errors
+    Response(Vec<GraphQLResponseError>),
+    /// The data in the response did not match the response type
+    Deserialize(serde_json::Error),
+    /// The WebSocket connection of a subscription failed
+    Connection(RealtimeError),
+}
+
+impl std::fmt::Display for GraphQLError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphQLError::Serialize(err) => write!(f, "Failed to serialize the variables: {err}"),
+            GraphQLError::Request(err) => write!(f, "The GraphQL request failed: {err}"),
+            GraphQLError::Response(errors) => {
+                write!(f, "The GraphQL server returned errors: ")?;
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", error.message)?;
+                }
+                Ok(())
+            }
+            GraphQLError::Deserialize(err) => {
+                write!(f, "Failed to deserialize the response: {err}")
+            }
+            GraphQLError::Connection(err) => write!(f, "The subscription failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for GraphQLError {}
+
+/// Run a GraphQL query with the [`GraphQLClient`] from the context.
+///
+/// The query is a [`Resource`], so it reruns when any signals read in `variables` change, and it can be suspended
+/// with [`Resource::suspend`] to show the closest suspense boundary while it loads. Responses are cached by the
+/// query and variables, so running the same query again reuses the response instead of sending another request. The
+/// query also reruns when a mutation or subscription changes an object in its response.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[derive(serde::Deserialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// struct UserQuery {
+///     user: User,
+/// }
+///
+/// #[component]
+/// fn Profile(id: ReadOnlySignal<u64>) -> Element {
+///     let user = use_graphql_query::<_, UserQuery>(
+///         "query User($id: ID!) { user(id: $id) { name } }",
+///         move || serde_json::json!({ "id": id() }),
+///     )
+///     .suspend()?;
+///
+///     let user = user.read();
+///     match &*user {
+///         Ok(response) => rsx! { "{response.user.name}" },
+///         Err(err) => rsx! { "Failed to load the user: {err}" },
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_graphql_query<V, R>(
+    query: &'static str,
+    mut variables: impl FnMut() -> V + 'static,
+) -> Resource<Result<R, GraphQLError>>
+where
+    V: Serialize + 'static,
+    R: DeserializeOwned + 'static,
+{
+    let client = use_hook(consume_context::<GraphQLClient>);
+    use_resource(move || {
+        let client = client.clone();
+        let variables = variables();
+        async move { client.query(query, &variables).await }
+    })
+}
+
+/// Create a [`UseMutation`] that sends a GraphQL mutation with the [`GraphQLClient`] from the context.
+///
+/// Objects in the response are written to the cache of the client, so queries that show them update. Call
+/// [`GraphQLClient::invalidate`] to reload queries the mutation changes in other ways.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[derive(serde::Serialize)]
+/// struct Rename {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// fn RenameButton() -> Element {
+///     let rename = use_graphql_mutation::<Rename, serde_json::Value>(
+///         "mutation Rename($id: ID!, $name: String!) { rename(id: $id, name: $name) { id } }",
+///     );
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 rename.mutate(Rename { id: 1, name: "Ferris".to_string() });
+///             },
+///             "Rename"
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_graphql_mutation<V, R>(mutation: &'static str) -> UseMutation<V, R, GraphQLError>
+where
+    V: Serialize + 'static,
+    R: DeserializeOwned + 'static,
+{
+    let client = use_hook(consume_context::<GraphQLClient>);
+    use_mutation(move |variables: V| {
+        let client = client.clone();
+        async move { client.mutate(mutation, &variables).await }
+    })
+}
+
+/// Subscribe to a GraphQL subscription with the [`GraphQLClient`] from the context.
+///
+/// The subscription runs over a WebSocket with the `graphql-transport-ws` protocol. The signal holds the latest
+/// event, or `None` until the first event arrives. Objects in every event are written to the cache of the client, so
+/// queries that show them update too. When a signal read in `variables` changes, the subscription is started again
+/// with the new variables. If the connection is lost, it reconnects and subscribes again.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[derive(serde::Deserialize)]
+/// struct Message {
+///     text: String,
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// struct MessageAdded {
+///     message_added: Message,
+/// }
+///
+/// #[component]
+/// fn LatestMessage(room: ReadOnlySignal<u64>) -> Element {
+///     let latest = use_graphql_subscription::<_, MessageAdded>(
+///         "subscription Messages($room: ID!) { message_added(room: $room) { text } }",
+///         move || serde_json::json!({ "room": room() }),
+///     );
+///
+///     let latest = latest.read();
+///     match &*latest {
+///         Some(Ok(event)) => rsx! { "{event.message_added.text}" },
+///         Some(Err(err)) => rsx! { "The subscription failed: {err}" },
+///         None => rsx! { "Waiting for messages" },
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_graphql_subscription<V, R>(
+    query: &'static str,
+    mut variables: impl FnMut() -> V + 'static,
+) -> ReadOnlySignal<Option<Result<R, GraphQLError>>>
+where
+    V: Serialize + 'static,
+    R: DeserializeOwned + 'static,
+{
+    let client = use_hook(consume_context::<GraphQLClient>);
+    let mut latest = use_signal(|| None);
+    // Set once the server acknowledged the connection. Subscriptions can only be started after that
+    let mut acknowledged = use_signal(|| false);
+    // The id of the running subscription. Events of older subscriptions are ignored
+    let mut running = use_hook(|| CopyValue::new(None::<u64>));
+    let mut next_id = use_hook(|| CopyValue::new(0u64));
+    let mut socket = use_hook(|| CopyValue::new(None::<WebSocket<ServerMessage>>));
+
+    let handler = {
+        let client = client.clone();
+        move |message: ServerMessage| match message {
+            ServerMessage::ConnectionAck => acknowledged.set(true),
+            ServerMessage::Ping => {
+                if let Some(socket) = *socket.read() {
+                    _ = socket.send(&serde_json::json!({ "type": "pong" }));
+                }
+            }
+            ServerMessage::Next { id, payload } if *running.read() == id.parse().ok() => {
+                if !payload.errors.is_empty() {
+                    latest.set(Some(Err(GraphQLError::Response(payload.errors))));
+                    return;
+                }
+                let data = payload.data.unwrap_or_default();
+                client.merge(&data);
+                let event = serde_json::from_value(data).map_err(GraphQLError::Deserialize);
+                latest.set(Some(event));
+            }
+            ServerMessage::Error { id, payload } if *running.read() == id.parse().ok() => {
+                running.set(None);
+                latest.set(Some(Err(GraphQLError::Response(payload))));
+            }
+            ServerMessage::Complete { id } if *running.read() == id.parse().ok() => {
+                running.set(None);
+            }
+            _ => {}
+        }
+    };
+    let connection = use_ws_with_protocol(
+        client.subscription_endpoint(),
+        Reconnect::default(),
+        "graphql-transport-ws",
+        handler,
+    );
+    socket.set(Some(connection));
+
+    // Every new connection has to be initialized before it can subscribe
+    use_effect(move || match connection.state() {
+        ConnectionState::Open => {
+            _ = connection.send(&serde_json::json!({ "type": "connection_init" }));
+        }
+        state => {
+            running.set(None);
+            acknowledged.set(false);
+            if let (ConnectionState::Closed, Some(err)) = (state, connection.error()) {
+                latest.set(Some(Err(GraphQLError::Connection(err))));
+            }
+        }
+    });
+
+    use_effect(move || {
+        let variables = serde_json::to_value(variables());
+        if !acknowledged() {
+            return;
+        }
+        // Stop the subscription with the old variables before starting the new one
+        if let Some(id) = running.write().take() {
+            _ = connection.send(&serde_json::json!({ "id": id.to_string(), "type": "complete" }));
+        }
+        let variables = match variables {
+            Ok(variables) => variables,
+            Err(err) => {
+                latest.set(Some(Err(GraphQLError::Serialize(err))));
+                return;
+            }
+        };
+        let id = *next_id.read() + 1;
+        next_id.set(id);
+        running.set(Some(id));
+        _ = connection.send(&serde_json::json!({
+            "id": id.to_string(),
+            "type": "subscribe",
+            "payload": { "query": query, "variables": variables },
+        }));
+    });
+
+    latest.into()
+}
+
+/// A message from the server in the `graphql-transport-ws` protocol
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    ConnectionAck,
+    Ping,
+    Next {
+        id: String,
+        payload: SubscriptionEvent,
+    },
+    Error {
+        id: String,
+        payload: Vec<GraphQLResponseError>,
+    },
+    Complete {
+        id: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct SubscriptionEvent {
+    data: Option<serde_json::Value>,
+    #[serde(default)]
+    errors: Vec<GraphQLResponseError>,
+}
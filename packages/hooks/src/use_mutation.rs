@@ -0,0 +1,247 @@
+use crate::{use_callback, use_signal, Resource};
+use dioxus_core::prelude::*;
+use dioxus_signals::*;
+use std::{future::Future, pin::Pin};
+
+type MutationFuture<T, E> = Pin<Box<dyn Future<Output = Result<T, E>>>>;
+type OptimisticUpdate = Box<dyn FnOnce() -> Box<dyn FnOnce()>>;
+
+/// Create a mutation that runs an async function which changes data on the server, with optimistic updates to the
+/// local state and automatic invalidation of the resources that read that data.
+///
+/// The mutation doesn't run until [`UseMutation::mutate`] is called. Optimistic updates are applied as soon as the
+/// mutation starts and rolled back if the mutator returns an error. Resources passed to [`MutationCall::invalidates`]
+/// are restarted once the mutator succeeds.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # async fn load_todos() -> Vec<String> { Vec::new() }
+/// # async fn save_todo(todo: String) -> Result<(), String> { Ok(()) }
+/// fn App() -> Element {
+///     let mut todos = use_signal(Vec::new);
+///     let todos_resource = use_resource(move || async move {
+///         let loaded = load_todos().await;
+///         todos.set(loaded);
+///     });
+///     let add_todo = use_mutation(|todo: String| async move { save_todo(todo).await });
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 let todo = "Write docs".to_string();
+///                 add_todo
+///                     // Show the new todo right away. If saving it fails, the list is restored
+///                     .optimistic(todos, {
+///                         let todo = todo.clone();
+///                         move |todos| todos.push(todo)
+///                     })
+///                     // Reload the todos from the server once the todo is saved
+///                     .invalidates(todos_resource)
+///                     .mutate(todo);
+///             },
+///             "Add todo"
+///         }
+///         for todo in todos.iter() {
+///             p { "{todo}" }
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_mutation<I, T, E, F>(mut mutator: impl FnMut(I) -> F + 'static) -> UseMutation<I, T, E>
+where
+    I: 'static,
+    T: 'static,
+    E: 'static,
+    F: Future<Output = Result<T, E>> + 'static,
+{
+    let value = use_signal(|| None);
+    let state = use_signal(|| UseMutationState::Idle);
+    let task = use_signal(|| None);
+    let mutator =
+        use_callback(move |input: I| -> MutationFuture<T, E> { Box::pin(mutator(input)) });
+
+    UseMutation {
+        value,
+        state,
+        task,
+        mutator,
+    }
+}
+
+/// The state of a [`UseMutation`]
+#[derive(Clone, Copy, PartialEq, Hash, Eq, Debug)]
+pub enum UseMutationState {
+    /// The mutation hasn't been started yet
+    Idle,
+
+    /// The mutator is still running
+    Pending,
+
+    /// The mutator returned `Ok`
+    Succeeded,
+
+    /// The mutator returned `Err` and the optimistic updates were rolled back
+    Failed,
+}
+
+/// A handle to a mutation created with [`use_mutation`].
+pub struct UseMutation<I: 'static, T: 'static, E: 'static> {
+    value: Signal<Option<Result<T, E>>>,
+    state: Signal<UseMutationState>,
+    task: Signal<Option<Task>>,
+    mutator: Callback<I, MutationFuture<T, E>>,
+}
+
+impl<I, T, E> PartialEq for UseMutation<I, T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && self.state == other.state
+            && self.task == other.task
+            && self.mutator == other.mutator
+    }
+}
+
+impl<I, T, E> Clone for UseMutation<I, T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, T, E> Copy for UseMutation<I, T, E> {}
+
+impl<I, T, E> UseMutation<I, T, E> {
+    /// Run the mutator without any optimistic updates or invalidations.
+    pub fn mutate(&self, input: I) -> Task {
+        self.call().mutate(input)
+    }
+
+    /// Start configuring a single run of the mutation. Call [`MutationCall::mutate`] to run it.
+    pub fn call(&self) -> MutationCall<I, T, E> {
+        MutationCall {
+            mutation: *self,
+            optimistic: Vec::new(),
+            invalidates: Vec::new(),
+        }
+    }
+
+    /// Apply an optimistic update to a signal when the mutation starts. This is a shorthand for
+    /// `mutation.call().optimistic(signal, update)`.
+    pub fn optimistic<V: Clone + 'static>(
+        &self,
+        signal: Signal<V>,
+        update: impl FnOnce(&mut V) + 'static,
+    ) -> MutationCall<I, T, E> {
+        self.call().optimistic(signal, update)
+    }
+
+    /// Restart a resource once the mutation succeeds. This is a shorthand for
+    /// `mutation.call().invalidates(resource)`.
+    pub fn invalidates<R: 'static>(&self, resource: Resource<R>) -> MutationCall<I, T, E> {
+        self.call().invalidates(resource)
+    }
+
+    /// Cancel the mutation that is currently running. Optimistic updates from the cancelled run are not rolled back.
+    pub fn cancel(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+            self.state.set(UseMutationState::Idle);
+        }
+    }
+
+    /// Get the result of the last mutation that finished, if there is one
+    pub fn value(&self) -> ReadOnlySignal<Option<Result<T, E>>> {
+        self.value.into()
+    }
+
+    /// Get a signal that contains the state of the mutation
+    pub fn state(&self) -> ReadOnlySignal<UseMutationState> {
+        self.state.into()
+    }
+
+    /// Check if the mutator is currently running
+    pub fn pending(&self) -> bool {
+        *self.state.read() == UseMutationState::Pending
+    }
+}
+
+/// A single run of a [`UseMutation`] with the optimistic updates and invalidations that belong to it.
+///
+/// Created with [`UseMutation::call`], [`UseMutation::optimistic`] or [`UseMutation::invalidates`].
+#[must_use = "The mutation doesn't run until `mutate` is called"]
+pub struct MutationCall<I: 'static, T: 'static, E: 'static> {
+    mutation: UseMutation<I, T, E>,
+    optimistic: Vec<OptimisticUpdate>,
+    invalidates: Vec<Box<dyn FnOnce()>>,
+}
+
+impl<I, T, E> MutationCall<I, T, E> {
+    /// Update a signal as soon as the mutation starts. If the mutator returns an error, the signal is reset to the
+    /// value it had before the update.
+    ///
+    /// Rolling back restores the whole value of the signal, so any writes to it that happen while the mutation is
+    /// running are lost if the mutation fails.
+    pub fn optimistic<V: Clone + 'static>(
+        mut self,
+        mut signal: Signal<V>,
+        update: impl FnOnce(&mut V) + 'static,
+    ) -> Self {
+        self.optimistic.push(Box::new(move || {
+            let previous = signal.peek().clone();
+            update(&mut *signal.write());
+            let rollback: Box<dyn FnOnce()> = Box::new(move || signal.set(previous));
+            rollback
+        }));
+        self
+    }
+
+    /// Restart a resource once the mutator succeeds so it reloads the data the mutation changed.
+    pub fn invalidates<R: 'static>(mut self, mut resource: Resource<R>) -> Self {
+        self.invalidates.push(Box::new(move || resource.restart()));
+        self
+    }
+
+    /// Apply the optimistic updates and run the mutator. If a previous run of the mutation is still pending, it is
+    /// cancelled.
+    pub fn mutate(self, input: I) -> Task {
+        let MutationCall {
+            mut mutation,
+            optimistic,
+            invalidates,
+        } = self;
+
+        if let Some(task) = mutation.task.take() {
+            task.cancel();
+        }
+
+        let rollbacks: Vec<_> = optimistic.into_iter().map(|update| update()).collect();
+        let future = mutation.mutator.call(input);
+        mutation.state.set(UseMutationState::Pending);
+
+        let task = spawn(async move {
+            let result = future.await;
+            match &result {
+                Ok(_) => {
+                    mutation.state.set(UseMutationState::Succeeded);
+                    for invalidate in invalidates {
+                        invalidate();
+                    }
+                }
+                Err(_) => {
+                    mutation.state.set(UseMutationState::Failed);
+                    // Undo the updates in the reverse order they were applied
+                    for rollback in rollbacks.into_iter().rev() {
+                        rollback();
+                    }
+                }
+            }
+            mutation.value.set(Some(result));
+            mutation.task.set(None);
+        });
+        mutation.task.set(Some(task));
+        task
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{use_effect, PersistentStorage, StorageBackend, StorageListener};
+use dioxus_core::prelude::*;
+use dioxus_signals::{Readable, Signal, Writable};
+use serde::{de::DeserializeOwned, Serialize};
+use std::rc::Rc;
+
+/// Creates a new Signal that is saved in the platform's [`PersistentStorage`] under a unique key, so it survives a
+/// reload or restart of the app. On the web the value is saved in localStorage, and on desktop it is saved in a file in
+/// the data directory of the app. When the server renders the app, nothing is saved and the signal starts with `init`.
+///
+/// The value is serialized to json whenever it changes. If another tab or window changes the value, the signal is
+/// updated too.
+///
+/// The signal starts with the saved value, and `init` is only called if nothing is saved or the saved value can't be
+/// parsed. When the page is hydrated, the first render always uses `init` so it matches the html from the server, and
+/// the saved value is loaded right after.
+///
+/// The signal is also saved in [`Checkpoint`]s. A restored checkpoint takes precedence over storage, but it is applied
+/// after the signal is created, so the saved value or `init` is still loaded first.
+///
+/// This is useful for settings, form state or anything else that should survive a restart of the app:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// fn Draft() -> Element {
+///     let mut draft = use_persistent("draft", String::new);
+///
+///     rsx! {
+///         textarea {
+///             value: "{draft}",
+///             oninput: move |event| draft.set(event.value()),
+///         }
+///     }
+/// }
+/// ```
+#[track_caller]
+#[must_use]
+pub fn use_persistent<T>(key: impl ToString, init: impl FnOnce() -> T) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    use_persistent_in(key, PersistentStorage::local, init)
+}
+
+/// Like [`use_persistent`], but the value is only saved for the current session. On the web the value is saved in
+/// sessionStorage, so it survives a reload but not closing the tab. On desktop the value lives as long as the app.
+#[track_caller]
+#[must_use]
+pub fn use_session_persistent<T>(key: impl ToString, init: impl FnOnce() -> T) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    use_persistent_in(key, PersistentStorage::session, init)
+}
+
+#[track_caller]
+fn use_persistent_in<T>(
+    key: impl ToString,
+    backend: fn(&PersistentStorage) -> Rc<dyn StorageBackend>,
+    init: impl FnOnce() -> T,
+) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let (signal, key, backend, _listener) = use_hook_with_cleanup(
+        || {
+            let key = key.to_string();
+            let storage = PersistentStorage::current();
+            let load_after_hydration = storage
+                .as_ref()
+                .is_some_and(PersistentStorage::loads_after_hydration);
+            let backend = storage.map(|storage| backend(&storage));
+            let load = {
+                let key = key.clone();
+                let backend = backend.clone();
+                move || {
+                    let saved = backend.as_ref()?.get(&key)?;
+                    match serde_json::from_str::<T>(&saved) {
+                        Ok(value) => Some(value),
+                        Err(err) => {
+                            tracing::warn!("Failed to load the persistent value {key}: {err}");
+                            None
+                        }
+                    }
+                }
+            };
+
+            let signal = match load_after_hydration {
+                true => Signal::new(init()),
+                false => Signal::new(load().unwrap_or_else(init)),
+            };
+            let created = serde_json::to_string(&*signal.peek()).ok();
+            persist_signal(key.clone(), signal);
+
+            if load_after_hydration {
+                // Effects run once the first render is applied, so the hydrated page matched the initial value
+                queue_effect(move || {
+                    // A restored checkpoint takes precedence over the saved value
+                    let current = signal
+                        .try_peek()
+                        .ok()
+                        .and_then(|current| serde_json::to_string(&*current).ok());
+                    if current != created {
+                        return;
+                    }
+                    if let Some(saved) = load() {
+                        let mut signal = signal;
+                        signal.set(saved);
+                    }
+                });
+            }
+
+            let listener = backend.as_ref().map(|backend| {
+                let listener: Rc<StorageListener> = Rc::new({
+                    let key = key.clone();
+                    move |changed: &str, value: Option<&str>| {
+                        let Some(value) = value.filter(|_| changed == key) else {
+                            return;
+                        };
+                        // Changes this hook saved itself are already in the signal
+                        let Ok(current) = signal.try_peek() else {
+                            return;
+                        };
+                        if serde_json::to_string(&*current).ok().as_deref() == Some(value) {
+                            return;
+                        }
+                        drop(current);
+                        match serde_json::from_str(value) {
+                            Ok(value) => {
+                                let mut signal = signal;
+                                signal.set(value);
+                            }
+                            Err(err) => {
+                                tracing::warn!("Failed to load the persistent value {key}: {err}")
+                            }
+                        }
+                    }
+                });
+                backend.watch(Rc::downgrade(&listener));
+                listener
+            });
+
+            (signal, key, backend, listener)
+        },
+        |(_, key, _, _)| unregister_checkpoint(&key),
+    );
+
+    use_effect(move || {
+        let saved = serde_json::to_string(&*signal.read());
+        let Some(backend) = &backend else {
+            return;
+        };
+        match saved {
+            Ok(saved) => backend.set(&key, Some(&saved)),
+            Err(err) => tracing::warn!("Failed to save the persistent value {key}: {err}"),
+        }
+    });
+
+    signal
+}
+
+/// Save an existing signal in [`Checkpoint`]s under a unique key. If a checkpoint with a value for the key was restored,
+/// the signal is set to that value immediately. Use this for global signals that should survive a restart of the app:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// static THEME: GlobalSignal<String> = Signal::global(|| "light".to_string());
+///
+/// fn App() -> Element {
+///     use_hook(|| persist_signal("theme", THEME.signal()));
+///     rsx! { "The theme is {THEME}" }
+/// }
+/// ```
+pub fn persist_signal<T>(key: impl ToString, signal: Signal<T>)
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    register_checkpoint(
+        key,
+        move || {
+            let value = signal.try_peek().ok()?;
+            serde_json::to_string(&*value).ok()
+        },
+        move |saved| match serde_json::from_str(saved) {
+            // Checkpoints are usually restored while the signal is created in a component body
+            Ok(value) => {
+                use warnings::Warning;
+                let mut signal = signal;
+                dioxus_signals::warnings::signal_write_in_component_body::allow(|| {
+                    signal.set(value)
+                })
+            }
+            Err(err) => tracing::warn!("Failed to restore a persistent signal: {err}"),
+        },
+    );
+}
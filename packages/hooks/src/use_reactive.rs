@@ -1,4 +1,6 @@
+use dioxus_core::prelude::use_hook;
 use dioxus_signals::{Readable, Writable};
+use std::{cell::RefCell, ops::Deref, rc::Rc};
 
 use crate::use_signal;
 
@@ -86,6 +88,79 @@ impl_dep!(A = a1 a2, B = b1 b2, C = c1 c2, D = d1 d2, E = e1 e2, F = f1 f2,);
 impl_dep!(A = a1 a2, B = b1 b2, C = c1 c2, D = d1 d2, E = e1 e2, F = f1 f2, G = g1 g2,);
 impl_dep!(A = a1 a2, B = b1 b2, C = c1 c2, D = d1 d2, E = e1 e2, F = f1 f2, G = g1 g2, H = h1 h2,);
 
+/// A dependency element that is compared by pointer instead of by value.
+///
+/// Wrap values that are expensive to compare or don't implement `PartialEq` in an [`Rc`] and `PtrEq` to only count
+/// them as changed when they point to a different allocation.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use std::rc::Rc;
+///
+/// #[component]
+/// fn Chart(points: Rc<Vec<f64>>) -> Element {
+///     let points = PtrEq(points);
+///     use_effect(use_reactive((&points,), |(points,)| {
+///         println!("Drawing {} points", points.len());
+///     }));
+///     rsx! {}
+/// }
+/// ```
+pub struct PtrEq<T: ?Sized>(pub Rc<T>);
+
+impl<T: ?Sized> Clone for PtrEq<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ?Sized> PartialEq for PtrEq<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: ?Sized> Deref for PtrEq<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Check if the dependencies changed since the last time the component rendered. This is the same comparison that
+/// [`use_reactive`] uses, for custom hooks that need to diff their own dependencies.
+///
+/// Returns `true` on the first render and every time one of the dependencies is different from the last render.
+/// Unlike [`use_reactive`], this doesn't subscribe to anything, so the component doesn't rerender when the dependencies
+/// change.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// fn use_log_changes(name: &str, value: u32) {
+///     if use_changed((&value,)) {
+///         println!("{name} changed to {value}");
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_changed<D: Dependency>(dependencies: D) -> bool {
+    let mut first_run = false;
+    let last_state = use_hook(|| {
+        first_run = true;
+        Rc::new(RefCell::new(dependencies.out()))
+    });
+    if first_run {
+        return true;
+    }
+    if dependencies.changed(&last_state.borrow()) {
+        *last_state.borrow_mut() = dependencies.out();
+        return true;
+    }
+    false
+}
+
 /// Takes some non-reactive data, and a closure and returns a closure that will subscribe to that non-reactive data as if it were reactive.
 ///
 /// # Example
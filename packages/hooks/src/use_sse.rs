@@ -0,0 +1,166 @@
+use crate::realtime::{self, Connection, ConnectionState, RealtimeError, Reconnect};
+use dioxus_core::prelude::*;
+use dioxus_signals::ReadOnlySignal;
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+
+/// Subscribe to a stream of server-sent events that lives as long as the current component.
+///
+/// The data of every `message` event is deserialized from json into `T` and becomes the latest
+/// [`EventSource::message`], which reruns any component that reads it. Messages that arrive before the component reads
+/// them are replaced by newer messages. Use [`use_sse_with_handler`] to handle every message instead. Named events are
+/// ignored. If the stream is lost, it is opened again with the default [`Reconnect`] backoff. If the url changes, the
+/// stream is opened again with the new url. The stream is closed when the component is dropped.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Notification {
+///     title: String,
+/// }
+///
+/// fn Notifications() -> Element {
+///     let events = use_sse::<Notification>("https://example.com/notifications");
+///
+///     rsx! {
+///         if events.state() == ConnectionState::Reconnecting {
+///             "Reconnecting..."
+///         }
+///         if let Some(notification) = &*events.message().read() {
+///             "{notification.title}"
+///         }
+///     }
+/// }
+/// ```
+#[track_caller]
+#[must_use]
+pub fn use_sse<T: DeserializeOwned + 'static>(url: impl ToString) -> EventSource<T> {
+    use_sse_with_reconnect(url, Reconnect::default())
+}
+
+/// Subscribe to a stream of server-sent events that lives as long as the current component and reconnects with a
+/// custom backoff. See [`use_sse`] for more details.
+#[track_caller]
+#[must_use]
+pub fn use_sse_with_reconnect<T: DeserializeOwned + 'static>(
+    url: impl ToString,
+    reconnect: Reconnect,
+) -> EventSource<T> {
+    let events = use_hook(|| open_sse(url.to_string(), reconnect));
+    events.connection.set_url(url.to_string());
+    events
+}
+
+/// Subscribe to a stream of server-sent events like [`use_sse_with_reconnect`] that passes every message to
+/// `on_message` as it arrives. No message is dropped, even if many messages arrive before the next render. The
+/// messages are not stored in [`EventSource::message`], which stays `None`.
+#[track_caller]
+#[must_use]
+pub fn use_sse_with_handler<T: DeserializeOwned + 'static>(
+    url: impl ToString,
+    reconnect: Reconnect,
+    on_message: impl FnMut(T) + 'static,
+) -> EventSource<T> {
+    let events = use_sse_with_reconnect(url, reconnect);
+    // Always call the handler from the latest render
+    events.connection.set_handler(on_message);
+    events
+}
+
+fn open_sse<T: DeserializeOwned + 'static>(url: String, reconnect: Reconnect) -> EventSource<T> {
+    let (connection, mut restarts) = Connection::new(url);
+
+    let task = spawn(async move {
+        let mut attempt = 0;
+        loop {
+            let url = connection.url();
+            let mut opened = false;
+            let result = realtime::until_restart(
+                &mut restarts,
+                realtime::event_source(
+                    &url,
+                    || {
+                        opened = true;
+                        connection.set_state(ConnectionState::Open);
+                    },
+                    |text| connection.receive(&text),
+                ),
+            )
+            .await;
+            if opened {
+                attempt = 0;
+            }
+            let Some(result) = result else {
+                // The url changed, so connect to the new url right away
+                attempt = 0;
+                connection.set_state(ConnectionState::Connecting);
+                continue;
+            };
+            if let Err(err) = result {
+                connection.fail(err);
+            }
+
+            let Some(delay) = reconnect.delay(attempt) else {
+                connection.set_state(ConnectionState::Closed);
+                // Give up until the url changes
+                if restarts.next().await.is_none() {
+                    return;
+                }
+                attempt = 0;
+                connection.set_state(ConnectionState::Connecting);
+                continue;
+            };
+            attempt += 1;
+            connection.set_state(ConnectionState::Reconnecting);
+            if realtime::until_restart(&mut restarts, realtime::sleep(delay))
+                .await
+                .is_none()
+            {
+                attempt = 0;
+            }
+        }
+    });
+
+    EventSource { connection, task }
+}
+
+/// A stream of server-sent events created with [`use_sse`]
+pub struct EventSource<T: 'static> {
+    connection: Connection<T>,
+    task: Task,
+}
+
+impl<T> Clone for EventSource<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for EventSource<T> {}
+
+impl<T: DeserializeOwned + 'static> EventSource<T> {
+    /// Get the latest message, or `None` if no message was received yet. Reading the signal subscribes the current
+    /// component to new messages.
+    pub fn message(&self) -> ReadOnlySignal<Option<T>> {
+        self.connection.message()
+    }
+
+    /// Get the state of the stream. Reading this subscribes the current component to changes.
+    pub fn state(&self) -> ConnectionState {
+        self.connection.state()
+    }
+
+    /// Get the last error, like a failed connection or a message that could not be deserialized. Reading this
+    /// subscribes the current component to new errors.
+    pub fn error(&self) -> Option<RealtimeError> {
+        self.connection.error()
+    }
+
+    /// Close the stream. It will not be opened again.
+    pub fn close(&self) {
+        self.task.cancel();
+        self.connection.set_state(ConnectionState::Closed);
+    }
+}
@@ -0,0 +1,174 @@
+use dioxus_core::prelude::*;
+use dioxus_core::{CapturedError, Runtime};
+use dioxus_signals::*;
+use futures_channel::oneshot;
+use std::future::Future;
+
+/// Create a [`TaskGroup`] that owns a set of concurrent tasks. Every task in the group is canceled when the component
+/// is dropped, and the first task that fails throws its error to the nearest [`ErrorBoundary`] and cancels the rest
+/// of the group.
+///
+/// ## Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # async fn fetch_user() -> dioxus::Result<String> { Ok("user".to_string()) }
+/// # async fn fetch_posts() -> dioxus::Result<Vec<String>> { Ok(Vec::new()) }
+/// fn Profile() -> Element {
+///     let group = use_task_group();
+///     let mut user = use_signal(|| None);
+///     let mut posts = use_signal(Vec::<String>::new);
+///
+///     use_hook(|| {
+///         group.spawn(async move {
+///             user.set(Some(fetch_user().await?));
+///             Ok(())
+///         });
+///         group.spawn(async move {
+///             posts.set(fetch_posts().await?);
+///             Ok(())
+///         });
+///     });
+///
+///     if !group.is_empty() {
+///         return rsx! { "Loading..." };
+///     }
+///
+///     let count = posts.len();
+///     rsx! {
+///         "{user:?} wrote {count} posts"
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_task_group() -> TaskGroup {
+    let group = use_hook(TaskGroup::new);
+    use_drop(move || group.cancel_all());
+    group
+}
+
+/// A set of tasks that are joined and canceled together. Create one with [`use_task_group`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct TaskGroup {
+    origin: ScopeId,
+    tasks: CopyValue<Vec<Task>>,
+    running: Signal<usize>,
+    error: Signal<Option<CapturedError>>,
+    waiters: CopyValue<Vec<oneshot::Sender<()>>>,
+}
+
+impl TaskGroup {
+    /// Create a new task group owned by the current scope
+    pub fn new() -> Self {
+        Self {
+            origin: current_scope_id().unwrap_or_else(|e| panic!("{}", e)),
+            tasks: CopyValue::new(Vec::new()),
+            running: Signal::new(0),
+            error: Signal::new(None),
+            waiters: CopyValue::new(Vec::new()),
+        }
+    }
+
+    /// Spawn a task in the group. The task runs in the scope that created the group, so it is not canceled when the
+    /// component that spawned it is dropped unless the group is dropped too.
+    ///
+    /// If the task fails, the error is thrown to the nearest [`ErrorBoundary`] and every other task in the group is
+    /// canceled. Spawning a task into a group with no running tasks clears the error from the last failure.
+    pub fn spawn(&self, fut: impl Future<Output = dioxus_core::Result<()>> + 'static) -> Task {
+        let mut group = *self;
+        if *group.running.peek() == 0 {
+            group.error.set(None);
+        }
+        group.running += 1;
+
+        let task = self.origin.in_runtime(|| {
+            spawn(async move {
+                let result = fut.await;
+                let current = Runtime::current().ok().and_then(|rt| rt.current_task());
+                group.tasks.write().retain(|task| Some(*task) != current);
+                match result {
+                    Ok(()) => group.finish(1),
+                    Err(error) => group.fail(error),
+                }
+            })
+        });
+        group.tasks.write().push(task);
+        task
+    }
+
+    /// Cancel every task in the group. Anything waiting on [`TaskGroup::join`] is woken up.
+    pub fn cancel_all(&self) {
+        let mut group = *self;
+        let tasks = match group.tasks.try_write() {
+            Ok(mut tasks) => std::mem::take(&mut *tasks),
+            Err(_) => return,
+        };
+        for task in &tasks {
+            task.cancel();
+        }
+        self.finish(tasks.len());
+    }
+
+    /// Wait until every task in the group has finished or been canceled. Returns the error from the first task that
+    /// failed, if any.
+    pub async fn join(&self) -> dioxus_core::Result<()> {
+        if *self.running.peek() > 0 {
+            let (tx, rx) = oneshot::channel();
+            let mut waiters = self.waiters;
+            waiters.write().push(tx);
+            _ = rx.await;
+        }
+        match self.error.peek().clone() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the number of running tasks. Reading this subscribes the current component to changes.
+    pub fn len(&self) -> usize {
+        *self.running.read()
+    }
+
+    /// Check if every task in the group has finished. Reading this subscribes the current component to changes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the error from the first task that failed. Reading this subscribes the current component to changes.
+    pub fn error(&self) -> Option<CapturedError> {
+        self.error.read().clone()
+    }
+
+    fn fail(&self, error: CapturedError) {
+        let mut group = *self;
+        let first = group.error.peek().is_none();
+        if first {
+            group.error.set(Some(error.clone()));
+        }
+        self.finish(1);
+        if first {
+            self.cancel_all();
+            self.origin.throw_error(error);
+        }
+    }
+
+    fn finish(&self, count: usize) {
+        let mut group = *self;
+        let Ok(mut running) = group.running.try_write() else {
+            return;
+        };
+        *running = running.saturating_sub(count);
+        if *running == 0 {
+            drop(running);
+            for waiter in group.waiters.write().drain(..) {
+                _ = waiter.send(());
+            }
+        }
+    }
+}
+
+impl Default for TaskGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,232 @@
+use crate::realtime::{self, Connection, ConnectionState, RealtimeError, Reconnect};
+use dioxus_core::prelude::*;
+use dioxus_signals::{CopyValue, ReadOnlySignal, Readable};
+use futures_channel::mpsc::{unbounded, UnboundedSender};
+use futures_util::StreamExt;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Open a WebSocket connection that lives as long as the current component.
+///
+/// Every text message is deserialized from json into `T` and becomes the latest [`WebSocket::message`], which reruns
+/// any component that reads it. Messages that arrive before the component reads them are replaced by newer messages.
+/// Use [`use_ws_with_handler`] to handle every message instead. If the connection is lost, it is opened again with the
+/// default [`Reconnect`] backoff. If the url changes, the connection is opened again with the new url. The connection
+/// is closed when the component is dropped.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize)]
+/// struct Price {
+///     symbol: String,
+///     price: f64,
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Subscribe {
+///     symbol: String,
+/// }
+///
+/// fn Ticker() -> Element {
+///     let socket = use_ws::<Price>("wss://example.com/prices");
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 _ = socket.send(&Subscribe { symbol: "DX".to_string() });
+///             },
+///             "Subscribe"
+///         }
+///         if let Some(price) = &*socket.message().read() {
+///             "{price.symbol}: {price.price}"
+///         }
+///     }
+/// }
+/// ```
+#[track_caller]
+#[must_use]
+pub fn use_ws<T: DeserializeOwned + 'static>(url: impl ToString) -> WebSocket<T> {
+    use_ws_with_reconnect(url, Reconnect::default())
+}
+
+/// Open a WebSocket connection that lives as long as the current component and reconnects with a custom backoff. See
+/// [`use_ws`] for more details.
+#[track_caller]
+#[must_use]
+pub fn use_ws_with_reconnect<T: DeserializeOwned + 'static>(
+    url: impl ToString,
+    reconnect: Reconnect,
+) -> WebSocket<T> {
+    let socket = use_hook(|| open_ws(url.to_string(), reconnect, None));
+    socket.connection.set_url(url.to_string());
+    socket
+}
+
+/// Open a WebSocket connection like [`use_ws_with_reconnect`] that passes every message to `on_message` as it
+/// arrives. No message is dropped, even if many messages arrive before the next render. The messages are not stored
+/// in [`WebSocket::message`], which stays `None`.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// fn Chat() -> Element {
+///     let mut lines = use_signal(Vec::<String>::new);
+///     let _socket = use_ws_with_handler("wss://example.com/chat", Reconnect::default(), move |line: String| {
+///         lines.push(line);
+///     });
+///
+///     rsx! {
+///         for line in lines.iter() {
+///             p { "{line}" }
+///         }
+///     }
+/// }
+/// ```
+#[track_caller]
+#[must_use]
+pub fn use_ws_with_handler<T: DeserializeOwned + 'static>(
+    url: impl ToString,
+    reconnect: Reconnect,
+    on_message: impl FnMut(T) + 'static,
+) -> WebSocket<T> {
+    let socket = use_ws_with_reconnect(url, reconnect);
+    // Always call the handler from the latest render
+    socket.connection.set_handler(on_message);
+    socket
+}
+
+/// Open a WebSocket connection like [`use_ws_with_handler`] that asks the server for a subprotocol
+#[cfg(feature = "graphql")]
+#[track_caller]
+pub(crate) fn use_ws_with_protocol<T: DeserializeOwned + 'static>(
+    url: impl ToString,
+    reconnect: Reconnect,
+    protocol: &'static str,
+    on_message: impl FnMut(T) + 'static,
+) -> WebSocket<T> {
+    let socket = use_hook(|| open_ws(url.to_string(), reconnect, Some(protocol)));
+    socket.connection.set_url(url.to_string());
+    socket.connection.set_handler(on_message);
+    socket
+}
+
+fn open_ws<T: DeserializeOwned + 'static>(
+    url: String,
+    reconnect: Reconnect,
+    protocol: Option<&'static str>,
+) -> WebSocket<T> {
+    let (connection, mut restarts) = Connection::new(url);
+    let (sender, mut outgoing) = unbounded();
+
+    let task = spawn(async move {
+        let mut attempt = 0;
+        loop {
+            let url = connection.url();
+            let mut opened = false;
+            let result = realtime::until_restart(
+                &mut restarts,
+                realtime::websocket(
+                    &url,
+                    protocol,
+                    &mut outgoing,
+                    || {
+                        opened = true;
+                        connection.set_state(ConnectionState::Open);
+                    },
+                    |text| connection.receive(&text),
+                ),
+            )
+            .await;
+            if opened {
+                attempt = 0;
+            }
+            let Some(result) = result else {
+                // The url changed, so connect to the new url right away
+                attempt = 0;
+                connection.set_state(ConnectionState::Connecting);
+                continue;
+            };
+            if let Err(err) = result {
+                connection.fail(err);
+            }
+
+            let Some(delay) = reconnect.delay(attempt) else {
+                connection.set_state(ConnectionState::Closed);
+                // Give up until the url changes
+                if restarts.next().await.is_none() {
+                    return;
+                }
+                attempt = 0;
+                connection.set_state(ConnectionState::Connecting);
+                continue;
+            };
+            attempt += 1;
+            connection.set_state(ConnectionState::Reconnecting);
+            if realtime::until_restart(&mut restarts, realtime::sleep(delay))
+                .await
+                .is_none()
+            {
+                attempt = 0;
+            }
+        }
+    });
+
+    WebSocket {
+        connection,
+        sender: CopyValue::new(sender),
+        task,
+    }
+}
+
+/// A WebSocket connection created with [`use_ws`]
+pub struct WebSocket<T: 'static> {
+    connection: Connection<T>,
+    sender: CopyValue<UnboundedSender<String>>,
+    task: Task,
+}
+
+impl<T> Clone for WebSocket<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WebSocket<T> {}
+
+impl<T: DeserializeOwned + 'static> WebSocket<T> {
+    /// Get the latest message, or `None` if no message was received yet. Reading the signal subscribes the current
+    /// component to new messages.
+    pub fn message(&self) -> ReadOnlySignal<Option<T>> {
+        self.connection.message()
+    }
+
+    /// Get the state of the connection. Reading this subscribes the current component to changes.
+    pub fn state(&self) -> ConnectionState {
+        self.connection.state()
+    }
+
+    /// Get the last error, like a failed connection or a message that could not be deserialized. Reading this
+    /// subscribes the current component to new errors.
+    pub fn error(&self) -> Option<RealtimeError> {
+        self.connection.error()
+    }
+
+    /// Serialize a message to json and send it. Messages sent while the connection is opening or reconnecting are
+    /// queued and sent once the connection is open.
+    pub fn send(&self, message: &impl Serialize) -> Result<(), RealtimeError> {
+        let text = serde_json::to_string(message)
+            .map_err(|err| RealtimeError::Serialize(err.to_string()))?;
+        self.sender
+            .read()
+            .unbounded_send(text)
+            .map_err(|_| RealtimeError::Closed)
+    }
+
+    /// Close the connection. It will not be opened again.
+    pub fn close(&self) {
+        self.task.cancel();
+        self.sender.read().close_channel();
+        self.connection.set_state(ConnectionState::Closed);
+    }
+}
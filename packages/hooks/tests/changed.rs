@@ -0,0 +1,37 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+#[test]
+fn changed_compares_dependencies() {
+    thread_local! {
+        static CHANGES: RefCell<Vec<bool>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn app() -> Element {
+        let value = generation() / 2;
+        let changed = use_changed((&value,));
+        CHANGES.with(|changes| changes.borrow_mut().push(changed));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    for _ in 0..3 {
+        dom.mark_dirty(ScopeId::APP);
+        dom.render_immediate(&mut NoOpMutations);
+    }
+
+    CHANGES.with(|changes| assert_eq!(*changes.borrow(), [true, false, true, false]));
+}
+
+#[test]
+fn ptr_eq_compares_by_pointer() {
+    let first = Rc::new(vec![1, 2, 3]);
+    let same_values = Rc::new(vec![1, 2, 3]);
+
+    assert!(PtrEq(first.clone()) == PtrEq(first.clone()));
+    assert!(PtrEq(first) != PtrEq(same_values));
+}
@@ -0,0 +1,66 @@
+#![allow(non_snake_case)]
+use std::cell::Cell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+#[derive(Clone, Default)]
+struct AppState {
+    user_name: String,
+    unread_messages: usize,
+}
+
+thread_local! {
+    static STATE: Cell<Option<Signal<AppState>>> = const { Cell::new(None) };
+    static NAME_RENDERS: Cell<usize> = const { Cell::new(0) };
+    static LAST_NAME: Cell<Option<Memo<String>>> = const { Cell::new(None) };
+}
+
+fn App() -> Element {
+    let state = use_context_provider(|| Signal::new(AppState::default()));
+    STATE.set(Some(state));
+    rsx! { UserName {} }
+}
+
+#[component]
+fn UserName() -> Element {
+    let name = use_context_selector(|state: &AppState| state.user_name.clone());
+    NAME_RENDERS.set(NAME_RENDERS.get() + 1);
+    LAST_NAME.set(Some(name));
+    rsx! { "{name}" }
+}
+
+/// Run the dom until no more work arrives
+async fn settle(dom: &mut VirtualDom) {
+    while tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work())
+        .await
+        .is_ok()
+    {
+        dom.render_immediate(&mut NoOpMutations);
+    }
+}
+
+#[tokio::test]
+async fn selector_only_reruns_when_the_selection_changes() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    assert_eq!(NAME_RENDERS.get(), 1);
+    let mut state = STATE.get().unwrap();
+
+    // Changes to other parts of the context don't rerun the component
+    dom.in_runtime(|| state.write().unread_messages += 1);
+    settle(&mut dom).await;
+    assert_eq!(NAME_RENDERS.get(), 1);
+
+    // Writing the same selected value doesn't rerun the component either
+    dom.in_runtime(|| state.write().user_name = String::new());
+    settle(&mut dom).await;
+    assert_eq!(NAME_RENDERS.get(), 1);
+
+    dom.in_runtime(|| state.write().user_name = "Ferris".to_string());
+    settle(&mut dom).await;
+    assert_eq!(NAME_RENDERS.get(), 2);
+    let name = LAST_NAME.get().unwrap();
+    assert_eq!(dom.in_runtime(|| name.read().clone()), "Ferris");
+}
@@ -0,0 +1,308 @@
+#![cfg(feature = "graphql")]
+#![allow(non_snake_case)]
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::prelude::RuntimeGuard;
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
+
+/// Serve a GraphQL endpoint that answers every request with `respond` and counts the requests
+async fn serve(respond: fn(&Value) -> Value) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                stream.read_line(&mut line).await.unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; length];
+            stream.read_exact(&mut body).await.unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            let request: Value = serde_json::from_slice(&body).unwrap();
+            let response = json!({ "data": respond(&request) }).to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{response}",
+                response.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+    (url, requests)
+}
+
+/// Answer queries for a user and the mutation that renames them
+fn users(request: &Value) -> Value {
+    let query = request["query"].as_str().unwrap();
+    if query.starts_with("mutation") {
+        let name = &request["variables"]["name"];
+        return json!({ "rename": { "__typename": "User", "id": 1, "name": name } });
+    }
+    json!({ "user": { "__typename": "User", "id": 1, "name": "Ferris", "friends": [] } })
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct User {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct UserQuery {
+    user: User,
+}
+
+const USER: &str = "query User { user { __typename id name friends { name } } }";
+const RENAME: &str =
+    "mutation Rename($name: String!) { rename(name: $name) { __typename id name } }";
+
+/// Create a client in a running dom. The client keeps its cache in signals, which need a runtime
+fn client(dom: &VirtualDom, url: String) -> GraphQLClient {
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || GraphQLClient::new(url))
+}
+
+fn name(user: &UserQuery) -> &str {
+    &user.user.name
+}
+
+/// Run the dom until `done` returns true or five seconds pass
+async fn run_until(dom: &mut VirtualDom, done: impl Fn(&VirtualDom) -> bool) {
+    _ = tokio::time::timeout(Duration::from_secs(5), async {
+        while !done(dom) {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn cached_queries_are_not_sent_again() {
+    let (url, requests) = serve(users).await;
+    let dom = VirtualDom::new(|| rsx! {});
+    let _guard = RuntimeGuard::new(dom.runtime());
+    let client = client(&dom, url);
+
+    let first: UserQuery = client.query(USER, &()).await.unwrap();
+    let second: UserQuery = client.query(USER, &()).await.unwrap();
+    assert_eq!(first, second);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    // Different variables are cached separately
+    let _: UserQuery = client.query(USER, &json!({ "id": 2 })).await.unwrap();
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    client.invalidate(USER);
+    let _: UserQuery = client.query(USER, &()).await.unwrap();
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn mutations_update_the_objects_in_cached_queries() {
+    let (url, requests) = serve(users).await;
+    let dom = VirtualDom::new(|| rsx! {});
+    let _guard = RuntimeGuard::new(dom.runtime());
+    let client = client(&dom, url);
+
+    let user: UserQuery = client.query(USER, &()).await.unwrap();
+    assert_eq!(name(&user), "Ferris");
+
+    let _: Value = client
+        .mutate(RENAME, &json!({ "name": "Corro" }))
+        .await
+        .unwrap();
+
+    // The cached query now refers to the renamed user without sending the query again
+    let user: UserQuery = client.query(USER, &()).await.unwrap();
+    assert_eq!(name(&user), "Corro");
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    // Fields the mutation didn't select are kept
+    let user: Value = client.query(USER, &()).await.unwrap();
+    assert_eq!(user["user"]["friends"], json!([]));
+}
+
+#[tokio::test]
+async fn queries_rerun_when_a_cached_object_changes() {
+    thread_local! {
+        static USER_QUERY: Cell<Option<Resource<Result<UserQuery, GraphQLError>>>> = const { Cell::new(None) };
+    }
+
+    let (url, _) = serve(users).await;
+
+    #[derive(Props, Clone, PartialEq)]
+    struct AppProps {
+        url: String,
+    }
+
+    fn App(props: AppProps) -> Element {
+        use_context_provider(|| GraphQLClient::new(props.url));
+        rsx! { Profile {} }
+    }
+
+    fn Profile() -> Element {
+        let user = use_graphql_query::<_, UserQuery>(USER, || ());
+        USER_QUERY.with(|cell| cell.set(Some(user)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new_with_props(App, AppProps { url });
+    dom.rebuild_in_place();
+    let user = USER_QUERY.with(Cell::get).unwrap();
+    let loaded = |dom: &VirtualDom, expected: &str| {
+        dom.in_runtime(|| {
+            user.read()
+                .as_ref()
+                .is_some_and(|user| user.as_ref().is_ok_and(|user| name(user) == expected))
+        })
+    };
+    run_until(&mut dom, |dom| loaded(dom, "Ferris")).await;
+    assert!(loaded(&dom, "Ferris"));
+
+    let client = dom.in_runtime(|| ScopeId::APP.consume_context::<GraphQLClient>().unwrap());
+    {
+        let _guard = RuntimeGuard::new(dom.runtime());
+        let _: Value = client
+            .mutate(RENAME, &json!({ "name": "Corro" }))
+            .await
+            .unwrap();
+    }
+    run_until(&mut dom, |dom| loaded(dom, "Corro")).await;
+    assert!(loaded(&dom, "Corro"));
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Tick {
+    count: u32,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct TickSubscription {
+    tick: Tick,
+}
+
+type TickSignal = ReadOnlySignal<Option<Result<TickSubscription, GraphQLError>>>;
+
+/// Accept the WebSocket handshake with the protocol GraphQL subscriptions use
+#[allow(clippy::result_large_err)]
+fn accept_graphql_protocol(
+    request: &Request,
+    mut response: Response,
+) -> Result<Response, ErrorResponse> {
+    assert_eq!(
+        request.headers()["Sec-WebSocket-Protocol"],
+        "graphql-transport-ws"
+    );
+    response.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        "graphql-transport-ws".parse().unwrap(),
+    );
+    Ok(response)
+}
+
+/// Receive the next text message of a WebSocket as json
+async fn receive(socket: &mut WebSocketStream<TcpStream>) -> Value {
+    loop {
+        if let Message::Text(text) = socket.next().await.unwrap().unwrap() {
+            return serde_json::from_str(&text).unwrap();
+        }
+    }
+}
+
+#[tokio::test]
+async fn subscriptions_receive_every_event() {
+    thread_local! {
+        static TICKS: Cell<Option<TickSignal>> = const { Cell::new(None) };
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut socket = tokio_tungstenite::accept_hdr_async(stream, accept_graphql_protocol)
+            .await
+            .unwrap();
+
+        assert_eq!(receive(&mut socket).await["type"], "connection_init");
+        socket
+            .send(Message::Text(
+                json!({ "type": "connection_ack" }).to_string(),
+            ))
+            .await
+            .unwrap();
+        let subscribe = receive(&mut socket).await;
+        assert_eq!(subscribe["type"], "subscribe");
+        assert_eq!(subscribe["payload"]["variables"], json!({ "every": 1 }));
+        let id = subscribe["id"].clone();
+        for count in 1..=3 {
+            let event = json!({
+                "id": id,
+                "type": "next",
+                "payload": { "data": { "tick": { "__typename": "Tick", "id": 1, "count": count } } },
+            });
+            socket.send(Message::Text(event.to_string())).await.unwrap();
+        }
+        socket
+            .send(Message::Text(
+                json!({ "id": id, "type": "complete" }).to_string(),
+            ))
+            .await
+            .unwrap();
+        // Keep the connection open until the client closes it
+        while socket.next().await.is_some() {}
+    });
+
+    #[derive(Props, Clone, PartialEq)]
+    struct AppProps {
+        url: String,
+    }
+
+    fn App(props: AppProps) -> Element {
+        use_context_provider(|| GraphQLClient::new(props.url));
+        rsx! { Ticks {} }
+    }
+
+    fn Ticks() -> Element {
+        let ticks = use_graphql_subscription::<_, TickSubscription>(
+            "subscription Ticks($every: Int!) { tick(every: $every) { __typename id count } }",
+            || json!({ "every": 1 }),
+        );
+        TICKS.with(|cell| cell.set(Some(ticks)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new_with_props(App, AppProps { url });
+    dom.rebuild_in_place();
+    let ticks = TICKS.with(Cell::get).unwrap();
+    let count = |dom: &VirtualDom| {
+        dom.in_runtime(|| match &*ticks.peek() {
+            Some(Ok(ticks)) => Some(ticks.tick.count),
+            _ => None,
+        })
+    };
+    run_until(&mut dom, |dom| count(dom) == Some(3)).await;
+    assert_eq!(count(&dom), Some(3));
+}
@@ -0,0 +1,129 @@
+#![allow(non_snake_case)]
+use std::cell::Cell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use tokio::sync::oneshot;
+
+type Release = oneshot::Receiver<Result<u32, String>>;
+
+thread_local! {
+    static MUTATION: Cell<Option<UseMutation<Release, u32, String>>> = const { Cell::new(None) };
+    static TODOS: Cell<Option<Signal<Vec<u32>>>> = const { Cell::new(None) };
+    static TODOS_RESOURCE: Cell<Option<Resource<()>>> = const { Cell::new(None) };
+    static RESOURCE_RUNS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The mutator finishes with whatever is sent through the channel it is called with
+fn App() -> Element {
+    let mutation = use_mutation(|release: Release| async move {
+        release.await.map_err(|_| "cancelled".to_string())?
+    });
+    let todos = use_signal(|| vec![1]);
+    let resource = use_resource(|| async { RESOURCE_RUNS.set(RESOURCE_RUNS.get() + 1) });
+    MUTATION.set(Some(mutation));
+    TODOS.set(Some(todos));
+    TODOS_RESOURCE.set(Some(resource));
+    rsx! {}
+}
+
+/// Run the dom until no more work arrives
+async fn settle(dom: &mut VirtualDom) {
+    while tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work())
+        .await
+        .is_ok()
+    {
+        dom.render_immediate(&mut NoOpMutations);
+    }
+}
+
+#[tokio::test]
+async fn mutations_move_through_their_states() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+    let mut mutation = MUTATION.get().unwrap();
+    let todos = TODOS.get().unwrap();
+    let resource = TODOS_RESOURCE.get().unwrap();
+    let state = move |dom: &VirtualDom| dom.in_runtime(|| *mutation.state().peek());
+    let todos_now = move |dom: &VirtualDom| dom.in_runtime(|| todos.peek().clone());
+    let value = move |dom: &VirtualDom| dom.in_runtime(|| mutation.value().peek().clone());
+    assert_eq!(state(&dom), UseMutationState::Idle);
+    assert_eq!(RESOURCE_RUNS.get(), 1);
+
+    // Optimistic updates are applied as soon as the mutation starts
+    let (release, receiver) = oneshot::channel();
+    dom.runtime().on_scope(ScopeId::APP, || {
+        mutation
+            .optimistic(todos, |todos| todos.push(2))
+            .invalidates(resource)
+            .mutate(receiver)
+    });
+    assert_eq!(state(&dom), UseMutationState::Pending);
+    assert!(dom.in_runtime(|| mutation.pending()));
+    assert_eq!(todos_now(&dom), [1, 2]);
+
+    // Succeeding keeps the update and restarts the invalidated resources
+    release.send(Ok(3)).unwrap();
+    settle(&mut dom).await;
+    assert_eq!(state(&dom), UseMutationState::Succeeded);
+    assert_eq!(value(&dom), Some(Ok(3)));
+    assert_eq!(todos_now(&dom), [1, 2]);
+    assert_eq!(RESOURCE_RUNS.get(), 2);
+
+    // Failing rolls the update back and leaves the resources alone
+    let (release, receiver) = oneshot::channel();
+    dom.runtime().on_scope(ScopeId::APP, || {
+        mutation
+            .optimistic(todos, |todos| todos.push(4))
+            .invalidates(resource)
+            .mutate(receiver)
+    });
+    assert_eq!(todos_now(&dom), [1, 2, 4]);
+    release.send(Err("offline".to_string())).unwrap();
+    settle(&mut dom).await;
+    assert_eq!(state(&dom), UseMutationState::Failed);
+    assert_eq!(value(&dom), Some(Err("offline".to_string())));
+    assert_eq!(todos_now(&dom), [1, 2]);
+    assert_eq!(RESOURCE_RUNS.get(), 2);
+
+    // Cancelling goes back to idle without running the rest of the mutation
+    let (release, receiver) = oneshot::channel();
+    dom.runtime()
+        .on_scope(ScopeId::APP, || mutation.mutate(receiver));
+    assert_eq!(state(&dom), UseMutationState::Pending);
+    dom.in_runtime(|| mutation.cancel());
+    assert_eq!(state(&dom), UseMutationState::Idle);
+    _ = release.send(Ok(5));
+    settle(&mut dom).await;
+    assert_eq!(state(&dom), UseMutationState::Idle);
+    assert_eq!(value(&dom), Some(Err("offline".to_string())));
+}
+
+#[tokio::test]
+async fn starting_a_mutation_cancels_the_pending_run() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let mutation = MUTATION.get().unwrap();
+
+    let (first, receiver) = oneshot::channel();
+    dom.runtime()
+        .on_scope(ScopeId::APP, || mutation.mutate(receiver));
+    let (second, receiver) = oneshot::channel();
+    dom.runtime()
+        .on_scope(ScopeId::APP, || mutation.mutate(receiver));
+
+    // The first run was cancelled, so its result never arrives
+    _ = first.send(Ok(1));
+    second.send(Ok(2)).unwrap();
+    settle(&mut dom).await;
+    assert_eq!(
+        dom.in_runtime(|| mutation.value().peek().clone()),
+        Some(Ok(2))
+    );
+    assert_eq!(
+        dom.in_runtime(|| *mutation.state().peek()),
+        UseMutationState::Succeeded
+    );
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "persist")]
+use std::cell::Cell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_hooks::{MemoryStorage, PersistentStorage};
+
+thread_local! {
+    static COUNT: Cell<Option<Signal<i32>>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    let count = use_persistent("count", || 0);
+    COUNT.with(|cell| cell.set(Some(count)));
+    rsx! {}
+}
+
+async fn run_effects(dom: &mut VirtualDom) {
+    _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+}
+
+#[tokio::test]
+async fn persistent_state_is_loaded_and_saved() {
+    let storage = PersistentStorage::new(MemoryStorage::default(), MemoryStorage::default());
+    storage.local().set("count", Some("5"));
+
+    let mut dom = VirtualDom::new(app).with_root_context(storage.clone());
+    dom.rebuild_in_place();
+    let mut count = COUNT.with(Cell::get).unwrap();
+    assert_eq!(dom.in_runtime(|| count.cloned()), 5);
+
+    dom.in_runtime(|| count.set(6));
+    run_effects(&mut dom).await;
+    assert_eq!(storage.local().get("count").as_deref(), Some("6"));
+    assert_eq!(storage.session().get("count"), None);
+
+    // Changes from another window or tab update the signal
+    dom.in_runtime(|| storage.local().set("count", Some("9")));
+    assert_eq!(dom.in_runtime(|| *count.peek()), 9);
+}
+
+#[tokio::test]
+async fn hydrated_pages_load_saved_state_after_the_first_render() {
+    let storage = PersistentStorage::new(MemoryStorage::default(), MemoryStorage::default())
+        .load_after_hydration();
+    storage.local().set("count", Some("5"));
+
+    let mut dom = VirtualDom::new(app).with_root_context(storage.clone());
+    dom.rebuild_in_place();
+    let count = COUNT.with(Cell::get).unwrap();
+    assert_eq!(dom.in_runtime(|| *count.peek()), 0);
+
+    run_effects(&mut dom).await;
+    assert_eq!(dom.in_runtime(|| count.cloned()), 5);
+    assert_eq!(storage.local().get("count").as_deref(), Some("5"));
+}
+
+#[tokio::test]
+async fn invalid_saved_state_falls_back_to_init() {
+    let storage = PersistentStorage::new(MemoryStorage::default(), MemoryStorage::default());
+    storage.local().set("count", Some("\"not a number\""));
+
+    let mut dom = VirtualDom::new(app).with_root_context(storage.clone());
+    dom.rebuild_in_place();
+    let count = COUNT.with(Cell::get).unwrap();
+    assert_eq!(dom.in_runtime(|| count.cloned()), 0);
+
+    run_effects(&mut dom).await;
+    assert_eq!(storage.local().get("count").as_deref(), Some("0"));
+}
+
+#[tokio::test]
+async fn without_storage_nothing_is_saved() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut count = COUNT.with(Cell::get).unwrap();
+    dom.in_runtime(|| count.set(3));
+    run_effects(&mut dom).await;
+    assert_eq!(dom.in_runtime(|| count.cloned()), 3);
+}
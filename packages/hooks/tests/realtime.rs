@@ -0,0 +1,210 @@
+#![cfg(feature = "realtime")]
+#![allow(non_snake_case)]
+use std::cell::Cell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_hooks::{ConnectionState, EventSource, Reconnect};
+use serde::Deserialize;
+use std::cell::RefCell;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+#[test]
+fn reconnect_delay_backs_off_exponentially() {
+    let reconnect = Reconnect {
+        initial_delay: Duration::from_millis(100),
+        max_delay: Duration::from_millis(500),
+        max_attempts: Some(4),
+    };
+
+    assert_eq!(reconnect.delay(0), Some(Duration::from_millis(100)));
+    assert_eq!(reconnect.delay(1), Some(Duration::from_millis(200)));
+    assert_eq!(reconnect.delay(2), Some(Duration::from_millis(400)));
+    assert_eq!(reconnect.delay(3), Some(Duration::from_millis(500)));
+    assert_eq!(reconnect.delay(4), None);
+    assert_eq!(Reconnect::never().delay(0), None);
+    // Without a limit, large attempts saturate at the max delay
+    assert_eq!(
+        Reconnect::default().delay(u32::MAX),
+        Some(Reconnect::default().max_delay)
+    );
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Tick {
+    count: u32,
+}
+
+/// Serve one event stream that sends each chunk of the body in its own write
+async fn serve_events(body: &'static [&'static str]) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/events", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = [0; 1024];
+        _ = stream.read(&mut request).await.unwrap();
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n\r\n")
+            .await
+            .unwrap();
+        for chunk in body {
+            stream.write_all(chunk.as_bytes()).await.unwrap();
+            stream.flush().await.unwrap();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    });
+    url
+}
+
+/// Run the dom until `done` returns true or five seconds pass
+async fn run_until(dom: &mut VirtualDom, mut done: impl FnMut(&VirtualDom) -> bool) {
+    _ = tokio::time::timeout(Duration::from_secs(5), async {
+        while !done(dom) {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn handlers_receive_every_message() {
+    thread_local! {
+        static RECEIVED: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+        static EVENTS: Cell<Option<EventSource<Tick>>> = const { Cell::new(None) };
+    }
+
+    // All of the events arrive in one chunk, before the component could render between them
+    let url =
+        serve_events(&["data: {\"count\": 1}\n\ndata: {\"count\": 2}\n\ndata: {\"count\": 3}\n\n"])
+            .await;
+
+    #[derive(Props, Clone, PartialEq)]
+    struct AppProps {
+        url: String,
+    }
+
+    fn App(props: AppProps) -> Element {
+        let events = use_sse_with_handler(props.url, Reconnect::never(), |tick: Tick| {
+            RECEIVED.with_borrow_mut(|received| received.push(tick.count))
+        });
+        EVENTS.with(|cell| cell.set(Some(events)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new_with_props(App, AppProps { url });
+    dom.rebuild_in_place();
+    let events = EVENTS.with(Cell::get).unwrap();
+    run_until(&mut dom, |dom| {
+        dom.in_runtime(|| events.state()) == ConnectionState::Closed
+    })
+    .await;
+
+    assert_eq!(RECEIVED.take(), [1, 2, 3]);
+    dom.in_runtime(|| assert_eq!(*events.message().read(), None));
+}
+
+#[tokio::test]
+async fn changing_the_url_reconnects() {
+    thread_local! {
+        static URL: Cell<Option<Signal<String>>> = const { Cell::new(None) };
+        static EVENTS: Cell<Option<EventSource<Tick>>> = const { Cell::new(None) };
+    }
+
+    let first = serve_events(&["data: {\"count\": 1}\n\n"]).await;
+    let second = serve_events(&["data: {\"count\": 2}\n\n"]).await;
+
+    #[derive(Props, Clone, PartialEq)]
+    struct AppProps {
+        url: String,
+    }
+
+    fn App(props: AppProps) -> Element {
+        let url = use_signal(|| props.url);
+        let events = use_sse_with_reconnect::<Tick>(url(), Reconnect::never());
+        URL.with(|cell| cell.set(Some(url)));
+        EVENTS.with(|cell| cell.set(Some(events)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new_with_props(App, AppProps { url: first });
+    dom.rebuild_in_place();
+    let events = EVENTS.with(Cell::get).unwrap();
+    run_until(&mut dom, |dom| {
+        dom.in_runtime(|| events.message().peek().clone()) == Some(Tick { count: 1 })
+    })
+    .await;
+
+    let mut url = URL.with(Cell::get).unwrap();
+    dom.in_runtime(|| url.set(second));
+    run_until(&mut dom, |dom| {
+        dom.in_runtime(|| events.message().peek().clone()) == Some(Tick { count: 2 })
+    })
+    .await;
+    dom.in_runtime(|| assert_eq!(events.message().peek().clone(), Some(Tick { count: 2 })));
+}
+
+#[tokio::test]
+async fn server_sent_events_are_deserialized() {
+    thread_local! {
+        static EVENTS: Cell<Option<EventSource<Tick>>> = const { Cell::new(None) };
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/events", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = [0; 1024];
+        _ = stream.read(&mut request).await.unwrap();
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n\r\n")
+            .await
+            .unwrap();
+        // Comments, named events and events split across writes
+        let body = [
+            ": keep alive\n\n",
+            "data: {\"count\": 1}\n\n",
+            "event: other\ndata: {\"count\": 100}\n\n",
+            "data: {\"cou",
+            "nt\": 2}\n\n",
+        ];
+        for chunk in body {
+            stream.write_all(chunk.as_bytes()).await.unwrap();
+            stream.flush().await.unwrap();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    });
+
+    #[derive(Props, Clone, PartialEq)]
+    struct AppProps {
+        url: String,
+    }
+
+    fn App(props: AppProps) -> Element {
+        let events = use_sse_with_reconnect::<Tick>(props.url, Reconnect::never());
+        EVENTS.with(|cell| cell.set(Some(events)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new_with_props(App, AppProps { url });
+    dom.rebuild_in_place();
+
+    let events = EVENTS.with(Cell::get).unwrap();
+    _ = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            if dom.in_runtime(|| events.state()) == ConnectionState::Closed {
+                break;
+            }
+        }
+    })
+    .await;
+
+    dom.in_runtime(|| {
+        assert_eq!(events.state(), ConnectionState::Closed);
+        assert_eq!(*events.message().read(), Some(Tick { count: 2 }));
+        assert_eq!(events.error(), None);
+    });
+}
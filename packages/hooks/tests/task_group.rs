@@ -0,0 +1,86 @@
+#![allow(non_snake_case)]
+use std::cell::Cell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::CapturedError;
+
+#[tokio::test]
+async fn join_waits_for_every_task() {
+    thread_local! {
+        static JOINED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    fn app() -> Element {
+        let group = use_task_group();
+        use_hook(|| {
+            for delay in [10, 20] {
+                group.spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    Ok(())
+                });
+            }
+            spawn(async move {
+                group.join().await.unwrap();
+                assert!(group.is_empty());
+                JOINED.with(|joined| joined.set(true));
+            });
+        });
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    _ = tokio::time::timeout(Duration::from_millis(200), dom.wait_for_work()).await;
+
+    assert!(JOINED.with(Cell::get));
+}
+
+#[tokio::test]
+async fn first_error_cancels_the_group() {
+    thread_local! {
+        static CANCELED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    struct CancelGuard;
+
+    impl Drop for CancelGuard {
+        fn drop(&mut self) {
+            CANCELED.with(|canceled| canceled.set(true));
+        }
+    }
+
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |_: ErrorContext| rsx! { "failed" },
+                Child {}
+            }
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        let group = use_task_group();
+        use_hook(|| {
+            group.spawn(async {
+                let _guard = CancelGuard;
+                std::future::pending::<()>().await;
+                Ok(())
+            });
+            group.spawn(async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                Err(CapturedError::from_display("the fetch failed"))
+            });
+        });
+        rsx! { "running" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    _ = tokio::time::timeout(Duration::from_millis(200), dom.wait_for_work()).await;
+
+    assert!(CANCELED.with(Cell::get));
+    let mutations = dom.render_immediate_to_vec();
+    assert!(format!("{mutations:?}").contains("failed"));
+}
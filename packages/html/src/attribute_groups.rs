@@ -359,6 +359,8 @@ mod_methods! {
     /// HTML from code is risky because it’s easy to inadvertently expose your users to a cross-site scripting (XSS)
     /// attack. So, you can set HTML directly from Dioxus, but you have to type out dangerous_inner_html to remind
     /// yourself that it’s dangerous
+    ///
+    /// HTML from users should go through a [`Sanitizer`](crate::Sanitizer) before it is passed to dangerous_inner_html.
     dangerous_inner_html;
 
     // This macro creates an explicit method call for each of the style attributes.
@@ -1740,6 +1742,23 @@ mod_methods! {
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-setsize>
     aria_setsize: "aria-setsize";
+
+    // ARIA 1.3 Attributes
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-braillelabel>
+    aria_braillelabel: "aria-braillelabel";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-brailleroledescription>
+    aria_brailleroledescription: "aria-brailleroledescription";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colindextext>
+    aria_colindextext: "aria-colindextext";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-description>
+    aria_description: "aria-description";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowindextext>
+    aria_rowindextext: "aria-rowindextext";
 }
 
 mod_methods! {
@@ -1763,6 +1782,8 @@ mod_methods! {
     /// HTML from code is risky because it’s easy to inadvertently expose your users to a cross-site scripting (XSS)
     /// attack. So, you can set HTML directly from Dioxus, but you have to type out dangerous_inner_html to remind
     /// yourself that it’s dangerous
+    ///
+    /// HTML from users should go through a [`Sanitizer`](crate::Sanitizer) before it is passed to dangerous_inner_html.
     dangerous_inner_html;
 
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/accent-height>
@@ -2488,9 +2509,43 @@ mod_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x2>
     x2;
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:actuate>
+    xlink_actuate: "xlink:actuate" in "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:arcrole>
+    xlink_arcrole: "xlink:arcrole" in "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:href>
+    ///
+    /// Deprecated in SVG 2 in favor of `href`, but still needed by older renderers and
+    /// SVG files that are pasted into rsx.
+    xlink_href: "xlink:href" in "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:role>
+    xlink_role: "xlink:role" in "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:show>
+    xlink_show: "xlink:show" in "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:title>
+    xlink_title: "xlink:title" in "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:type>
+    xlink_type: "xlink:type" in "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:lang>
+    xml_lang: "xml:lang" in "http://www.w3.org/XML/1998/namespace";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:space>
+    xml_space: "xml:space" in "http://www.w3.org/XML/1998/namespace";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xmlns>
     xmlns;
 
+    /// Declares the `xlink` prefix used by the `xlink:*` attributes. Browsers know the prefix without it, but
+    /// standalone SVG files need it.
+    xmlns_xlink: "xmlns:xlink" in "http://www.w3.org/2000/xmlns/";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xChannelSelector>
     x_channel_selector: "xChannelSelector";
 
@@ -2512,4 +2567,164 @@ mod_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/zoomAndPan>
     zoom_and_pan: "zoomAndPan";
 
+    // ARIA attributes
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-activedescendant>
+    aria_activedescendant: "aria-activedescendant";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-atomic>
+    aria_atomic: "aria-atomic";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-autocomplete>
+    aria_autocomplete: "aria-autocomplete";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-braillelabel>
+    aria_braillelabel: "aria-braillelabel";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-brailleroledescription>
+    aria_brailleroledescription: "aria-brailleroledescription";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-busy>
+    aria_busy: "aria-busy";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-checked>
+    aria_checked: "aria-checked";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colcount>
+    aria_colcount: "aria-colcount";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colindex>
+    aria_colindex: "aria-colindex";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colindextext>
+    aria_colindextext: "aria-colindextext";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colspan>
+    aria_colspan: "aria-colspan";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-controls>
+    aria_controls: "aria-controls";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-current>
+    aria_current: "aria-current";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-describedby>
+    aria_describedby: "aria-describedby";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-description>
+    aria_description: "aria-description";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-details>
+    aria_details: "aria-details";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-disabled>
+    aria_disabled: "aria-disabled";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-dropeffect>
+    aria_dropeffect: "aria-dropeffect";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-errormessage>
+    aria_errormessage: "aria-errormessage";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-expanded>
+    aria_expanded: "aria-expanded";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-flowto>
+    aria_flowto: "aria-flowto";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-grabbed>
+    aria_grabbed: "aria-grabbed";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-haspopup>
+    aria_haspopup: "aria-haspopup";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-hidden>
+    aria_hidden: "aria-hidden";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-invalid>
+    aria_invalid: "aria-invalid";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-keyshortcuts>
+    aria_keyshortcuts: "aria-keyshortcuts";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-label>
+    aria_label: "aria-label";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-labelledby>
+    aria_labelledby: "aria-labelledby";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-level>
+    aria_level: "aria-level";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-live>
+    aria_live: "aria-live";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-modal>
+    aria_modal: "aria-modal";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-multiline>
+    aria_multiline: "aria-multiline";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-multiselectable>
+    aria_multiselectable: "aria-multiselectable";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-orientation>
+    aria_orientation: "aria-orientation";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-owns>
+    aria_owns: "aria-owns";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-placeholder>
+    aria_placeholder: "aria-placeholder";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-posinset>
+    aria_posinset: "aria-posinset";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-pressed>
+    aria_pressed: "aria-pressed";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-readonly>
+    aria_readonly: "aria-readonly";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-relevant>
+    aria_relevant: "aria-relevant";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-required>
+    aria_required: "aria-required";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-roledescription>
+    aria_roledescription: "aria-roledescription";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowcount>
+    aria_rowcount: "aria-rowcount";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowindex>
+    aria_rowindex: "aria-rowindex";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowindextext>
+    aria_rowindextext: "aria-rowindextext";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowspan>
+    aria_rowspan: "aria-rowspan";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-selected>
+    aria_selected: "aria-selected";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-setsize>
+    aria_setsize: "aria-setsize";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-sort>
+    aria_sort: "aria-sort";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuemax>
+    aria_valuemax: "aria-valuemax";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuemin>
+    aria_valuemin: "aria-valuemin";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuenow>
+    aria_valuenow: "aria-valuenow";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuetext>
+    aria_valuetext: "aria-valuetext";
 }
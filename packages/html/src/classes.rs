@@ -0,0 +1,205 @@
+//! Structured values for the `class` attribute.
+
+use std::fmt::Display;
+
+use dioxus_core::{prelude::IntoAttributeValue, AttributeValue};
+
+/// A list of css classes that can be passed to the `class` attribute. Each class is only included once, and the list
+/// is joined with spaces when it is rendered.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn Button(primary: bool, disabled: bool) -> Element {
+///     let classes = Classes::new()
+///         .add("btn")
+///         .add_if("btn-primary", primary)
+///         .add(disabled.then_some("disabled"));
+///
+///     rsx! {
+///         button { class: classes, "Click me" }
+///     }
+/// }
+/// ```
+///
+/// Lists, arrays and `(class, condition)` pairs can be passed to `class` directly:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn Tab(active: bool) -> Element {
+///     rsx! {
+///         div { class: [("tab", true), ("active", active)], "Home" }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Classes(Vec<String>);
+
+impl Classes {
+    /// Create an empty list of classes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add classes to the list. Strings are split on whitespace, so `"btn btn-primary"` adds two classes.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, classes: impl IntoClasses) -> Self {
+        self.push(classes);
+        self
+    }
+
+    /// Add classes to the list if `condition` is true
+    pub fn add_if(self, classes: impl IntoClasses, condition: bool) -> Self {
+        match condition {
+            true => self.add(classes),
+            false => self,
+        }
+    }
+
+    /// Add classes to the list in place
+    pub fn push(&mut self, classes: impl IntoClasses) {
+        classes.push_classes(self);
+    }
+
+    /// Check if the list contains a class
+    pub fn contains(&self, class: &str) -> bool {
+        self.0.iter().any(|existing| existing == class)
+    }
+
+    /// Iterate over the classes in the list in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    /// Check if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn push_class(&mut self, class: &str) {
+        for class in class.split_whitespace() {
+            if !self.contains(class) {
+                self.0.push(class.to_string());
+            }
+        }
+    }
+}
+
+impl Display for Classes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, class) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(class)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: IntoClasses> FromIterator<C> for Classes {
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        let mut classes = Self::new();
+        classes.extend(iter);
+        classes
+    }
+}
+
+impl<C: IntoClasses> Extend<C> for Classes {
+    fn extend<I: IntoIterator<Item = C>>(&mut self, iter: I) {
+        for class in iter {
+            self.push(class);
+        }
+    }
+}
+
+// An empty list removes the attribute instead of rendering `class=""`
+impl IntoAttributeValue for Classes {
+    fn into_value(self) -> AttributeValue {
+        match self.is_empty() {
+            true => AttributeValue::None,
+            false => AttributeValue::Text(self.to_string()),
+        }
+    }
+}
+
+/// Something that can be added to a list of [`Classes`]
+pub trait IntoClasses {
+    /// Add the classes to the list
+    fn push_classes(self, classes: &mut Classes);
+}
+
+impl IntoClasses for &str {
+    fn push_classes(self, classes: &mut Classes) {
+        classes.push_class(self);
+    }
+}
+
+impl IntoClasses for String {
+    fn push_classes(self, classes: &mut Classes) {
+        classes.push_class(&self);
+    }
+}
+
+impl IntoClasses for &String {
+    fn push_classes(self, classes: &mut Classes) {
+        classes.push_class(self);
+    }
+}
+
+impl IntoClasses for Classes {
+    fn push_classes(self, classes: &mut Classes) {
+        classes.extend(self.0);
+    }
+}
+
+impl<C: IntoClasses> IntoClasses for Option<C> {
+    fn push_classes(self, classes: &mut Classes) {
+        if let Some(class) = self {
+            class.push_classes(classes);
+        }
+    }
+}
+
+impl<C: IntoClasses> IntoClasses for (C, bool) {
+    fn push_classes(self, classes: &mut Classes) {
+        let (class, condition) = self;
+        if condition {
+            class.push_classes(classes);
+        }
+    }
+}
+
+impl<C: IntoClasses> IntoClasses for Vec<C> {
+    fn push_classes(self, classes: &mut Classes) {
+        classes.extend(self);
+    }
+}
+
+impl<C: IntoClasses, const N: usize> IntoClasses for [C; N] {
+    fn push_classes(self, classes: &mut Classes) {
+        classes.extend(self);
+    }
+}
+
+#[doc(hidden)]
+pub struct ClassListMarker;
+
+impl<C: IntoClasses> IntoAttributeValue<ClassListMarker> for Vec<C> {
+    fn into_value(self) -> AttributeValue {
+        Classes::from_iter(self).into_value()
+    }
+}
+
+impl<C: IntoClasses, const N: usize> IntoAttributeValue<ClassListMarker> for [C; N] {
+    fn into_value(self) -> AttributeValue {
+        Classes::from_iter(self).into_value()
+    }
+}
+
+impl<C: IntoClasses> IntoAttributeValue<ClassListMarker> for (C, bool) {
+    fn into_value(self) -> AttributeValue {
+        Classes::new().add(self).into_value()
+    }
+}
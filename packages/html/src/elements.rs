@@ -402,14 +402,14 @@ macro_rules! impl_element_match {
     };
 
     (
-        $el:ident $name:ident [$_:literal, $namespace:tt] {
+        $el:ident $name:ident [$tag:literal, $namespace:tt] {
             $(
                 $fil:ident: $vil:ident $extra:tt,
             )*
         }
     ) => {
         if $el == stringify!($name) {
-            return Some((stringify!($name), Some($namespace)));
+            return Some(($tag, Some($namespace)));
         }
     };
 }
@@ -1175,7 +1175,7 @@ builder_constructors! {
         scrolling: String DEFAULT,
         margin_height: String "marginHeight",
         frame_border: String "frameBorder",
-        // sandbox: SpacedSet<Sandbox>,
+        sandbox: String DEFAULT,
     };
 
     /// Build a
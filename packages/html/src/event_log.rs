@@ -0,0 +1,330 @@
+//! Record the events a user triggers so they can be attached to a bug report and replayed in a test.
+//!
+//! Recording is opt-in: provide an [`EventRecorder`] as a context in the root component. The desktop and liveview
+//! renderers record every event they receive while a recorder is provided.
+//!
+//! Sensitive fields can be masked with the [`PRIVATE_ATTRIBUTE`]. The text of events from an element with the attribute,
+//! or from inside of one, is always removed. Password inputs are masked automatically.
+
+use crate::{EventData, HtmlEvent, SerializedHtmlEventConverter};
+use dioxus_core::{AttributeValue, ElementId, Event, NoOpMutations, Runtime, VirtualDom};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, rc::Rc};
+use web_time::Instant;
+
+/// The attribute that marks an element and everything inside of it as private. The text of events from private elements
+/// is never recorded, even if the recorder [keeps text](EventRecorder::keep_text).
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # let mut card_number = use_signal(String::new);
+/// rsx! {
+///     form { "data-private": true,
+///         input { name: "card", oninput: move |event| card_number.set(event.value()) }
+///     }
+/// };
+/// ```
+///
+/// Setting the attribute to `false` unmarks the element.
+pub const PRIVATE_ATTRIBUTE: &str = "data-private";
+
+/// A log of the events and navigations recorded by an [`EventRecorder`].
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct EventLog {
+    /// The recorded entries in the order they happened
+    pub entries: Vec<LoggedEvent>,
+}
+
+/// A single entry in an [`EventLog`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct LoggedEvent {
+    /// The number of milliseconds between the start of the recording and the entry
+    pub time_ms: u64,
+    /// What happened
+    pub kind: LoggedEventKind,
+}
+
+/// The kind of an entry in an [`EventLog`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum LoggedEventKind {
+    /// An event from the renderer
+    Event(HtmlEvent),
+    /// The app navigated to a new route
+    Navigation {
+        /// The route that was navigated to
+        route: String,
+    },
+}
+
+impl EventLog {
+    /// Parse a log that was exported with [`EventRecorder::export`]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize the log to json
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Replay the log against a virtual dom.
+    ///
+    /// The virtual dom should already be rebuilt and render the same app the log was recorded from, in the same
+    /// initial state, so the element ids in the log point to the same elements. Every event is sent to the virtual dom
+    /// and followed by a render, but timestamps are ignored and async tasks spawned by the event handlers are not
+    /// awaited.
+    ///
+    /// Navigation entries are passed to `navigate`, which should move the app to the route. With the router, that
+    /// usually means calling `router().replace(route)` inside of the runtime of the virtual dom.
+    pub fn replay(self, dom: &mut VirtualDom, mut navigate: impl FnMut(&mut VirtualDom, &str)) {
+        crate::set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+        for entry in self.entries {
+            match entry.kind {
+                LoggedEventKind::Event(HtmlEvent {
+                    element,
+                    name,
+                    bubbles,
+                    data,
+                }) => {
+                    let event = Event::new(data.into_any(), bubbles);
+                    dom.runtime().handle_event(&name, event, element);
+                }
+                LoggedEventKind::Navigation { route } => navigate(dom, &route),
+            }
+            dom.render_immediate(&mut NoOpMutations);
+        }
+    }
+}
+
+/// An opt-in recorder for the events the user triggers and the routes the app navigates to.
+///
+/// By default, the text the user types is removed from form, keyboard, and composition events before they are
+/// recorded. Call [`EventRecorder::keep_text`] to record the text as well. Text from elements marked with the
+/// [`PRIVATE_ATTRIBUTE`] and from password inputs is removed either way.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::html::EventRecorder;
+///
+/// fn App() -> Element {
+///     let recorder = use_context_provider(EventRecorder::new);
+///     rsx! {
+///         button {
+///             onclick: move |_| println!("{}", recorder.export()),
+///             "Export a trace for a bug report"
+///         }
+///     }
+/// }
+/// ```
+///
+/// The renderer can't see navigations, so record them from the `on_update` callback of the router config:
+///
+/// ```rust, ignore
+/// RouterConfig::default().on_update(|state| {
+///     consume_context::<EventRecorder>().record_navigation(state.current::<Route>());
+///     None
+/// })
+/// ```
+#[derive(Clone)]
+pub struct EventRecorder {
+    log: Rc<RefCell<EventLog>>,
+    started: Instant,
+    scrub_text: bool,
+}
+
+impl Default for EventRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventRecorder {
+    /// Start a new recording that removes the text the user types
+    pub fn new() -> Self {
+        Self {
+            log: Default::default(),
+            started: Instant::now(),
+            scrub_text: true,
+        }
+    }
+
+    /// Keep the text the user types in the recorded events, except for events from private elements
+    pub fn keep_text(self) -> Self {
+        Self {
+            scrub_text: false,
+            ..self
+        }
+    }
+
+    /// Record an event from the renderer. Mounted events are skipped because the renderer creates them on its own
+    /// when the app is replayed.
+    ///
+    /// Private elements can only be detected when this is called inside the runtime of the virtual dom that rendered
+    /// the element, like the desktop and liveview renderers do.
+    pub fn record_event(&self, event: &HtmlEvent) {
+        if let EventData::Mounted = event.data {
+            return;
+        }
+
+        // Round trip the event through json to copy it since the event data isn't Clone
+        let Ok(mut event) =
+            serde_json::to_value(event).and_then(serde_json::from_value::<HtmlEvent>)
+        else {
+            tracing::warn!("Failed to record the {} event", event.name);
+            return;
+        };
+        if self.scrub_text || is_private(event.element) {
+            match &mut event.data {
+                EventData::Form(data) => data.scrub_text(),
+                EventData::Keyboard(data) => data.scrub_text(),
+                EventData::Composition(data) => data.scrub_text(),
+                _ => {}
+            }
+        }
+        self.push(LoggedEventKind::Event(event));
+    }
+
+    /// Record a navigation to a new route
+    pub fn record_navigation(&self, route: impl ToString) {
+        self.push(LoggedEventKind::Navigation {
+            route: route.to_string(),
+        });
+    }
+
+    /// Export the recording as json that can be loaded with [`EventLog::from_json`]
+    pub fn export(&self) -> String {
+        self.log.borrow().to_json()
+    }
+
+    /// Take the entries that were recorded so far and start a new recording
+    pub fn take(&self) -> EventLog {
+        std::mem::take(&mut *self.log.borrow_mut())
+    }
+
+    fn push(&self, kind: LoggedEventKind) {
+        let time_ms = self.started.elapsed().as_millis() as u64;
+        self.log
+            .borrow_mut()
+            .entries
+            .push(LoggedEvent { time_ms, kind });
+    }
+}
+
+/// Check if an element is marked as private or is a password input
+fn is_private(element: ElementId) -> bool {
+    let Ok(runtime) = Runtime::current() else {
+        return false;
+    };
+    let private = match runtime.find_attribute(element, PRIVATE_ATTRIBUTE) {
+        Some(AttributeValue::Bool(private)) => private,
+        Some(AttributeValue::Text(private)) => private != "false",
+        Some(_) => true,
+        None => false,
+    };
+    private
+        || matches!(
+            runtime.find_attribute(element, "type"),
+            Some(AttributeValue::Text(kind)) if kind == "password"
+        )
+}
+
+#[test]
+fn scrubs_typed_text() {
+    use crate::{FormValue, SerializedFormData};
+    use dioxus_core::ElementId;
+    use std::collections::HashMap;
+
+    let recorder = EventRecorder::new();
+    recorder.record_event(&HtmlEvent {
+        element: ElementId(1),
+        name: "input".to_string(),
+        bubbles: true,
+        data: EventData::Form(SerializedFormData::new(
+            "secret".to_string(),
+            HashMap::from([(
+                "password".to_string(),
+                FormValue(vec!["secret".to_string()]),
+            )]),
+        )),
+    });
+    recorder.record_navigation("/settings");
+
+    let log = EventLog::from_json(&recorder.export()).unwrap();
+    assert_eq!(log.entries.len(), 2);
+    let LoggedEventKind::Event(event) = &log.entries[0].kind else {
+        panic!("expected an event");
+    };
+    assert_eq!(
+        event.data,
+        EventData::Form(SerializedFormData::new(
+            String::new(),
+            HashMap::from([("password".to_string(), FormValue(vec![String::new()]))]),
+        ))
+    );
+    assert_eq!(
+        log.entries[1].kind,
+        LoggedEventKind::Navigation {
+            route: "/settings".to_string()
+        }
+    );
+}
+
+#[test]
+fn masks_private_elements() {
+    use dioxus::prelude::*;
+    use dioxus_core::Mutation;
+    use std::collections::HashMap;
+
+    fn app() -> Element {
+        rsx! {
+            div { "data-private": true,
+                input { oninput: |_| {} }
+            }
+            input { onchange: |_| {} }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let edits = dom.rebuild_to_vec().edits;
+    let listener = |event: &str| {
+        edits
+            .iter()
+            .find_map(|edit| match edit {
+                Mutation::NewEventListener { name, id } if name == event => Some(*id),
+                _ => None,
+            })
+            .unwrap()
+    };
+    let typed = |element: ElementId, name: &str| crate::HtmlEvent {
+        element,
+        name: name.to_string(),
+        bubbles: true,
+        data: crate::EventData::Form(crate::SerializedFormData::new(
+            "secret".to_string(),
+            HashMap::new(),
+        )),
+    };
+
+    let recorder = crate::EventRecorder::new().keep_text();
+    dom.runtime().on_scope(ScopeId::APP, || {
+        recorder.record_event(&typed(listener("input"), "input"));
+        recorder.record_event(&typed(listener("change"), "change"));
+    });
+
+    let values: Vec<_> = recorder
+        .take()
+        .entries
+        .into_iter()
+        .map(|entry| match entry.kind {
+            LoggedEventKind::Event(crate::HtmlEvent {
+                data: crate::EventData::Form(data),
+                ..
+            }) => crate::HasFormData::value(&data),
+            _ => panic!("expected a form event"),
+        })
+        .collect();
+    assert_eq!(values, ["", "secret"]);
+}
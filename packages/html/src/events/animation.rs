@@ -29,6 +29,13 @@ impl AnimationData {
         self.inner.elapsed_time()
     }
 
+    /// Check if the animation ran on the element the event was dispatched to, rather than on a static child of that
+    /// element. Use [`SelfTargetEvent::is_self_target`](crate::SelfTargetEvent::is_self_target) on the event to also
+    /// exclude animations that bubbled up from child elements with their own listeners.
+    pub fn dispatched_on_target(&self) -> bool {
+        self.inner.dispatched_on_target()
+    }
+
     /// Downcast this event to a concrete event type
     #[inline(always)]
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
@@ -48,6 +55,7 @@ impl std::fmt::Debug for AnimationData {
             .field("animation_name", &self.animation_name())
             .field("pseudo_element", &self.pseudo_element())
             .field("elapsed_time", &self.elapsed_time())
+            .field("dispatched_on_target", &self.dispatched_on_target())
             .finish()
     }
 }
@@ -57,6 +65,7 @@ impl PartialEq for AnimationData {
         self.animation_name() == other.animation_name()
             && self.pseudo_element() == other.pseudo_element()
             && self.elapsed_time() == other.elapsed_time()
+            && self.dispatched_on_target() == other.dispatched_on_target()
     }
 }
 
@@ -67,6 +76,8 @@ pub struct SerializedAnimationData {
     animation_name: String,
     pseudo_element: String,
     elapsed_time: f32,
+    #[serde(default = "crate::events::default_true")]
+    dispatched_on_target: bool,
 }
 
 #[cfg(feature = "serialize")]
@@ -76,6 +87,7 @@ impl From<&AnimationData> for SerializedAnimationData {
             animation_name: data.animation_name(),
             pseudo_element: data.pseudo_element(),
             elapsed_time: data.elapsed_time(),
+            dispatched_on_target: data.dispatched_on_target(),
         }
     }
 }
@@ -94,6 +106,10 @@ impl HasAnimationData for SerializedAnimationData {
         self.elapsed_time
     }
 
+    fn dispatched_on_target(&self) -> bool {
+        self.dispatched_on_target
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -127,10 +143,21 @@ pub trait HasAnimationData: std::any::Any {
     /// The amount of time the animation has been running
     fn elapsed_time(&self) -> f32;
 
+    /// If the animation ran on the element the event was dispatched to, rather than on a static child of that element
+    fn dispatched_on_target(&self) -> bool {
+        true
+    }
+
     /// return self as Any
     fn as_any(&self) -> &dyn std::any::Any;
 }
 
+impl crate::SelfTargetEvent for AnimationEvent {
+    fn is_self_target(&self) -> bool {
+        self.is_at_target() && self.data.dispatched_on_target()
+    }
+}
+
 impl_event! [
     AnimationData;
 
@@ -142,4 +169,7 @@ impl_event! [
 
     /// onanimationiteration
     onanimationiteration
+
+    /// onanimationcancel
+    onanimationcancel
 ];
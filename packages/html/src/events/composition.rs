@@ -58,6 +58,14 @@ impl From<&CompositionData> for SerializedCompositionData {
     }
 }
 
+#[cfg(feature = "serialize")]
+impl SerializedCompositionData {
+    /// Remove the composed text
+    pub(crate) fn scrub_text(&mut self) {
+        self.data.clear();
+    }
+}
+
 #[cfg(feature = "serialize")]
 impl HasCompositionData for SerializedCompositionData {
     fn data(&self) -> String {
@@ -232,6 +232,15 @@ impl SerializedFormData {
             files: None,
         }
     }
+
+    /// Remove the text the user entered. The names of the fields are kept
+    pub(crate) fn scrub_text(&mut self) {
+        self.value.clear();
+        for value in self.values.values_mut() {
+            value.0.iter_mut().for_each(String::clear);
+        }
+        self.files = None;
+    }
 }
 
 #[cfg(feature = "serialize")]
@@ -144,6 +144,17 @@ impl SerializedKeyboardData {
                 .expect("could not convert charcode to usize"),
         }
     }
+
+    /// Remove the key if it would type a character. Named keys like `Enter` and the modifiers are kept
+    pub(crate) fn scrub_text(&mut self) {
+        if self.key.chars().count() == 1 {
+            self.key = Key::Unidentified.to_string();
+            self.code = Code::Unidentified;
+            self.key_code = KeyCode::Unknown;
+            self.char_code = 0;
+            self.which = 0;
+        }
+    }
 }
 
 #[cfg(feature = "serialize")]
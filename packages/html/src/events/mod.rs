@@ -278,6 +278,12 @@ impl From<&PlatformEventData> for WheelData {
     }
 }
 
+/// Serialized events from older clients may not include fields that default to true
+#[cfg(feature = "serialize")]
+pub(crate) fn default_true() -> bool {
+    true
+}
+
 mod animation;
 mod clipboard;
 mod composition;
@@ -290,6 +296,7 @@ mod media;
 mod mounted;
 mod mouse;
 mod pointer;
+mod rate_limit;
 mod resize;
 mod scroll;
 mod selection;
@@ -311,6 +318,7 @@ pub use media::*;
 pub use mounted::*;
 pub use mouse::*;
 pub use pointer::*;
+pub use rate_limit::*;
 pub use resize::*;
 pub use scroll::*;
 pub use selection::*;
@@ -133,7 +133,11 @@ impl MountedData {
     }
 }
 
+use dioxus_core::prelude::{use_hook, EventHandler, SuperFrom};
 use dioxus_core::Event;
+use dioxus_hooks::use_signal;
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use std::rc::Rc;
 
 use crate::geometry::{PixelsRect, PixelsSize, PixelsVector2D};
 
@@ -203,6 +207,133 @@ impl_event! [
     onmounted
 ];
 
+/// A handle to an element that is filled in once the element is mounted. Create one with [`use_node_ref`] and attach it
+/// to an element by passing it to `onmounted`.
+///
+/// Reading the element with [`NodeRef::get`] subscribes to the node ref, so the component reruns once the element is
+/// mounted or unmounted. The node ref is cleared when the element it is attached to is removed.
+#[derive(Clone, Copy, PartialEq)]
+pub struct NodeRef {
+    element: Signal<Option<Rc<MountedData>>>,
+    /// How many listeners were created for the node ref. Only the latest listener clears the element when it is dropped
+    listeners: CopyValue<usize>,
+}
+
+impl NodeRef {
+    /// Get the element if it has been mounted and subscribe to changes
+    pub fn get(&self) -> Option<Rc<MountedData>> {
+        self.element.cloned()
+    }
+
+    /// Get the element if it has been mounted without subscribing to changes
+    pub fn peek(&self) -> Option<Rc<MountedData>> {
+        self.element.peek().clone()
+    }
+
+    /// Check if the element has been mounted
+    pub fn is_mounted(&self) -> bool {
+        self.element.read().is_some()
+    }
+
+    /// Get the platform specific element, like a `web_sys::Element` on web. See [`MountedData::downcast`]
+    pub fn downcast<T: Clone + 'static>(&self) -> Option<T> {
+        self.element
+            .read()
+            .as_ref()
+            .and_then(|element| element.downcast::<T>().cloned())
+    }
+
+    /// Move focus to the element. Fails with [`MountedError::NotMounted`] if the element has not been mounted yet
+    pub async fn focus(&self) -> MountedResult<()> {
+        self.set_focus(true).await
+    }
+
+    /// Remove focus from the element. Fails with [`MountedError::NotMounted`] if the element has not been mounted yet
+    pub async fn blur(&self) -> MountedResult<()> {
+        self.set_focus(false).await
+    }
+
+    async fn set_focus(&self, focus: bool) -> MountedResult<()> {
+        let element = self.peek().ok_or(MountedError::NotMounted)?;
+        element.set_focus(focus).await
+    }
+}
+
+#[doc(hidden)]
+pub struct NodeRefMarker;
+
+impl SuperFrom<NodeRef, NodeRefMarker> for EventHandler<MountedEvent> {
+    fn super_from(node: NodeRef) -> Self {
+        let mut listeners = node.listeners;
+        *listeners.write() += 1;
+        let guard = ClearOnDrop {
+            node,
+            listener: *listeners.peek(),
+        };
+        EventHandler::new(move |event: MountedEvent| {
+            // Use the whole guard so the closure owns it instead of only the signal it writes to
+            let guard = &guard;
+            let mut element = guard.node.element;
+            element.set(Some(event.data()))
+        })
+    }
+}
+
+/// Moved into the mounted listener of a [`NodeRef`]. The listener is dropped with the element, or when a rerender
+/// replaces it with a new listener.
+struct ClearOnDrop {
+    node: NodeRef,
+    listener: usize,
+}
+
+impl Drop for ClearOnDrop {
+    fn drop(&mut self) {
+        // A rerender creates the new listener before the old one is dropped, so the element is still mounted
+        let latest = self.node.listeners.try_peek().map(|listeners| *listeners);
+        if latest.ok() != Some(self.listener) {
+            return;
+        }
+        // The node ref may already be dropped with its component
+        let mounted = self
+            .node
+            .element
+            .try_peek()
+            .is_ok_and(|element| element.is_some());
+        if mounted {
+            if let Ok(mut element) = self.node.element.try_write() {
+                *element = None;
+            }
+        }
+    }
+}
+
+/// Create a [`NodeRef`] that gives a component access to one of the elements it renders after the element is mounted.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Search() -> Element {
+///     let search = use_node_ref();
+///
+///     rsx! {
+///         input { onmounted: search }
+///         button {
+///             onclick: move |_| async move {
+///                 _ = search.focus().await;
+///             },
+///             "Focus the search box"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_node_ref() -> NodeRef {
+    NodeRef {
+        element: use_signal(|| None),
+        listeners: use_hook(|| CopyValue::new(0)),
+    }
+}
+
 /// The MountedResult type for the MountedData
 pub type MountedResult<T> = Result<T, MountedError>;
 
@@ -212,6 +343,8 @@ pub type MountedResult<T> = Result<T, MountedError>;
 pub enum MountedError {
     /// The renderer does not support the requested operation
     NotSupported,
+    /// The element has not been mounted yet
+    NotMounted,
     /// The element was not found
     OperationFailed(Box<dyn std::error::Error>),
 }
@@ -222,6 +355,9 @@ impl Display for MountedError {
             MountedError::NotSupported => {
                 write!(f, "The renderer does not support the requested operation")
             }
+            MountedError::NotMounted => {
+                write!(f, "The element has not been mounted yet")
+            }
             MountedError::OperationFailed(e) => {
                 write!(f, "The operation failed: {}", e)
             }
@@ -230,3 +366,60 @@ impl Display for MountedError {
 }
 
 impl std::error::Error for MountedError {}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn node_refs_are_cleared_when_the_element_is_removed() {
+    use dioxus::prelude::*;
+    use dioxus_core::{Mutation, NoOpMutations};
+    use std::{any::Any, cell::Cell};
+
+    thread_local! {
+        static NODE: Cell<Option<crate::NodeRef>> = const { Cell::new(None) };
+        static SHOW: Cell<bool> = const { Cell::new(true) };
+    }
+
+    fn app() -> Element {
+        // The dioxus prelude comes from another build of this crate
+        let node = crate::use_node_ref();
+        NODE.set(Some(node));
+        // Like an onmounted prop in rsx, the listener is only created when the element is rendered
+        let attributes = SHOW.get().then(|| vec![crate::onmounted(node)]);
+        rsx! {
+            if let Some(attributes) = attributes {
+                div { ..attributes }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+    let element = mutations
+        .edits
+        .iter()
+        .find_map(|mutation| match mutation {
+            Mutation::NewEventListener { name, id } if name == "mounted" => Some(*id),
+            _ => None,
+        })
+        .unwrap();
+    let node = NODE.get().unwrap();
+    assert!(!dom.in_runtime(|| node.is_mounted()));
+
+    crate::set_event_converter(Box::new(crate::SerializedHtmlEventConverter));
+    let event = Event::new(
+        Rc::new(crate::PlatformEventData::new(Box::new(()))) as Rc<dyn Any>,
+        false,
+    );
+    dom.runtime().handle_event("mounted", event, element);
+    assert!(dom.in_runtime(|| node.is_mounted()));
+
+    // Rerendering replaces the listener, but the element stays mounted
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+    assert!(dom.in_runtime(|| node.is_mounted()));
+
+    SHOW.set(false);
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+    assert!(!dom.in_runtime(|| node.is_mounted()));
+}
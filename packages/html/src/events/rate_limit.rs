@@ -0,0 +1,353 @@
+//! Event handler modifiers that limit how often a handler runs.
+
+use std::{
+    any::Any, cell::RefCell, collections::HashMap, marker::PhantomData, panic::Location, rc::Rc,
+    time::Duration,
+};
+
+use dioxus_core::prelude::{
+    after_render, current_scope_id, spawn, EventHandler, SuperFrom, SuperInto,
+};
+use dioxus_core::{vdom_is_rendering, Event};
+
+use crate::timer::sleep;
+use web_time::Instant;
+
+/// An event handler wrapped with [`throttle`] or [`debounce`]. Pass it to an event listener like any other handler.
+pub struct RateLimited<F> {
+    handler: F,
+    limit: RateLimit,
+    location: &'static Location<'static>,
+}
+
+#[derive(Clone, Copy)]
+enum RateLimit {
+    Throttle(Duration),
+    Debounce(Duration),
+}
+
+/// Run an event handler at most once per `interval`. The first event runs the handler immediately and any events
+/// that arrive before the interval has passed are dropped before they reach the handler.
+///
+/// Every element this call renders is throttled on its own, so each item in a loop has its own rate limit. The
+/// limits survive rerenders of the component: the nth listener this call renders keeps the limit of the nth listener
+/// it rendered last time.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     let mut count = use_signal(|| 0);
+///
+///     rsx! {
+///         button {
+///             onclick: throttle(Duration::from_millis(300), move |_: MouseEvent| count += 1),
+///             "Clicked {count} times"
+///         }
+///     }
+/// }
+/// ```
+#[track_caller]
+pub fn throttle<F>(interval: Duration, handler: F) -> RateLimited<F> {
+    RateLimited {
+        handler,
+        limit: RateLimit::Throttle(interval),
+        location: Location::caller(),
+    }
+}
+
+/// Run an event handler once events stop arriving for `delay`. The handler is called with the last event that
+/// arrived, so calling [`Event::prevent_default`] or [`Event::stop_propagation`] inside of it has no effect.
+///
+/// Every element this call renders is debounced on its own. When an element is removed, events that are still
+/// waiting for the delay are dropped.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn Search() -> Element {
+///     let mut query = use_signal(String::new);
+///
+///     rsx! {
+///         input {
+///             oninput: debounce(Duration::from_millis(250), move |event: FormEvent| query.set(event.value())),
+///         }
+///         "Searching for {query}"
+///     }
+/// }
+/// ```
+#[track_caller]
+pub fn debounce<F>(delay: Duration, handler: F) -> RateLimited<F> {
+    RateLimited {
+        handler,
+        limit: RateLimit::Debounce(delay),
+        location: Location::caller(),
+    }
+}
+
+#[doc(hidden)]
+pub struct RateLimitedMarker<M>(PhantomData<M>);
+
+impl<T: 'static, F, M> SuperFrom<RateLimited<F>, RateLimitedMarker<M>> for EventHandler<Event<T>>
+where
+    F: SuperInto<EventHandler<Event<T>>, M>,
+{
+    fn super_from(limited: RateLimited<F>) -> Self {
+        let state = Limiter::<T>::get(limited.location);
+        // Debounced events run after the component may have rerendered, so they always use the latest handler
+        state.borrow_mut().handler = Some(limited.handler.super_into());
+
+        match limited.limit {
+            RateLimit::Throttle(interval) => EventHandler::new(move |event: Event<T>| {
+                let handler = {
+                    let mut state = state.borrow_mut();
+                    let now = Instant::now();
+                    if state.last.is_some_and(|last| now - last < interval) {
+                        return;
+                    }
+                    state.last = Some(now);
+                    state.handler
+                };
+                if let Some(handler) = handler {
+                    handler.call(event);
+                }
+            }),
+            RateLimit::Debounce(delay) => EventHandler::new(move |event: Event<T>| {
+                {
+                    let mut state = state.borrow_mut();
+                    state.last = Some(Instant::now());
+                    state.pending = Some(event);
+                    if state.waiting {
+                        return;
+                    }
+                    state.waiting = true;
+                }
+
+                // Only one timer runs at a time. Events that arrive while it is waiting push the deadline back
+                let state = state.clone();
+                spawn(async move {
+                    loop {
+                        let remaining = {
+                            let state = state.borrow();
+                            let deadline = state.last.unwrap_or_else(Instant::now) + delay;
+                            deadline.saturating_duration_since(Instant::now())
+                        };
+                        if remaining.is_zero() {
+                            break;
+                        }
+                        sleep(remaining).await;
+                    }
+
+                    let (handler, event) = {
+                        let mut state = state.borrow_mut();
+                        state.waiting = false;
+                        (state.handler, state.pending.take())
+                    };
+                    if let (Some(handler), Some(event)) = (handler, event) {
+                        handler.call(event);
+                    }
+                });
+            }),
+        }
+    }
+}
+
+/// The state of one rate limited listener. It is stored on the scope that rendered the listener so it survives
+/// rerenders, and it is dropped with the scope or once the listener is no longer rendered.
+struct Limiter<T: 'static> {
+    handler: Option<EventHandler<Event<T>>>,
+    last: Option<Instant>,
+    pending: Option<Event<T>>,
+    waiting: bool,
+}
+
+/// Every rate limited listener in a scope
+#[derive(Clone, Default)]
+struct Limiters(Rc<RefCell<LimiterMap>>);
+
+#[derive(Default)]
+struct LimiterMap {
+    /// How many listeners each call site rendered so far in the current render
+    rendered: HashMap<&'static Location<'static>, usize>,
+    /// The limiters keyed by their call site and the order the call site rendered them in
+    limiters: HashMap<(&'static Location<'static>, usize), LimiterEntry>,
+}
+
+struct LimiterEntry {
+    limiter: Rc<dyn Any>,
+    /// If the current render rendered the listener
+    rendered: bool,
+    /// Drop the handler and any pending event so a timer that is still running doesn't call into a removed element
+    cancel: Box<dyn Fn()>,
+}
+
+impl Limiters {
+    /// Forget the listeners the last render didn't render and start counting again for the next render
+    fn finish_render(&self) {
+        let mut map = self.0.borrow_mut();
+        map.rendered.clear();
+        map.limiters.retain(|_, entry| {
+            let rendered = std::mem::take(&mut entry.rendered);
+            if !rendered {
+                (entry.cancel)();
+            }
+            rendered
+        });
+    }
+}
+
+impl<T: 'static> Limiter<T> {
+    fn new() -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            handler: None,
+            last: None,
+            pending: None,
+            waiting: false,
+        }))
+    }
+
+    fn get(location: &'static Location<'static>) -> Rc<RefCell<Self>> {
+        // Listeners that are created outside of a render can't be matched with the listener from the last render
+        if !vdom_is_rendering() {
+            return Self::new();
+        }
+        let Ok(scope) = current_scope_id() else {
+            return Self::new();
+        };
+        // The limiters are only looked up on the scope that is rendering. Children and siblings have their own
+        let limiters = scope.has_context::<Limiters>().unwrap_or_else(|| {
+            let limiters = scope.provide_context(Limiters::default());
+            after_render({
+                let limiters = limiters.clone();
+                move || limiters.finish_render()
+            });
+            limiters
+        });
+
+        let mut map = limiters.0.borrow_mut();
+        let index = map.rendered.entry(location).or_default();
+        let key = (location, *index);
+        *index += 1;
+
+        if let Some(entry) = map.limiters.get_mut(&key) {
+            if let Ok(limiter) = entry.limiter.clone().downcast::<RefCell<Self>>() {
+                entry.rendered = true;
+                return limiter;
+            }
+        }
+
+        let limiter = Self::new();
+        let cancel = {
+            let limiter = limiter.clone();
+            move || {
+                let mut limiter = limiter.borrow_mut();
+                limiter.handler = None;
+                limiter.pending = None;
+            }
+        };
+        map.limiters.insert(
+            key,
+            LimiterEntry {
+                limiter: limiter.clone(),
+                rendered: true,
+                cancel: Box::new(cancel),
+            },
+        );
+        limiter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type MakeListener = fn(usize) -> EventHandler<Event<usize>>;
+
+    thread_local! {
+        static ITEMS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+        static LISTENER: std::cell::Cell<Option<MakeListener>> = const { std::cell::Cell::new(None) };
+        static HANDLERS: RefCell<Vec<EventHandler<Event<usize>>>> = const { RefCell::new(Vec::new()) };
+        static CALLS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Render one listener per item and remember the handlers so the tests can call them
+    fn render_items(listener: MakeListener, items: usize) -> dioxus_core::VirtualDom {
+        use dioxus::prelude::*;
+
+        fn app() -> Element {
+            let listener = LISTENER.get().unwrap();
+            HANDLERS.set((0..ITEMS.get()).map(listener).collect());
+            rsx! {}
+        }
+
+        ITEMS.set(items);
+        LISTENER.set(Some(listener));
+        CALLS.take();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dom
+    }
+
+    fn rerender_items(dom: &mut dioxus_core::VirtualDom, items: usize) {
+        ITEMS.set(items);
+        dom.mark_dirty(dioxus_core::ScopeId::APP);
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    }
+
+    fn call_item(dom: &dioxus_core::VirtualDom, item: usize) {
+        let handler = HANDLERS.with_borrow(|handlers| handlers[item]);
+        dom.in_runtime(|| handler.call(Event::new(Rc::new(item), true)));
+    }
+
+    fn record_call(event: Event<usize>) {
+        CALLS.with_borrow_mut(|calls| calls.push(*event.data()));
+    }
+
+    #[test]
+    fn throttles_every_listener_on_its_own() {
+        let mut dom = render_items(
+            |_| throttle(Duration::from_secs(3600), record_call).super_into(),
+            2,
+        );
+
+        call_item(&dom, 0);
+        call_item(&dom, 0);
+        call_item(&dom, 1);
+        assert_eq!(CALLS.take(), [0, 1]);
+
+        // The listeners from the next render keep the limits of the listeners they replace
+        rerender_items(&mut dom, 2);
+        call_item(&dom, 0);
+        call_item(&dom, 1);
+        assert!(CALLS.take().is_empty());
+    }
+
+    #[tokio::test]
+    async fn drops_pending_events_of_removed_listeners() {
+        let mut dom = render_items(
+            |_| debounce(Duration::from_millis(10), record_call).super_into(),
+            2,
+        );
+
+        call_item(&dom, 0);
+        call_item(&dom, 1);
+        call_item(&dom, 1);
+
+        // The second item is removed before its delay passes
+        rerender_items(&mut dom, 1);
+
+        let settled = tokio::time::sleep(Duration::from_millis(100));
+        tokio::pin!(settled);
+        loop {
+            tokio::select! {
+                _ = dom.wait_for_work() => {}
+                _ = &mut settled => break,
+            }
+        }
+        assert_eq!(CALLS.take(), [0]);
+    }
+}
@@ -18,6 +18,7 @@ impl std::fmt::Debug for TransitionData {
             .field("property_name", &self.inner.property_name())
             .field("pseudo_element", &self.inner.pseudo_element())
             .field("elapsed_time", &self.inner.elapsed_time())
+            .field("dispatched_on_target", &self.inner.dispatched_on_target())
             .finish()
     }
 }
@@ -27,6 +28,7 @@ impl PartialEq for TransitionData {
         self.inner.property_name() == other.inner.property_name()
             && self.inner.pseudo_element() == other.inner.pseudo_element()
             && self.inner.elapsed_time() == other.inner.elapsed_time()
+            && self.inner.dispatched_on_target() == other.inner.dispatched_on_target()
     }
 }
 
@@ -38,6 +40,28 @@ impl TransitionData {
         }
     }
 
+    /// The name of the css property the transition is for
+    pub fn property_name(&self) -> String {
+        self.inner.property_name()
+    }
+
+    /// The name of the pseudo-element the transition runs on
+    pub fn pseudo_element(&self) -> String {
+        self.inner.pseudo_element()
+    }
+
+    /// The amount of time the transition has been running
+    pub fn elapsed_time(&self) -> f32 {
+        self.inner.elapsed_time()
+    }
+
+    /// Check if the transition ran on the element the event was dispatched to, rather than on a static child of that
+    /// element. Use [`SelfTargetEvent::is_self_target`] on the event to also exclude transitions that bubbled up from
+    /// child elements with their own listeners.
+    pub fn dispatched_on_target(&self) -> bool {
+        self.inner.dispatched_on_target()
+    }
+
     /// Downcast this event to a concrete event type
     #[inline(always)]
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
@@ -52,6 +76,8 @@ pub struct SerializedTransitionData {
     property_name: String,
     pseudo_element: String,
     elapsed_time: f32,
+    #[serde(default = "crate::events::default_true")]
+    dispatched_on_target: bool,
 }
 
 #[cfg(feature = "serialize")]
@@ -61,6 +87,7 @@ impl From<&TransitionData> for SerializedTransitionData {
             property_name: data.inner.property_name(),
             pseudo_element: data.inner.pseudo_element(),
             elapsed_time: data.inner.elapsed_time(),
+            dispatched_on_target: data.inner.dispatched_on_target(),
         }
     }
 }
@@ -79,6 +106,10 @@ impl HasTransitionData for SerializedTransitionData {
         self.elapsed_time
     }
 
+    fn dispatched_on_target(&self) -> bool {
+        self.dispatched_on_target
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -105,13 +136,69 @@ pub trait HasTransitionData: std::any::Any {
     fn property_name(&self) -> String;
     fn pseudo_element(&self) -> String;
     fn elapsed_time(&self) -> f32;
+    /// If the transition ran on the element the event was dispatched to, rather than on a static child of that element
+    fn dispatched_on_target(&self) -> bool {
+        true
+    }
     /// return self as Any
     fn as_any(&self) -> &dyn std::any::Any;
 }
 
+/// An animation or transition event that can tell if it started on the element the listener is attached to
+pub trait SelfTargetEvent {
+    /// Check if the event started on the element the listener is attached to, rather than bubbling up from a child.
+    /// Listeners on a parent element see the events of every child, so use this to sequence the transitions of a
+    /// single element.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// fn Drawer() -> Element {
+    ///     let mut open = use_signal(|| false);
+    ///     let mut settled = use_signal(|| true);
+    ///
+    ///     rsx! {
+    ///         div {
+    ///             class: if open() { "drawer open" } else { "drawer" },
+    ///             ontransitionend: move |evt| {
+    ///                 // Ignore the transitions of the buttons inside of the drawer
+    ///                 if evt.is_self_target() && evt.property_name() == "transform" {
+    ///                     settled.set(true);
+    ///                 }
+    ///             },
+    ///             button {
+    ///                 onclick: move |_| {
+    ///                     settled.set(false);
+    ///                     open.toggle();
+    ///                 },
+    ///                 "Toggle"
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn is_self_target(&self) -> bool;
+}
+
+impl SelfTargetEvent for TransitionEvent {
+    fn is_self_target(&self) -> bool {
+        self.is_at_target() && self.data.dispatched_on_target()
+    }
+}
+
 impl_event! {
     TransitionData;
 
+    /// transitionrun
+    ontransitionrun
+
+    /// transitionstart
+    ontransitionstart
+
     /// transitionend
     ontransitionend
+
+    /// transitioncancel
+    ontransitioncancel
 }
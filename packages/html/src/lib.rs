@@ -25,21 +25,35 @@ pub mod events;
 pub(crate) mod file_data;
 pub use file_data::*;
 mod attribute_groups;
+mod classes;
 pub mod geometry;
 pub mod input_data;
 pub mod point_interaction;
+mod pre_rendered;
 mod render_template;
+mod sanitize;
+mod style_value;
+mod timer;
 
+#[cfg(feature = "serialize")]
+mod event_log;
 #[cfg(feature = "serialize")]
 mod transit;
 
+#[cfg(feature = "serialize")]
+pub use event_log::*;
+
 #[cfg(feature = "serialize")]
 pub use transit::*;
 
 pub use attribute_groups::*;
+pub use classes::*;
 pub use elements::*;
 pub use events::*;
+pub use pre_rendered::*;
 pub use render_template::*;
+pub use sanitize::*;
+pub use style_value::*;
 
 pub mod extensions {
     pub use crate::attribute_groups::{GlobalAttributesExtension, SvgAttributesExtension};
@@ -48,8 +62,10 @@ pub mod extensions {
 
 pub mod prelude {
     pub use crate::attribute_groups::{GlobalAttributesExtension, SvgAttributesExtension};
+    pub use crate::classes::{Classes, IntoClasses};
     pub use crate::elements::extensions::*;
     pub use crate::events::*;
     pub use crate::point_interaction::*;
+    pub use crate::style_value::{Style, StyleProperty};
     pub use keyboard_types::{self, Code, Key, Location, Modifiers};
 }
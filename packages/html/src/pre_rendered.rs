@@ -0,0 +1,61 @@
+use dioxus_core::prelude::*;
+
+/// The tag of the element [`pre_rendered`] wraps its markup in. Server side rendering writes the markup without the
+/// element unless the page will be hydrated.
+pub const PRE_RENDERED_TAG: &str = "dioxus-pre-rendered";
+
+static PRE_RENDERED_ROOTS: &[TemplateNode] = &[TemplateNode::Element {
+    tag: PRE_RENDERED_TAG,
+    namespace: None,
+    attrs: &[
+        TemplateAttribute::Static {
+            name: "style",
+            value: "display:contents;",
+            namespace: None,
+        },
+        TemplateAttribute::Dynamic { id: 0 },
+    ],
+    children: &[],
+}];
+
+static PRE_RENDERED_TEMPLATE: Template = Template {
+    roots: PRE_RENDERED_ROOTS,
+    node_paths: &[],
+    attr_paths: &[&[0]],
+};
+
+/// Render trusted markup as it is, without escaping it.
+///
+/// This is meant for HTML your server produced itself, like the output of a markdown renderer or a syntax
+/// highlighter that runs at build time. Never pass it markup from users without running it through a
+/// [`Sanitizer`](crate::Sanitizer) first.
+///
+/// Server side rendering writes the markup directly into the page. Other renderers and pages that are pre-rendered
+/// for hydration put the markup inside a `<dioxus-pre-rendered>` element with `display: contents`, so the wrapper
+/// doesn't change the layout.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::pre_rendered;
+///
+/// fn app() -> Element {
+///     // Renders as `<pre><span class="keyword">fn</span> main() {}</pre>` on the server
+///     let highlighted = r#"<span class="keyword">fn</span> main() {}"#;
+///     rsx! {
+///         pre { {pre_rendered(highlighted)} }
+///     }
+/// }
+/// ```
+pub fn pre_rendered(markup: impl ToString) -> Element {
+    Ok(VNode::new(
+        None,
+        PRE_RENDERED_TEMPLATE,
+        Box::new([]),
+        Box::new([Box::new([Attribute::new(
+            "dangerous_inner_html",
+            markup.to_string(),
+            None,
+            false,
+        )])]),
+    ))
+}
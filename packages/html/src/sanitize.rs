@@ -0,0 +1,583 @@
+//! An allowlist based HTML sanitizer for markup that is passed to `dangerous_inner_html`.
+
+use std::collections::{HashMap, HashSet};
+
+/// Tags that are removed together with everything inside of them. Their contents are not text a user should see.
+const DROP_CONTENT: &[&str] = &[
+    "script", "style", "iframe", "object", "template", "noscript", "textarea", "title", "svg",
+    "math",
+];
+
+/// Elements without a closing tag
+const VOID: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Attributes that hold a url. Their scheme is checked against the allowed schemes.
+const URL_ATTRIBUTES: &[&str] = &["href", "src", "cite", "action", "formaction", "poster"];
+
+/// Removes everything from untrusted HTML except for the tags and attributes it allows.
+///
+/// The default sanitizer allows the markup markdown renderers produce: text formatting, headings, lists, tables, code
+/// blocks, links and images. Links and images may only use `http`, `https` and `mailto` urls. Scripts, styles, event
+/// handlers and every other tag or attribute are removed. The text inside of removed tags is kept, except for tags
+/// like `script` whose contents are not meant to be shown.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::Sanitizer;
+///
+/// let markdown_output = r#"<p onclick="steal()">Hi <a href="javascript:steal()">there</a><script>steal()</script></p>"#;
+/// let html = Sanitizer::default().sanitize(markdown_output);
+/// assert_eq!(html, "<p>Hi <a>there</a></p>");
+///
+/// rsx! {
+///     div { dangerous_inner_html: html }
+/// };
+/// ```
+///
+/// The allowlist can be extended for markup you control:
+///
+/// ```rust
+/// use dioxus_html::Sanitizer;
+///
+/// let sanitizer = Sanitizer::default()
+///     .allow_tags(["details", "summary"])
+///     .allow_attributes("details", ["open"]);
+/// assert_eq!(
+///     sanitizer.sanitize("<details open><summary>More</summary></details>"),
+///     r#"<details open=""><summary>More</summary></details>"#
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sanitizer {
+    tags: HashSet<&'static str>,
+    attributes: HashMap<&'static str, HashSet<&'static str>>,
+    generic_attributes: HashSet<&'static str>,
+    url_schemes: HashSet<&'static str>,
+}
+
+impl Default for Sanitizer {
+    fn default() -> Self {
+        Self::empty()
+            .allow_tags([
+                "a",
+                "abbr",
+                "b",
+                "blockquote",
+                "br",
+                "code",
+                "dd",
+                "del",
+                "div",
+                "dl",
+                "dt",
+                "em",
+                "h1",
+                "h2",
+                "h3",
+                "h4",
+                "h5",
+                "h6",
+                "hr",
+                "i",
+                "img",
+                "ins",
+                "kbd",
+                "li",
+                "mark",
+                "ol",
+                "p",
+                "pre",
+                "q",
+                "s",
+                "span",
+                "strong",
+                "sub",
+                "sup",
+                "table",
+                "tbody",
+                "td",
+                "tfoot",
+                "th",
+                "thead",
+                "tr",
+                "u",
+                "ul",
+            ])
+            .allow_generic_attributes(["class", "dir", "lang", "title"])
+            .allow_attributes("a", ["href"])
+            .allow_attributes("img", ["alt", "height", "src", "width"])
+            .allow_attributes("ol", ["start"])
+            .allow_attributes("td", ["align", "colspan", "rowspan"])
+            .allow_attributes("th", ["align", "colspan", "rowspan"])
+            .allow_url_schemes(["http", "https", "mailto"])
+    }
+}
+
+impl Sanitizer {
+    /// Create a sanitizer that doesn't allow any tags. Only the text of the HTML is kept.
+    pub fn empty() -> Self {
+        Self {
+            tags: HashSet::new(),
+            attributes: HashMap::new(),
+            generic_attributes: HashSet::new(),
+            url_schemes: HashSet::new(),
+        }
+    }
+
+    /// Allow tags. Tag names are lowercase.
+    pub fn allow_tags(mut self, tags: impl IntoIterator<Item = &'static str>) -> Self {
+        self.tags.extend(tags);
+        self
+    }
+
+    /// Allow attributes on one tag. Attribute names are lowercase. Allowing an attribute does not allow the tag.
+    pub fn allow_attributes(
+        mut self,
+        tag: &'static str,
+        attributes: impl IntoIterator<Item = &'static str>,
+    ) -> Self {
+        self.attributes.entry(tag).or_default().extend(attributes);
+        self
+    }
+
+    /// Allow attributes on every allowed tag. Attribute names are lowercase.
+    pub fn allow_generic_attributes(
+        mut self,
+        attributes: impl IntoIterator<Item = &'static str>,
+    ) -> Self {
+        self.generic_attributes.extend(attributes);
+        self
+    }
+
+    /// Allow url schemes like `https` in attributes that hold a url. Relative urls are always allowed.
+    pub fn allow_url_schemes(mut self, schemes: impl IntoIterator<Item = &'static str>) -> Self {
+        self.url_schemes.extend(schemes);
+        self
+    }
+
+    /// Sanitize HTML. The output only contains allowed tags and attributes and every tag that is opened is closed.
+    pub fn sanitize(&self, html: &str) -> String {
+        let mut out = String::with_capacity(html.len());
+        let mut open: Vec<String> = Vec::new();
+        let mut rest = html;
+
+        while let Some(start) = rest.find('<') {
+            write_text(&mut out, &rest[..start]);
+            rest = &rest[start..];
+
+            // Comments, doctypes and processing instructions are removed
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+                continue;
+            }
+            if rest.starts_with("<!") || rest.starts_with("<?") {
+                rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+                continue;
+            }
+
+            let Some(tag) = Tag::parse(rest) else {
+                // A `<` that doesn't start a tag is text
+                out.push_str("&lt;");
+                rest = &rest[1..];
+                continue;
+            };
+            rest = &rest[tag.len..];
+
+            if tag.closing {
+                if let Some(index) = open.iter().rposition(|name| *name == tag.name) {
+                    for name in open.drain(index..).rev() {
+                        out.push_str("</");
+                        out.push_str(&name);
+                        out.push('>');
+                    }
+                }
+                continue;
+            }
+
+            if DROP_CONTENT.contains(&tag.name.as_str()) {
+                if !tag.self_closing {
+                    rest = skip_past_closing_tag(rest, &tag.name);
+                }
+                continue;
+            }
+
+            if !self.tags.contains(tag.name.as_str()) {
+                continue;
+            }
+
+            out.push('<');
+            out.push_str(&tag.name);
+            for (name, value) in &tag.attributes {
+                if self.allows_attribute(&tag.name, name, value) {
+                    out.push(' ');
+                    out.push_str(name);
+                    out.push_str("=\"");
+                    write_escaped(&mut out, value);
+                    out.push('"');
+                }
+            }
+            out.push('>');
+
+            if !VOID.contains(&tag.name.as_str()) {
+                open.push(tag.name);
+            }
+        }
+        write_text(&mut out, rest);
+
+        for name in open.into_iter().rev() {
+            out.push_str("</");
+            out.push_str(&name);
+            out.push('>');
+        }
+
+        out
+    }
+
+    fn allows_attribute(&self, tag: &str, name: &str, value: &str) -> bool {
+        let allowed = self.generic_attributes.contains(name)
+            || self
+                .attributes
+                .get(tag)
+                .is_some_and(|attributes| attributes.contains(name));
+        if !allowed {
+            return false;
+        }
+        if !URL_ATTRIBUTES.contains(&name) {
+            return true;
+        }
+        match url_scheme(value) {
+            Some(scheme) => self.url_schemes.contains(scheme.as_str()),
+            None => true,
+        }
+    }
+}
+
+/// Sanitize HTML with the default [`Sanitizer`]
+pub fn sanitize_html(html: &str) -> String {
+    Sanitizer::default().sanitize(html)
+}
+
+/// An opening or closing tag
+struct Tag {
+    name: String,
+    attributes: Vec<(String, String)>,
+    closing: bool,
+    self_closing: bool,
+    /// The length of the tag in the source
+    len: usize,
+}
+
+impl Tag {
+    /// Parse the tag at the start of `source`, which starts with `<`
+    fn parse(source: &str) -> Option<Self> {
+        let bytes = source.as_bytes();
+        let mut i = 1;
+        let closing = bytes.get(i) == Some(&b'/');
+        if closing {
+            i += 1;
+        }
+        if !bytes.get(i)?.is_ascii_alphabetic() {
+            return None;
+        }
+        let name_start = i;
+        while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'-') {
+            i += 1;
+        }
+        let name = source[name_start..i].to_ascii_lowercase();
+
+        let mut attributes = Vec::new();
+        let mut self_closing = false;
+        loop {
+            while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b'/') {
+                self_closing = bytes[i] == b'/';
+                i += 1;
+            }
+            match bytes.get(i) {
+                // A tag that is never closed is not a tag
+                None => return None,
+                Some(b'>') => break,
+                _ => {}
+            }
+            self_closing = false;
+
+            let attribute_start = i;
+            while i < bytes.len() && !matches!(bytes[i], b'=' | b'>' | b'/') {
+                if bytes[i].is_ascii_whitespace() {
+                    break;
+                }
+                i += 1;
+            }
+            let attribute = source[attribute_start..i].to_ascii_lowercase();
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+
+            let mut value = String::new();
+            if bytes.get(i) == Some(&b'=') {
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                    i += 1;
+                }
+                let raw = match bytes.get(i) {
+                    Some(quote @ (b'"' | b'\'')) => {
+                        let end = i + 1 + source[i + 1..].find(*quote as char)?;
+                        let raw = &source[i + 1..end];
+                        i = end + 1;
+                        raw
+                    }
+                    _ => {
+                        let start = i;
+                        while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>'
+                        {
+                            i += 1;
+                        }
+                        &source[start..i]
+                    }
+                };
+                value = decode_entities(raw);
+            }
+
+            if !attribute.is_empty() {
+                attributes.push((attribute, value));
+            }
+            // Parsing must always move forward. Step over a whole character so the next slice stays on a boundary
+            if i == attribute_start {
+                i += source[i..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+
+        Some(Self {
+            name,
+            attributes,
+            closing,
+            self_closing,
+            len: i + 1,
+        })
+    }
+}
+
+/// Skip everything up to and including the closing tag with the name
+fn skip_past_closing_tag<'a>(source: &'a str, name: &str) -> &'a str {
+    let mut rest = source;
+    while let Some(start) = rest.find("</") {
+        rest = &rest[start..];
+        if let Some(tag) = Tag::parse(rest) {
+            if tag.closing && tag.name == name {
+                return &rest[tag.len..];
+            }
+        }
+        rest = &rest[2..];
+    }
+    ""
+}
+
+/// The lowercase scheme of a url, or `None` for relative urls
+fn url_scheme(url: &str) -> Option<String> {
+    // Browsers ignore whitespace and control characters in urls, so `java\tscript:` is still a javascript url
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect();
+    let end = url.find([':', '/', '?', '#'])?;
+    url[end..]
+        .starts_with(':')
+        .then(|| url[..end].to_ascii_lowercase())
+}
+
+/// Write text and escape any markup in it. Character references are kept as they are.
+fn write_text(out: &mut String, text: &str) {
+    let mut rest = text;
+    while let Some(index) = rest.find(['&', '<', '>']) {
+        out.push_str(&rest[..index]);
+        match rest.as_bytes()[index] {
+            b'&' if entity_len(&rest[index..]).is_some() => out.push('&'),
+            b'&' => out.push_str("&amp;"),
+            b'<' => out.push_str("&lt;"),
+            _ => out.push_str("&gt;"),
+        }
+        rest = &rest[index + 1..];
+    }
+    out.push_str(rest);
+}
+
+/// Write an attribute value that was decoded
+fn write_escaped(out: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// The length of the character reference at the start of `source`, like `&amp;` or `&#60;`
+fn entity_len(source: &str) -> Option<usize> {
+    let end = source[1..].find(';')? + 1;
+    let name = &source[1..end];
+    let valid = match name.strip_prefix('#') {
+        Some(number) => match number.strip_prefix(['x', 'X']) {
+            Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+            None => !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()),
+        },
+        None => !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric()),
+    };
+    valid.then_some(end + 1)
+}
+
+/// Decode the character references in an attribute value. Unknown named references are kept as they are.
+fn decode_entities(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(index) = rest.find('&') {
+        out.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let decoded =
+            entity_len(rest).and_then(|len| Some((decode_entity(&rest[1..len - 1])?, len)));
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "colon" => Some(':'),
+        "Tab" => Some('\t'),
+        "NewLine" => Some('\n'),
+        "nbsp" => Some('\u{a0}'),
+        _ => None,
+    }
+}
+
+#[test]
+fn removes_scripts_and_event_handlers() {
+    let sanitized = sanitize_html(
+        r#"<h1 id="x" class="title" onmouseover="steal()">Title</h1><SCRIPT>steal()</SCRIPT><style>p{}</style><p>a &amp; b < c</p>"#,
+    );
+    assert_eq!(
+        sanitized,
+        r#"<h1 class="title">Title</h1><p>a &amp; b &lt; c</p>"#
+    );
+}
+
+#[test]
+fn checks_url_schemes() {
+    assert_eq!(
+        sanitize_html(
+            r#"<a href="https://dioxuslabs.com">ok</a><a href="/docs">relative</a><a href=" JaVa&#x09;script:steal()">bad</a>"#
+        ),
+        r#"<a href="https://dioxuslabs.com">ok</a><a href="/docs">relative</a><a>bad</a>"#
+    );
+    assert_eq!(
+        sanitize_html(r#"<img src="data:image/svg+xml,<svg onload=steal()>" alt='"quoted"'>"#),
+        r#"<img alt="&quot;quoted&quot;">"#
+    );
+}
+
+#[test]
+fn balances_tags() {
+    assert_eq!(
+        sanitize_html("<ul><li><b>one</li><li>two</i></ul><p>open"),
+        "<ul><li><b>one</b></li><li>two</li></ul><p>open</p>"
+    );
+    assert_eq!(
+        Sanitizer::empty().sanitize("<p>only <em>text</em></p><!-- comment -->"),
+        "only text"
+    );
+}
+
+#[test]
+fn stray_equals_signs_and_non_ascii() {
+    assert_eq!(sanitize_html(r#"<p ="x"é>ok</p>"#), "<p>ok</p>");
+    assert_eq!(sanitize_html("<p =>hello<b>x</b>"), "<p>hello<b>x</b></p>");
+    assert_eq!(sanitize_html("<p é=ü ö>grüße €</p>"), "<p>grüße €</p>");
+    assert_eq!(
+        sanitize_html("<é>ß</é><b title=日本>語</b>"),
+        "&lt;é&gt;ß&lt;/é&gt;<b title=\"日本\">語</b>"
+    );
+}
+
+#[test]
+fn sanitizing_random_markup_never_panics() {
+    const PIECES: &[&str] = &[
+        "<",
+        ">",
+        "</",
+        "/>",
+        "=",
+        "\"",
+        "'",
+        " ",
+        "&",
+        ";",
+        "#",
+        "x",
+        "p",
+        "b",
+        "a",
+        "href",
+        "javascript:",
+        "script",
+        "<!--",
+        "-->",
+        "é",
+        "日本",
+        "€",
+        "\u{1f600}",
+        "&amp;",
+        "&#x",
+        "<p",
+        "<a href=",
+        "<script>",
+        "</script>",
+        "<img src=",
+        "\t",
+        "\n",
+    ];
+
+    // A small deterministic generator keeps the test reproducible without a fuzzing dependency
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    for _ in 0..5000 {
+        let len = next() % 24;
+        let input: String = (0..len)
+            .map(|_| PIECES[(next() % PIECES.len() as u64) as usize])
+            .collect();
+        let sanitized = sanitize_html(&input);
+        // The output is already safe, so sanitizing it again doesn't change it
+        assert_eq!(sanitize_html(&sanitized), sanitized, "input: {input:?}");
+    }
+}
@@ -0,0 +1,160 @@
+//! Structured values for the `style` attribute.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use dioxus_core::{prelude::IntoAttributeValue, AttributeValue};
+
+use crate::AttributeDescription;
+
+/// A set of css properties that can be passed to the `style` attribute. Setting a property that is already in the set
+/// replaces its value.
+///
+/// Properties can be set by name or with the typed style attributes from
+/// [`global_attributes`](crate::global_attributes), which catches typos in property names at compile time:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// use dioxus::html::global_attributes::{background_color, padding};
+///
+/// #[component]
+/// fn Highlight(color: String) -> Element {
+///     let style = Style::new()
+///         .set(background_color, color)
+///         .set(padding, "4px")
+///         .set("--highlight-depth", 2);
+///
+///     rsx! {
+///         span { style, "Highlighted" }
+///     }
+/// }
+/// ```
+///
+/// A [`BTreeMap`] of property names and values can be passed to `style` directly.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Style(Vec<(String, String)>);
+
+impl Style {
+    /// Create an empty set of properties
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a property
+    pub fn set(mut self, property: impl StyleProperty, value: impl Display) -> Self {
+        self.insert(property, value);
+        self
+    }
+
+    /// Set a property if the value is `Some`
+    pub fn set_some(self, property: impl StyleProperty, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.set(property, value),
+            None => self,
+        }
+    }
+
+    /// Set a property in place
+    pub fn insert(&mut self, property: impl StyleProperty, value: impl Display) {
+        let name = property.property_name();
+        let value = value.to_string();
+        match self.0.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.0.push((name.to_string(), value)),
+        }
+    }
+
+    /// Remove a property
+    pub fn remove(&mut self, property: impl StyleProperty) -> Option<String> {
+        let name = property.property_name();
+        let index = self.0.iter().position(|(existing, _)| existing == name)?;
+        Some(self.0.remove(index).1)
+    }
+
+    /// Get the value of a property
+    pub fn get(&self, property: impl StyleProperty) -> Option<&str> {
+        let name = property.property_name();
+        self.0
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Check if no properties are set
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, value) in &self.0 {
+            write!(f, "{name}:{value};")?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: StyleProperty, V: Display> FromIterator<(P, V)> for Style {
+    fn from_iter<I: IntoIterator<Item = (P, V)>>(iter: I) -> Self {
+        let mut style = Self::new();
+        style.extend(iter);
+        style
+    }
+}
+
+impl<P: StyleProperty, V: Display> Extend<(P, V)> for Style {
+    fn extend<I: IntoIterator<Item = (P, V)>>(&mut self, iter: I) {
+        for (property, value) in iter {
+            self.insert(property, value);
+        }
+    }
+}
+
+// An empty set removes the attribute instead of rendering `style=""`
+impl IntoAttributeValue for Style {
+    fn into_value(self) -> AttributeValue {
+        match self.is_empty() {
+            true => AttributeValue::None,
+            false => AttributeValue::Text(self.to_string()),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct StyleMapMarker;
+
+impl<P: StyleProperty, V: Display> IntoAttributeValue<StyleMapMarker> for BTreeMap<P, V> {
+    fn into_value(self) -> AttributeValue {
+        Style::from_iter(self).into_value()
+    }
+}
+
+/// The name of a css property that can be set in a [`Style`]
+pub trait StyleProperty {
+    /// Get the name of the property in css, like `background-color`
+    fn property_name(&self) -> &str;
+}
+
+impl StyleProperty for &str {
+    fn property_name(&self) -> &str {
+        self
+    }
+}
+
+impl StyleProperty for String {
+    fn property_name(&self) -> &str {
+        self
+    }
+}
+
+impl StyleProperty for AttributeDescription {
+    fn property_name(&self) -> &str {
+        debug_assert_eq!(
+            self.1,
+            Some("style"),
+            "{} is an attribute, not a style property",
+            self.0
+        );
+        self.0
+    }
+}
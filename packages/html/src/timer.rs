@@ -0,0 +1,99 @@
+//! A timer that works on every platform
+
+use std::time::Duration;
+
+/// Wait for a duration without depending on a specific async runtime.
+///
+/// On the web this uses a browser timer. On other platforms one background thread wakes every sleeping future when
+/// its deadline passes, so this works on any executor and outside of a tokio runtime.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    thread::sleep(duration).await;
+}
+
+/// A single background thread that wakes every sleeping future when its deadline passes
+#[cfg(not(target_arch = "wasm32"))]
+mod thread {
+    use std::{
+        cmp::Ordering,
+        collections::BinaryHeap,
+        sync::{mpsc, OnceLock},
+        time::{Duration, Instant},
+    };
+
+    struct Timer {
+        deadline: Instant,
+        wake: futures_channel::oneshot::Sender<()>,
+    }
+
+    // The heap is a max heap, so the timer with the earliest deadline is the greatest
+    impl Ord for Timer {
+        fn cmp(&self, other: &Self) -> Ordering {
+            other.deadline.cmp(&self.deadline)
+        }
+    }
+
+    impl PartialOrd for Timer {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl PartialEq for Timer {
+        fn eq(&self, other: &Self) -> bool {
+            self.deadline == other.deadline
+        }
+    }
+
+    impl Eq for Timer {}
+
+    static TIMERS: OnceLock<mpsc::Sender<Timer>> = OnceLock::new();
+
+    fn run(timers: mpsc::Receiver<Timer>) {
+        let mut waiting = BinaryHeap::new();
+        loop {
+            let next = match waiting.peek() {
+                Some(Timer { deadline, .. }) => {
+                    timers.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+                None => timers
+                    .recv()
+                    .map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+            };
+            match next {
+                Ok(timer) => waiting.push(timer),
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => return,
+            }
+
+            let now = Instant::now();
+            while waiting.peek().is_some_and(|timer| timer.deadline <= now) {
+                if let Some(timer) = waiting.pop() {
+                    _ = timer.wake.send(());
+                }
+            }
+        }
+    }
+
+    pub(super) async fn sleep(duration: Duration) {
+        let timers = TIMERS.get_or_init(|| {
+            let (tx, rx) = mpsc::channel();
+            _ = std::thread::Builder::new()
+                .name("dioxus-timer".into())
+                .spawn(move || run(rx));
+            tx
+        });
+        let (wake, woken) = futures_channel::oneshot::channel();
+        let timer = Timer {
+            deadline: Instant::now() + duration,
+            wake,
+        };
+        // If the timer thread couldn't start, don't wait at all instead of waiting forever
+        if timers.send(timer).is_ok() {
+            _ = woken.await;
+        }
+    }
+}
@@ -119,10 +119,14 @@ fn deserialize_raw(name: &str, data: &serde_json::Value) -> Result<EventData, se
         | "loadend" | "timeout" => Media(de(data)?),
 
         // Animation
-        "animationstart" | "animationend" | "animationiteration" => Animation(de(data)?),
+        "animationstart" | "animationend" | "animationiteration" | "animationcancel" => {
+            Animation(de(data)?)
+        }
 
         // Transition
-        "transitionend" => Transition(de(data)?),
+        "transitionrun" | "transitionstart" | "transitionend" | "transitioncancel" => {
+            Transition(de(data)?)
+        }
 
         // Toggle
         "toggle" => Toggle(de(data)?),
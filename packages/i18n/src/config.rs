@@ -0,0 +1,83 @@
+use unic_langid::LanguageIdentifier;
+
+use crate::negotiate_accept_language;
+
+/// The messages and locales available to [`use_init_i18n`](crate::use_init_i18n).
+///
+/// ```rust
+/// use dioxus::i18n::{langid, I18nConfig};
+///
+/// let config = I18nConfig::new(langid!("en-US"))
+///     .with_resource(langid!("en-US"), "greeting = Hello")
+///     .with_resource(langid!("fr"), "greeting = Bonjour")
+///     .with_accept_language("fr-CA,fr;q=0.9,en;q=0.8");
+/// assert_eq!(config.locale(), &langid!("fr"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct I18nConfig {
+    pub(crate) fallback: LanguageIdentifier,
+    pub(crate) locale: Option<LanguageIdentifier>,
+    pub(crate) resources: Vec<(LanguageIdentifier, String)>,
+    pub(crate) use_isolating: bool,
+}
+
+impl I18nConfig {
+    /// Create a config that falls back to `fallback` when a message is missing in the current locale. The fallback is
+    /// also the initial locale unless another locale is set with [`I18nConfig::with_locale`].
+    pub fn new(fallback: LanguageIdentifier) -> Self {
+        Self {
+            fallback,
+            locale: None,
+            resources: Vec::new(),
+            use_isolating: true,
+        }
+    }
+
+    /// Add the source of a Fluent (`.ftl`) resource for a locale. A locale can have multiple resources.
+    ///
+    /// Resources are usually included from a file with [`include_str!`].
+    pub fn with_resource(mut self, locale: LanguageIdentifier, source: impl Into<String>) -> Self {
+        self.resources.push((locale, source.into()));
+        self
+    }
+
+    /// Set the initial locale
+    pub fn with_locale(mut self, locale: LanguageIdentifier) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Set the initial locale to the best match for the value of an `Accept-Language` header. If none of the
+    /// requested locales have resources, the fallback locale is used.
+    ///
+    /// Add every resource before calling this method so all of the locales are considered.
+    pub fn with_accept_language(mut self, header: &str) -> Self {
+        let locale = negotiate_accept_language(header, &self.locales(), &self.fallback);
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Set if arguments are wrapped in unicode isolation marks when they are inserted into a message. This is on by
+    /// default so right to left arguments don't change the direction of the surrounding left to right text, and the
+    /// other way around.
+    pub fn use_isolating(mut self, use_isolating: bool) -> Self {
+        self.use_isolating = use_isolating;
+        self
+    }
+
+    /// Get the initial locale
+    pub fn locale(&self) -> &LanguageIdentifier {
+        self.locale.as_ref().unwrap_or(&self.fallback)
+    }
+
+    /// Get every locale with at least one resource
+    pub fn locales(&self) -> Vec<LanguageIdentifier> {
+        let mut locales: Vec<LanguageIdentifier> = Vec::new();
+        for (locale, _) in &self.resources {
+            if !locales.contains(locale) {
+                locales.push(locale.clone());
+            }
+        }
+        locales
+    }
+}
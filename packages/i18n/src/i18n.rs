@@ -0,0 +1,282 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use dioxus_core::prelude::*;
+use dioxus_document::{set_document_language, DocumentLanguage};
+use dioxus_signals::*;
+use fluent_bundle::{FluentArgs, FluentBundle, FluentError, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+use crate::{negotiate::negotiate_locale, I18nConfig};
+
+/// Load the resources in an [`I18nConfig`] and provide an [`I18n`] context to the current component and its children.
+///
+/// The `lang` and `dir` attributes of the document are set to the initial locale, and updated whenever the locale
+/// changes.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::i18n::{langid, I18nConfig};
+///
+/// fn app() -> Element {
+///     use_init_i18n(|| {
+///         I18nConfig::new(langid!("en-US"))
+///             .with_resource(langid!("en-US"), "greeting = Hello, { $name }!")
+///             .with_resource(langid!("es"), "greeting = ¡Hola, { $name }!")
+///     });
+///
+///     rsx! {
+///         {t!("greeting", name: "Ferris")}
+///     }
+/// }
+/// ```
+pub fn use_init_i18n(init: impl FnOnce() -> I18nConfig) -> I18n {
+    use_hook(|| {
+        let i18n = I18n::new(init());
+        set_document_language(DocumentLanguage::new(i18n.locale.peek().to_string()));
+        provide_context(i18n)
+    })
+}
+
+/// Get the [`I18n`] context from the nearest [`use_init_i18n`]
+pub fn use_i18n() -> I18n {
+    use_hook(i18n)
+}
+
+/// Get the [`I18n`] context from the nearest [`use_init_i18n`]. Unlike [`use_i18n`], this can be called outside of
+/// the body of a component, like in an event handler.
+///
+/// # Panics
+///
+/// Panics if no parent component called [`use_init_i18n`]
+pub fn i18n() -> I18n {
+    match try_consume_context() {
+        Some(i18n) => i18n,
+        None => panic!(
+            "No i18n context was found. Call use_init_i18n in a parent component to provide one."
+        ),
+    }
+}
+
+/// Translated messages for a set of locales and the current locale. Create one with [`use_init_i18n`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct I18n {
+    origin: ScopeId,
+    locale: Signal<LanguageIdentifier>,
+    bundles: CopyValue<Bundles>,
+}
+
+struct Bundles {
+    fallback: LanguageIdentifier,
+    bundles: Vec<FluentBundle<FluentResource>>,
+}
+
+impl Bundles {
+    fn new(config: I18nConfig) -> Self {
+        let mut bundles: Vec<FluentBundle<FluentResource>> = Vec::new();
+        for (locale, source) in config.resources {
+            let resource = match FluentResource::try_new(source) {
+                Ok(resource) => resource,
+                Err((resource, errors)) => {
+                    tracing::error!("Failed to parse a Fluent resource for {locale}: {errors:?}");
+                    resource
+                }
+            };
+            let index = match bundles
+                .iter()
+                .position(|bundle| bundle.locales[0] == locale)
+            {
+                Some(index) => index,
+                None => {
+                    let mut bundle = FluentBundle::new(vec![locale.clone()]);
+                    bundle.set_use_isolating(config.use_isolating);
+                    bundles.push(bundle);
+                    bundles.len() - 1
+                }
+            };
+            if let Err(errors) = bundles[index].add_resource(resource) {
+                tracing::error!("Failed to add a Fluent resource for {locale}: {errors:?}");
+            }
+        }
+
+        Self {
+            fallback: config.fallback,
+            bundles,
+        }
+    }
+
+    fn locales(&self) -> Vec<LanguageIdentifier> {
+        self.bundles
+            .iter()
+            .map(|bundle| bundle.locales[0].clone())
+            .collect()
+    }
+
+    /// Get the locale with resources that is closest to the requested locale
+    fn resolve(&self, locale: LanguageIdentifier) -> LanguageIdentifier {
+        negotiate_locale(&[locale], &self.locales()).unwrap_or_else(|| self.fallback.clone())
+    }
+
+    /// Get the bundles to search for a message in, starting with the locale and ending with the fallback
+    fn search<'a>(
+        &'a self,
+        locale: &LanguageIdentifier,
+    ) -> impl Iterator<Item = &'a FluentBundle<FluentResource>> {
+        let current = self
+            .bundles
+            .iter()
+            .find(|bundle| bundle.locales[0] == *locale);
+        let fallback = self
+            .bundles
+            .iter()
+            .find(|bundle| bundle.locales[0] == self.fallback && bundle.locales[0] != *locale);
+        current.into_iter().chain(fallback)
+    }
+}
+
+impl I18n {
+    /// Create a new i18n context owned by the current scope
+    pub fn new(config: I18nConfig) -> Self {
+        let initial = config.locale().clone();
+        let bundles = Bundles::new(config);
+        let locale = bundles.resolve(initial);
+        Self {
+            origin: current_scope_id().unwrap_or_else(|e| panic!("{}", e)),
+            locale: Signal::new(locale),
+            bundles: CopyValue::new(bundles),
+        }
+    }
+
+    /// Get the current locale. Reading this subscribes the current component to changes.
+    pub fn locale(&self) -> LanguageIdentifier {
+        self.locale.read().clone()
+    }
+
+    /// Get every locale with at least one resource
+    pub fn locales(&self) -> Vec<LanguageIdentifier> {
+        self.bundles.read().locales()
+    }
+
+    /// Switch to a new locale. Every component that translated a message reruns with the new locale, and the `lang`
+    /// and `dir` attributes of the document are updated.
+    ///
+    /// If there are no resources for the locale, the closest locale with resources is used instead. For example,
+    /// `en-GB` switches to `en-US` if that is the only English locale. If no locale is close, the fallback is used.
+    pub fn set_locale(&self, locale: LanguageIdentifier) {
+        let locale = self.bundles.read().resolve(locale);
+        if *self.locale.peek() == locale {
+            return;
+        }
+
+        use ::warnings::Warning;
+        let mut signal = self.locale;
+        // The locale can be set from a route parameter while rendering, which is fine because it only changes once
+        dioxus_signals::warnings::signal_write_in_component_body::allow(|| {
+            signal.set(locale.clone());
+        });
+        self.origin.in_runtime(|| {
+            set_document_language(DocumentLanguage::new(locale.to_string()));
+        });
+    }
+
+    /// Translate a message in the current locale. If the message is missing, the id is returned and a warning is
+    /// logged.
+    ///
+    /// Use `message.attribute` as the id to translate an attribute of a message.
+    pub fn translate(&self, id: &str) -> String {
+        self.translate_with_args(id, None)
+    }
+
+    /// Translate a message with arguments in the current locale. If the message is missing, the id is returned and a
+    /// warning is logged.
+    pub fn translate_with_args(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        match self.try_translate_with_args(id, args) {
+            Ok(message) => message,
+            Err(error) => {
+                tracing::warn!("{error}");
+                id.to_string()
+            }
+        }
+    }
+
+    /// Translate a message with arguments in the current locale, or return an error if the message is missing from
+    /// both the current locale and the fallback locale.
+    ///
+    /// Reading the message subscribes the current component to changes to the locale.
+    pub fn try_translate_with_args(
+        &self,
+        id: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, I18nError> {
+        let locale = self.locale.read().clone();
+        let bundles = self.bundles.read();
+        let (message_id, attribute) = match id.split_once('.') {
+            Some((message_id, attribute)) => (message_id, Some(attribute)),
+            None => (id, None),
+        };
+
+        for bundle in bundles.search(&locale) {
+            let Some(message) = bundle.get_message(message_id) else {
+                continue;
+            };
+            let pattern = match attribute {
+                Some(attribute) => message
+                    .get_attribute(attribute)
+                    .map(|attribute| attribute.value()),
+                None => message.value(),
+            };
+            let Some(pattern) = pattern else {
+                continue;
+            };
+
+            let mut errors = Vec::new();
+            let formatted = bundle.format_pattern(pattern, args, &mut errors);
+            // A message that references a missing argument still formats, so log the errors instead of failing
+            if !errors.is_empty() {
+                log_format_errors(id, &errors);
+            }
+            return Ok(formatted.into_owned());
+        }
+
+        Err(I18nError::MissingMessage {
+            id: id.to_string(),
+            locale,
+        })
+    }
+}
+
+fn log_format_errors(id: &str, errors: &[FluentError]) {
+    for error in errors {
+        tracing::warn!("Error while formatting the message {id}: {error}");
+    }
+}
+
+/// An error that can occur when translating a message
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum I18nError {
+    /// The message is missing from both the current locale and the fallback locale
+    MissingMessage {
+        /// The id of the message
+        id: String,
+        /// The locale the message was translated in
+        locale: LanguageIdentifier,
+    },
+}
+
+impl Display for I18nError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            I18nError::MissingMessage { id, locale } => {
+                write!(
+                    f,
+                    "The message {id} is missing in {locale} and the fallback locale"
+                )
+            }
+        }
+    }
+}
+
+impl Error for I18nError {}
@@ -0,0 +1,43 @@
+#![doc = include_str!("../README.md")]
+#![warn(missing_docs)]
+
+mod config;
+mod i18n;
+mod negotiate;
+
+pub use config::*;
+pub use i18n::*;
+pub use negotiate::*;
+
+pub use fluent_bundle::{FluentArgs, FluentValue};
+pub use unic_langid::{langid, LanguageIdentifier};
+
+/// Translate a message with the [`I18n`] context from the nearest [`use_init_i18n`].
+///
+/// Arguments are passed as `name: value` pairs after the message id. Numbers can be used to select plural forms in
+/// the message.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus::i18n::t;
+/// #[component]
+/// fn Inbox(name: String, unread: i64) -> Element {
+///     rsx! {
+///         h1 { {t!("inbox-title")} }
+///         p { {t!("inbox-unread", name: name, count: unread)} }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($id:expr $(,)?) => {
+        $crate::i18n().translate($id)
+    };
+    ($id:expr, $($name:ident : $value:expr),+ $(,)?) => {{
+        let mut args = $crate::FluentArgs::new();
+        $(
+            args.set(stringify!($name), $value);
+        )+
+        $crate::i18n().translate_with_args($id, Some(&args))
+    }};
+}
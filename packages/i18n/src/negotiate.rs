@@ -0,0 +1,33 @@
+use fluent_langneg::{accepted_languages, negotiate_languages, NegotiationStrategy};
+use unic_langid::LanguageIdentifier;
+
+/// Pick the locale from `available` that best matches the value of an `Accept-Language` header, like
+/// `fr-CA,fr;q=0.9,en;q=0.8`. Requested locales match available locales with the same language even if the region is
+/// different. Returns `default` if nothing matches.
+///
+/// ```rust
+/// use dioxus::i18n::{langid, negotiate_accept_language};
+///
+/// let available = [langid!("en-US"), langid!("de")];
+/// let default = langid!("en-US");
+/// assert_eq!(negotiate_accept_language("de-AT, en", &available, &default), langid!("de"));
+/// assert_eq!(negotiate_accept_language("ja", &available, &default), default);
+/// ```
+pub fn negotiate_accept_language(
+    header: &str,
+    available: &[LanguageIdentifier],
+    default: &LanguageIdentifier,
+) -> LanguageIdentifier {
+    let requested = accepted_languages::parse(header);
+    negotiate_locale(&requested[..], available).unwrap_or_else(|| default.clone())
+}
+
+/// Pick the locale from `available` that best matches the requested locales, in order of preference
+pub(crate) fn negotiate_locale(
+    requested: &[LanguageIdentifier],
+    available: &[LanguageIdentifier],
+) -> Option<LanguageIdentifier> {
+    negotiate_languages(requested, available, None, NegotiationStrategy::Filtering)
+        .first()
+        .map(|locale| (*locale).clone())
+}
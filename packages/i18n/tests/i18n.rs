@@ -0,0 +1,87 @@
+use std::cell::Cell;
+
+use dioxus::i18n::{langid, I18n, I18nConfig};
+use dioxus::prelude::*;
+
+const EN: &str = r#"
+greeting = Hello, { $name }!
+unread = { $count ->
+    [one] one unread message
+   *[other] { $count } unread messages
+}
+login = Log in
+    .title = Log in to your account
+"#;
+
+const DE: &str = r#"
+greeting = Hallo, { $name }!
+unread = { $count ->
+    [one] eine ungelesene Nachricht
+   *[other] { $count } ungelesene Nachrichten
+}
+"#;
+
+fn config() -> I18nConfig {
+    I18nConfig::new(langid!("en-US"))
+        .with_resource(langid!("en-US"), EN)
+        .with_resource(langid!("de"), DE)
+        .use_isolating(false)
+}
+
+thread_local! {
+    static I18N: Cell<Option<I18n>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    let i18n = use_init_i18n(config);
+    I18N.with(|cell| cell.set(Some(i18n)));
+    rsx! { Messages {} }
+}
+
+#[component]
+fn Messages() -> Element {
+    rsx! {
+        p { {t!("greeting", name: "Ferris")} }
+        p { {t!("unread", count: 1)} }
+        p { {t!("unread", count: 3)} }
+        p { title: t!("login.title"), {t!("login")} }
+        p { {t!("missing")} }
+    }
+}
+
+#[test]
+fn translates_messages_with_arguments_and_plurals() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<p>Hello, Ferris!</p><p>one unread message</p><p>3 unread messages</p><p title=\"Log in to your account\">Log in</p><p>missing</p>"
+    );
+}
+
+#[test]
+fn switching_locales_rerenders_and_falls_back() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let i18n = I18N.with(Cell::get).unwrap();
+    dom.in_runtime(|| i18n.set_locale(langid!("de-AT")));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(dom.in_runtime(|| i18n.locale()), langid!("de"));
+    // Messages that are missing in German use the fallback locale
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<p>Hallo, Ferris!</p><p>eine ungelesene Nachricht</p><p>3 ungelesene Nachrichten</p><p title=\"Log in to your account\">Log in</p><p>missing</p>"
+    );
+}
+
+#[test]
+fn initial_locale_is_negotiated_from_accept_language() {
+    let config = config().with_accept_language("fr-FR, de-CH;q=0.8, en;q=0.5");
+    assert_eq!(config.locale(), &langid!("de"));
+
+    let config = I18nConfig::new(langid!("en-US")).with_accept_language("de");
+    assert_eq!(config.locale(), &langid!("en-US"));
+}
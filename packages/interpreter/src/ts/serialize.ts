@@ -43,6 +43,7 @@ export function serializeEvent(
       property_name: event.propertyName,
       elapsed_time: event.elapsedTime,
       pseudo_element: event.pseudoElement,
+      dispatched_on_target: dispatchedOnTarget(event),
     });
   }
   if (event instanceof CompositionEvent) {
@@ -292,9 +293,18 @@ function serializeAnimationEvent(event: AnimationEvent): SerializedEvent {
     animation_name: event.animationName,
     elapsed_time: event.elapsedTime,
     pseudo_element: event.pseudoElement,
+    dispatched_on_target: dispatchedOnTarget(event),
   };
 }
 
+// Events that start on a static child without a dioxus id are dispatched to the closest parent with an id instead
+function dispatchedOnTarget(event: Event): boolean {
+  return (
+    event.target instanceof Element &&
+    event.target.hasAttribute("data-dioxus-id")
+  );
+}
+
 function serializeDragEvent(event: DragEvent): SerializedEvent {
   let files = undefined;
   // On desktop if there is file data, we insert it from wry. We just add a placeholder to let the rust side of dioxus know there's files
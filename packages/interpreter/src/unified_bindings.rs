@@ -117,7 +117,7 @@ mod js {
             } else if (ns == "style") {
                 node.style.removeProperty(field);
             } else {
-                node.removeAttributeNS(ns, field);
+                node.removeAttributeNS(ns, field.slice(field.indexOf(":") + 1));
             }
         }"#
     }
@@ -1,8 +1,8 @@
 use dioxus_core::prelude::queue_effect;
 use dioxus_core::ScopeId;
 use dioxus_document::{
-    create_element_in_head, Document, Eval, EvalError, Evaluator, LinkProps, MetaProps,
-    ScriptProps, StyleProps,
+    create_element_in_head, Document, DocumentLanguage, Eval, EvalError, Evaluator, LinkProps,
+    MetaProps, ScriptProps, StyleProps,
 };
 use dioxus_history::History;
 use generational_box::{AnyStorage, GenerationalBox, UnsyncStorage};
@@ -97,6 +97,14 @@ impl Document for LiveviewDocument {
         });
     }
 
+    /// Set the language of the document
+    fn set_language(&self, language: DocumentLanguage) {
+        let myself = self.clone();
+        queue_effect(move || {
+            myself.eval(language.update_script());
+        });
+    }
+
     /// Create a new meta tag in the head
     fn create_meta(&self, props: MetaProps) {
         let myself = self.clone();
@@ -16,11 +16,17 @@ pub(crate) struct LiveviewHistory {
 struct Timeline {
     current_index: usize,
     routes: BTreeMap<usize, String>,
+    /// The state payloads saved with the entries
+    payloads: BTreeMap<usize, String>,
+    /// The host of the page, once the client has sent it
+    host: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 struct State {
     index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    payload: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -33,8 +39,8 @@ struct Session {
 enum Action {
     GoBack,
     GoForward,
-    Push(String),
-    Replace(String),
+    Push(String, Option<String>),
+    Replace(String, Option<String>),
     External(String),
 }
 
@@ -43,6 +49,8 @@ impl Timeline {
         Self {
             current_index: 0,
             routes: BTreeMap::from([(0, initial_path)]),
+            payloads: BTreeMap::new(),
+            host: None,
         }
     }
 
@@ -69,10 +77,14 @@ impl Timeline {
             None => {
                 let index = depth - 1;
                 self.current_index = index;
-                State { index }
+                State {
+                    index,
+                    payload: None,
+                }
             }
         };
         self.routes.insert(state.index, route);
+        self.set_payload(state.payload.clone());
         state
     }
 
@@ -80,30 +92,43 @@ impl Timeline {
         if let Some(state) = state {
             self.current_index = state.index;
             self.routes.insert(self.current_index, route);
+            self.set_payload(state.payload.clone());
             state
         } else {
-            self.push(route)
+            self.push(route, None)
         }
     }
 
-    fn push(&mut self, route: String) -> State {
+    fn push(&mut self, route: String, payload: Option<String>) -> State {
         // top of stack
         let index = self.current_index + 1;
         self.current_index = index;
         self.routes.insert(index, route);
         self.routes.retain(|&rhs, _| index >= rhs);
+        self.payloads.retain(|&rhs, _| index >= rhs);
+        self.set_payload(payload.clone());
         State {
             index: self.current_index,
+            payload,
         }
     }
 
-    fn replace(&mut self, route: String) -> State {
+    fn replace(&mut self, route: String, payload: Option<String>) -> State {
         self.routes.insert(self.current_index, route);
+        self.set_payload(payload.clone());
         State {
             index: self.current_index,
+            payload,
         }
     }
 
+    fn set_payload(&mut self, payload: Option<String>) {
+        match payload {
+            Some(payload) => self.payloads.insert(self.current_index, payload),
+            None => self.payloads.remove(&self.current_index),
+        };
+    }
+
     fn current_route(&self) -> &str {
         &self.routes[&self.current_index]
     }
@@ -166,9 +191,9 @@ impl LiveviewHistory {
                                 history.forward();
                             "#,
                         ),
-                        Action::Push(route) => {
+                        Action::Push(route, payload) => {
                             let mut timeline = timeline.lock().expect("unpoisoned mutex");
-                            let state = timeline.push(route.clone());
+                            let state = timeline.push(route.clone(), payload);
                             let state = serde_json::to_string(&state).expect("serializable state");
                             let session = serde_json::to_string(&timeline.session())
                                 .expect("serializable session");
@@ -180,9 +205,9 @@ impl LiveviewHistory {
                             "#
                             ))
                         }
-                        Action::Replace(route) => {
+                        Action::Replace(route, payload) => {
                             let mut timeline = timeline.lock().expect("unpoisoned mutex");
-                            let state = timeline.replace(route.clone());
+                            let state = timeline.replace(route.clone(), payload);
                             let state = serde_json::to_string(&state).expect("serializable state");
                             let session = serde_json::to_string(&timeline.session())
                                 .expect("serializable session");
@@ -218,16 +243,22 @@ impl LiveviewHistory {
                           history.state,
                           JSON.parse(sessionStorage.getItem("liveview")),
                           history.length,
+                          document.location.host,
                         ];
                     "#,
                     ).await.expect("serializable state");
-                    let (route, state, session, depth) =
-                        serde_json::from_value::<(String, Option<State>, Option<Session>, usize)>(
-                            init_eval,
-                        )
-                        .expect("serializable state");
+                    let (route, state, session, depth, host) = serde_json::from_value::<(
+                        String,
+                        Option<State>,
+                        Option<Session>,
+                        usize,
+                        String,
+                    )>(init_eval)
+                    .expect("serializable state");
                     let mut timeline = timeline.lock().expect("unpoisoned mutex");
                     let state = timeline.init(route.clone(), state, session, depth);
+                    // The updater reruns host aware route matchers with the host of the page
+                    timeline.host = Some(host);
                     let state = serde_json::to_string(&state).expect("serializable state");
                     let session =
                         serde_json::to_string(&timeline.session()).expect("serializable session");
@@ -296,11 +327,24 @@ impl History for LiveviewHistory {
     }
 
     fn push(&self, route: String) {
-        let _ = self.action_tx.send(Action::Push(route));
+        let _ = self.action_tx.send(Action::Push(route, None));
     }
 
     fn replace(&self, route: String) {
-        let _ = self.action_tx.send(Action::Replace(route));
+        let _ = self.action_tx.send(Action::Replace(route, None));
+    }
+
+    fn push_with_state(&self, route: String, state: String) {
+        let _ = self.action_tx.send(Action::Push(route, Some(state)));
+    }
+
+    fn replace_with_state(&self, route: String, state: String) {
+        let _ = self.action_tx.send(Action::Replace(route, Some(state)));
+    }
+
+    fn current_state(&self) -> Option<String> {
+        let timeline = self.timeline.lock().expect("unpoisoned mutex");
+        timeline.payloads.get(&timeline.current_index).cloned()
     }
 
     fn external(&self, url: String) -> bool {
@@ -313,6 +357,11 @@ impl History for LiveviewHistory {
         timeline.current_route().to_string()
     }
 
+    fn current_host(&self) -> Option<String> {
+        let timeline = self.timeline.lock().expect("unpoisoned mutex");
+        timeline.host.clone()
+    }
+
     fn can_go_back(&self) -> bool {
         let timeline = self.timeline.lock().expect("unpoisoned mutex");
         // Check if the one before is contiguous (i.e., not an external page)
@@ -6,7 +6,7 @@ use crate::{
     LiveViewError,
 };
 use dioxus_core::prelude::*;
-use dioxus_html::{EventData, HtmlEvent, PlatformEventData};
+use dioxus_html::{EventData, EventRecorder, HtmlEvent, PlatformEventData};
 use dioxus_interpreter_js::MutationState;
 use futures_util::{pin_mut, SinkExt, StreamExt};
 use serde::Serialize;
@@ -177,6 +177,13 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
                         if let Ok(message) = serde_json::from_str::<IpcMessage>(&String::from_utf8_lossy(evt)) {
                             match message {
                                 IpcMessage::Event(evt) => {
+                                    // Record the event if the app opted into recording
+                                    vdom.runtime().on_scope(ScopeId::APP, || {
+                                        if let Some(recorder) = has_context::<EventRecorder>() {
+                                            recorder.record_event(&evt);
+                                        }
+                                    });
+
                                     // Intercept the mounted event and insert a custom element type
                                     let event = if let EventData::Mounted = &evt.data {
                                         let element = LiveviewElement::new(evt.element, query_engine.clone());
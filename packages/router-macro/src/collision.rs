@@ -0,0 +1,123 @@
+use proc_macro2::Span;
+
+use crate::{nest::Nest, route::RouteType, segment::RouteSegment, RouteEndpoint};
+
+/// A segment of the paths a route matches, ignoring the names and types of dynamic segments
+#[derive(PartialEq)]
+enum ShapeSegment<'a> {
+    Static(&'a str),
+    Dynamic,
+    CatchAll,
+}
+
+impl<'a> From<&'a RouteSegment> for ShapeSegment<'a> {
+    fn from(segment: &'a RouteSegment) -> Self {
+        match segment {
+            RouteSegment::Static(segment) => Self::Static(segment),
+            RouteSegment::Dynamic(..) => Self::Dynamic,
+            RouteSegment::CatchAll(..) => Self::CatchAll,
+        }
+    }
+}
+
+struct DeclaredRoute<'a> {
+    redirect: bool,
+    shape: Vec<ShapeSegment<'a>>,
+    route: String,
+    span: Span,
+}
+
+/// Find routes that match exactly the same paths. The router would always pick the route that is declared first, so
+/// the later route could only be reached if a dynamic segment of the first route fails to parse.
+///
+/// Static segments are already checked before dynamic segments and catch all segments are checked last, so routes
+/// like `/users/new` and `/users/:id` are not ambiguous. Redirects are only compared with other redirects because a
+/// redirect placed before a route is the intended way to take over some paths of that route. Routes that only have
+/// static segments are skipped because the same static path in different layouts has always resolved to the route
+/// that is declared first.
+pub(crate) fn check_route_collisions(
+    endpoints: &[RouteEndpoint],
+    nests: &[Nest],
+) -> syn::Result<()> {
+    let mut declared: Vec<DeclaredRoute> = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+
+    for endpoint in endpoints {
+        let (redirect, nest_ids, segments, route, span) = match endpoint {
+            RouteEndpoint::Route(route) => {
+                // Child routes match every path under their prefix, so the child router decides what they match
+                if let RouteType::Child(_) = &route.ty {
+                    continue;
+                }
+                (
+                    false,
+                    &route.nests,
+                    &route.segments,
+                    route.route.clone(),
+                    route.route_name.span(),
+                )
+            }
+            RouteEndpoint::Redirect(redirect) => (
+                true,
+                &redirect.nests,
+                &redirect.segments,
+                redirect.route.value(),
+                redirect.route.span(),
+            ),
+        };
+
+        let route_nests = nest_ids.iter().map(|id| &nests[id.0]);
+        let route = route_nests
+            .clone()
+            .map(|nest| nest.route.as_str())
+            .collect::<String>()
+            + &route;
+        let shape = route_nests
+            .flat_map(|nest| nest.segments.iter())
+            .chain(segments)
+            .map(ShapeSegment::from)
+            // Trailing slashes are ignored when a route is parsed
+            .filter(|segment| *segment != ShapeSegment::Static(""))
+            .collect::<Vec<_>>();
+        if shape
+            .iter()
+            .all(|segment| matches!(segment, ShapeSegment::Static(_)))
+        {
+            continue;
+        }
+
+        match declared
+            .iter()
+            .find(|other| other.redirect == redirect && other.shape == shape)
+        {
+            Some(other) => {
+                let mut error = syn::Error::new(
+                    span,
+                    format!(
+                        "The route `{route}` is ambiguous with `{}`. Both routes match the same paths, so `{route}` is only matched if parsing `{}` fails. Change a static segment of one of the routes or merge them into one route.",
+                        other.route, other.route
+                    ),
+                );
+                error.combine(syn::Error::new(
+                    other.span,
+                    format!("`{}` is declared here", other.route),
+                ));
+                match &mut errors {
+                    Some(errors) => errors.combine(error),
+                    None => errors = Some(error),
+                }
+            }
+            None => declared.push(DeclaredRoute {
+                redirect,
+                shape,
+                route,
+                span,
+            }),
+        }
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
+}
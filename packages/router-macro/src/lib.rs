@@ -16,6 +16,7 @@ use proc_macro2::TokenStream as TokenStream2;
 
 use crate::{layout::LayoutId, route_tree::ParseRouteTree};
 
+mod collision;
 mod hash;
 mod layout;
 mod nest;
@@ -42,6 +43,27 @@ mod segment;
 /// 1. By there specificity this order: Query Routes ("/?:query"), Static Routes ("/route"), Dynamic Routes ("/:route"), Catch All Routes ("/:..route")
 /// 2. By the order they are defined in the enum
 ///
+/// Two routes with dynamic segments that match exactly the same paths are a compile error, even if their dynamic
+/// segments have different names or types. Routes that only have static segments are not checked, and redirects are
+/// only compared with other redirects:
+///
+/// ```rust, compile_fail
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Routable)]
+/// enum Route {
+///     #[route("/users/:id")]
+///     User { id: usize },
+///     // error: The route `/users/:name` is ambiguous with `/users/:id`
+///     #[route("/users/:name")]
+///     UserByName { name: String },
+/// }
+/// # #[component]
+/// # fn User(id: usize) -> Element { VNode::empty() }
+/// # #[component]
+/// # fn UserByName(name: String) -> Element { VNode::empty() }
+/// ```
+///
 /// All features:
 /// ```rust
 /// use dioxus::prelude::*;
@@ -467,6 +489,8 @@ impl RouteEnum {
             children.push(current);
         }
 
+        collision::check_route_collisions(&endpoints, &nests)?;
+
         let myself = Self {
             name: name.clone(),
             endpoints,
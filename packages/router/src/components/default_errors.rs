@@ -17,3 +17,21 @@ pub fn FailureExternalNavigation() -> Element {
         a { onclick: move |_| { router.clear_error() }, "Click here to go back" }
     }
 }
+
+/// The default component to render when no route matches the current path.
+#[allow(non_snake_case)]
+pub fn DefaultNotFound() -> Element {
+    rsx! {
+        h1 { "Page not found" }
+        p { "The page you are looking for doesn't exist." }
+    }
+}
+
+/// The default component to render when access to a route is denied.
+#[allow(non_snake_case)]
+pub fn DefaultForbidden() -> Element {
+    rsx! {
+        h1 { "Forbidden" }
+        p { "You don't have permission to access this page." }
+    }
+}
@@ -0,0 +1,176 @@
+use dioxus_lib::prelude::*;
+
+use crate::components::Link;
+use crate::hooks::Pagination;
+
+/// The properties for a [`Paginator`].
+#[derive(Debug, Props, Clone, PartialEq)]
+pub struct PaginatorProps {
+    /// The pagination to render controls for, from [`use_pagination`](crate::hooks::use_pagination).
+    pub pagination: Pagination,
+
+    /// The total number of items in the list.
+    pub total: usize,
+
+    /// How many pages to show on each side of the current page. The first and last page are always shown, and the
+    /// rest of the pages are replaced with an ellipsis.
+    #[props(default = 1)]
+    pub siblings: usize,
+
+    /// The accessible label of the navigation landmark. Change this if there is more than one paginator on a page.
+    #[props(default = "Pagination".to_string(), into)]
+    pub label: String,
+
+    /// The class attribute for the `nav` element.
+    pub class: Option<String>,
+}
+
+/// Accessible page controls for a list, bound to the query parameters of a [`Pagination`].
+///
+/// The controls are a `nav` landmark with a list of links to the previous page, the pages around the current page and
+/// the next page. The current page is marked with `aria-current="page"`, and the previous and next controls are
+/// marked with `aria-disabled` on the first and last page. The other pages are real links, so they can be opened in a
+/// new tab and work before the app is hydrated.
+///
+/// # Panic
+/// - When the [`Paginator`] is not nested within a [`Router`](crate::components::Router) component.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// # use dioxus_history::{History, MemoryHistory};
+/// # use std::rc::Rc;
+/// #[derive(Clone, Routable)]
+/// enum Route {
+///     #[route("/")]
+///     Index {},
+/// }
+///
+/// #[component]
+/// fn Index() -> Element {
+///     let pagination = use_pagination(10);
+///
+///     rsx! {
+///         Paginator { pagination, total: 35 }
+///     }
+/// }
+/// #
+/// # #[component]
+/// # fn App() -> Element {
+/// #     rsx! { Router::<Route> {} }
+/// # }
+/// #
+/// # let mut vdom = VirtualDom::new(App).with_root_context(
+/// #     Rc::new(MemoryHistory::with_initial_path("/?page=2")) as Rc<dyn History>
+/// # );
+/// # vdom.rebuild_in_place();
+/// # assert_eq!(
+/// #     dioxus_ssr::render(&vdom),
+/// #     r#"<nav aria-label="Pagination"><ul><li><a href="/" rel="prev">Previous</a></li><li><a href="/">1</a></li><li><span aria-current="page">2</span></li><li><a href="/?page=3">3</a></li><li><a href="/?page=4">4</a></li><li><a href="/?page=3" rel="next">Next</a></li></ul></nav>"#
+/// # );
+/// ```
+#[allow(non_snake_case)]
+pub fn Paginator(props: PaginatorProps) -> Element {
+    let PaginatorProps {
+        pagination,
+        total,
+        siblings,
+        label,
+        class,
+    } = props;
+
+    let count = pagination.page_count(total);
+    let current = pagination.page().min(count);
+
+    rsx! {
+        nav { class, aria_label: label,
+            ul {
+                li {
+                    if current > 1 {
+                        Link { to: pagination.href(current - 1), rel: "prev", "Previous" }
+                    } else {
+                        span { aria_disabled: "true", "Previous" }
+                    }
+                }
+                for item in page_items(current, count, siblings) {
+                    li {
+                        {match item {
+                            PageItem::Page(page) if page == current => rsx! {
+                                span { aria_current: "page", "{page}" }
+                            },
+                            PageItem::Page(page) => rsx! {
+                                Link { to: pagination.href(page), "{page}" }
+                            },
+                            PageItem::Gap => rsx! {
+                                span { aria_hidden: "true", "…" }
+                            },
+                        }}
+                    }
+                }
+                li {
+                    if current < count {
+                        Link { to: pagination.href(current + 1), rel: "next", "Next" }
+                    } else {
+                        span { aria_disabled: "true", "Next" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PageItem {
+    Page(usize),
+    Gap,
+}
+
+/// Get the pages to show controls for. The first, last and current page are always shown along with `siblings` pages
+/// on each side of the current page. Gaps that would only hide a single page show that page instead.
+fn page_items(current: usize, count: usize, siblings: usize) -> Vec<PageItem> {
+    let start = current.saturating_sub(siblings).max(1);
+    let end = current.saturating_add(siblings).min(count);
+
+    let mut items = Vec::new();
+    match start {
+        1 => {}
+        2 | 3 => items.extend((1..start).map(PageItem::Page)),
+        _ => {
+            items.push(PageItem::Page(1));
+            items.push(PageItem::Gap);
+        }
+    }
+    items.extend((start..=end).map(PageItem::Page));
+    match count - end {
+        0 => {}
+        1 | 2 => items.extend((end + 1..=count).map(PageItem::Page)),
+        _ => {
+            items.push(PageItem::Gap);
+            items.push(PageItem::Page(count));
+        }
+    }
+
+    items
+}
+
+#[test]
+fn page_items_collapse_distant_pages() {
+    use PageItem::*;
+
+    assert_eq!(page_items(1, 1, 1), vec![Page(1)]);
+    assert_eq!(
+        page_items(2, 4, 1),
+        vec![Page(1), Page(2), Page(3), Page(4)]
+    );
+    assert_eq!(page_items(1, 10, 1), vec![Page(1), Page(2), Gap, Page(10)]);
+    assert_eq!(
+        page_items(5, 10, 1),
+        vec![Page(1), Gap, Page(4), Page(5), Page(6), Gap, Page(10)]
+    );
+    // A gap that would only hide page 2 shows page 2 instead
+    assert_eq!(
+        page_items(4, 10, 1),
+        vec![Page(1), Page(2), Page(3), Page(4), Page(5), Gap, Page(10)]
+    );
+}
@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use dioxus_lib::prelude::*;
+
+use tracing::error;
+
+use crate::utils::use_router_internal::use_router_internal;
+
+/// The properties for a [`RouterProgressBar`].
+#[derive(Debug, Props, Clone, PartialEq)]
+pub struct RouterProgressBarProps {
+    /// How long the router must be loading, in milliseconds, before the bar is shown. Fast navigations finish before
+    /// the delay and never show the bar.
+    #[props(default = 150)]
+    pub delay: u64,
+
+    /// The shortest time, in milliseconds, the bar is shown once it appears. This keeps the bar from flickering when
+    /// the router finishes loading right after the delay.
+    #[props(default = 400)]
+    pub min_duration: u64,
+
+    /// The css color of the bar.
+    #[props(default = "#29d".to_string(), into)]
+    pub color: String,
+
+    /// The css height of the bar.
+    #[props(default = "3px".to_string(), into)]
+    pub height: String,
+
+    /// The class attribute for the bar.
+    pub class: Option<String>,
+}
+
+/// A progress bar at the top of the page that is shown while the router is loading.
+///
+/// The router is loading while a guard from [`RouterContext::begin_loading`] is alive or a future passed to
+/// [`RouterContext::track`] is running. If a loader reports its progress with [`LoadingGuard::set_progress`], the bar
+/// shows that progress. Otherwise it slowly creeps forward until loading finishes.
+///
+/// Only works as descendant of a [`Router`] component, otherwise it will be inactive.
+///
+/// # Panic
+/// - When the [`RouterProgressBar`] is not nested within a [`Router`] component, but only in debug builds.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// #[derive(Clone, Routable)]
+/// #[rustfmt::skip]
+/// enum Route {
+///     #[layout(Layout)]
+///         #[route("/")]
+///         Index {},
+/// }
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         Router::<Route> {}
+///     }
+/// }
+///
+/// #[component]
+/// fn Layout() -> Element {
+///     rsx! {
+///         RouterProgressBar { delay: 100, color: "tomato" }
+///         Outlet::<Route> {}
+///     }
+/// }
+///
+/// #[component]
+/// fn Index() -> Element {
+///     rsx! { "Index" }
+/// }
+/// #
+/// # let mut vdom = VirtualDom::new(App);
+/// # vdom.rebuild_in_place();
+/// # assert!(dioxus_ssr::render(&vdom).ends_with("Index"));
+/// ```
+///
+/// [`RouterContext::begin_loading`]: crate::prelude::RouterContext::begin_loading
+/// [`RouterContext::track`]: crate::prelude::RouterContext::track
+/// [`LoadingGuard::set_progress`]: crate::prelude::LoadingGuard::set_progress
+/// [`Router`]: crate::components::Router
+pub fn RouterProgressBar(props: RouterProgressBarProps) -> Element {
+    let RouterProgressBarProps {
+        delay,
+        min_duration,
+        color,
+        height,
+        class,
+    } = props;
+
+    // hook up to router
+    let router = match use_router_internal() {
+        Some(r) => r,
+        #[allow(unreachable_code)]
+        None => {
+            let msg = "`RouterProgressBar` must have access to a parent router";
+            error!("{msg}, will be inactive");
+            #[cfg(debug_assertions)]
+            panic!("{}", msg);
+            return VNode::empty();
+        }
+    };
+
+    let mut shown = use_signal(|| false);
+    let mut min_elapsed = use_signal(|| true);
+    // Progress updates change the loading state too. Only start and end of loading should restart the delay
+    let is_loading = use_memo(move || router.is_loading());
+
+    // Show the bar once the router has been loading for longer than the delay. If loading finishes first, the
+    // resource restarts and the pending timer is dropped
+    let _delay = use_resource(move || {
+        let loading = is_loading();
+        async move {
+            if !loading || *shown.peek() {
+                return;
+            }
+            sleep(Duration::from_millis(delay)).await;
+            shown.set(true);
+            min_elapsed.set(false);
+            // Spawn the minimum duration in the component so it keeps running when loading finishes
+            spawn(async move {
+                sleep(Duration::from_millis(min_duration)).await;
+                min_elapsed.set(true);
+            });
+        }
+    });
+
+    use_effect(move || {
+        if shown() && min_elapsed() && !is_loading() {
+            shown.set(false);
+        }
+    });
+
+    let loading = is_loading();
+    let hidden = !shown();
+    let (width, opacity, transition) = match (shown(), loading) {
+        (false, _) => ("0%".to_string(), 0, "opacity 300ms ease"),
+        (true, true) => match router.loading_progress() {
+            Some(progress) => (
+                format!("{}%", progress * 100.0),
+                1,
+                "width 200ms ease, opacity 300ms ease",
+            ),
+            None => (
+                "80%".to_string(),
+                1,
+                "width 10s cubic-bezier(0.1, 0.7, 0.3, 1), opacity 300ms ease",
+            ),
+        },
+        (true, false) => (
+            "100%".to_string(),
+            1,
+            "width 200ms ease, opacity 300ms ease",
+        ),
+    };
+
+    rsx! {
+        div {
+            class,
+            role: "progressbar",
+            aria_hidden: "{hidden}",
+            style: "position: fixed; top: 0; left: 0; z-index: 9999; pointer-events: none; height: {height}; background: {color}; width: {width}; opacity: {opacity}; transition: {transition};",
+        }
+    }
+}
+
+/// Wait for a duration with a browser timer on the web and a background thread everywhere else, so the delay works
+/// without a specific async runtime.
+async fn sleep(duration: Duration) {
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let (wake, woken) = futures_channel::oneshot::channel();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            _ = wake.send(());
+        });
+        _ = woken.await;
+    }
+}
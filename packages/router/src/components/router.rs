@@ -1,11 +1,14 @@
 use dioxus_lib::prelude::*;
 
-use std::str::FromStr;
+use std::{cell::RefCell, rc::Rc, str::FromStr};
 
 use crate::{
     prelude::{provide_router_context, Outlet},
     routable::Routable,
+    route_error::{RouteError, RouteStatus},
     router_cfg::RouterConfig,
+    snapshot::RouterSnapshot,
+    utils::use_router_internal::use_router_internal,
 };
 
 /// The props for [`Router`].
@@ -46,13 +49,69 @@ where
     use crate::prelude::{outlet::OutletContext, RouterContext};
 
     use_hook(|| {
-        provide_router_context(RouterContext::new(props.config.call(())));
+        let router = RouterContext::new(props.config.call(()));
+        provide_router_context(router);
+
+        // Save the location in checkpoints so the app can be restored where it was left
+        register_checkpoint(
+            ROUTER_CHECKPOINT,
+            move || Some(router.snapshot().to_string()),
+            move |saved| match saved.parse::<RouterSnapshot>() {
+                Ok(snapshot) => router.restore(snapshot),
+                Err(err) => tracing::warn!("Failed to restore the router: {err}"),
+            },
+        );
 
         provide_context(OutletContext::<R> {
             current_level: 0,
+            modal: false,
             _marker: std::marker::PhantomData,
         });
     });
 
-    rsx! { Outlet::<R> {} }
+    use_drop(|| unregister_checkpoint(ROUTER_CHECKPOINT));
+
+    rsx! {
+        RouteErrorBoundary { Outlet::<R> {} }
+    }
+}
+
+/// The key the router location is saved under in checkpoints
+const ROUTER_CHECKPOINT: &str = "dioxus-router";
+
+/// Catches errors thrown by routes and renders the matching error page from the [`RouterConfig`].
+#[component]
+fn RouteErrorBoundary(children: Element) -> Element {
+    let router = use_router_internal().expect("RouteErrorBoundary must be inside of a router");
+    let errors = use_hook(provide_error_boundary);
+    let errored_route = use_hook(|| Rc::new(RefCell::new(None::<String>)));
+
+    // Once the route changes, clear the errors so the new route gets a chance to render
+    let route = router.full_route_string();
+    if !errors.errors().is_empty() {
+        let mut errored_route = errored_route.borrow_mut();
+        match &*errored_route {
+            Some(errored) if *errored != route => {
+                errors.clear_errors();
+                *errored_route = None;
+            }
+            Some(_) => {}
+            None => *errored_route = Some(route),
+        }
+    }
+
+    let Some(error) = errors.errors().first().cloned() else {
+        return children;
+    };
+
+    let status = match error.downcast::<RouteError>() {
+        Some(route_error) => route_error.status(),
+        None => RouteStatus::InternalServerError,
+    };
+
+    match router.render_error_page(status) {
+        Some(page) => page,
+        // If there is no page for this error, pass it on to the next error boundary
+        None => Err(RenderError::Aborted(error)),
+    }
 }
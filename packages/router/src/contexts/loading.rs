@@ -0,0 +1,40 @@
+use dioxus_lib::prelude::*;
+
+/// The navigations and loaders the router is waiting on
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct LoadingState {
+    pub(crate) pending: usize,
+    pub(crate) progress: Option<f64>,
+}
+
+/// A pending navigation or loader, created with [`RouterContext::begin_loading`](crate::prelude::RouterContext::begin_loading).
+///
+/// The router is loading while any guard is alive. Drop the guard once the data for the route has loaded.
+pub struct LoadingGuard {
+    state: Signal<LoadingState>,
+}
+
+impl LoadingGuard {
+    pub(crate) fn new(mut state: Signal<LoadingState>) -> Self {
+        state.write().pending += 1;
+        Self { state }
+    }
+
+    /// Report how far the loader has come, from `0.0` to `1.0`. The progress is shown by the
+    /// [`RouterProgressBar`](crate::components::RouterProgressBar) instead of an indeterminate bar.
+    pub fn set_progress(&mut self, progress: f64) {
+        self.state.write().progress = Some(progress.clamp(0.0, 1.0));
+    }
+}
+
+impl Drop for LoadingGuard {
+    fn drop(&mut self) {
+        let Ok(mut state) = self.state.try_write() else {
+            return;
+        };
+        state.pending = state.pending.saturating_sub(1);
+        if state.pending == 0 {
+            state.progress = None;
+        }
+    }
+}
@@ -61,4 +61,65 @@ impl Navigator {
     ) -> Option<ExternalNavigationFailure> {
         self.0.replace(target)
     }
+
+    /// Push a new location and save a state payload with its history entry.
+    ///
+    /// See [`RouterContext::push_with_state`] for more details.
+    pub fn push_with_state(
+        &self,
+        target: impl Into<NavigationTarget>,
+        state: &impl serde::Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        self.0.push_with_state(target, state)
+    }
+
+    /// Replace the current location and save a state payload with its history entry.
+    ///
+    /// See [`RouterContext::replace_with_state`] for more details.
+    pub fn replace_with_state(
+        &self,
+        target: impl Into<NavigationTarget>,
+        state: &impl serde::Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        self.0.replace_with_state(target, state)
+    }
+
+    /// Push several locations at once, for example to rebuild the steps of a multi step flow. The router only updates
+    /// once, after every location has been pushed.
+    ///
+    /// Each of the locations will be available to go back to. If one of the targets is external, none of the
+    /// locations are pushed.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_router::prelude::*;
+    /// # #[derive(Routable, Clone, PartialEq, Debug)]
+    /// # enum Route {
+    /// #     #[route("/step/:id")]
+    /// #     Step { id: usize },
+    /// # }
+    /// # #[component]
+    /// # fn Step(id: usize) -> Element { VNode::empty() }
+    /// fn resume_wizard() {
+    ///     navigator().push_many([Route::Step { id: 1 }, Route::Step { id: 2 }]);
+    /// }
+    /// ```
+    pub fn push_many<T: Into<NavigationTarget>>(
+        &self,
+        targets: impl IntoIterator<Item = T>,
+    ) -> Option<ExternalNavigationFailure> {
+        self.0.push_many(targets)
+    }
+
+    /// Replace the current location with the first target and push the rest of the targets after it. The router only
+    /// updates once, after every location has been added.
+    ///
+    /// The current location will **not** be available to go back to. Browsers don't allow removing the locations
+    /// before the current one, so those are kept. If one of the targets is external, the history is not changed.
+    pub fn replace_stack<T: Into<NavigationTarget>>(
+        &self,
+        targets: impl IntoIterator<Item = T>,
+    ) -> Option<ExternalNavigationFailure> {
+        self.0.replace_stack(targets)
+    }
 }
@@ -1,9 +1,13 @@
 use dioxus_lib::prelude::*;
 
-use crate::{routable::Routable, utils::use_router_internal::use_router_internal};
+use crate::{
+    routable::Routable, route_error::RouteStatus, utils::use_router_internal::use_router_internal,
+};
 
 pub(crate) struct OutletContext<R> {
     pub current_level: usize,
+    /// Whether this outlet is inside of a modal route instead of the page under it
+    pub modal: bool,
     pub _marker: std::marker::PhantomData<R>,
 }
 
@@ -11,6 +15,7 @@ impl<R> Clone for OutletContext<R> {
     fn clone(&self) -> Self {
         OutletContext {
             current_level: self.current_level,
+            modal: self.modal,
             _marker: std::marker::PhantomData,
         }
     }
@@ -20,6 +25,7 @@ pub(crate) fn use_outlet_context<R: 'static>() -> OutletContext<R> {
     use_hook(|| {
         try_consume_context().unwrap_or(OutletContext::<R> {
             current_level: 1,
+            modal: false,
             _marker: std::marker::PhantomData,
         })
     })
@@ -36,6 +42,7 @@ impl<R> OutletContext<R> {
         provide_context({
             OutletContext::<R> {
                 current_level: current_level + 1,
+                modal: outlet.modal,
                 _marker: std::marker::PhantomData,
             }
         });
@@ -48,6 +55,53 @@ impl<R> OutletContext<R> {
             };
         }
 
-        router.current::<R>().render(current_level)
+        if current_level == 0 {
+            router.record_modal_background();
+        }
+
+        // Outlets in the page under a modal keep rendering the page instead of the modal route
+        let route = match outlet.modal {
+            true => router.try_current::<R>(),
+            false => router.try_page::<R>(),
+        };
+        let Some(route) = route else {
+            return if current_level == 0 {
+                router
+                    .render_error_page(RouteStatus::NotFound)
+                    .unwrap_or_else(VNode::empty)
+            } else {
+                VNode::empty()
+            };
+        };
+
+        if current_level == 0 {
+            router.set_error_status(None);
+
+            // The page is always rendered in the same place so it stays mounted when a modal opens or closes
+            let modal_open = router.is_modal_open();
+            return rsx! {
+                {route.render(current_level)}
+                if modal_open {
+                    ModalOutlet::<R> {}
+                }
+            };
+        }
+
+        route.render(current_level)
+    }
+}
+
+/// Renders the current modal route on top of the page.
+fn ModalOutlet<R: Routable + Clone>() -> Element {
+    let router = use_router_internal().expect("ModalOutlet must be inside of a router");
+    provide_context(OutletContext::<R> {
+        current_level: 1,
+        modal: true,
+        _marker: std::marker::PhantomData,
+    });
+
+    match router.try_current::<R>() {
+        Some(route) => route.render(0),
+        None => VNode::empty(),
     }
 }
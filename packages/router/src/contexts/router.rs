@@ -1,4 +1,5 @@
 use std::{
+    any::Any,
     collections::HashSet,
     sync::{Arc, Mutex},
 };
@@ -7,8 +8,15 @@ use dioxus_history::history;
 use dioxus_lib::prelude::*;
 
 use crate::{
-    components::child_router::consume_child_route_mapping, navigation::NavigationTarget,
-    prelude::SiteMapSegment, routable::Routable, router_cfg::RouterConfig,
+    components::child_router::consume_child_route_mapping,
+    contexts::loading::{LoadingGuard, LoadingState},
+    navigation::{HistoryLocation, NavigationTarget},
+    prelude::SiteMapSegment,
+    routable::Routable,
+    route_error::RouteStatus,
+    route_matcher::{RouteMatcher, SegmentMatcher},
+    router_cfg::{ErrorPages, RouterConfig},
+    snapshot::RouterSnapshot,
 };
 
 /// This context is set in the root of the virtual dom if there is a router present.
@@ -45,6 +53,8 @@ pub struct ExternalNavigationFailure(pub String);
 pub(crate) type RoutingCallback<R> =
     Arc<dyn Fn(GenericRouterContext<R>) -> Option<NavigationTarget<R>>>;
 pub(crate) type AnyRoutingCallback = Arc<dyn Fn(RouterContext) -> Option<NavigationTarget>>;
+/// A function that checks if a route should be rendered as a modal.
+pub(crate) type ModalRoutes<R> = Arc<dyn Fn(&R) -> bool>;
 
 struct RouterContextInner {
     unresolved_error: Option<ExternalNavigationFailure>,
@@ -54,7 +64,26 @@ struct RouterContextInner {
 
     failure_external_navigation: fn() -> Element,
 
-    internal_route: fn(&str) -> bool,
+    error_pages: ErrorPages,
+
+    /// The status of the error page that was rendered last, if any
+    error_status: Option<RouteStatus>,
+
+    internal_route: Arc<dyn Fn(&str) -> bool>,
+
+    /// The `Arc<dyn RouteMatcher<R>>` for the route type of the router
+    route_matcher: Arc<dyn Any>,
+
+    is_modal_route: Option<ModalRoutes<str>>,
+
+    /// The last page that was rendered that is not a modal route
+    modal_background: Option<String>,
+
+    /// The scope that set the current scroll container
+    scroll_container_owner: Option<ScopeId>,
+
+    /// The navigations and loaders that are still pending
+    loading: Signal<LoadingState>,
 
     site_map: &'static [SiteMapSegment],
 }
@@ -100,6 +129,9 @@ impl RouterContext {
     {
         let subscribers = Arc::new(Mutex::new(HashSet::new()));
         let mapping = consume_child_route_mapping();
+        let matcher: Arc<dyn RouteMatcher<R>> = cfg
+            .route_matcher
+            .unwrap_or_else(|| Arc::new(SegmentMatcher));
 
         let myself = RouterContextInner {
             unresolved_error: None,
@@ -124,7 +156,27 @@ impl RouterContext {
 
             failure_external_navigation: cfg.failure_external_navigation,
 
-            internal_route: |route| R::from_str(route).is_ok(),
+            error_pages: cfg.error_pages,
+            error_status: None,
+
+            internal_route: {
+                let matcher = matcher.clone();
+                Arc::new(move |route: &str| matcher.match_route(route).is_some())
+            },
+            route_matcher: Arc::new(matcher.clone()),
+
+            is_modal_route: cfg.modal_routes.map(|is_modal| {
+                Arc::new(move |route: &str| {
+                    matcher
+                        .match_route(route)
+                        .is_some_and(|route| is_modal(&route))
+                }) as Arc<dyn Fn(&str) -> bool>
+            }),
+            modal_background: None,
+
+            scroll_container_owner: None,
+
+            loading: Signal::new_in_scope(LoadingState::default(), ScopeId::ROOT),
 
             site_map: R::SITE_MAP,
         };
@@ -228,20 +280,205 @@ impl RouterContext {
         self.change_route()
     }
 
+    /// Push a new location and save a state payload with its history entry.
+    ///
+    /// The state survives navigating back and forward, so it can remember per entry UI state like the selected tab.
+    /// Read it again with [`RouterContext::current_location`]. If the target is the current location, only the state
+    /// of the current entry is replaced. External targets ignore the state.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_router::prelude::*;
+    /// # #[derive(Routable, Clone, PartialEq, Debug)]
+    /// # enum Route {
+    /// #     #[route("/settings")]
+    /// #     Settings {},
+    /// # }
+    /// #[component]
+    /// fn Settings() -> Element {
+    ///     let tab = router()
+    ///         .current_location::<Route>()
+    ///         .state::<String>()
+    ///         .unwrap_or_else(|| "general".to_string());
+    ///     rsx! {
+    ///         button {
+    ///             onclick: move |_| _ = router().replace_with_state(Route::Settings {}, &"privacy"),
+    ///             "Privacy"
+    ///         }
+    ///         "Selected: {tab}"
+    ///     }
+    /// }
+    /// ```
+    pub fn push_with_state(
+        &self,
+        target: impl Into<NavigationTarget>,
+        state: &impl serde::Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        let target = target.into();
+        {
+            let mut write = self.inner.write_unchecked();
+            match target {
+                NavigationTarget::Internal(p) => match serialize_state(state) {
+                    Some(state) => history().push_with_state(p, state),
+                    None => history().push(p),
+                },
+                NavigationTarget::External(e) => return write.external(e),
+            }
+        }
+
+        self.change_route()
+    }
+
+    /// Replace the current location and save a state payload with its history entry.
+    ///
+    /// The previous location will **not** be available to go back to. See [`RouterContext::push_with_state`] for more
+    /// details.
+    pub fn replace_with_state(
+        &self,
+        target: impl Into<NavigationTarget>,
+        state: &impl serde::Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        let target = target.into();
+        {
+            let mut write = self.inner.write_unchecked();
+            match target {
+                NavigationTarget::Internal(p) => match serialize_state(state) {
+                    Some(state) => history().replace_with_state(p, state),
+                    None => history().replace(p),
+                },
+                NavigationTarget::External(e) => return write.external(e),
+            }
+        }
+
+        self.change_route()
+    }
+
+    /// Push several locations at once. The router only updates once, after every location has been pushed.
+    ///
+    /// Each of the locations will be available to go back to. If one of the targets is external, none of the
+    /// locations are pushed and the router navigates to the first external target instead.
+    pub fn push_many<T: Into<NavigationTarget>>(
+        &self,
+        targets: impl IntoIterator<Item = T>,
+    ) -> Option<ExternalNavigationFailure> {
+        let paths = match internal_paths(targets) {
+            Ok(paths) => paths,
+            Err(external) => return self.inner.write_unchecked().external(external),
+        };
+        {
+            let _write = self.inner.write_unchecked();
+            let history = history();
+            for path in paths {
+                history.push(path);
+            }
+        }
+
+        self.change_route()
+    }
+
+    /// Replace the current location with the first target and push the rest of the targets after it. The router only
+    /// updates once, after every location has been added.
+    ///
+    /// This is useful at the end of a multi step flow, like a wizard, where going back should not return to the
+    /// last step. Browsers don't allow removing the locations before the current one, so only the current location is
+    /// replaced.
+    ///
+    /// If one of the targets is external, the history is not changed and the router navigates to the first external
+    /// target instead.
+    pub fn replace_stack<T: Into<NavigationTarget>>(
+        &self,
+        targets: impl IntoIterator<Item = T>,
+    ) -> Option<ExternalNavigationFailure> {
+        let paths = match internal_paths(targets) {
+            Ok(paths) => paths,
+            Err(external) => return self.inner.write_unchecked().external(external),
+        };
+        {
+            let _write = self.inner.write_unchecked();
+            let history = history();
+            for (index, path) in paths.into_iter().enumerate() {
+                match index {
+                    0 => history.replace(path),
+                    _ => history.push(path),
+                }
+            }
+        }
+
+        self.change_route()
+    }
+
     /// The route that is currently active.
     pub fn current<R: Routable>(&self) -> R {
+        self.try_current()
+            .unwrap_or_else(|| panic!("route's display implementation must be parsable by FromStr"))
+    }
+
+    /// The route that is currently active and the state that was saved with its history entry.
+    pub fn current_location<R: Routable>(&self) -> HistoryLocation<R> {
+        HistoryLocation::new(self.current(), history().current_state())
+    }
+
+    /// The route that is currently active, or [`None`] if the current path doesn't match any route.
+    pub fn try_current<R: Routable>(&self) -> Option<R> {
         let absolute_route = self.full_route_string();
+        self.parse_route(&absolute_route)
+    }
+
+    /// The route of the page that is rendered under the open modal, or the current route if no modal is open.
+    pub(crate) fn try_page<R: Routable>(&self) -> Option<R> {
+        match self.modal_background() {
+            Some(background) => self.parse_route(&background),
+            None => self.try_current(),
+        }
+    }
+
+    fn parse_route<R: Routable>(&self, absolute_route: &str) -> Option<R> {
         // If this is a child route, map the absolute route to the child route before parsing
         let mapping = consume_child_route_mapping::<R>();
         match mapping.as_ref() {
-            Some(mapping) => mapping
-                .parse_route_from_root_route(&absolute_route)
-                .unwrap_or_else(|| {
-                    panic!("route's display implementation must be parsable by FromStr")
-                }),
-            None => R::from_str(&absolute_route).unwrap_or_else(|_| {
-                panic!("route's display implementation must be parsable by FromStr")
-            }),
+            Some(mapping) => mapping.parse_route_from_root_route(absolute_route),
+            None => {
+                let matcher = self
+                    .inner
+                    .read()
+                    .route_matcher
+                    .downcast_ref::<Arc<dyn RouteMatcher<R>>>()
+                    .cloned();
+                match matcher {
+                    Some(matcher) => matcher.match_route(absolute_route),
+                    None => R::from_str(absolute_route).ok(),
+                }
+            }
+        }
+    }
+
+    /// Check if the current route is a modal route that is rendered on top of another page.
+    ///
+    /// Modal routes are registered with [`RouterConfig::modal_routes`].
+    pub fn is_modal_open(&self) -> bool {
+        self.modal_background().is_some()
+    }
+
+    fn modal_background(&self) -> Option<String> {
+        let current = self.full_route_string();
+        let inner = self.inner.read();
+        let is_modal = inner.is_modal_route.as_ref()?;
+        if is_modal(&current) {
+            inner.modal_background.clone()
+        } else {
+            None
+        }
+    }
+
+    /// Remember the current route as the page to render under modals if it is not a modal route itself.
+    pub(crate) fn record_modal_background(&self) {
+        let current = self.full_route_string();
+        let mut inner = self.inner.write_unchecked();
+        let Some(is_modal) = inner.is_modal_route.clone() else {
+            return;
+        };
+        if !is_modal(&current) {
+            inner.modal_background = Some(current);
         }
     }
 
@@ -281,7 +518,103 @@ impl RouterContext {
             .map(|_| (inner_write.failure_external_navigation)())
     }
 
+    /// The status of the error page the router rendered for the current route, if any.
+    ///
+    /// Server renderers use this to set the status code of the response.
+    pub fn error_status(&self) -> Option<RouteStatus> {
+        self.inner.read().error_status
+    }
+
+    pub(crate) fn set_error_status(&self, status: Option<RouteStatus>) {
+        self.inner.write_unchecked().error_status = status;
+    }
+
+    /// Render the error page for the given status, or [`None`] if there is no page registered for the status.
+    pub(crate) fn render_error_page(&self, status: RouteStatus) -> Option<Element> {
+        let page = self.inner.read().error_pages.get(status)?;
+        self.set_error_status(Some(status));
+        Some(page())
+    }
+
+    pub(crate) fn set_scroll_container(&self, owner: ScopeId, selector: String) {
+        self.inner.write_unchecked().scroll_container_owner = Some(owner);
+        history().set_scroll_container(Some(selector));
+    }
+
+    /// Reset the scroll container to the window if it was set by the owner. If another route already replaced the
+    /// container, it is left alone.
+    pub(crate) fn clear_scroll_container(&self, owner: ScopeId) {
+        let mut inner = self.inner.write_unchecked();
+        if inner.scroll_container_owner == Some(owner) {
+            inner.scroll_container_owner = None;
+            history().set_scroll_container(None);
+        }
+    }
+
+    /// Mark the router as loading until the returned guard is dropped. Use this in loaders that fetch the data for a
+    /// route to show a [`RouterProgressBar`](crate::components::RouterProgressBar) while they run.
+    pub fn begin_loading(&self) -> LoadingGuard {
+        LoadingGuard::new(self.inner.read().loading)
+    }
+
+    /// Mark the router as loading until the future finishes.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_router::prelude::*;
+    /// # async fn fetch_post(id: u32) -> String { String::new() }
+    /// #[component]
+    /// fn Post(id: ReadOnlySignal<u32>) -> Element {
+    ///     let post = use_resource(move || router().track(fetch_post(id())));
+    ///     rsx! { "{post().unwrap_or_default()}" }
+    /// }
+    /// ```
+    pub fn track<F: std::future::Future>(
+        &self,
+        future: F,
+    ) -> impl std::future::Future<Output = F::Output> {
+        let guard = self.begin_loading();
+        async move {
+            let output = future.await;
+            drop(guard);
+            output
+        }
+    }
+
+    /// Check if any navigation or loader is still pending. This subscribes the current component to changes.
+    pub fn is_loading(&self) -> bool {
+        self.inner.read().loading.read().pending > 0
+    }
+
+    /// The progress the loaders reported with [`LoadingGuard::set_progress`], if any
+    pub fn loading_progress(&self) -> Option<f64> {
+        self.inner.read().loading.read().progress
+    }
+
+    /// Take a snapshot of the current location and scroll position that can be restored with
+    /// [`RouterContext::restore`].
+    pub fn snapshot(&self) -> RouterSnapshot {
+        let history = history();
+        RouterSnapshot {
+            route: history.current_route(),
+            scroll: history.scroll_position(),
+        }
+    }
+
+    /// Navigate to the location of a snapshot and scroll back to where it was taken. The current location is replaced,
+    /// so it will **not** be available to go back to.
+    pub fn restore(&self, snapshot: RouterSnapshot) {
+        history().replace(snapshot.route);
+        self.change_route();
+        if let Some((x, y)) = snapshot.scroll {
+            history().scroll_to(x, y);
+        }
+    }
+
     fn change_route(&self) -> Option<ExternalNavigationFailure> {
+        let _span =
+            tracing::info_span!("dioxus::navigation", route = %history().current_route()).entered();
+
         let self_read = self.inner.read();
         if let Some(callback) = &self_read.routing_callback {
             let myself = *self;
@@ -309,6 +642,26 @@ impl RouterContext {
     }
 }
 
+/// Serialize the state of a history entry, or log why it can't be serialized
+fn serialize_state(state: &impl serde::Serialize) -> Option<String> {
+    serde_json::to_string(state)
+        .inspect_err(|err| tracing::error!("Failed to serialize the history state: {err}"))
+        .ok()
+}
+
+/// Collect the paths of several targets, or the first target that is external
+fn internal_paths<T: Into<NavigationTarget>>(
+    targets: impl IntoIterator<Item = T>,
+) -> Result<Vec<String>, String> {
+    targets
+        .into_iter()
+        .map(|target| match target.into() {
+            NavigationTarget::Internal(path) => Ok(path),
+            NavigationTarget::External(external) => Err(external),
+        })
+        .collect()
+}
+
 pub struct GenericRouterContext<R> {
     inner: RouterContext,
     _marker: std::marker::PhantomData<R>,
@@ -364,6 +717,50 @@ where
         self.inner.replace(target.into())
     }
 
+    /// Push a new location and save a state payload with its history entry.
+    ///
+    /// See [`RouterContext::push_with_state`] for more details.
+    pub fn push_with_state(
+        &self,
+        target: impl Into<NavigationTarget<R>>,
+        state: &impl serde::Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        self.inner.push_with_state(target.into(), state)
+    }
+
+    /// Replace the current location and save a state payload with its history entry.
+    ///
+    /// See [`RouterContext::replace_with_state`] for more details.
+    pub fn replace_with_state(
+        &self,
+        target: impl Into<NavigationTarget<R>>,
+        state: &impl serde::Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        self.inner.replace_with_state(target.into(), state)
+    }
+
+    /// Push several locations at once. The router only updates once, after every location has been pushed.
+    ///
+    /// See [`RouterContext::push_many`] for more details.
+    pub fn push_many<T: Into<NavigationTarget<R>>>(
+        &self,
+        targets: impl IntoIterator<Item = T>,
+    ) -> Option<ExternalNavigationFailure> {
+        self.inner
+            .push_many(targets.into_iter().map(Into::<NavigationTarget<R>>::into))
+    }
+
+    /// Replace the current location with the first target and push the rest of the targets after it.
+    ///
+    /// See [`RouterContext::replace_stack`] for more details.
+    pub fn replace_stack<T: Into<NavigationTarget<R>>>(
+        &self,
+        targets: impl IntoIterator<Item = T>,
+    ) -> Option<ExternalNavigationFailure> {
+        self.inner
+            .replace_stack(targets.into_iter().map(Into::<NavigationTarget<R>>::into))
+    }
+
     /// The route that is currently active.
     pub fn current(&self) -> R
     where
@@ -372,6 +769,14 @@ where
         self.inner.current()
     }
 
+    /// The route that is currently active and the state that was saved with its history entry.
+    pub fn current_location(&self) -> HistoryLocation<R>
+    where
+        R: Clone,
+    {
+        self.inner.current_location()
+    }
+
     /// The prefix that is currently active.
     pub fn prefix(&self) -> Option<String> {
         self.inner.prefix()
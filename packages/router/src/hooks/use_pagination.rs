@@ -0,0 +1,112 @@
+use std::ops::Range;
+
+use crate::hooks::{router, use_search_param, SearchParam};
+
+use super::use_search_param::with_query_params;
+
+/// A hook that binds the current page and page size of a list to the `page` and `per_page` query parameters.
+///
+/// The first page is `1`. Both parameters are removed from the url when they are set to their default, so the first
+/// page of a list with the default page size has a clean url. Pass the [`Pagination`] to a
+/// [`Paginator`](crate::components::Paginator) to render page controls, and use [`Pagination::range`] to select the
+/// items of the current page.
+///
+/// # Panic
+/// - When the calling component is not nested within a [`Router`](crate::components::Router) component.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// #[component]
+/// fn Posts() -> Element {
+///     let posts: Vec<String> = (1..=95).map(|i| format!("Post {i}")).collect();
+///     let pagination = use_pagination(20);
+///
+///     rsx! {
+///         for post in &posts[pagination.range(posts.len())] {
+///             p { "{post}" }
+///         }
+///         Paginator { pagination, total: posts.len() }
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn use_pagination(default_page_size: usize) -> Pagination {
+    let page = use_search_param("page", || 1);
+    let page_size = use_search_param("per_page", || default_page_size.max(1));
+    Pagination { page, page_size }
+}
+
+/// The page and page size of a list, stored in the query parameters of the current url. Create one with
+/// [`use_pagination`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct Pagination {
+    page: SearchParam<usize>,
+    page_size: SearchParam<usize>,
+}
+
+impl std::fmt::Debug for Pagination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pagination")
+            .field("page", &self.page.name())
+            .field("page_size", &self.page_size.name())
+            .finish()
+    }
+}
+
+impl Pagination {
+    /// Get the current page, starting at `1`. Reading this subscribes the current component to route changes.
+    ///
+    /// The page is not clamped to the number of pages, because that depends on the number of items. Use
+    /// [`Pagination::range`] to get a range that is always valid.
+    pub fn page(&self) -> usize {
+        self.page.get().max(1)
+    }
+
+    /// Get the number of items on each page. Reading this subscribes the current component to route changes.
+    pub fn page_size(&self) -> usize {
+        self.page_size.get().max(1)
+    }
+
+    /// Navigate to a page
+    pub fn set_page(&self, page: usize) {
+        self.page.set(page.max(1));
+    }
+
+    /// Change the number of items on each page and go back to the first page
+    pub fn set_page_size(&self, page_size: usize) {
+        let route = router().full_route_string();
+        let route = with_query_params(
+            &route,
+            &[
+                (self.page.name(), None),
+                (
+                    self.page_size.name(),
+                    self.page_size.encode(page_size.max(1)),
+                ),
+            ],
+        );
+        router().push(route);
+    }
+
+    /// Get the number of pages for a number of items. There is always at least one page, even if there are no items.
+    pub fn page_count(&self, total: usize) -> usize {
+        total.div_ceil(self.page_size()).max(1)
+    }
+
+    /// Get the range of the items on the current page. If the current page is past the last page, the range of the
+    /// last page is returned.
+    pub fn range(&self, total: usize) -> Range<usize> {
+        let page_size = self.page_size();
+        let page = self.page().min(self.page_count(total));
+        let start = ((page - 1) * page_size).min(total);
+        start..(start + page_size).min(total)
+    }
+
+    /// Get the current route with the page set. This can be used as the target of a
+    /// [`Link`](crate::components::Link).
+    pub fn href(&self, page: usize) -> String {
+        self.page.href(page.max(1))
+    }
+}
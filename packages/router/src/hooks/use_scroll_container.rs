@@ -0,0 +1,66 @@
+use dioxus_lib::prelude::{current_scope_id, use_drop, use_hook};
+
+use crate::utils::use_router_internal::use_router_internal;
+
+/// A hook that makes the router save and restore the scroll position of an element instead of the window.
+///
+/// This is useful for app shells where the window doesn't scroll and only an inner pane does. Call the hook in the
+/// route or layout that renders the scrolling element and pass a css selector that matches it. The container is used
+/// while the component is mounted. Once it unmounts, the router restores the scroll position of the window again.
+///
+/// Scroll restoration is handled by the history provider, so this only has an effect on platforms with a history that
+/// restores scroll positions like the web.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// #[derive(Clone, Routable)]
+/// enum Route {
+///     #[layout(Shell)]
+///         #[route("/")]
+///         Index {},
+/// }
+///
+/// #[component]
+/// fn Shell() -> Element {
+///     use_scroll_container("#content");
+///
+///     rsx! {
+///         nav { "Sidebar" }
+///         main { id: "content", overflow_y: "auto", Outlet::<Route> {} }
+///     }
+/// }
+///
+/// #[component]
+/// fn Index() -> Element {
+///     rsx! { "Index" }
+/// }
+/// #
+/// # #[component]
+/// # fn App() -> Element {
+/// #     rsx! {
+/// #         Router::<Route> {}
+/// #     }
+/// # }
+/// #
+/// # let mut vdom = VirtualDom::new(App);
+/// # vdom.rebuild_in_place();
+/// ```
+///
+/// # Panic
+/// - When the calling component is not nested within a [`Router`] component.
+///
+/// [`Router`]: crate::components::Router
+pub fn use_scroll_container(selector: impl Into<String>) {
+    let router = use_router_internal()
+        .expect("use_scroll_container must be called in a descendant of a Router component");
+
+    let selector = selector.into();
+    let owner = use_hook(move || {
+        let owner = current_scope_id().expect("use_scroll_container must be called in a component");
+        router.set_scroll_container(owner, selector);
+        owner
+    });
+
+    use_drop(move || router.clear_scroll_container(owner));
+}
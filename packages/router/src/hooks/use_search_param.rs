@@ -0,0 +1,191 @@
+use std::{fmt::Display, str::FromStr};
+
+use dioxus_lib::prelude::*;
+
+use crate::hooks::router;
+
+/// A hook that binds a value to a query parameter in the current url.
+///
+/// Reading the value subscribes the component to route changes, so it reruns when the parameter changes. Setting the
+/// value pushes the current route with the new parameter, so the back button restores the previous value. Setting the
+/// parameter to the default removes it from the url.
+///
+/// Unlike the typed query segments of a [`Routable`](crate::prelude::Routable) route, search params work with any
+/// route, even if the route doesn't declare a query segment.
+///
+/// # Panic
+/// - When the calling component is not nested within a [`Router`](crate::components::Router) component.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// #[component]
+/// fn Search() -> Element {
+///     let query = use_search_param("q", String::new);
+///
+///     rsx! {
+///         input {
+///             value: query.get(),
+///             oninput: move |evt| query.replace(evt.value()),
+///         }
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn use_search_param<T: FromStr + Display + Clone + PartialEq + 'static>(
+    name: &'static str,
+    default: impl FnOnce() -> T,
+) -> SearchParam<T> {
+    use_hook(|| SearchParam {
+        name,
+        default: CopyValue::new(default()),
+    })
+}
+
+/// A query parameter in the current url. Create one with [`use_search_param`].
+pub struct SearchParam<T: 'static> {
+    name: &'static str,
+    default: CopyValue<T>,
+}
+
+impl<T> Clone for SearchParam<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SearchParam<T> {}
+
+impl<T> PartialEq for SearchParam<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.default == other.default
+    }
+}
+
+impl<T: FromStr + Display + Clone + PartialEq + 'static> SearchParam<T> {
+    /// Get the name of the query parameter
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the value of the parameter, or the default if the parameter is missing or can't be parsed
+    pub fn get(&self) -> T {
+        let route = router().full_route_string();
+        query_param(&route, self.name)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_else(|| self.default.read().clone())
+    }
+
+    /// Set the parameter and push the new url so the previous value is kept in the history
+    pub fn set(&self, value: T) {
+        router().push(self.href(value));
+    }
+
+    /// Set the parameter and replace the current url. This is useful for values that change often, like a search
+    /// input, that shouldn't add an entry to the history for every change.
+    pub fn replace(&self, value: T) {
+        router().replace(self.href(value));
+    }
+
+    /// Get the current route with the parameter set to a value. This can be used as the target of a
+    /// [`Link`](crate::components::Link).
+    pub fn href(&self, value: T) -> String {
+        let route = router().full_route_string();
+        with_query_params(&route, &[(self.name, self.encode(value))])
+    }
+
+    /// Get the text of the parameter in the url, or `None` if the value is the default and should be removed
+    pub(crate) fn encode(&self, value: T) -> Option<String> {
+        (*self.default.read() != value).then(|| value.to_string())
+    }
+}
+
+/// Split a route into the path, the query without the leading `?`, and the hash including the leading `#`
+fn split_route(route: &str) -> (&str, &str, &str) {
+    let (route, hash) = match route.find('#') {
+        Some(index) => route.split_at(index),
+        None => (route, ""),
+    };
+    let (path, query) = route.split_once('?').unwrap_or((route, ""));
+    (path, query, hash)
+}
+
+fn decode(text: &str) -> String {
+    let text = text.replace('+', " ");
+    urlencoding::decode(&text)
+        .map(|decoded| decoded.into_owned())
+        .unwrap_or(text)
+}
+
+/// Get the decoded value of the first query parameter with a name in a route
+pub(crate) fn query_param(route: &str, name: &str) -> Option<String> {
+    let (_, query, _) = split_route(route);
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        (decode(key) == name).then(|| decode(value))
+    })
+}
+
+/// Set or remove query parameters in a route. Other parameters keep their order, and new parameters are added to
+/// the end of the query.
+pub(crate) fn with_query_params(route: &str, params: &[(&str, Option<String>)]) -> String {
+    let (path, query, hash) = split_route(route);
+
+    let mut pairs: Vec<String> = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter(|pair| {
+            let key = pair.split_once('=').map_or(*pair, |(key, _)| key);
+            let key = decode(key);
+            !params.iter().any(|(name, _)| *name == key)
+        })
+        .map(str::to_string)
+        .collect();
+    for (name, value) in params {
+        if let Some(value) = value {
+            pairs.push(format!(
+                "{}={}",
+                urlencoding::encode(name),
+                urlencoding::encode(value)
+            ));
+        }
+    }
+
+    match pairs.is_empty() {
+        true => format!("{path}{hash}"),
+        false => format!("{path}?{}{hash}", pairs.join("&")),
+    }
+}
+
+#[test]
+fn query_params_are_replaced_in_place() {
+    assert_eq!(
+        query_param("/posts?page=2&q=a+b%21", "q"),
+        Some("a b!".to_string())
+    );
+    assert_eq!(
+        query_param("/posts?page=2#top", "page"),
+        Some("2".to_string())
+    );
+    assert_eq!(query_param("/posts", "page"), None);
+
+    assert_eq!(
+        with_query_params(
+            "/posts?q=rust&page=2#top",
+            &[("page", Some("3".to_string()))]
+        ),
+        "/posts?q=rust&page=3#top"
+    );
+    assert_eq!(
+        with_query_params(
+            "/posts?page=2",
+            &[("page", None), ("per_page", Some("50".to_string()))]
+        ),
+        "/posts?per_page=50"
+    );
+    assert_eq!(
+        with_query_params("/posts?page=2", &[("page", None)]),
+        "/posts"
+    );
+}
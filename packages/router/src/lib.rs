@@ -7,6 +7,9 @@
 
 pub mod navigation;
 pub mod routable;
+pub mod route_error;
+pub mod route_matcher;
+pub mod snapshot;
 
 /// Components interacting with the router.
 pub mod components {
@@ -22,6 +25,12 @@ pub mod components {
     mod outlet;
     pub use outlet::*;
 
+    mod paginator;
+    pub use paginator::*;
+
+    mod progress_bar;
+    pub use progress_bar::*;
+
     mod router;
     pub use router::*;
 
@@ -33,9 +42,11 @@ pub mod components {
 }
 
 mod contexts {
+    pub(crate) mod loading;
     pub(crate) mod navigator;
     pub(crate) mod outlet;
     pub(crate) mod router;
+    pub use loading::LoadingGuard;
     pub use navigator::*;
     pub(crate) use router::*;
     pub use router::{root_router, RouterContext};
@@ -53,6 +64,15 @@ pub mod hooks {
 
     mod use_navigator;
     pub use use_navigator::*;
+
+    mod use_scroll_container;
+    pub use use_scroll_container::*;
+
+    mod use_search_param;
+    pub use use_search_param::*;
+
+    mod use_pagination;
+    pub use use_pagination::*;
 }
 
 pub use hooks::router;
@@ -60,14 +80,17 @@ pub use hooks::router;
 /// A collection of useful items most applications might need.
 pub mod prelude {
     pub use crate::components::{
-        GoBackButton, GoForwardButton, HistoryButtonProps, Link, LinkProps, Outlet, Router,
-        RouterProps,
+        GoBackButton, GoForwardButton, HistoryButtonProps, Link, LinkProps, Outlet, Paginator,
+        PaginatorProps, Router, RouterProgressBar, RouterProgressBarProps, RouterProps,
     };
     pub use crate::contexts::*;
     pub use crate::hooks::*;
     pub use crate::navigation::*;
     pub use crate::routable::*;
+    pub use crate::route_error::*;
+    pub use crate::route_matcher::*;
     pub use crate::router_cfg::RouterConfig;
+    pub use crate::snapshot::RouterSnapshot;
     pub use dioxus_router_macro::Routable;
 
     #[doc(hidden)]
@@ -170,3 +170,37 @@ impl<R: Routable> FromStr for NavigationTarget<R> {
         }
     }
 }
+
+/// The route that is currently active and the state payload that was saved with its history entry.
+///
+/// Get the location with [`RouterContext::current_location`](crate::prelude::RouterContext::current_location). The
+/// state is saved with [`RouterContext::push_with_state`](crate::prelude::RouterContext::push_with_state) or
+/// [`RouterContext::replace_with_state`](crate::prelude::RouterContext::replace_with_state).
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryLocation<R = String> {
+    /// The route that is currently active
+    pub route: R,
+    state: Option<String>,
+}
+
+impl<R> HistoryLocation<R> {
+    pub(crate) fn new(route: R, state: Option<String>) -> Self {
+        Self { route, state }
+    }
+
+    /// Deserialize the state that was saved with the history entry. Returns [`None`] if the entry has no state or the
+    /// state is not a `T`.
+    pub fn state<T: serde::de::DeserializeOwned>(&self) -> Option<T> {
+        let state = self.state.as_deref()?;
+        serde_json::from_str(state)
+            .inspect_err(|err| {
+                tracing::trace!("The history state is not the requested type: {err}")
+            })
+            .ok()
+    }
+
+    /// The serialized state that was saved with the history entry, if any
+    pub fn raw_state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+}
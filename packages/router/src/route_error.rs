@@ -0,0 +1,88 @@
+//! Types pertaining to status-specific error pages.
+
+use std::fmt::Display;
+
+/// An error status the router can render a dedicated page for.
+///
+/// Pages for each status can be registered with [`RouterConfig::error_page`](crate::prelude::RouterConfig::error_page).
+/// During server side rendering, the status code of the page that was rendered is used as the status of the response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RouteStatus {
+    /// Access to the route was denied. Rendered when a route throws [`RouteError::Forbidden`].
+    Forbidden,
+    /// No route matched the current path, or a route threw [`RouteError::NotFound`].
+    NotFound,
+    /// A route failed to render with an error that was not a [`RouteError`].
+    InternalServerError,
+}
+
+impl RouteStatus {
+    /// Get the http status code for this status.
+    pub fn code(&self) -> u16 {
+        match self {
+            RouteStatus::Forbidden => 403,
+            RouteStatus::NotFound => 404,
+            RouteStatus::InternalServerError => 500,
+        }
+    }
+}
+
+impl Display for RouteStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RouteStatus::Forbidden => write!(f, "403 Forbidden"),
+            RouteStatus::NotFound => write!(f, "404 Not Found"),
+            RouteStatus::InternalServerError => write!(f, "500 Internal Server Error"),
+        }
+    }
+}
+
+/// An error a route component can return to render the error page for a specific status.
+///
+/// Layouts can use this to guard all of their child routes.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// # fn is_admin() -> bool { false }
+/// #[component]
+/// fn AdminLayout() -> Element {
+///     if !is_admin() {
+///         return Err(RouteError::Forbidden.into());
+///     }
+///     rsx! { Outlet::<Route> {} }
+/// }
+/// # #[component]
+/// # fn Dashboard() -> Element { VNode::empty() }
+/// # #[derive(Clone, Routable)]
+/// # enum Route {
+/// #     #[layout(AdminLayout)]
+/// #     #[route("/")]
+/// #     Dashboard {},
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RouteError {
+    /// Render the [`RouteStatus::Forbidden`] page.
+    Forbidden,
+    /// Render the [`RouteStatus::NotFound`] page.
+    NotFound,
+}
+
+impl RouteError {
+    /// Get the status of the page this error renders.
+    pub fn status(&self) -> RouteStatus {
+        match self {
+            RouteError::Forbidden => RouteStatus::Forbidden,
+            RouteError::NotFound => RouteStatus::NotFound,
+        }
+    }
+}
+
+impl Display for RouteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.status().fmt(f)
+    }
+}
+
+impl std::error::Error for RouteError {}
@@ -0,0 +1,141 @@
+//! Pluggable matching of paths to routes.
+
+use crate::routable::Routable;
+
+/// Finds the route that matches a path.
+///
+/// The router uses [`SegmentMatcher`] by default, which parses the path with the [`FromStr`](std::str::FromStr)
+/// implementation generated by `#[derive(Routable)]`. A custom matcher can be set with
+/// [`RouterConfig::route_matcher`](crate::prelude::RouterConfig::route_matcher) to use a different matching
+/// strategy, like a radix tree for apps with many routes or matching on data outside of the path.
+///
+/// The router still uses the [`Display`](std::fmt::Display) implementation of the route to build links, so every
+/// route the matcher returns should display as a path the matcher maps back to the same route.
+///
+/// Closures that take a path and return an optional route implement this trait.
+pub trait RouteMatcher<R>: 'static {
+    /// Find the route for a path. The path includes the query and hash. Return `None` if no route matches.
+    fn match_route(&self, path: &str) -> Option<R>;
+}
+
+/// The default [`RouteMatcher`], which matches the path segment by segment against the routes of the `Routable`
+/// enum.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SegmentMatcher;
+
+impl<R: Routable> RouteMatcher<R> for SegmentMatcher {
+    fn match_route(&self, path: &str) -> Option<R> {
+        R::from_str(path).ok()
+    }
+}
+
+impl<R, F> RouteMatcher<R> for F
+where
+    F: Fn(&str) -> Option<R> + 'static,
+{
+    fn match_route(&self, path: &str) -> Option<R> {
+        self(path)
+    }
+}
+
+/// A [`RouteMatcher`] that picks a different matcher for each host the app is served from, so multi tenant apps can
+/// serve different route trees on different domains or subdomains.
+///
+/// The host comes from [`History::current_host`](dioxus_history::History::current_host). During server side
+/// rendering it is the host of the request, and on the web it is the host of the page. Liveview uses the fallback
+/// matcher until the client sends the host of the page after it connects. Renderers that don't know the host always
+/// use the fallback matcher.
+///
+/// Hosts are compared without their port and without case sensitivity. A host that starts with `*.` matches every
+/// subdomain of the rest of the host.
+///
+/// ```rust,no_run
+/// # use dioxus_router::prelude::*;
+/// # use dioxus::prelude::*;
+/// # use std::str::FromStr;
+/// # #[component]
+/// # fn Home() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Dashboard() -> Element { VNode::empty() }
+/// #[derive(Clone, Routable)]
+/// enum Route {
+///     #[route("/")]
+///     Home {},
+///     #[route("/admin")]
+///     Dashboard {},
+/// }
+///
+/// // Serve the admin pages from the root of the admin subdomain
+/// let cfg = RouterConfig::<Route>::default().route_matcher(
+///     HostMatcher::new(SegmentMatcher).host("admin.example.com", |path: &str| {
+///         Route::from_str(&format!("/admin{}", path.trim_end_matches('/'))).ok()
+///     }),
+/// );
+/// ```
+///
+/// Responses cached with incremental rendering are keyed by the path only, so pages that depend on the host should
+/// not be cached.
+pub struct HostMatcher<R> {
+    hosts: Vec<(String, Box<dyn RouteMatcher<R>>)>,
+    fallback: Box<dyn RouteMatcher<R>>,
+}
+
+impl<R: 'static> HostMatcher<R> {
+    /// Create a host matcher that uses `fallback` for hosts that don't have their own matcher
+    pub fn new(fallback: impl RouteMatcher<R>) -> Self {
+        Self {
+            hosts: Vec::new(),
+            fallback: Box::new(fallback),
+        }
+    }
+
+    /// Use `matcher` for requests to `host`. Hosts are checked in the order they were added.
+    pub fn host(mut self, host: impl ToString, matcher: impl RouteMatcher<R>) -> Self {
+        self.hosts
+            .push((host.to_string().to_ascii_lowercase(), Box::new(matcher)));
+        self
+    }
+
+    fn matcher_for(&self, host: Option<&str>) -> &dyn RouteMatcher<R> {
+        let Some(host) = host else {
+            return &*self.fallback;
+        };
+        // Remove the port
+        let host = host
+            .rsplit_once(':')
+            .filter(|(_, port)| port.chars().all(|c| c.is_ascii_digit()))
+            .map_or(host, |(host, _)| host);
+        let host = host.to_ascii_lowercase();
+        self.hosts
+            .iter()
+            .find(|(pattern, _)| match pattern.strip_prefix("*.") {
+                Some(domain) => host
+                    .strip_suffix(domain)
+                    .is_some_and(|subdomain| subdomain.ends_with('.')),
+                None => *pattern == host,
+            })
+            .map_or(&*self.fallback, |(_, matcher)| &**matcher)
+    }
+}
+
+impl<R: 'static> RouteMatcher<R> for HostMatcher<R> {
+    fn match_route(&self, path: &str) -> Option<R> {
+        let host = dioxus_history::history().current_host();
+        self.matcher_for(host.as_deref()).match_route(path)
+    }
+}
+
+#[test]
+fn host_matcher_picks_the_matcher_for_the_host() {
+    let matcher = HostMatcher::new(|_: &str| Some("fallback"))
+        .host("admin.example.com", |_: &str| Some("admin"))
+        .host("*.tenants.example.com", |_: &str| Some("tenant"));
+    let route = |host| matcher.matcher_for(host).match_route("/");
+
+    assert_eq!(route(Some("Admin.Example.com:8080")), Some("admin"));
+    assert_eq!(route(Some("acme.tenants.example.com")), Some("tenant"));
+    assert_eq!(route(Some("tenants.example.com")), Some("fallback"));
+    assert_eq!(route(Some("app.example.com")), Some("fallback"));
+    assert_eq!(route(Some("[::1]")), Some("fallback"));
+    assert_eq!(route(None), Some("fallback"));
+}
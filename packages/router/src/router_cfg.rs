@@ -1,4 +1,7 @@
-use crate::{components::FailureExternalNavigation, prelude::*};
+use crate::{
+    components::{DefaultForbidden, DefaultNotFound, FailureExternalNavigation},
+    prelude::*,
+};
 use dioxus_lib::prelude::*;
 use std::sync::Arc;
 
@@ -29,6 +32,27 @@ use std::sync::Arc;
 pub struct RouterConfig<R> {
     pub(crate) failure_external_navigation: fn() -> Element,
     pub(crate) on_update: Option<RoutingCallback<R>>,
+    pub(crate) error_pages: ErrorPages,
+    pub(crate) modal_routes: Option<ModalRoutes<R>>,
+    pub(crate) route_matcher: Option<Arc<dyn RouteMatcher<R>>>,
+}
+
+/// The components the router renders for each [`RouteStatus`].
+#[derive(Clone, Copy)]
+pub(crate) struct ErrorPages {
+    pub(crate) forbidden: fn() -> Element,
+    pub(crate) not_found: fn() -> Element,
+    pub(crate) internal_server_error: Option<fn() -> Element>,
+}
+
+impl ErrorPages {
+    pub(crate) fn get(&self, status: RouteStatus) -> Option<fn() -> Element> {
+        match status {
+            RouteStatus::Forbidden => Some(self.forbidden),
+            RouteStatus::NotFound => Some(self.not_found),
+            RouteStatus::InternalServerError => self.internal_server_error,
+        }
+    }
 }
 
 impl<R> Default for RouterConfig<R> {
@@ -36,6 +60,13 @@ impl<R> Default for RouterConfig<R> {
         Self {
             failure_external_navigation: FailureExternalNavigation,
             on_update: None,
+            error_pages: ErrorPages {
+                forbidden: DefaultForbidden,
+                not_found: DefaultNotFound,
+                internal_server_error: None,
+            },
+            modal_routes: None,
+            route_matcher: None,
         }
     }
 }
@@ -77,4 +108,115 @@ where
             ..self
         }
     }
+
+    /// A component to render for a specific [`RouteStatus`].
+    ///
+    /// - [`RouteStatus::NotFound`] is rendered when no route matches the current path or a route returns [`RouteError::NotFound`].
+    ///   Defaults to a router-internal component called [`DefaultNotFound`].
+    /// - [`RouteStatus::Forbidden`] is rendered when a route returns [`RouteError::Forbidden`]. Defaults to a router-internal component called [`DefaultForbidden`].
+    /// - [`RouteStatus::InternalServerError`] is rendered when a route fails with any other error. If no component is set, the error is passed on to the
+    ///   nearest error boundary above the router.
+    ///
+    /// During server side rendering, the status code of the response is set to the status of the page that was rendered.
+    ///
+    /// ```rust,no_run
+    /// # use dioxus_router::prelude::*;
+    /// # use dioxus::prelude::*;
+    /// # #[component]
+    /// # fn Index() -> Element {
+    /// #     VNode::empty()
+    /// # }
+    /// #[derive(Clone, Routable)]
+    /// enum Route {
+    ///     #[route("/")]
+    ///     Index {},
+    /// }
+    ///
+    /// fn NotFound() -> Element {
+    ///     rsx! { "There is nothing here" }
+    /// }
+    ///
+    /// let cfg = RouterConfig::<Route>::default().error_page(RouteStatus::NotFound, NotFound);
+    /// ```
+    pub fn error_page(mut self, status: RouteStatus, component: fn() -> Element) -> Self {
+        match status {
+            RouteStatus::Forbidden => self.error_pages.forbidden = component,
+            RouteStatus::NotFound => self.error_pages.not_found = component,
+            RouteStatus::InternalServerError => {
+                self.error_pages.internal_server_error = Some(component)
+            }
+        }
+        self
+    }
+
+    /// Render some routes as modals on top of the page that was open before them.
+    ///
+    /// When the user navigates from a normal route to a route the callback returns `true` for, the previous page
+    /// stays mounted and the modal route is rendered after it. Navigating back closes the modal without rerendering
+    /// the page. If a modal route is opened directly, for example from a shared link, it is rendered as a normal page.
+    ///
+    /// Outlets inside of the page keep rendering the page's route, and outlets inside of the modal render the modal's
+    /// route. [`RouterContext::is_modal_open`] can be used to check if a modal is open.
+    ///
+    /// Defaults to [`None`], which renders every route as a normal page.
+    ///
+    /// ```rust,no_run
+    /// # use dioxus_router::prelude::*;
+    /// # use dioxus::prelude::*;
+    /// # #[component]
+    /// # fn Gallery() -> Element {
+    /// #     VNode::empty()
+    /// # }
+    /// # #[component]
+    /// # fn Photo(id: u32) -> Element {
+    /// #     VNode::empty()
+    /// # }
+    /// #[derive(Clone, Routable)]
+    /// enum Route {
+    ///     #[route("/")]
+    ///     Gallery {},
+    ///     #[route("/photos/:id")]
+    ///     Photo { id: u32 },
+    /// }
+    ///
+    /// let cfg = RouterConfig::<Route>::default().modal_routes(|route| matches!(route, Route::Photo { .. }));
+    /// ```
+    pub fn modal_routes(self, is_modal: impl Fn(&R) -> bool + 'static) -> Self {
+        Self {
+            modal_routes: Some(Arc::new(is_modal)),
+            ..self
+        }
+    }
+
+    /// Set the [`RouteMatcher`] that finds the route for the current path.
+    ///
+    /// Child routers keep parsing their routes with the segment matcher.
+    ///
+    /// Defaults to [`SegmentMatcher`], which parses the path with the [`FromStr`](std::str::FromStr) implementation
+    /// generated by `#[derive(Routable)]`.
+    ///
+    /// ```rust,no_run
+    /// # use dioxus_router::prelude::*;
+    /// # use dioxus::prelude::*;
+    /// # #[component]
+    /// # fn Index() -> Element {
+    /// #     VNode::empty()
+    /// # }
+    /// #[derive(Clone, Routable)]
+    /// enum Route {
+    ///     #[route("/")]
+    ///     Index {},
+    /// }
+    ///
+    /// // Match paths without case sensitivity
+    /// let cfg = RouterConfig::<Route>::default().route_matcher(|path: &str| -> Option<Route> {
+    ///     SegmentMatcher.match_route(&path.to_lowercase())
+    /// });
+    /// ```
+    pub fn route_matcher(self, matcher: impl RouteMatcher<R>) -> Self {
+        Self {
+            route_matcher: Some(Arc::new(matcher)),
+            ..self
+        }
+    }
 }
@@ -0,0 +1,85 @@
+//! Snapshots of the router state that can be restored later.
+
+use std::{fmt::Display, str::FromStr};
+
+/// The location and scroll position of the router.
+///
+/// Create a snapshot with [`RouterContext::snapshot`](crate::prelude::RouterContext::snapshot) and restore it with
+/// [`RouterContext::restore`](crate::prelude::RouterContext::restore). The snapshot can be converted to a string and
+/// parsed again to store it between runs of the app.
+///
+/// ```rust
+/// # use dioxus_router::prelude::RouterSnapshot;
+/// let snapshot = RouterSnapshot {
+///     route: "/blog/1".to_string(),
+///     scroll: Some((0.0, 120.0)),
+/// };
+/// let saved = snapshot.to_string();
+/// assert_eq!(saved.parse::<RouterSnapshot>().unwrap(), snapshot);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouterSnapshot {
+    /// The full route that was active
+    pub route: String,
+
+    /// The scroll position of the scroll container or the window, if the history provider knows it
+    pub scroll: Option<(f64, f64)>,
+}
+
+impl Display for RouterSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.route)?;
+        if let Some((x, y)) = self.scroll {
+            write!(f, "\n{x} {y}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An error that occurs when a [`RouterSnapshot`] can't be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotParseError(String);
+
+impl Display for SnapshotParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse the router snapshot: {}", self.0)
+    }
+}
+
+impl std::error::Error for SnapshotParseError {}
+
+impl FromStr for RouterSnapshot {
+    type Err = SnapshotParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let route = lines
+            .next()
+            .filter(|route| route.starts_with('/'))
+            .ok_or_else(|| {
+                SnapshotParseError("the snapshot must start with a route".to_string())
+            })?;
+        let scroll = match lines.next() {
+            Some(scroll) => {
+                let parse = |coordinate: Option<&str>| {
+                    coordinate.and_then(|coordinate| coordinate.parse::<f64>().ok())
+                };
+                let mut coordinates = scroll.split(' ');
+                match (parse(coordinates.next()), parse(coordinates.next())) {
+                    (Some(x), Some(y)) => Some((x, y)),
+                    _ => {
+                        return Err(SnapshotParseError(format!(
+                            "invalid scroll position {scroll:?}"
+                        )))
+                    }
+                }
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            route: route.to_string(),
+            scroll,
+        })
+    }
+}
@@ -75,3 +75,69 @@ fn without_trailing_slashes_parse() {
         RouteWithoutTrailingSlash::Dynamic { id: 123 }
     );
 }
+
+#[component]
+fn NewUser() -> Element {
+    unimplemented!()
+}
+
+#[component]
+fn User(id: usize) -> Element {
+    unimplemented!()
+}
+
+#[component]
+fn UserPosts(id: usize) -> Element {
+    unimplemented!()
+}
+
+#[component]
+fn File(path: Vec<String>) -> Element {
+    unimplemented!()
+}
+
+#[component]
+fn Person(name: String) -> Element {
+    unimplemented!()
+}
+
+// Routes that share a prefix or a shape with a redirect are not ambiguous
+#[test]
+fn similar_routes_parse() {
+    #[derive(Routable, Clone, PartialEq, Debug)]
+    enum Route {
+        #[route("/")]
+        Root {},
+        #[route("/users/new")]
+        NewUser {},
+        #[route("/users/:id")]
+        User { id: usize },
+        #[route("/users/:id/posts")]
+        UserPosts { id: usize },
+        #[route("/files/:..path")]
+        File { path: Vec<String> },
+        #[redirect("/people/:id", |id: usize| Route::User { id })]
+        #[route("/people/:name")]
+        Person { name: String },
+    }
+
+    assert_eq!(Route::from_str("/users/new").unwrap(), Route::NewUser {});
+    assert_eq!(Route::from_str("/users/1").unwrap(), Route::User { id: 1 });
+    assert_eq!(
+        Route::from_str("/users/1/posts").unwrap(),
+        Route::UserPosts { id: 1 }
+    );
+    assert_eq!(
+        Route::from_str("/files/a/b").unwrap(),
+        Route::File {
+            path: vec!["a".to_string(), "b".to_string()]
+        }
+    );
+    assert_eq!(Route::from_str("/people/1").unwrap(), Route::User { id: 1 });
+    assert_eq!(
+        Route::from_str("/people/ferris").unwrap(),
+        Route::Person {
+            name: "ferris".to_string()
+        }
+    );
+}
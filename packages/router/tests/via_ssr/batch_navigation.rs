@@ -0,0 +1,103 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_history::{History, MemoryHistory};
+use std::rc::Rc;
+
+fn render_after(navigate: impl FnOnce(RouterContext)) -> (VirtualDom, String) {
+    let mut vdom = VirtualDom::new(App)
+        .with_root_context(Rc::new(MemoryHistory::default()) as Rc<dyn History>);
+    vdom.rebuild_in_place();
+    vdom.runtime()
+        .on_scope(ScopeId::APP, || navigate(root_router().unwrap()));
+    vdom.render_immediate(&mut NoOpMutations);
+    let html = dioxus_ssr::render(&vdom);
+    (vdom, html)
+}
+
+#[test]
+fn push_many_keeps_every_step_in_history() {
+    let (mut vdom, html) = render_after(|router| {
+        router.push_many([Route::Step { id: 1 }, Route::Step { id: 2 }]);
+    });
+    assert_eq!(html, "Step 2");
+
+    vdom.runtime()
+        .on_scope(ScopeId::APP, || root_router().unwrap().go_back());
+    vdom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&vdom), "Step 1");
+}
+
+#[test]
+fn replace_stack_replaces_the_current_location() {
+    let (mut vdom, html) = render_after(|router| {
+        router.push(Route::Step { id: 1 });
+        router.replace_stack([Route::Done {}, Route::Step { id: 3 }]);
+    });
+    assert_eq!(html, "Step 3");
+
+    vdom.runtime()
+        .on_scope(ScopeId::APP, || root_router().unwrap().go_back());
+    vdom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&vdom), "Done");
+
+    vdom.runtime()
+        .on_scope(ScopeId::APP, || root_router().unwrap().go_back());
+    vdom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&vdom), "Home");
+}
+
+#[test]
+fn external_targets_leave_the_history_unchanged() {
+    let external =
+        || -> NavigationTarget { NavigationTarget::External("https://dioxuslabs.com".to_string()) };
+    let (vdom, _) = render_after(|router| {
+        let failure = router.push_many([
+            NavigationTarget::from(Route::Step { id: 1 }),
+            external(),
+            NavigationTarget::from(Route::Step { id: 2 }),
+        ]);
+        assert!(failure.is_some());
+
+        let failure = router.replace_stack([NavigationTarget::from(Route::Done {}), external()]);
+        assert!(failure.is_some());
+    });
+
+    vdom.runtime().on_scope(ScopeId::APP, || {
+        let router = root_router().unwrap();
+        assert!(!router.can_go_back());
+        assert_eq!(router.current::<Route>(), Route::Home {});
+    });
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/step/:id")]
+    Step { id: usize },
+    #[route("/done")]
+    Done {},
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "Home" }
+}
+
+#[component]
+fn Step(id: usize) -> Element {
+    rsx! { "Step {id}" }
+}
+
+#[component]
+fn Done() -> Element {
+    rsx! { "Done" }
+}
+
+#[component]
+fn App() -> Element {
+    rsx! {
+        Router::<Route> {}
+    }
+}
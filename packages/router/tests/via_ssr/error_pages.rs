@@ -0,0 +1,134 @@
+use dioxus::prelude::*;
+use dioxus_history::{History, MemoryHistory};
+use dioxus_router::components::HistoryProvider;
+use std::rc::Rc;
+
+fn render(path: &str, config: fn() -> RouterConfig<Route>) -> String {
+    render_with_status(path, config).0
+}
+
+/// Render the page like the fullstack server does and return the status it would send with the page
+fn render_with_status(path: &str, config: fn() -> RouterConfig<Route>) -> (String, Option<u16>) {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: path.to_string(),
+        },
+    )
+    .with_root_context(config);
+    vdom.rebuild_in_place();
+    // Errors are caught on the next render after they are thrown
+    vdom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    let status = vdom.in_runtime(|| {
+        dioxus_router::prelude::root_router()
+            .and_then(|router| router.error_status())
+            .map(|status| status.code())
+    });
+    (dioxus_ssr::render(&vdom), status)
+}
+
+#[test]
+fn unmatched_routes_render_not_found() {
+    assert_eq!(
+        render("/missing", RouterConfig::default),
+        "<h1>Page not found</h1><p>The page you are looking for doesn&#x27;t exist.</p>"
+    );
+    assert_eq!(
+        render("/missing", || RouterConfig::default()
+            .error_page(RouteStatus::NotFound, not_found)),
+        "Custom not found"
+    );
+}
+
+#[test]
+fn route_errors_render_the_matching_page() {
+    assert_eq!(
+        render("/admin", RouterConfig::default),
+        "<h1>Forbidden</h1><p>You don&#x27;t have permission to access this page.</p>"
+    );
+    assert_eq!(
+        render("/broken", || RouterConfig::default().error_page(
+            RouteStatus::InternalServerError,
+            internal_server_error
+        )),
+        "Custom internal server error"
+    );
+}
+
+#[test]
+fn matched_routes_render_normally() {
+    assert_eq!(render("/", RouterConfig::default), "Home");
+}
+
+#[test]
+fn error_pages_report_their_status() {
+    assert_eq!(render_with_status("/", RouterConfig::default).1, None);
+    assert_eq!(
+        render_with_status("/missing", RouterConfig::default).1,
+        Some(404)
+    );
+
+    let (html, status) = render_with_status("/admin", RouterConfig::default);
+    assert_eq!(status, Some(403));
+    assert!(html.contains("Forbidden"));
+
+    let (html, status) = render_with_status("/broken", || {
+        RouterConfig::default().error_page(RouteStatus::InternalServerError, internal_server_error)
+    });
+    assert_eq!(status, Some(500));
+    assert_eq!(html, "Custom internal server error");
+}
+
+#[derive(Clone, Routable, Debug, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[layout(Guard)]
+        #[route("/admin")]
+        Admin {},
+    #[end_layout]
+    #[route("/broken")]
+    Broken {},
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "Home" }
+}
+
+#[component]
+fn Guard() -> Element {
+    Err(RouteError::Forbidden.into())
+}
+
+#[component]
+fn Admin() -> Element {
+    rsx! { "Admin" }
+}
+
+#[component]
+fn Broken() -> Element {
+    Err(RenderError::Aborted(dioxus::CapturedError::from_display(
+        "broken",
+    )))
+}
+
+fn not_found() -> Element {
+    rsx! { "Custom not found" }
+}
+
+fn internal_server_error() -> Element {
+    rsx! { "Custom internal server error" }
+}
+
+#[component]
+fn App(path: String) -> Element {
+    let config = use_context::<fn() -> RouterConfig<Route>>();
+    rsx! {
+        HistoryProvider {
+            history: move |_| Rc::new(MemoryHistory::with_initial_path(path.clone())) as Rc<dyn History>,
+            Router::<Route> { config: move |_| config() }
+        }
+    }
+}
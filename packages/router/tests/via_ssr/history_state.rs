@@ -0,0 +1,84 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_history::{History, MemoryHistory};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+#[test]
+fn state_is_restored_when_navigating_back() {
+    let mut vdom = VirtualDom::new(App)
+        .with_root_context(Rc::new(MemoryHistory::default()) as Rc<dyn History>);
+    vdom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&vdom), "Settings: general");
+
+    let navigate = |vdom: &mut VirtualDom, f: fn(RouterContext)| {
+        vdom.runtime()
+            .on_scope(ScopeId::APP, || f(root_router().unwrap()));
+        vdom.render_immediate(&mut NoOpMutations);
+        dioxus_ssr::render(vdom)
+    };
+
+    let html = navigate(&mut vdom, |router| {
+        router.replace_with_state(Route::Settings {}, &Tab::Privacy);
+    });
+    assert_eq!(html, "Settings: privacy");
+
+    let html = navigate(&mut vdom, |router| {
+        router.push(Route::About {});
+    });
+    assert_eq!(html, "About");
+
+    let html = navigate(&mut vdom, |router| {
+        assert_eq!(router.current_location::<Route>().raw_state(), None);
+        router.go_back();
+    });
+    assert_eq!(html, "Settings: privacy");
+
+    // Pushing the current route again only replaces its state
+    let html = navigate(&mut vdom, |router| {
+        router.push_with_state(Route::Settings {}, &Tab::General);
+        assert!(!router.can_go_back());
+        assert!(router.can_go_forward());
+    });
+    assert_eq!(html, "Settings: general");
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+enum Tab {
+    General,
+    Privacy,
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/")]
+    Settings {},
+    #[route("/about")]
+    About {},
+}
+
+#[component]
+fn Settings() -> Element {
+    let tab = router()
+        .current_location::<Route>()
+        .state::<Tab>()
+        .unwrap_or(Tab::General);
+    let tab = match tab {
+        Tab::General => "general",
+        Tab::Privacy => "privacy",
+    };
+    rsx! { "Settings: {tab}" }
+}
+
+#[component]
+fn About() -> Element {
+    rsx! { "About" }
+}
+
+#[component]
+fn App() -> Element {
+    rsx! {
+        Router::<Route> {}
+    }
+}
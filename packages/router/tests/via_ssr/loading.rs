@@ -0,0 +1,76 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_history::{History, MemoryHistory};
+use std::{rc::Rc, time::Duration};
+
+#[test]
+fn loading_guards_track_pending_loaders() {
+    let mut vdom = VirtualDom::new(App)
+        .with_root_context(Rc::new(MemoryHistory::default()) as Rc<dyn History>);
+    vdom.rebuild_in_place();
+    assert!(dioxus_ssr::render(&vdom).contains(r#"aria-hidden="true""#));
+
+    vdom.runtime().on_scope(ScopeId::APP, || {
+        let router = root_router().unwrap();
+        assert!(!router.is_loading());
+
+        let first = router.begin_loading();
+        let mut second = router.begin_loading();
+        second.set_progress(1.5);
+        assert!(router.is_loading());
+        assert_eq!(router.loading_progress(), Some(1.0));
+
+        drop(second);
+        assert!(router.is_loading());
+        drop(first);
+        assert!(!router.is_loading());
+        assert_eq!(router.loading_progress(), None);
+    });
+}
+
+#[tokio::test]
+async fn progress_updates_dont_restart_the_delay() {
+    let mut vdom = VirtualDom::new(App)
+        .with_root_context(Rc::new(MemoryHistory::default()) as Rc<dyn History>);
+    vdom.rebuild_in_place();
+
+    let mut guard = vdom
+        .runtime()
+        .on_scope(ScopeId::APP, || root_router().unwrap().begin_loading());
+    // Report progress more often than the delay. The bar still appears once the delay passes
+    let shown = tokio::time::timeout(Duration::from_secs(5), async {
+        for step in 0.. {
+            _ = tokio::time::timeout(Duration::from_millis(10), vdom.wait_for_work()).await;
+            vdom.runtime()
+                .on_scope(ScopeId::APP, || guard.set_progress(step as f64 / 1000.0));
+            vdom.render_immediate(&mut NoOpMutations);
+            if dioxus_ssr::render(&vdom).contains(r#"aria-hidden="false""#) {
+                break;
+            }
+        }
+    })
+    .await;
+    assert!(shown.is_ok());
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/")]
+    Home {},
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! {
+        RouterProgressBar { delay: 50 }
+        "Home"
+    }
+}
+
+#[component]
+fn App() -> Element {
+    rsx! {
+        Router::<Route> {}
+    }
+}
@@ -1,5 +1,13 @@
+mod batch_navigation;
+mod error_pages;
+mod history_state;
 mod link;
+mod loading;
+mod modal_routes;
 mod navigation;
 mod outlet;
+mod pagination;
 mod redirect;
+mod route_matcher;
+mod snapshot;
 mod without_index;
@@ -0,0 +1,77 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_history::{History, MemoryHistory};
+use dioxus_router::components::HistoryProvider;
+use std::{rc::Rc, sync::atomic::AtomicUsize};
+
+#[test]
+fn modal_routes_render_on_top_of_the_previous_page() {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: "/".to_string(),
+        },
+    );
+    vdom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&vdom), "Gallery");
+
+    // Gallery opens the photo in an effect after the first render
+    vdom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&vdom), "GalleryPhoto 1");
+}
+
+#[test]
+fn modal_routes_opened_directly_render_as_a_page() {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: "/photos/2".to_string(),
+        },
+    );
+    vdom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&vdom), "Photo 2");
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/")]
+    Gallery {},
+    #[route("/photos/:id")]
+    Photo { id: u32 },
+}
+
+#[component]
+fn Gallery() -> Element {
+    static GALLERIES_CREATED: AtomicUsize = AtomicUsize::new(0);
+    use_hook(|| {
+        let galleries_created =
+            GALLERIES_CREATED.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        if galleries_created > 0 {
+            panic!("the page under a modal should stay mounted when the modal opens");
+        }
+    });
+
+    use_effect(|| {
+        router().push(Route::Photo { id: 1 });
+    });
+
+    rsx! { "Gallery" }
+}
+
+#[component]
+fn Photo(id: u32) -> Element {
+    rsx! { "Photo {id}" }
+}
+
+#[component]
+fn App(path: String) -> Element {
+    rsx! {
+        HistoryProvider {
+            history: move |_| Rc::new(MemoryHistory::with_initial_path(path.clone())) as Rc<dyn History>,
+            Router::<Route> {
+                config: |_| RouterConfig::default().modal_routes(|route| matches!(route, Route::Photo { .. })),
+            }
+        }
+    }
+}
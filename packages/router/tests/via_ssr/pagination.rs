@@ -0,0 +1,114 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_history::{History, MemoryHistory};
+
+const TOTAL: usize = 23;
+
+thread_local! {
+    // The pagination of the posts page and the scope it was created in, which is inside the router
+    static PAGINATION: Cell<Option<(Pagination, ScopeId)>> = const { Cell::new(None) };
+}
+
+fn render_at(path: &str) -> (VirtualDom, String) {
+    let mut vdom = VirtualDom::new(App)
+        .with_root_context(Rc::new(MemoryHistory::with_initial_path(path)) as Rc<dyn History>);
+    vdom.rebuild_in_place();
+    let html = dioxus_ssr::render(&vdom);
+    (vdom, html)
+}
+
+#[test]
+fn pages_are_read_from_the_query() {
+    let (_, html) = render_at("/posts?per_page=5&page=3");
+    assert_eq!(
+        html,
+        concat!(
+            "<p>11,12,13,14,15</p>",
+            r#"<nav aria-label="Pagination"><ul>"#,
+            r#"<li><a href="/posts?per_page=5&page=2" rel="prev">Previous</a></li>"#,
+            r#"<li><a href="/posts?per_page=5">1</a></li>"#,
+            r#"<li><a href="/posts?per_page=5&page=2">2</a></li>"#,
+            r#"<li><span aria-current="page">3</span></li>"#,
+            r#"<li><a href="/posts?per_page=5&page=4">4</a></li>"#,
+            r#"<li><a href="/posts?per_page=5&page=5">5</a></li>"#,
+            r#"<li><a href="/posts?per_page=5&page=4" rel="next">Next</a></li>"#,
+            "</ul></nav>"
+        )
+    );
+}
+
+#[test]
+fn pages_past_the_end_show_the_last_page() {
+    let (_, html) = render_at("/posts?page=9");
+    assert_eq!(
+        html,
+        concat!(
+            "<p>21,22,23</p>",
+            r#"<nav aria-label="Pagination"><ul>"#,
+            r#"<li><a href="/posts?page=2" rel="prev">Previous</a></li>"#,
+            r#"<li><a href="/posts">1</a></li>"#,
+            r#"<li><a href="/posts?page=2">2</a></li>"#,
+            r#"<li><span aria-current="page">3</span></li>"#,
+            r#"<li><span aria-disabled="true">Next</span></li>"#,
+            "</ul></nav>"
+        )
+    );
+}
+
+#[test]
+fn changing_the_page_size_goes_back_to_the_first_page() {
+    let (mut vdom, _) = render_at("/posts?q=rust&page=2");
+
+    let (pagination, scope) = PAGINATION.with(Cell::get).unwrap();
+    let route = vdom.runtime().on_scope(scope, || {
+        pagination.set_page_size(20);
+        root_router().unwrap().full_route_string()
+    });
+    assert_eq!(route, "/posts?q=rust&per_page=20");
+
+    vdom.render_immediate(&mut NoOpMutations);
+    assert!(dioxus_ssr::render(&vdom)
+        .starts_with("<p>1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20</p>"));
+
+    // Setting the default page size removes the parameter
+    let (pagination, scope) = PAGINATION.with(Cell::get).unwrap();
+    let route = vdom.runtime().on_scope(scope, || {
+        pagination.set_page_size(10);
+        root_router().unwrap().full_route_string()
+    });
+    assert_eq!(route, "/posts?q=rust");
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/posts")]
+    Posts {},
+}
+
+#[component]
+fn Posts() -> Element {
+    let pagination = use_pagination(10);
+    PAGINATION.with(|cell| cell.set(Some((pagination, current_scope_id().unwrap()))));
+
+    let posts = pagination
+        .range(TOTAL)
+        .map(|index| (index + 1).to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    rsx! {
+        p { "{posts}" }
+        Paginator { pagination, total: TOTAL }
+    }
+}
+
+#[component]
+fn App() -> Element {
+    rsx! {
+        Router::<Route> {}
+    }
+}
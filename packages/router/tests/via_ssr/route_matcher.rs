@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+use dioxus_history::{History, MemoryHistory};
+use dioxus_router::components::HistoryProvider;
+use std::rc::Rc;
+
+#[test]
+fn custom_route_matchers_find_the_current_route() {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: "/BLOG".to_string(),
+        },
+    );
+    vdom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&vdom), "Blog");
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/blog")]
+    Blog {},
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "Home" }
+}
+
+#[component]
+fn Blog() -> Element {
+    rsx! { "Blog" }
+}
+
+fn case_insensitive(path: &str) -> Option<Route> {
+    SegmentMatcher.match_route(&path.to_lowercase())
+}
+
+#[component]
+fn App(path: String) -> Element {
+    rsx! {
+        HistoryProvider {
+            history: move |_| Rc::new(MemoryHistory::with_initial_path(path.clone())) as Rc<dyn History>,
+            Router::<Route> {
+                config: |_| RouterConfig::default().route_matcher(case_insensitive),
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_history::{History, MemoryHistory};
+use std::rc::Rc;
+
+#[test]
+fn restoring_a_snapshot_navigates_to_its_route() {
+    let mut vdom = VirtualDom::new(App)
+        .with_root_context(Rc::new(MemoryHistory::default()) as Rc<dyn History>);
+    vdom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&vdom), "Home");
+
+    let snapshot = vdom.runtime().on_scope(ScopeId::APP, || {
+        let router = root_router().unwrap();
+        router.restore("/blog".parse().unwrap());
+        router.snapshot()
+    });
+    assert_eq!(
+        snapshot,
+        RouterSnapshot {
+            route: "/blog".to_string(),
+            scroll: None,
+        }
+    );
+
+    vdom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&vdom), "Blog");
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/blog")]
+    Blog {},
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "Home" }
+}
+
+#[component]
+fn Blog() -> Element {
+    rsx! { "Blog" }
+}
+
+#[component]
+fn App() -> Element {
+    rsx! {
+        Router::<Route> {}
+    }
+}
@@ -244,6 +244,7 @@ impl HotReloadResult {
             BodyNode::Component(component) => self.hotreload_component::<Ctx>(component),
             BodyNode::ForLoop(forloop) => self.hotreload_for_loop::<Ctx>(forloop),
             BodyNode::IfChain(ifchain) => self.hotreload_if_chain::<Ctx>(ifchain),
+            BodyNode::Match(match_expr) => self.hotreload_match::<Ctx>(match_expr),
             BodyNode::RawExpr(expr) => self.hotreload_raw_expr(expr),
             BodyNode::Element(_) => Some(()),
         }
@@ -565,6 +566,84 @@ impl HotReloadResult {
         Some(chain_templates)
     }
 
+    /// Hot reload a match expression
+    fn hotreload_match<Ctx: HotReloadingContext>(&mut self, new_match: &MatchExpr) -> Option<()> {
+        let mut best_match = None;
+        let mut best_score = usize::MAX;
+
+        let matches = self
+            .full_rebuild_state
+            .dynamic_nodes
+            .inner
+            .iter()
+            .enumerate()
+            .filter_map(|(index, node)| {
+                if let BodyNode::Match(match_expr) = &node.inner {
+                    return Some((index, match_expr));
+                }
+                None
+            });
+
+        // Find the match that has the same expression and arms and wastes the least dynamic items
+        for (index, old_match) in matches {
+            let Some(arm_templates) = Self::diff_matches::<Ctx>(
+                old_match,
+                new_match,
+                self.full_rebuild_state.name.clone(),
+            ) else {
+                continue;
+            };
+            let score = arm_templates
+                .iter()
+                .map(|t| t.full_rebuild_state.unused_dynamic_items())
+                .sum();
+            if score < best_score {
+                best_score = score;
+                best_match = Some((index, arm_templates));
+            }
+        }
+
+        // If we found a hot reloadable match, hotreload it
+        let (index, arm_templates) = best_match?;
+        // Mark the match as used
+        self.full_rebuild_state.dynamic_nodes.inner[index]
+            .used
+            .set(true);
+        // Merge the hot reload changes into the current state
+        for template in arm_templates {
+            self.extend(template);
+        }
+
+        // Push the new match as a dynamic node
+        self.dynamic_nodes
+            .push(HotReloadDynamicNode::Dynamic(index));
+
+        Some(())
+    }
+
+    /// Diff the arms of two match expressions. The expression, patterns and guards must be the same
+    fn diff_matches<Ctx: HotReloadingContext>(
+        old_match: &MatchExpr,
+        new_match: &MatchExpr,
+        name: String,
+    ) -> Option<Vec<Self>> {
+        if old_match.expr != new_match.expr || old_match.arms.len() != new_match.arms.len() {
+            return None;
+        }
+
+        old_match
+            .arms
+            .iter()
+            .zip(&new_match.arms)
+            .map(|(old_arm, new_arm)| {
+                if old_arm.pat != new_arm.pat || old_arm.guard != new_arm.guard {
+                    return None;
+                }
+                Self::new::<Ctx>(&old_arm.body, &new_arm.body, name.clone())
+            })
+            .collect()
+    }
+
     /// Take a new template body and return the attributes that can be hot reloaded from the last build
     ///
     /// IE if we shuffle attributes, remove attributes or add new attributes with the same dynamic segments, around we should be able to hot reload them.
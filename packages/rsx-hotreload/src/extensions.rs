@@ -97,6 +97,9 @@ pub fn to_template_node<Ctx: HotReloadingContext>(node: &BodyNode) -> dioxus_cor
         BodyNode::IfChain(chain) => TemplateNode::Dynamic {
             id: chain.dyn_idx.get(),
         },
+        BodyNode::Match(match_expr) => TemplateNode::Dynamic {
+            id: match_expr.dyn_idx.get(),
+        },
     }
 }
 pub fn text_to_template_node(node: &TextNode) -> TemplateNode {
@@ -654,6 +654,42 @@ fn if_chains() {
     dbg!(very_complex_chain);
 }
 
+#[test]
+fn match_arms() {
+    let valid = can_hotreload(
+        quote! {
+            match value {
+                Some(value) => { "foo {value}" }
+                None => { "none" }
+            }
+        },
+        quote! {
+            match value {
+                Some(value) => { "bar {value}" }
+                None => { "empty" }
+            }
+        },
+    );
+    assert!(valid);
+
+    // Changing the arms themselves requires a rebuild
+    let changed_pattern = can_hotreload(
+        quote! {
+            match value {
+                Some(value) => { "foo {value}" }
+                None => { "none" }
+            }
+        },
+        quote! {
+            match value {
+                Some(value) if value > 1 => { "foo {value}" }
+                _ => { "none" }
+            }
+        },
+    );
+    assert!(!changed_pattern);
+}
+
 #[test]
 fn component_bodies() {
     let valid = can_hotreload(
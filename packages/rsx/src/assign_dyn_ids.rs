@@ -58,9 +58,10 @@ impl<'a> DynIdVisitor<'a> {
             }
 
             // Raw exprs are always dynamic
-            BodyNode::RawExpr(_) | BodyNode::ForLoop(_) | BodyNode::IfChain(_) => {
-                self.assign_path_to_node(node)
-            }
+            BodyNode::RawExpr(_)
+            | BodyNode::ForLoop(_)
+            | BodyNode::IfChain(_)
+            | BodyNode::Match(_) => self.assign_path_to_node(node),
             BodyNode::Component(component) => {
                 self.assign_path_to_node(node);
                 let mut index = 0;
@@ -5,6 +5,7 @@ use quote::{quote, ToTokens, TokenStreamExt};
 use std::fmt::{Display, Formatter};
 use syn::{
     parse::{Parse, ParseStream},
+    parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
     token::Brace,
@@ -185,6 +186,10 @@ impl ToTokens for Element {
                 let id = exp.dyn_idx.get();
                 quote! { dioxus_core::TemplateNode::Dynamic { id: #id } }
             }
+            BodyNode::Match(exp) => {
+                let id = exp.dyn_idx.get();
+                quote! { dioxus_core::TemplateNode::Dynamic { id: #id } }
+            }
         });
 
         let ns = ns(quote!(NAME_SPACE));
@@ -223,6 +228,7 @@ impl Element {
     /// div {
     ///     class: "abc-def",
     ///     class: if some_expr { "abc" },
+    ///     class: some_option,
     /// }
     /// ```
     fn merge_attributes(&mut self) {
@@ -281,6 +287,24 @@ impl Element {
                     continue;
                 }
 
+                // Merge expressions like `class: active.then_some("active")` into the output. Options that are
+                // `None` merge as an empty string
+                if let AttributeValue::AttrExpr(_) | AttributeValue::Shorthand(_) =
+                    &matching_attr.value
+                {
+                    let value = &matching_attr.value;
+                    out.push_expr(parse_quote! {
+                        match dioxus_core::prelude::IntoAttributeValue::into_value(#value) {
+                            dioxus_core::AttributeValue::Text(value) => value,
+                            dioxus_core::AttributeValue::Float(value) => value.to_string(),
+                            dioxus_core::AttributeValue::Int(value) => value.to_string(),
+                            dioxus_core::AttributeValue::Bool(value) => value.to_string(),
+                            _ => ::std::string::String::new(),
+                        }
+                    });
+                    continue;
+                }
+
                 Self::add_merging_non_string_diagnostic(
                     &mut self.diagnostics,
                     matching_attr.span(),
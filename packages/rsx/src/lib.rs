@@ -55,6 +55,7 @@ mod component;
 mod element;
 mod forloop;
 mod ifchain;
+mod matchexpr;
 mod node;
 mod raw_expr;
 mod rsx_block;
@@ -93,6 +94,7 @@ pub(crate) mod innerlude {
     pub use crate::forloop::*;
     pub use crate::ifchain::*;
     pub use crate::location::*;
+    pub use crate::matchexpr::*;
     pub use crate::node::*;
     pub use crate::raw_expr::*;
     pub use crate::rsx_block::*;
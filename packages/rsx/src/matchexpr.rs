@@ -0,0 +1,197 @@
+use crate::location::DynIdx;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use quote::{ToTokens, TokenStreamExt};
+use syn::{
+    parse::{Parse, ParseStream},
+    token::Brace,
+    Expr, Pat, Result, Token,
+};
+
+use crate::{BodyNode, TemplateBody};
+
+/// A match expression with rsx arms
+///
+/// ```rust, ignore
+/// match status {
+///     Status::Loading => { "Loading..." }
+///     Status::Error(err) if err.is_fatal() => { p { "Fatal error: {err}" } }
+///     Status::Ready(data) => { DataView { data } }
+/// }
+/// ```
+///
+/// Every arm must be a braced rsx body. Match expressions with any other arms are parsed as a raw expression
+/// instead, so arms like `value => rsx! { div {} }` keep working.
+#[non_exhaustive]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct MatchExpr {
+    pub match_token: Token![match],
+    pub expr: Box<Expr>,
+    pub brace: Brace,
+    pub arms: Vec<MatchArm>,
+    pub dyn_idx: DynIdx,
+}
+
+/// An arm of a [`MatchExpr`]
+#[non_exhaustive]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct MatchArm {
+    pub pat: Pat,
+    pub guard: Option<(Token![if], Box<Expr>)>,
+    pub fat_arrow_token: Token![=>],
+    pub brace: Brace,
+    pub body: TemplateBody,
+}
+
+impl MatchExpr {
+    pub fn for_each_branch(&self, f: &mut impl FnMut(&TemplateBody)) {
+        for arm in &self.arms {
+            f(&arm.body);
+        }
+    }
+}
+
+impl Parse for MatchExpr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let match_token = input.parse()?;
+
+        // stolen from ExprMatch
+        let expr = Box::new(input.call(Expr::parse_without_eager_brace)?);
+
+        let content;
+        let brace = syn::braced!(content in input);
+
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            arms.push(content.parse()?);
+        }
+
+        Ok(Self {
+            match_token,
+            expr,
+            brace,
+            arms,
+            dyn_idx: DynIdx::default(),
+        })
+    }
+}
+
+impl Parse for MatchArm {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let pat = input.call(Pat::parse_multi_with_leading_vert)?;
+
+        let guard = if input.peek(Token![if]) {
+            let if_token = input.parse()?;
+            let guard = input.parse()?;
+            Some((if_token, Box::new(guard)))
+        } else {
+            None
+        };
+
+        let fat_arrow_token = input.parse()?;
+
+        let content;
+        let brace = syn::braced!(content in input);
+        let body: TemplateBody = content.parse()?;
+
+        // A braced rust expression like `{ value }` also parses as an incomplete component. Bail out so the match
+        // is parsed as a raw expression instead
+        let incomplete = body.roots.iter().any(|node| match node {
+            BodyNode::Element(el) => el.brace.is_none(),
+            BodyNode::Component(comp) => comp.brace.is_none(),
+            _ => false,
+        });
+        if incomplete || !body.diagnostics.is_empty() {
+            return Err(syn::Error::new(
+                brace.span.join(),
+                "match arms in rsx must be rsx bodies",
+            ));
+        }
+
+        // The comma after a block arm is optional
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            pat,
+            guard,
+            fat_arrow_token,
+            brace,
+            body,
+        })
+    }
+}
+
+impl ToTokens for MatchExpr {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let MatchExpr {
+            match_token,
+            expr,
+            arms,
+            ..
+        } = self;
+
+        let arms = arms.iter().map(|arm| {
+            let MatchArm {
+                pat,
+                guard,
+                fat_arrow_token,
+                body,
+                ..
+            } = arm;
+            let guard = guard
+                .as_ref()
+                .map(|(if_token, guard)| quote! { #if_token #guard });
+            quote! {
+                #pat #guard #fat_arrow_token {
+                    { #body }
+                }
+            }
+        });
+
+        tokens.append_all(quote! {
+            {
+                let ___nodes = (#match_token #expr { #(#arms)* }).into_dyn_node();
+                ___nodes
+            }
+        })
+    }
+}
+
+#[test]
+fn parses_match_expr() {
+    let input = quote! {
+        match value {
+            Some(1) | Some(2) => { "small" }
+            Some(value) if value > 100 => {
+                div { "big {value}" }
+            },
+            _ => {}
+        }
+    };
+
+    let expr: MatchExpr = syn::parse2(input).unwrap();
+    assert_eq!(expr.arms.len(), 3);
+    assert!(expr.arms[1].guard.is_some());
+    assert!(expr.arms[2].body.is_empty());
+}
+
+#[test]
+fn rejects_rust_match_arms() {
+    let expression_arm = quote! {
+        match value {
+            Some(_) => rsx! { "some" },
+            None => rsx! { "none" },
+        }
+    };
+    assert!(syn::parse2::<MatchExpr>(expression_arm).is_err());
+
+    let braced_expression = quote! {
+        match value {
+            Some(value) => { value }
+            None => { fallback }
+        }
+    };
+    assert!(syn::parse2::<MatchExpr>(braced_expression).is_err());
+}
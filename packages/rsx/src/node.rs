@@ -28,6 +28,9 @@ pub enum BodyNode {
 
     /// if cond {} else if cond {} (else {}?)
     IfChain(IfChain),
+
+    /// match expr { pat => {} }
+    Match(MatchExpr),
 }
 
 impl Parse for BodyNode {
@@ -46,12 +49,8 @@ impl Parse for BodyNode {
             return Ok(BodyNode::IfChain(stream.parse()?));
         }
 
-        // Match statements are special but have no special arm syntax
-        // we could allow arm syntax if we wanted.
-        //
-        // And it might even backwards compatible? - I think it is with the right fallback
-        // -> parse as bodynode (BracedRawExpr will kick in on multiline arms)
-        // -> if that fails parse as an expr, since that arm might be a one-liner
+        // Match statements with braced rsx arms are parsed like if chains. Any other match falls back to a raw
+        // expression so arms that are rust expressions keep working:
         //
         // ```
         // match expr {
@@ -60,6 +59,9 @@ impl Parse for BodyNode {
         // }
         // ```
         if stream.peek(Token![match]) {
+            if stream.fork().parse::<MatchExpr>().is_ok() {
+                return Ok(BodyNode::Match(stream.parse()?));
+            }
             return Ok(BodyNode::RawExpr(stream.parse()?));
         }
 
@@ -121,6 +123,7 @@ impl ToTokens for BodyNode {
             BodyNode::ForLoop(floop) => floop.to_tokens(tokens),
             BodyNode::Component(comp) => comp.to_tokens(tokens),
             BodyNode::IfChain(ifchain) => ifchain.to_tokens(tokens),
+            BodyNode::Match(match_expr) => match_expr.to_tokens(tokens),
         }
     }
 }
@@ -133,6 +136,7 @@ impl BodyNode {
             BodyNode::Component(comp) => comp.dyn_idx.get(),
             BodyNode::ForLoop(floop) => floop.dyn_idx.get(),
             BodyNode::IfChain(chain) => chain.dyn_idx.get(),
+            BodyNode::Match(match_expr) => match_expr.dyn_idx.get(),
             BodyNode::Element(_) => panic!("Cannot get dyn_idx for this node"),
         }
     }
@@ -144,6 +148,7 @@ impl BodyNode {
             BodyNode::Component(comp) => comp.dyn_idx.set(idx),
             BodyNode::ForLoop(floop) => floop.dyn_idx.set(idx),
             BodyNode::IfChain(chain) => chain.dyn_idx.set(idx),
+            BodyNode::Match(match_expr) => match_expr.dyn_idx.set(idx),
             BodyNode::Element(_) => panic!("Cannot set dyn_idx for this node"),
         }
     }
@@ -160,6 +165,7 @@ impl BodyNode {
             BodyNode::RawExpr(exp) => exp.span(),
             BodyNode::ForLoop(fl) => fl.for_token.span(),
             BodyNode::IfChain(f) => f.if_token.span(),
+            BodyNode::Match(m) => m.match_token.span(),
         }
     }
 
@@ -232,6 +238,17 @@ mod tests {
             BodyNode::RawExpr(_)
         ),);
 
+        let rsx_match = quote! {
+            match blah {
+                val => { div {} }
+                other_val => { "other" }
+            }
+        };
+        assert!(matches!(
+            syn::parse2::<BodyNode>(rsx_match).unwrap(),
+            BodyNode::Match(_)
+        ),);
+
         let incomplete_component = quote! {
             some::cool::Component
         };
@@ -111,6 +111,11 @@ impl CallBody {
                     self.cascade_hotreload_info(&body.roots)
                 }),
 
+                BodyNode::Match(match_expr) => match_expr.for_each_branch(&mut |body| {
+                    body.template_idx.set(self.next_template_idx());
+                    self.cascade_hotreload_info(&body.roots)
+                }),
+
                 _ => {}
             }
         }
@@ -70,6 +70,7 @@ type AttributePath = Vec<u8>;
 /// - The children of a component
 /// - The children of a for loop
 /// - The children of an if chain
+/// - The children of a match arm
 ///
 /// The TemplateBody when needs to be parsed into a surrounding `Body` to be correctly re-indexed
 /// By default every body has a `0` default index
@@ -34,6 +34,9 @@ use std::{fmt::Write, ops::AddAssign};
 
 use crate::renderer::{str_truthy, BOOL_ATTRS};
 
+/// The tag of the element `dioxus_html::pre_rendered` wraps trusted markup in
+const PRE_RENDERED_TAG: &str = "dioxus-pre-rendered";
+
 #[derive(Debug)]
 pub(crate) struct StringCache {
     pub segments: Vec<Segment>,
@@ -144,6 +147,30 @@ fn from_template_recursive(
     chain: &mut StringChain,
 ) -> Result<(), std::fmt::Error> {
     match root {
+        // Trusted markup is written as it is. The wrapper element is only needed to hydrate the markup
+        TemplateNode::Element { tag, attrs, .. } if *tag == PRE_RENDERED_TAG => {
+            chain.if_hydration_enabled(|chain| {
+                write!(chain, "<{tag}")?;
+                for attr in *attrs {
+                    if let TemplateAttribute::Static { name, value, .. } = attr {
+                        write!(chain, " {name}=\"{value}\"")?;
+                    }
+                }
+                std::fmt::Result::Ok(())
+            })?;
+            for attr in *attrs {
+                if let TemplateAttribute::Dynamic { id } = attr {
+                    *chain += Segment::Attr(*id);
+                }
+            }
+            chain.if_hydration_enabled(|chain| {
+                write!(chain, " data-node-hydration=\"")?;
+                *chain += Segment::AttributeNodeMarker;
+                write!(chain, "\">")
+            })?;
+            *chain += Segment::InnerHtmlMarker;
+            chain.if_hydration_enabled(|chain| write!(chain, "</{tag}>"))?;
+        }
         TemplateNode::Element {
             tag,
             attrs,
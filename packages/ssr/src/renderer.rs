@@ -24,6 +24,12 @@ pub struct Renderer {
 
     /// The current dynamic node id for hydration
     dynamic_node_id: usize,
+
+    /// Stop rendering at the first suspense boundary that is still suspended
+    stop_at_suspense: bool,
+
+    /// If the last render stopped at a suspended suspense boundary
+    stopped_at_suspense: bool,
 }
 
 impl Renderer {
@@ -62,6 +68,87 @@ impl Renderer {
         self.render_scope(buf, dom, ScopeId::ROOT)
     }
 
+    /// Render the virtual dom into an [`std::io::Write`] like a file or socket.
+    ///
+    /// The html is written to the writer as it is rendered instead of being collected into a string first. Wrap the
+    /// writer in a [`std::io::BufWriter`] to avoid many small writes.
+    pub fn render_to_io<W: std::io::Write + ?Sized>(
+        &mut self,
+        buf: &mut W,
+        dom: &VirtualDom,
+    ) -> std::io::Result<()> {
+        let mut writer = IoWriter {
+            inner: buf,
+            error: None,
+        };
+        self.render_to(&mut writer, dom).map_err(|_| {
+            writer
+                .error
+                .take()
+                .unwrap_or_else(|| std::io::Error::other("failed to render the virtual dom"))
+        })
+    }
+
+    /// Render the virtual dom into a [`futures_util::AsyncWrite`] like a socket, streaming the html in order as suspense
+    /// resolves.
+    ///
+    /// Everything before the first suspense boundary that is still suspended is written and flushed right away. The
+    /// virtual dom then resolves that boundary, and rendering continues from where it stopped until the next
+    /// suspended boundary or the end of the document. The html that was already flushed can't change, so updates
+    /// before a boundary that happen while it resolves are not streamed.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # async fn stream() -> std::io::Result<()> {
+    /// fn app() -> Element {
+    ///     let user = use_resource(|| async { "Ferris" }).suspend()?;
+    ///     rsx! { "Hello {user}" }
+    /// }
+    ///
+    /// let mut dom = VirtualDom::new(app);
+    /// dom.rebuild_in_place();
+    ///
+    /// let mut html = Vec::new();
+    /// dioxus_ssr::Renderer::new()
+    ///     .render_to_async_io(&mut html, &mut dom)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn render_to_async_io<W: futures_util::AsyncWrite + Unpin + ?Sized>(
+        &mut self,
+        buf: &mut W,
+        dom: &mut VirtualDom,
+    ) -> std::io::Result<()> {
+        use futures_util::AsyncWriteExt;
+
+        let mut flushed = 0;
+        loop {
+            // Once no suspended tasks are left, the rest of the document renders with any remaining fallbacks
+            self.stop_at_suspense = dom.suspended_tasks_remaining();
+            let mut html = String::new();
+            let result = self.render_to(&mut html, dom);
+            self.stop_at_suspense = false;
+            let stopped = std::mem::take(&mut self.stopped_at_suspense);
+            if result.is_err() && !stopped {
+                return Err(std::io::Error::other("failed to render the virtual dom"));
+            }
+
+            // Every render starts from the top of the document, so skip the part that was already written
+            if let Some(rendered) = html.get(flushed..) {
+                buf.write_all(rendered.as_bytes()).await?;
+                buf.flush().await?;
+                flushed = html.len();
+            }
+            if !stopped {
+                return Ok(());
+            }
+
+            dom.wait_for_suspense_work().await;
+            dom.render_suspense_immediate().await;
+        }
+    }
+
     /// Render an element to a string
     pub fn render_element(&mut self, element: Element) -> String {
         let mut buf = String::new();
@@ -139,7 +226,10 @@ impl Renderer {
                         if attr.name == "dangerous_inner_html" {
                             inner_html = Some(attr);
                         } else if attr.namespace == Some("style") {
-                            accumulated_dynamic_styles.push(attr);
+                            // Styles set to `None` are removed like any other attribute
+                            if !matches!(attr.value, AttributeValue::None) {
+                                accumulated_dynamic_styles.push(attr);
+                            }
                         } else if BOOL_ATTRS.contains(&attr.name) {
                             if truthy(&attr.value) {
                                 write_attribute(buf, attr)?;
@@ -160,6 +250,19 @@ impl Renderer {
                 }
                 Segment::Node(idx) => match &template.dynamic_nodes[*idx] {
                     DynamicNode::Component(node) => {
+                        if self.stop_at_suspense {
+                            let scope_id = node.mounted_scope_id(*idx, template, dom).unwrap();
+                            let suspended = SuspenseContext::downcast_suspense_boundary_from_scope(
+                                &dom.runtime(),
+                                scope_id,
+                            )
+                            .is_some_and(|suspense| suspense.is_suspended());
+                            if suspended {
+                                self.stopped_at_suspense = true;
+                                return Err(std::fmt::Error);
+                            }
+                        }
+
                         if let Some(render_components) = self.render_components.clone() {
                             let scope_id = node.mounted_scope_id(*idx, template, dom).unwrap();
 
@@ -264,6 +367,21 @@ impl Renderer {
     }
 }
 
+/// Adapts an [`std::io::Write`] to [`std::fmt::Write`] and keeps the io error that stopped rendering
+struct IoWriter<'a, W: ?Sized> {
+    inner: &'a mut W,
+    error: Option<std::io::Error>,
+}
+
+impl<W: std::io::Write + ?Sized> Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            std::fmt::Error
+        })
+    }
+}
+
 #[test]
 fn to_string_works() {
     use dioxus::prelude::*;
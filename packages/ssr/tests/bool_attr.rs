@@ -35,3 +35,24 @@ fn dynamic_boolean_attributes() {
         r#"<div></div><div hidden=true></div>"#
     );
 }
+
+#[test]
+fn optional_attributes() {
+    fn app() -> Element {
+        let title: Option<&str> = None;
+        let active = true;
+        rsx! {
+            div { title, id: Some("present") }
+            div { color: None::<&str>, width: Some("10px") }
+            div { hidden: Some(false), class: "button", class: active.then_some("active") }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div id="present"></div><div style="width:10px;"></div><div class="button active"></div>"#
+    );
+}
@@ -24,3 +24,38 @@ fn dynamic_inner_html() {
 
     assert_eq!(dioxus_ssr::render(&dom), r#"<div><div>1234</div></div>"#);
 }
+
+#[test]
+fn pre_rendered_markup_is_not_wrapped() {
+    fn app() -> Element {
+        let markup = "<b>trusted</b> & raw";
+        rsx! {
+            p { {dioxus::html::pre_rendered(markup)} }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(dioxus_ssr::render(&dom), r#"<p><b>trusted</b> & raw</p>"#);
+
+    // The client needs the wrapper element to hydrate the markup
+    let pre_rendered = dioxus_ssr::pre_render(&dom);
+    assert!(pre_rendered
+        .contains(r#"<dioxus-pre-rendered style="display:contents;" data-node-hydration="#));
+    assert!(pre_rendered.contains(r#"<b>trusted</b> & raw</dioxus-pre-rendered>"#));
+}
+
+#[test]
+fn sanitized_inner_html() {
+    fn app() -> Element {
+        let html =
+            dioxus::html::sanitize_html(r#"<em onclick="steal()">hi</em><script>steal()</script>"#);
+        rsx! { div { dangerous_inner_html: html } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(dioxus_ssr::render(&dom), r#"<div><em>hi</em></div>"#);
+}
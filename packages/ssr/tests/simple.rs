@@ -84,3 +84,19 @@ fn fragments() {
         "<div></div>"
     );
 }
+
+#[test]
+fn render_to_io() {
+    fn App() -> Element {
+        rsx! { div { "hello!" } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let mut buf = Vec::new();
+    dioxus_ssr::Renderer::new()
+        .render_to_io(&mut buf, &dom)
+        .unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), "<div>hello!</div>");
+}
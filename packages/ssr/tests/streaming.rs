@@ -0,0 +1,91 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Collects the html that was written between every flush
+#[derive(Default)]
+struct Chunks {
+    pending: Vec<u8>,
+    flushed: Vec<String>,
+}
+
+impl futures_util::AsyncWrite for Chunks {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.pending.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let chunk = String::from_utf8(std::mem::take(&mut self.pending)).unwrap();
+        self.flushed.push(chunk);
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+#[tokio::test]
+async fn streams_the_html_before_suspended_boundaries() {
+    fn App() -> Element {
+        rsx! {
+            h1 { "before" }
+            SuspenseBoundary {
+                fallback: |_| rsx! { "loading" },
+                User {}
+            }
+            p { "after" }
+        }
+    }
+
+    fn User() -> Element {
+        let name = use_resource(|| async {
+            tokio::task::yield_now().await;
+            "Ferris"
+        })
+        .suspend()?;
+        rsx! { div { "{name}" } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+
+    let mut chunks = Chunks::default();
+    dioxus_ssr::Renderer::new()
+        .render_to_async_io(&mut chunks, &mut dom)
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.flushed.first().unwrap(), "<h1>before</h1>");
+    assert_eq!(
+        chunks.flushed.concat(),
+        "<h1>before</h1><div>Ferris</div><p>after</p>"
+    );
+}
+
+#[tokio::test]
+async fn renders_documents_without_suspense_in_one_chunk() {
+    fn App() -> Element {
+        rsx! { div { "hello!" } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+
+    let mut chunks = Chunks::default();
+    dioxus_ssr::Renderer::new()
+        .render_to_async_io(&mut chunks, &mut dom)
+        .await
+        .unwrap();
+    assert_eq!(chunks.flushed, ["<div>hello!</div>"]);
+}
@@ -38,3 +38,35 @@ fn dynamic_styles() {
         r#"<div style="width:123px;"></div>"#
     );
 }
+
+#[test]
+fn structured_styles() {
+    use dioxus::html::global_attributes::margin;
+
+    let style = Style::new()
+        .set(margin, "4px")
+        .set("--depth", 2)
+        .set(margin, "8px");
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { style }
+        }),
+        r#"<div style="margin:8px;--depth:2;"></div>"#
+    );
+}
+
+#[test]
+fn structured_classes() {
+    let active = true;
+    let disabled = false;
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { class: [("tab", true), ("active", active), ("disabled", disabled)] }
+            div { class: Classes::new().add("btn btn-primary").add(vec!["btn", "wide"]) }
+            div { class: Classes::new().add_if("hidden", disabled) }
+        }),
+        r#"<div class="tab active"></div><div class="btn btn-primary wide"></div><div></div>"#
+    );
+}
@@ -0,0 +1,35 @@
+use dioxus::prelude::*;
+
+#[test]
+fn svg_attributes_keep_their_case_and_prefix() {
+    let id = "icon";
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            svg { view_box: "0 0 24 24", width: "24", xmlns_xlink: "http://www.w3.org/1999/xlink",
+                r#use { xlink_href: "#{id}" }
+                text { xml_space: "preserve", "a  b" }
+            }
+        }),
+        concat!(
+            r#"<svg viewBox="0 0 24 24" width="24" xmlns:xlink="http://www.w3.org/1999/xlink">"#,
+            r##"<use xlink:href="#icon"></use>"##,
+            r#"<text xml:space="preserve">a  b</text>"#,
+            r#"</svg>"#
+        )
+    );
+}
+
+#[test]
+fn dynamic_svg_attributes() {
+    let points = "0,0 10,10";
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            svg {
+                polyline { points, stroke_width: 2, fill: "none" }
+            }
+        }),
+        r#"<svg><polyline points="0,0 10,10" stroke-width=2 fill="none"></polyline></svg>"#
+    );
+}
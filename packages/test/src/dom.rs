@@ -0,0 +1,473 @@
+//! An in-memory DOM that applies the mutations from a [`VirtualDom`](dioxus_core::VirtualDom)
+
+use crate::selector::Selector;
+use dioxus_core::{
+    AttributeValue, ElementId, Template, TemplateAttribute, TemplateNode, WriteMutations,
+};
+use rustc_hash::FxHashMap;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct NodeId(usize);
+
+pub(crate) struct Node {
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    kind: NodeKind,
+    element_id: Option<ElementId>,
+}
+
+pub(crate) enum NodeKind {
+    Element {
+        tag: String,
+        attributes: Vec<Attribute>,
+        listeners: Vec<String>,
+    },
+    Text(String),
+    Placeholder,
+}
+
+pub(crate) struct Attribute {
+    name: String,
+    namespace: Option<String>,
+    value: String,
+}
+
+/// A tree of nodes that mirrors the tree a renderer would create from the mutations of the virtual dom
+pub(crate) struct MemoryDom {
+    nodes: Vec<Node>,
+    elements: FxHashMap<ElementId, NodeId>,
+    stack: Vec<NodeId>,
+}
+
+impl MemoryDom {
+    /// The node that the virtual dom mounts the app to
+    pub(crate) const ROOT: NodeId = NodeId(0);
+
+    pub(crate) fn new() -> Self {
+        let mut dom = Self {
+            nodes: Vec::new(),
+            elements: FxHashMap::default(),
+            stack: Vec::new(),
+        };
+        let root = dom.create_node(NodeKind::Element {
+            tag: "main".to_string(),
+            attributes: Vec::new(),
+            listeners: Vec::new(),
+        });
+        dom.register(root, ElementId(0));
+        dom
+    }
+
+    pub(crate) fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    /// Get the node that was assigned an element id by the virtual dom
+    pub(crate) fn element(&self, id: ElementId) -> Option<NodeId> {
+        self.elements.get(&id).copied()
+    }
+
+    /// Find every node under the root that matches a selector in document order
+    pub(crate) fn select(&self, selector: &Selector) -> Vec<NodeId> {
+        let mut matches = Vec::new();
+        let mut to_visit: Vec<_> = self
+            .node(Self::ROOT)
+            .children
+            .iter()
+            .rev()
+            .copied()
+            .collect();
+        while let Some(id) = to_visit.pop() {
+            if selector.matches(self, id) {
+                matches.push(id);
+            }
+            to_visit.extend(self.node(id).children.iter().rev().copied());
+        }
+        matches
+    }
+
+    fn create_node(&mut self, kind: NodeKind) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            parent: None,
+            children: Vec::new(),
+            kind,
+            element_id: None,
+        });
+        id
+    }
+
+    fn create_template_node(&mut self, node: &TemplateNode) -> NodeId {
+        match node {
+            TemplateNode::Element {
+                tag,
+                attrs,
+                children,
+                ..
+            } => {
+                let attributes = attrs
+                    .iter()
+                    .filter_map(|attr| match attr {
+                        TemplateAttribute::Static {
+                            name,
+                            value,
+                            namespace,
+                        } => Some(Attribute {
+                            name: name.to_string(),
+                            namespace: namespace.map(str::to_string),
+                            value: value.to_string(),
+                        }),
+                        TemplateAttribute::Dynamic { .. } => None,
+                    })
+                    .collect();
+                let id = self.create_node(NodeKind::Element {
+                    tag: tag.to_string(),
+                    attributes,
+                    listeners: Vec::new(),
+                });
+                for child in children.iter() {
+                    let child = self.create_template_node(child);
+                    self.nodes[child.0].parent = Some(id);
+                    self.nodes[id.0].children.push(child);
+                }
+                id
+            }
+            TemplateNode::Text { text } => self.create_node(NodeKind::Text(text.to_string())),
+            TemplateNode::Dynamic { .. } => self.create_node(NodeKind::Placeholder),
+        }
+    }
+
+    fn register(&mut self, node: NodeId, id: ElementId) {
+        if let Some(old) = self.elements.insert(id, node) {
+            self.nodes[old.0].element_id = None;
+        }
+        self.nodes[node.0].element_id = Some(id);
+    }
+
+    fn get(&self, id: ElementId) -> NodeId {
+        self.element(id)
+            .unwrap_or_else(|| panic!("The virtual dom referenced {id:?}, which was never created"))
+    }
+
+    fn pop_nodes(&mut self, m: usize) -> Vec<NodeId> {
+        self.stack.split_off(self.stack.len() - m)
+    }
+
+    fn load_child(&self, path: &[u8]) -> NodeId {
+        let mut node = *self.stack.last().expect("The stack is empty");
+        for index in path {
+            node = self.node(node).children[*index as usize];
+        }
+        node
+    }
+
+    fn detach(&mut self, node: NodeId) {
+        if let Some(parent) = self.nodes[node.0].parent.take() {
+            self.nodes[parent.0].children.retain(|child| *child != node);
+        }
+    }
+
+    /// Insert nodes into the parent of `target`, at `offset` from the position of `target`
+    fn insert_siblings(&mut self, target: NodeId, offset: usize, nodes: Vec<NodeId>) {
+        for node in &nodes {
+            self.detach(*node);
+        }
+        let Some(parent) = self.node(target).parent else {
+            // Nodes that are not mounted yet are still on the stack
+            if let Some(position) = self.stack.iter().position(|node| *node == target) {
+                let position = position + offset;
+                self.stack.splice(position..position, nodes);
+            }
+            return;
+        };
+        for node in &nodes {
+            self.nodes[node.0].parent = Some(parent);
+        }
+        let children = &mut self.nodes[parent.0].children;
+        let position = children.iter().position(|child| *child == target).unwrap() + offset;
+        children.splice(position..position, nodes);
+    }
+
+    fn replace(&mut self, target: NodeId, nodes: Vec<NodeId>) {
+        self.insert_siblings(target, 1, nodes);
+        self.detach(target);
+        self.stack.retain(|node| *node != target);
+    }
+
+    fn element_mut(&mut self, id: ElementId) -> Option<(&mut Vec<Attribute>, &mut Vec<String>)> {
+        let node = self.get(id);
+        match &mut self.nodes[node.0].kind {
+            NodeKind::Element {
+                attributes,
+                listeners,
+                ..
+            } => Some((attributes, listeners)),
+            _ => None,
+        }
+    }
+
+    /// Get the text content of a node and all of its children
+    pub(crate) fn text(&self, id: NodeId) -> String {
+        let mut text = String::new();
+        self.write_text(id, &mut text);
+        text
+    }
+
+    fn write_text(&self, id: NodeId, to: &mut String) {
+        match &self.node(id).kind {
+            NodeKind::Text(text) => to.push_str(text),
+            NodeKind::Element { .. } => {
+                for child in &self.node(id).children {
+                    self.write_text(*child, to);
+                }
+            }
+            NodeKind::Placeholder => {}
+        }
+    }
+
+    /// Render the children of a node to html
+    pub(crate) fn inner_html(&self, id: NodeId) -> String {
+        let mut html = String::new();
+        for child in &self.node(id).children {
+            self.write_html(*child, &mut html);
+        }
+        html
+    }
+
+    /// Render a node and its children to html
+    pub(crate) fn outer_html(&self, id: NodeId) -> String {
+        let mut html = String::new();
+        self.write_html(id, &mut html);
+        html
+    }
+
+    fn write_html(&self, id: NodeId, to: &mut String) {
+        let node = self.node(id);
+        match &node.kind {
+            NodeKind::Text(text) => write_escaped(text, to),
+            NodeKind::Placeholder => to.push_str("<!--placeholder-->"),
+            NodeKind::Element {
+                tag, attributes, ..
+            } => {
+                let mut inner_html = None;
+                let mut styles = String::new();
+                let _ = write!(to, "<{tag}");
+                for attribute in attributes {
+                    match (attribute.name.as_str(), attribute.namespace.as_deref()) {
+                        ("dangerous_inner_html", _) => inner_html = Some(&attribute.value),
+                        (name, Some("style")) => {
+                            let _ = write!(styles, "{name}:{};", attribute.value);
+                        }
+                        (name, _) => {
+                            let _ = write!(to, " {name}=\"");
+                            write_escaped(&attribute.value, to);
+                            to.push('"');
+                        }
+                    }
+                }
+                if !styles.is_empty() {
+                    to.push_str(" style=\"");
+                    write_escaped(&styles, to);
+                    to.push('"');
+                }
+                to.push('>');
+                match inner_html {
+                    Some(html) => to.push_str(html),
+                    None => {
+                        for child in &node.children {
+                            self.write_html(*child, to);
+                        }
+                    }
+                }
+                let _ = write!(to, "</{tag}>");
+            }
+        }
+    }
+}
+
+fn write_escaped(text: &str, to: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => to.push_str("&amp;"),
+            '<' => to.push_str("&lt;"),
+            '>' => to.push_str("&gt;"),
+            '"' => to.push_str("&quot;"),
+            '\'' => to.push_str("&#x27;"),
+            c => to.push(c),
+        }
+    }
+}
+
+impl Node {
+    pub(crate) fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    pub(crate) fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    pub(crate) fn kind(&self) -> &NodeKind {
+        &self.kind
+    }
+
+    pub(crate) fn element_id(&self) -> Option<ElementId> {
+        self.element_id
+    }
+
+    pub(crate) fn tag(&self) -> Option<&str> {
+        match &self.kind {
+            NodeKind::Element { tag, .. } => Some(tag),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        match &self.kind {
+            NodeKind::Element { attributes, .. } => attributes
+                .iter()
+                .find(|attribute| attribute.name == name && attribute.namespace.is_none())
+                .map(|attribute| attribute.value.as_str()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn has_listener(&self, name: &str) -> bool {
+        match &self.kind {
+            NodeKind::Element { listeners, .. } => {
+                listeners.iter().any(|listener| listener == name)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl WriteMutations for MemoryDom {
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        let parent = self.get(id);
+        for child in self.pop_nodes(m) {
+            self.detach(child);
+            self.nodes[child.0].parent = Some(parent);
+            self.nodes[parent.0].children.push(child);
+        }
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        let node = self.load_child(path);
+        self.register(node, id);
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        let node = self.create_node(NodeKind::Placeholder);
+        self.register(node, id);
+        self.stack.push(node);
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        let node = self.create_node(NodeKind::Text(value.to_string()));
+        self.register(node, id);
+        self.stack.push(node);
+    }
+
+    fn load_template(&mut self, template: Template, index: usize, id: ElementId) {
+        let node = self.create_template_node(&template.roots[index]);
+        self.register(node, id);
+        self.stack.push(node);
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        let nodes = self.pop_nodes(m);
+        let target = self.get(id);
+        self.replace(target, nodes);
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        let nodes = self.pop_nodes(m);
+        let target = self.load_child(path);
+        self.replace(target, nodes);
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        let nodes = self.pop_nodes(m);
+        let target = self.get(id);
+        self.insert_siblings(target, 1, nodes);
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        let nodes = self.pop_nodes(m);
+        let target = self.get(id);
+        self.insert_siblings(target, 0, nodes);
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        let Some((attributes, _)) = self.element_mut(id) else {
+            return;
+        };
+        let value = match value {
+            AttributeValue::Text(text) => text.clone(),
+            AttributeValue::Float(float) => float.to_string(),
+            AttributeValue::Int(int) => int.to_string(),
+            AttributeValue::Bool(bool) => bool.to_string(),
+            AttributeValue::None => {
+                attributes.retain(|attribute| {
+                    attribute.name != name || attribute.namespace.as_deref() != ns
+                });
+                return;
+            }
+            // Listeners are added with `create_event_listener` and values of any type only mean something to the
+            // renderer they were made for, so neither shows up in the html
+            AttributeValue::Listener(_) | AttributeValue::Any(_) => return,
+        };
+        match attributes
+            .iter_mut()
+            .find(|attribute| attribute.name == name && attribute.namespace.as_deref() == ns)
+        {
+            Some(attribute) => attribute.value = value,
+            None => attributes.push(Attribute {
+                name: name.to_string(),
+                namespace: ns.map(str::to_string),
+                value,
+            }),
+        }
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        let node = self.get(id);
+        if let NodeKind::Text(text) = &mut self.nodes[node.0].kind {
+            *text = value.to_string();
+        }
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        if let Some((_, listeners)) = self.element_mut(id) {
+            listeners.push(name.to_string());
+        }
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        if let Some((_, listeners)) = self.element_mut(id) {
+            if let Some(position) = listeners.iter().position(|listener| listener == name) {
+                listeners.remove(position);
+            }
+        }
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        if let Some(node) = self.elements.remove(&id) {
+            self.nodes[node.0].element_id = None;
+            self.detach(node);
+        }
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        let node = self.get(id);
+        self.stack.push(node);
+    }
+}
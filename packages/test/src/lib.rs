@@ -0,0 +1,327 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+mod dom;
+mod selector;
+
+use dioxus_core::prelude::*;
+use dioxus_core::{ElementId, Event, VirtualDom};
+use dioxus_html::prelude::{Code, Key, Location, Modifiers};
+use dioxus_html::{
+    EventData, FormValue, SerializedFormData, SerializedHtmlEventConverter, SerializedKeyboardData,
+    SerializedMouseData,
+};
+use dom::{MemoryDom, NodeId, NodeKind};
+use futures_util::FutureExt;
+use selector::Selector;
+use std::collections::HashMap;
+
+/// The most times [`TestDom::update`] polls the virtual dom before it gives up on the work settling
+const MAX_UPDATES: usize = 1000;
+
+/// A virtual dom that renders into an in-memory DOM so components can be tested without a browser.
+///
+/// The app is rendered as soon as the test dom is created. Events fired with [`TestDom::fire_event`] and the helpers
+/// built on it run their handlers and render the result before they return.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// use dioxus_test::TestDom;
+///
+/// fn app() -> Element {
+///     let mut name = use_signal(String::new);
+///     rsx! {
+///         input { id: "name", oninput: move |event| name.set(event.value()) }
+///         p { "Hello {name}" }
+///     }
+/// }
+///
+/// let mut dom = TestDom::new(app);
+/// dom.input("#name", "Ferris");
+/// assert_eq!(dom.find("p").unwrap().text(), "Hello Ferris");
+/// ```
+pub struct TestDom {
+    vdom: VirtualDom,
+    dom: MemoryDom,
+}
+
+impl TestDom {
+    /// Render a component without any props
+    pub fn new(app: fn() -> Element) -> Self {
+        Self::from_virtual_dom(VirtualDom::new(app))
+    }
+
+    /// Render a component with props
+    pub fn new_with_props<P: Clone + 'static, M: 'static>(
+        root: impl ComponentFunction<P, M>,
+        root_props: P,
+    ) -> Self {
+        Self::from_virtual_dom(VirtualDom::new_with_props(root, root_props))
+    }
+
+    /// Render a virtual dom that was created, but not rebuilt yet. This is useful to provide root contexts before
+    /// the app renders.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// use dioxus_test::TestDom;
+    ///
+    /// fn app() -> Element {
+    ///     let user = use_context::<String>();
+    ///     rsx! { "{user}" }
+    /// }
+    ///
+    /// let dom = TestDom::from_virtual_dom(VirtualDom::new(app).with_root_context("Ferris".to_string()));
+    /// assert_eq!(dom.html(), "Ferris");
+    /// ```
+    pub fn from_virtual_dom(mut vdom: VirtualDom) -> Self {
+        dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+        let mut dom = MemoryDom::new();
+        vdom.rebuild(&mut dom);
+        let mut dom = Self { vdom, dom };
+        dom.update();
+        dom
+    }
+
+    /// Get the virtual dom that renders the app
+    pub fn virtual_dom(&self) -> &VirtualDom {
+        &self.vdom
+    }
+
+    /// Get a mutable reference to the virtual dom that renders the app. Call [`TestDom::update`] after changing the
+    /// virtual dom to render the changes.
+    pub fn virtual_dom_mut(&mut self) -> &mut VirtualDom {
+        &mut self.vdom
+    }
+
+    /// Run any work that is ready without waiting, and render the result. This polls any futures that can make
+    /// progress until the app settles.
+    pub fn update(&mut self) {
+        for _ in 0..MAX_UPDATES {
+            self.vdom.render_immediate(&mut self.dom);
+            if self.vdom.wait_for_work().now_or_never().is_none() {
+                return;
+            }
+        }
+        tracing::warn!(
+            "The app still had work to do after {MAX_UPDATES} updates. A future or effect may be waking itself up forever"
+        );
+    }
+
+    /// Wait for the next piece of async work, like a timer or a request, and render the result.
+    ///
+    /// This waits forever if the app has no pending work, so run it with a timeout if the app might already be idle.
+    pub async fn wait_for_work(&mut self) {
+        self.vdom.wait_for_work().await;
+        self.update();
+    }
+
+    /// Wait until every suspended component in the app has resolved and render the result
+    pub async fn wait_for_suspense(&mut self) {
+        while self.vdom.suspended_tasks_remaining() {
+            self.wait_for_work().await;
+        }
+    }
+
+    /// Render the app to html. Event listeners and the ids the virtual dom assigns to elements are not included.
+    pub fn html(&self) -> String {
+        self.dom.inner_html(MemoryDom::ROOT)
+    }
+
+    /// Get the text content of the app
+    pub fn text(&self) -> String {
+        self.dom.text(MemoryDom::ROOT)
+    }
+
+    /// Find the first element that matches a selector. See the [crate docs](crate#selectors) for the supported
+    /// selectors.
+    pub fn find(&self, selector: &str) -> Option<TestNode<'_>> {
+        self.find_all(selector).into_iter().next()
+    }
+
+    /// Find every element that matches a selector in document order
+    pub fn find_all(&self, selector: &str) -> Vec<TestNode<'_>> {
+        self.dom
+            .select(&Selector::parse(selector))
+            .into_iter()
+            .map(|id| TestNode { dom: &self.dom, id })
+            .collect()
+    }
+
+    /// Get the node the virtual dom assigned an element id to
+    pub fn get(&self, id: ElementId) -> Option<TestNode<'_>> {
+        self.dom
+            .element(id)
+            .map(|id| TestNode { dom: &self.dom, id })
+    }
+
+    /// Fire an event at the first element that matches the selector and render the result. The name of the event
+    /// should not include the `on` prefix, like `click` or `input`.
+    ///
+    /// Like in the browser, the event bubbles up from the element if it is a bubbling event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no element matches the selector.
+    #[track_caller]
+    pub fn fire_event(&mut self, selector: &str, name: &str, data: EventData) {
+        let target = self
+            .find(selector)
+            .and_then(|node| node.closest_element_id())
+            .unwrap_or_else(|| panic!("No element matches the selector `{selector}`"));
+        self.fire_event_on(target, name, data);
+    }
+
+    /// Fire an event at an element id and render the result
+    pub fn fire_event_on(&mut self, element: ElementId, name: &str, data: EventData) {
+        let name = name.strip_prefix("on").unwrap_or(name);
+        let event = Event::new(data.into_any(), dioxus_core_types::event_bubbles(name));
+        self.vdom.runtime().handle_event(name, event, element);
+        self.update();
+    }
+
+    /// Click the first element that matches the selector
+    #[track_caller]
+    pub fn click(&mut self, selector: &str) {
+        self.fire_event(
+            selector,
+            "click",
+            EventData::Mouse(SerializedMouseData::default()),
+        );
+    }
+
+    /// Fire an input event with the given value at the first element that matches the selector
+    #[track_caller]
+    pub fn input(&mut self, selector: &str, value: impl ToString) {
+        self.fire_event(
+            selector,
+            "input",
+            EventData::Form(SerializedFormData::new(value.to_string(), HashMap::new())),
+        );
+    }
+
+    /// Submit the form that matches the selector with the given values
+    #[track_caller]
+    pub fn submit<'a>(
+        &mut self,
+        selector: &str,
+        values: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) {
+        let values = values
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), FormValue(vec![value.to_string()])))
+            .collect();
+        self.fire_event(
+            selector,
+            "submit",
+            EventData::Form(SerializedFormData::new(String::new(), values)),
+        );
+    }
+
+    /// Fire a keydown event with the given key at the first element that matches the selector
+    #[track_caller]
+    pub fn key_down(&mut self, selector: &str, key: Key) {
+        let data = SerializedKeyboardData::new(
+            key,
+            Code::Unidentified,
+            Location::Standard,
+            false,
+            Modifiers::empty(),
+            false,
+        );
+        self.fire_event(selector, "keydown", EventData::Keyboard(data));
+    }
+}
+
+/// A node in the in-memory DOM of a [`TestDom`]
+#[derive(Clone, Copy)]
+pub struct TestNode<'a> {
+    dom: &'a MemoryDom,
+    id: NodeId,
+}
+
+impl<'a> TestNode<'a> {
+    /// Get the tag of the element, or `None` if this is a text node or a placeholder
+    pub fn tag(&self) -> Option<&'a str> {
+        self.dom.node(self.id).tag()
+    }
+
+    /// Get the value of an attribute on the element. Dynamic boolean attributes are stored as `true` or `false`
+    pub fn attribute(&self, name: &str) -> Option<&'a str> {
+        self.dom.node(self.id).attribute(name)
+    }
+
+    /// Check if the element has an event listener. The name should not include the `on` prefix, like `click`
+    pub fn has_listener(&self, name: &str) -> bool {
+        self.dom.node(self.id).has_listener(name)
+    }
+
+    /// Check if this is a text node
+    pub fn is_text(&self) -> bool {
+        matches!(self.dom.node(self.id).kind(), NodeKind::Text(_))
+    }
+
+    /// Get the text content of the node and all of its children
+    pub fn text(&self) -> String {
+        self.dom.text(self.id)
+    }
+
+    /// Render the children of the node to html
+    pub fn inner_html(&self) -> String {
+        self.dom.inner_html(self.id)
+    }
+
+    /// Render the node and its children to html
+    pub fn outer_html(&self) -> String {
+        self.dom.outer_html(self.id)
+    }
+
+    /// Get the children of the node, including text nodes
+    pub fn children(&self) -> impl Iterator<Item = TestNode<'a>> + 'a {
+        let dom = self.dom;
+        dom.node(self.id)
+            .children()
+            .iter()
+            .map(move |id| TestNode { dom, id: *id })
+    }
+
+    /// Get the parent of the node. The element the app is mounted to has no parent
+    pub fn parent(&self) -> Option<TestNode<'a>> {
+        self.dom
+            .node(self.id)
+            .parent()
+            .filter(|parent| *parent != MemoryDom::ROOT)
+            .map(|id| TestNode { dom: self.dom, id })
+    }
+
+    /// Get the id the virtual dom assigned to this node. Only nodes the virtual dom needs to update have an id
+    pub fn element_id(&self) -> Option<ElementId> {
+        self.dom.node(self.id).element_id()
+    }
+
+    /// Find the id of this node or the closest ancestor the virtual dom assigned an id to. Events that are fired at
+    /// this node are sent to that id.
+    pub fn closest_element_id(&self) -> Option<ElementId> {
+        let mut current = Some(self.id);
+        while let Some(id) = current {
+            if id == MemoryDom::ROOT {
+                return None;
+            }
+            let node = self.dom.node(id);
+            if let Some(element_id) = node.element_id() {
+                return Some(element_id);
+            }
+            current = node.parent();
+        }
+        None
+    }
+}
+
+impl std::fmt::Debug for TestNode<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.outer_html())
+    }
+}
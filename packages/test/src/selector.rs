@@ -0,0 +1,171 @@
+//! A small subset of css selectors for finding nodes in the test dom
+
+use crate::dom::{MemoryDom, NodeId};
+
+/// A parsed selector. Each compound selector must match an ancestor of the node that matches the compound selector
+/// after it.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Selector {
+    compounds: Vec<Compound>,
+}
+
+/// A list of simple selectors that must all match the same element, like `button.primary[type="submit"]`
+#[derive(Debug, Default, PartialEq)]
+struct Compound {
+    tag: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+    attributes: Vec<(String, Option<String>)>,
+}
+
+impl Selector {
+    /// Parse a selector, panicking with a helpful message if it is not supported
+    pub(crate) fn parse(selector: &str) -> Self {
+        let compounds: Vec<_> = selector
+            .split_whitespace()
+            .map(|compound| {
+                Compound::parse(compound).unwrap_or_else(|| {
+                    panic!("`{compound}` in the selector `{selector}` is not a supported selector")
+                })
+            })
+            .collect();
+        if compounds.is_empty() {
+            panic!("The selector `{selector}` is empty");
+        }
+        Self { compounds }
+    }
+
+    pub(crate) fn matches(&self, dom: &MemoryDom, node: NodeId) -> bool {
+        let (last, ancestors) = self.compounds.split_last().unwrap();
+        if !last.matches(dom, node) {
+            return false;
+        }
+
+        // Match the remaining compounds against the closest ancestors that satisfy them
+        let mut ancestors = ancestors.iter().rev().peekable();
+        let mut current = dom.node(node).parent();
+        while let (Some(compound), Some(node)) = (ancestors.peek(), current) {
+            if node == MemoryDom::ROOT {
+                break;
+            }
+            if compound.matches(dom, node) {
+                ancestors.next();
+            }
+            current = dom.node(node).parent();
+        }
+        ancestors.peek().is_none()
+    }
+}
+
+impl Compound {
+    fn parse(mut compound: &str) -> Option<Self> {
+        let mut parsed = Self::default();
+
+        let tag_end = compound.find(['#', '.', '[']).unwrap_or(compound.len());
+        if tag_end > 0 && &compound[..tag_end] != "*" {
+            parsed.tag = Some(compound[..tag_end].to_string());
+        }
+        compound = &compound[tag_end..];
+
+        while let Some(first) = compound.chars().next() {
+            compound = &compound[1..];
+            match first {
+                '#' | '.' => {
+                    let end = compound.find(['#', '.', '[']).unwrap_or(compound.len());
+                    let name = &compound[..end];
+                    if name.is_empty() {
+                        return None;
+                    }
+                    if first == '#' {
+                        parsed.id = Some(name.to_string());
+                    } else {
+                        parsed.classes.push(name.to_string());
+                    }
+                    compound = &compound[end..];
+                }
+                '[' => {
+                    let end = compound.find(']')?;
+                    let attribute = &compound[..end];
+                    let (name, value) = match attribute.split_once('=') {
+                        Some((name, value)) => {
+                            let value = value.trim_matches(|c| c == '"' || c == '\'');
+                            (name, Some(value.to_string()))
+                        }
+                        None => (attribute, None),
+                    };
+                    if name.is_empty() {
+                        return None;
+                    }
+                    parsed.attributes.push((name.to_string(), value));
+                    compound = &compound[end + 1..];
+                }
+                _ => return None,
+            }
+        }
+
+        Some(parsed)
+    }
+
+    fn matches(&self, dom: &MemoryDom, node: NodeId) -> bool {
+        let node = dom.node(node);
+        let Some(tag) = node.tag() else {
+            return false;
+        };
+        if self.tag.as_ref().is_some_and(|expected| expected != tag) {
+            return false;
+        }
+        if self
+            .id
+            .as_ref()
+            .is_some_and(|id| node.attribute("id") != Some(id))
+        {
+            return false;
+        }
+        let classes = node.attribute("class").unwrap_or_default();
+        if !self
+            .classes
+            .iter()
+            .all(|class| classes.split_whitespace().any(|c| c == class))
+        {
+            return false;
+        }
+        self.attributes
+            .iter()
+            .all(|(name, value)| match (node.attribute(name), value) {
+                (Some(actual), Some(expected)) => actual == expected,
+                (Some(_), None) => true,
+                (None, _) => false,
+            })
+    }
+}
+
+#[test]
+fn parses_compound_selectors() {
+    assert_eq!(
+        Selector::parse(r#"form button.primary.large[type="submit"]"#),
+        Selector {
+            compounds: vec![
+                Compound {
+                    tag: Some("form".to_string()),
+                    ..Default::default()
+                },
+                Compound {
+                    tag: Some("button".to_string()),
+                    id: None,
+                    classes: vec!["primary".to_string(), "large".to_string()],
+                    attributes: vec![("type".to_string(), Some("submit".to_string()))],
+                },
+            ]
+        }
+    );
+    assert_eq!(
+        Selector::parse("#name[disabled]"),
+        Selector {
+            compounds: vec![Compound {
+                id: Some("name".to_string()),
+                attributes: vec![("disabled".to_string(), None)],
+                ..Default::default()
+            }]
+        }
+    );
+}
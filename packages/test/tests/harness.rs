@@ -0,0 +1,146 @@
+use dioxus::prelude::*;
+use dioxus_test::TestDom;
+use std::time::Duration;
+
+#[test]
+fn clicks_update_the_tree() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        rsx! {
+            div { class: "counter",
+                button { id: "increment", onclick: move |_| count += 1,
+                    span { "+" }
+                }
+                p { "Count: {count}" }
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    assert_eq!(
+        dom.html(),
+        r#"<div class="counter"><button id="increment"><span>+</span></button><p>Count: 0</p></div>"#
+    );
+
+    dom.click("#increment");
+    // Events fired at static children bubble up to the listener
+    dom.click(".counter button span");
+    assert_eq!(dom.find("p").unwrap().text(), "Count: 2");
+    assert!(dom.find("button").unwrap().has_listener("click"));
+}
+
+#[test]
+fn inputs_and_lists() {
+    fn app() -> Element {
+        let mut todos = use_signal(Vec::<String>::new);
+        let mut draft = use_signal(String::new);
+        rsx! {
+            input { value: "{draft}", oninput: move |event| draft.set(event.value()) }
+            button {
+                disabled: draft.read().is_empty(),
+                onclick: move |_| todos.write().push(draft.take()),
+                "Add"
+            }
+            ul {
+                for (i, todo) in todos.iter().enumerate() {
+                    li { key: "{todo}", "data-index": "{i}",
+                        "{todo}"
+                        button { onclick: move |_| _ = todos.write().remove(i), "Remove" }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    assert_eq!(
+        dom.find("button").unwrap().attribute("disabled"),
+        Some("true")
+    );
+
+    dom.input("input", "Write tests");
+    assert_eq!(
+        dom.find("input").unwrap().attribute("value"),
+        Some("Write tests")
+    );
+    assert_eq!(
+        dom.find("button").unwrap().attribute("disabled"),
+        Some("false")
+    );
+    dom.click("button");
+    dom.input("input", "Ship it");
+    dom.click("button");
+
+    let items: Vec<_> = dom.find_all("li").iter().map(|li| li.text()).collect();
+    assert_eq!(items, ["Write testsRemove", "Ship itRemove"]);
+
+    dom.click(r#"li[data-index="0"] button"#);
+    let items: Vec<_> = dom.find_all("ul li").iter().map(|li| li.text()).collect();
+    assert_eq!(items, ["Ship itRemove"]);
+    assert!(dom.find("li.missing").is_none());
+}
+
+#[tokio::test]
+async fn waits_for_async_work() {
+    fn app() -> Element {
+        let mut loaded = use_signal(|| false);
+        use_future(move || async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            loaded.set(true);
+        });
+        rsx! {
+            if loaded() {
+                "Loaded"
+            } else {
+                "Loading"
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    assert_eq!(dom.text(), "Loading");
+    dom.wait_for_work().await;
+    assert_eq!(dom.text(), "Loaded");
+}
+
+#[tokio::test]
+async fn waits_for_suspense() {
+    fn app() -> Element {
+        rsx! {
+            SuspenseBoundary { fallback: |_| rsx! { "Loading" },
+                Message {}
+            }
+        }
+    }
+
+    #[component]
+    fn Message() -> Element {
+        let message = use_resource(|| async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            "Hello from the server"
+        })
+        .suspend()?;
+        let message = *message.read();
+        rsx! { h1 { "{message}" } }
+    }
+
+    let mut dom = TestDom::new(app);
+    dom.wait_for_suspense().await;
+    assert_eq!(dom.find("h1").unwrap().text(), "Hello from the server");
+}
+
+#[test]
+fn attributes_of_any_type_are_skipped() {
+    #[derive(PartialEq)]
+    struct Chart;
+
+    fn app() -> Element {
+        let chart = dioxus::dioxus_core::AttributeValue::any_value(Chart);
+        rsx! {
+            canvas { id: "chart", "data": chart }
+        }
+    }
+
+    let dom = TestDom::new(app);
+    assert_eq!(dom.html(), r#"<canvas id="chart"></canvas>"#);
+}
@@ -0,0 +1,86 @@
+//! Accessibility warnings for debug builds.
+//!
+//! After each flush, the renderer checks the page for common accessibility problems and logs a warning for each element
+//! once. The checks only run in debug builds and never change the page.
+
+use dioxus_core::ElementId;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Element;
+
+use crate::dom::WebsysDom;
+
+/// The property on elements the renderer already warned about
+const WARNED: &str = "__dioxusA11yWarned";
+
+/// Elements that can be focused and activated with the keyboard without a role or tabindex
+const INTERACTIVE_TAGS: &[&str] = &[
+    "a", "area", "button", "details", "input", "option", "select", "summary", "textarea",
+];
+
+impl WebsysDom {
+    /// Check an element with a click listener once the edits are flushed
+    pub(crate) fn queue_a11y_click_check(&mut self, id: ElementId) {
+        self.queued_a11y_checks.push(id);
+    }
+
+    /// Warn about images without alt text and click handlers that can't be reached with the keyboard
+    pub(crate) fn flush_a11y_checks(&mut self) {
+        let images = match self.root.dyn_ref::<web_sys::ShadowRoot>() {
+            Some(shadow_root) => shadow_root.query_selector_all("img:not([alt])"),
+            None => self.document.query_selector_all("img:not([alt])"),
+        };
+        if let Ok(images) = images {
+            for i in 0..images.length() {
+                if let Some(image) = images.get(i).and_then(|node| node.dyn_into().ok()) {
+                    warn_once(
+                        &image,
+                        "Images need an `alt` attribute that describes them to screen readers. Use `alt: \"\"` for decorative images.",
+                    );
+                }
+            }
+        }
+
+        for id in std::mem::take(&mut self.queued_a11y_checks) {
+            let node = self.interpreter.base().get_node(id.0 as u32);
+            let Some(element) = node.dyn_ref::<Element>() else {
+                continue;
+            };
+            if element.is_connected() && !is_keyboard_accessible(element) {
+                warn_once(
+                    element,
+                    "Elements with a click handler should be interactive elements like `button`, or have a `role` and `tabindex` so keyboard and screen reader users can activate them.",
+                );
+            }
+        }
+    }
+}
+
+fn is_keyboard_accessible(element: &Element) -> bool {
+    let tag = element.tag_name().to_ascii_lowercase();
+    if INTERACTIVE_TAGS.contains(&tag.as_str()) {
+        // Links without an href are not focusable
+        return tag != "a" || element.has_attribute("href");
+    }
+    (element.has_attribute("role") && element.has_attribute("tabindex"))
+        || element.has_attribute("contenteditable")
+}
+
+/// Log a warning about an element if we haven't already
+fn warn_once(element: &Element, message: &str) {
+    let key = JsValue::from_str(WARNED);
+    if js_sys::Reflect::has(element, &key).unwrap_or(true) {
+        return;
+    }
+    _ = js_sys::Reflect::set(element, &key, &JsValue::TRUE);
+
+    let mut html = element.outer_html();
+    if html.len() > 120 {
+        let end = (0..=120)
+            .rev()
+            .find(|i| html.is_char_boundary(*i))
+            .unwrap_or(0);
+        html.truncate(end);
+        html.push_str("...");
+    }
+    tracing::warn!("Accessibility: {message}\n{html}");
+}
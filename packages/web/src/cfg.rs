@@ -13,6 +13,9 @@ use wasm_bindgen::JsCast as _;
 pub struct Config {
     pub(crate) hydrate: bool,
     pub(crate) root: ConfigRoot,
+    pub(crate) performance_marks: bool,
+    pub(crate) shadow_root: bool,
+    pub(crate) batch_frames: bool,
 }
 
 impl LaunchConfig for Config {}
@@ -52,6 +55,42 @@ impl Config {
         self
     }
 
+    /// Add entries to the browser performance timeline for every navigation and for the diff and commit phase of every
+    /// render. The entries are named after the current route, so real user monitoring tools can attribute slow
+    /// interactions to the route that caused them.
+    pub fn performance_marks(mut self, enabled: bool) -> Self {
+        self.performance_marks = enabled;
+        self
+    }
+
+    /// Wait for the next animation frame before diffing and writing changes to the DOM. Every state update that
+    /// happens before the frame, like a burst of `mousemove` events, is coalesced into a single write so the browser
+    /// only has to lay out the page once per frame.
+    ///
+    /// This can delay the response to an event by up to a frame, so it is disabled by default. Hydrating streamed
+    /// suspense boundaries also waits until the browser is idle when this is enabled.
+    pub fn batch_frames(mut self, enabled: bool) -> Self {
+        self.batch_frames = enabled;
+        self
+    }
+
+    /// Mount the app inside of a shadow root attached to the root element instead of the root element itself.
+    ///
+    /// This isolates an app that is embedded in another page, like a third party widget. The page's css does not apply
+    /// to the app, styles and stylesheets the app creates with the document components are added to the shadow root
+    /// instead of the page's head, and event listeners are attached to the shadow root instead of the page. An open
+    /// shadow root is attached if the root element doesn't already have one.
+    ///
+    /// The server can't render into a shadow root, so this can't be combined with hydration.
+    ///
+    /// ```rust, ignore
+    /// dioxus_web::launch::launch_cfg(Widget, Config::new().rootname("widget").shadow_root(true))
+    /// ```
+    pub fn shadow_root(mut self, enabled: bool) -> Self {
+        self.shadow_root = enabled;
+        self
+    }
+
     /// Set the element that Dioxus will use as root.
     ///
     /// This is akin to calling React.render() on the given element.
@@ -74,6 +113,9 @@ impl Default for Config {
         Self {
             hydrate: false,
             root: ConfigRoot::RootName("main".to_string()),
+            performance_marks: false,
+            shadow_root: false,
+            batch_frames: false,
         }
     }
 }
@@ -1,8 +1,8 @@
 use dioxus_core::prelude::queue_effect;
 use dioxus_core::ScopeId;
 use dioxus_document::{
-    create_element_in_head, Document, Eval, EvalError, Evaluator, LinkProps, MetaProps,
-    ScriptProps, StyleProps,
+    create_element_in_head, Capabilities, Document, DocumentLanguage, Eval, EvalError, Evaluator,
+    LinkProps, MetaProps, ScriptProps, StyleProps,
 };
 use dioxus_history::History;
 use futures_util::FutureExt;
@@ -63,6 +63,42 @@ extern "C" {
     pub async fn rust_recv(this: &WeakDioxusChannel) -> wasm_bindgen::JsValue;
 }
 
+thread_local! {
+    /// The shadow root the app is mounted in, if it was launched with [`Config::shadow_root`](crate::Config::shadow_root)
+    static SHADOW_ROOT: std::cell::RefCell<Option<web_sys::ShadowRoot>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Add styles and stylesheets to the shadow root instead of the head of the page
+pub(crate) fn set_shadow_root(shadow_root: web_sys::ShadowRoot) {
+    SHADOW_ROOT.with(|root| *root.borrow_mut() = Some(shadow_root));
+}
+
+/// Try to create an element in the shadow root the app is mounted in. Returns false if the app is not mounted in a
+/// shadow root
+fn create_element_in_shadow_root(
+    tag: &str,
+    attributes: &[(&str, String)],
+    children: Option<&str>,
+) -> bool {
+    let Some(shadow_root) = SHADOW_ROOT.with(|root| root.borrow().clone()) else {
+        return false;
+    };
+    let Some(document) = shadow_root.owner_document() else {
+        return false;
+    };
+    let Ok(element) = document.create_element(tag) else {
+        return false;
+    };
+    for (name, value) in attributes {
+        _ = element.set_attribute(name, value);
+    }
+    if let Some(children) = children {
+        element.set_text_content(Some(children));
+    }
+    _ = shadow_root.append_child(&element);
+    true
+}
+
 /// Provides the Document through [`ScopeId::provide_context`].
 pub fn init_document() {
     let provider: Rc<dyn Document> = Rc::new(WebDocument);
@@ -91,6 +127,14 @@ impl Document for WebDocument {
         });
     }
 
+    /// Set the language of the document
+    fn set_language(&self, language: DocumentLanguage) {
+        let myself = self.clone();
+        queue_effect(move || {
+            myself.eval(language.update_script());
+        });
+    }
+
     /// Create a new meta tag in the head
     fn create_meta(&self, props: MetaProps) {
         let myself = self.clone();
@@ -111,25 +155,55 @@ impl Document for WebDocument {
         });
     }
 
-    /// Create a new style tag in the head
+    /// Create a new style tag in the head, or in the shadow root the app is mounted in
     fn create_style(&self, props: StyleProps) {
         let myself = self.clone();
         queue_effect(move || {
-            myself.eval(create_element_in_head(
-                "style",
-                &props.attributes(),
-                props.style_contents().ok(),
-            ));
+            let attributes = props.attributes();
+            let contents = props.style_contents().ok();
+            if !create_element_in_shadow_root("style", &attributes, contents.as_deref()) {
+                myself.eval(create_element_in_head("style", &attributes, contents));
+            }
         });
     }
 
-    /// Create a new link tag in the head
+    /// Create a new link tag in the head. Stylesheets are created in the shadow root the app is mounted in
     fn create_link(&self, props: LinkProps) {
         let myself = self.clone();
         queue_effect(move || {
-            myself.eval(create_element_in_head("link", &props.attributes(), None));
+            let attributes = props.attributes();
+            let stylesheet = props.rel.as_deref() == Some("stylesheet");
+            if !(stylesheet && create_element_in_shadow_root("link", &attributes, None)) {
+                myself.eval(create_element_in_head("link", &attributes, None));
+            }
         });
     }
+
+    /// Detect the features the browser supports
+    fn capabilities(&self) -> Capabilities {
+        let get = |target: &JsValue, name: &str| {
+            js_sys::Reflect::get(target, &JsValue::from_str(name)).unwrap_or(JsValue::UNDEFINED)
+        };
+        // Reflect.has throws if the target is not an object, which we treat as the feature being missing
+        let has = |target: &JsValue, name: &str| {
+            js_sys::Reflect::has(target, &JsValue::from_str(name)).unwrap_or(false)
+        };
+        let global: JsValue = js_sys::global().into();
+        let navigator = get(&global, "navigator");
+        let document = get(&global, "document");
+
+        let mut capabilities = Capabilities::default();
+        capabilities.clipboard = has(&navigator, "clipboard");
+        capabilities.notifications = has(&global, "Notification");
+        capabilities.file_system = has(&global, "showOpenFilePicker");
+        capabilities.view_transitions = has(&document, "startViewTransition");
+        capabilities.touch = has(&global, "ontouchstart")
+            || get(&navigator, "maxTouchPoints")
+                .as_f64()
+                .unwrap_or_default()
+                > 0.0;
+        capabilities
+    }
 }
 
 /// Required to avoid blocking the Rust WASM thread.
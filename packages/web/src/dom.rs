@@ -18,12 +18,24 @@ use web_sys::{Document, Event, Node};
 use crate::{load_document, virtual_event_from_websys_event, Config, WebEventConverter};
 
 pub struct WebsysDom {
-    #[allow(dead_code)]
     pub(crate) root: Node,
     pub(crate) document: Document,
     pub(crate) templates: FxHashMap<Template, u16>,
     pub(crate) interpreter: Interpreter,
 
+    /// Templates that contain an element with a static `autofocus` attribute
+    pub(crate) autofocus_templates: rustc_hash::FxHashSet<u16>,
+
+    /// If an element with `autofocus` was added or patched since the last flush
+    pub(crate) pending_autofocus: bool,
+
+    /// The portals that need to be moved into their targets and the events they forward
+    pub(crate) portals: crate::portal::Portals,
+
+    /// Elements that got a click listener since the last flush, checked for accessibility problems after the flush
+    #[cfg(debug_assertions)]
+    pub(crate) queued_a11y_checks: Vec<ElementId>,
+
     #[cfg(feature = "mounted")]
     pub(crate) runtime: Rc<Runtime>,
 
@@ -78,6 +90,12 @@ impl WebsysDom {
             }
         };
 
+        let root = if cfg.shadow_root {
+            attach_shadow_root(root)
+        } else {
+            root
+        };
+
         let interpreter = Interpreter::default();
 
         // The closure type we pass to the dom may be invoked recursively if one event triggers another. For example,
@@ -120,6 +138,9 @@ impl WebsysDom {
         );
 
         dioxus_html::set_event_converter(Box::new(WebEventConverter));
+        let portals = crate::portal::Portals::new(
+            handler.as_ref().unchecked_ref::<js_sys::Function>().clone(),
+        );
         handler.forget();
 
         Self {
@@ -127,6 +148,11 @@ impl WebsysDom {
             root,
             interpreter,
             templates: FxHashMap::default(),
+            autofocus_templates: Default::default(),
+            pending_autofocus: false,
+            portals,
+            #[cfg(debug_assertions)]
+            queued_a11y_checks: Default::default(),
             #[cfg(feature = "mounted")]
             runtime,
             #[cfg(feature = "mounted")]
@@ -139,6 +165,38 @@ impl WebsysDom {
     }
 }
 
+/// Attach an open shadow root to the root element, or reuse the shadow root it already has, so the app is isolated
+/// from the page it is embedded in
+fn attach_shadow_root(root: Node) -> Node {
+    let Some(element) = root.dyn_ref::<web_sys::Element>() else {
+        web_sys::console::error_1(
+            &"the root must be an element to mount the app in a shadow root. mounting to the root node.".into(),
+        );
+        return root;
+    };
+    let shadow_root = match element.shadow_root() {
+        Some(shadow_root) => shadow_root,
+        None => match element
+            .attach_shadow(&web_sys::ShadowRootInit::new(web_sys::ShadowRootMode::Open))
+        {
+            Ok(shadow_root) => shadow_root,
+            Err(err) => {
+                web_sys::console::error_2(
+                    &"failed to attach a shadow root. mounting to the root element.".into(),
+                    &err,
+                );
+                return root;
+            }
+        },
+    };
+
+    // Styles the document components create need to be inside of the shadow root to apply to the app
+    #[cfg(feature = "document")]
+    crate::document::set_shadow_root(shadow_root.clone());
+
+    shadow_root.unchecked_into()
+}
+
 fn walk_event_for_id(event: &web_sys::Event) -> Option<(ElementId, web_sys::Element)> {
     let target = event
         .target()
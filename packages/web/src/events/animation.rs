@@ -16,6 +16,10 @@ impl HasAnimationData for Synthetic<AnimationEvent> {
         self.event.elapsed_time()
     }
 
+    fn dispatched_on_target(&self) -> bool {
+        super::dispatched_on_target(&self.event)
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         &self.event
     }
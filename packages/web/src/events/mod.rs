@@ -253,6 +253,15 @@ pub(crate) fn load_document() -> Document {
         .expect("should have access to the Document")
 }
 
+/// Check if an event started on the element it is dispatched to. Events that start on a static child without a dioxus
+/// id are dispatched to the closest parent with an id instead
+pub(crate) fn dispatched_on_target(event: &Event) -> bool {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .is_some_and(|target| target.has_attribute("data-dioxus-id"))
+}
+
 macro_rules! uncheck_convert {
     ($t:ty) => {
         impl From<Event> for Synthetic<$t> {
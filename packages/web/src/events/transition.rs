@@ -16,6 +16,10 @@ impl HasTransitionData for Synthetic<TransitionEvent> {
         self.event.pseudo_element()
     }
 
+    fn dispatched_on_target(&self) -> bool {
+        super::dispatched_on_target(&self.event)
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         &self.event
     }
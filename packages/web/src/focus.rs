@@ -0,0 +1,170 @@
+//! Focus management for the web renderer.
+//!
+//! Edits can replace attributes on the focused element, like the `value` of an input, which moves the caret or drops
+//! focus entirely in some browsers. Before edits are flushed, the renderer takes a snapshot of the focused element and
+//! its selection and restores them afterwards. Browsers only honor the `autofocus` attribute while the page loads, so
+//! the renderer also focuses elements it adds or patches with `autofocus` after each flush.
+
+use dioxus_core::TemplateNode;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, HtmlElement, HtmlInputElement, HtmlTextAreaElement, ShadowRoot};
+
+use crate::dom::WebsysDom;
+
+/// The property on elements the renderer already autofocused. Elements are only autofocused once, when they are first
+/// rendered with the attribute.
+const AUTOFOCUSED: &str = "__dioxusAutofocused";
+
+/// The focused element and the selection inside of it before a flush
+pub(crate) struct FocusSnapshot {
+    element: Element,
+    selection: Option<Selection>,
+}
+
+struct Selection {
+    start: u32,
+    end: u32,
+    direction: Option<String>,
+}
+
+impl FocusSnapshot {
+    /// Take a snapshot of the element that currently has focus, if any
+    pub(crate) fn take(dom: &WebsysDom) -> Option<Self> {
+        let element = dom.active_element()?;
+        if dom.document.body().is_some_and(|body| *body == element) {
+            return None;
+        }
+        let selection = Selection::read(&element);
+        Some(Self { element, selection })
+    }
+
+    /// Restore focus and the selection if the flush moved them. Elements that were removed are left alone.
+    pub(crate) fn restore(self, dom: &WebsysDom) {
+        if !self.element.is_connected() {
+            return;
+        }
+        if dom.active_element().as_ref() != Some(&self.element) {
+            if let Some(element) = self.element.dyn_ref::<HtmlElement>() {
+                _ = element.focus();
+            }
+        }
+        if let Some(selection) = self.selection {
+            selection.restore(&self.element);
+        }
+    }
+}
+
+impl Selection {
+    fn read(element: &Element) -> Option<Self> {
+        // Some input types, like `number` or `email`, don't support selections and throw when they are read
+        if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+            Some(Self {
+                start: input.selection_start().ok()??,
+                end: input.selection_end().ok()??,
+                direction: input.selection_direction().ok().flatten(),
+            })
+        } else if let Some(textarea) = element.dyn_ref::<HtmlTextAreaElement>() {
+            Some(Self {
+                start: textarea.selection_start().ok()??,
+                end: textarea.selection_end().ok()??,
+                direction: textarea.selection_direction().ok().flatten(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn restore(self, element: &Element) {
+        let Some(current) = Self::read(element) else {
+            return;
+        };
+        if current.start == self.start && current.end == self.end {
+            return;
+        }
+
+        // Selections are measured in utf-16 code units. Clamp the old selection to the new value
+        if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+            let (start, end) = self.clamp(&input.value());
+            _ = match &self.direction {
+                Some(direction) => input.set_selection_range_with_direction(start, end, direction),
+                None => input.set_selection_range(start, end),
+            };
+        } else if let Some(textarea) = element.dyn_ref::<HtmlTextAreaElement>() {
+            let (start, end) = self.clamp(&textarea.value());
+            _ = match &self.direction {
+                Some(direction) => {
+                    textarea.set_selection_range_with_direction(start, end, direction)
+                }
+                None => textarea.set_selection_range(start, end),
+            };
+        }
+    }
+
+    fn clamp(&self, value: &str) -> (u32, u32) {
+        let len = value.encode_utf16().count() as u32;
+        (self.start.min(len), self.end.min(len))
+    }
+}
+
+/// Check if any element in a template sets `autofocus` statically
+pub(crate) fn template_has_autofocus(nodes: &[TemplateNode]) -> bool {
+    nodes.iter().any(|node| match node {
+        TemplateNode::Element {
+            attrs, children, ..
+        } => {
+            attrs.iter().any(|attr| {
+                matches!(
+                    attr,
+                    dioxus_core::TemplateAttribute::Static {
+                        name: "autofocus",
+                        value,
+                        ..
+                    } if *value != "false"
+                )
+            }) || template_has_autofocus(children)
+        }
+        _ => false,
+    })
+}
+
+impl WebsysDom {
+    /// Get the focused element. If the app is mounted in a shadow root, the document only knows that the shadow host
+    /// is focused, so we ask the shadow root instead
+    fn active_element(&self) -> Option<Element> {
+        match self.root.dyn_ref::<ShadowRoot>() {
+            Some(shadow_root) => shadow_root.active_element(),
+            None => self.document.active_element(),
+        }
+    }
+
+    /// Focus the first element with `autofocus` that has not been autofocused yet
+    pub(crate) fn flush_autofocus(&mut self) {
+        if !std::mem::take(&mut self.pending_autofocus) {
+            return;
+        }
+        let elements = match self.root.dyn_ref::<ShadowRoot>() {
+            Some(shadow_root) => shadow_root.query_selector_all("[autofocus]"),
+            None => self.document.query_selector_all("[autofocus]"),
+        };
+        let Ok(elements) = elements else {
+            return;
+        };
+
+        let key = JsValue::from_str(AUTOFOCUSED);
+        let mut focused = false;
+        for i in 0..elements.length() {
+            let Some(element) = elements.get(i) else {
+                continue;
+            };
+            if js_sys::Reflect::has(&element, &key).unwrap_or(true) {
+                continue;
+            }
+            _ = js_sys::Reflect::set(&element, &key, &JsValue::TRUE);
+            if !focused {
+                if let Some(element) = element.dyn_ref::<HtmlElement>() {
+                    focused = element.focus().is_ok();
+                }
+            }
+        }
+    }
+}
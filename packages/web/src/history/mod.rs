@@ -1,4 +1,5 @@
 use scroll::ScrollPosition;
+use std::{cell::RefCell, rc::Rc};
 use wasm_bindgen::JsCast;
 use wasm_bindgen::{prelude::Closure, JsValue};
 use web_sys::{window, Window};
@@ -13,9 +14,13 @@ fn base_path() -> Option<String> {
 }
 
 #[allow(clippy::extra_unused_type_parameters)]
-fn update_scroll(window: &Window, history: &History) {
-    let scroll = ScrollPosition::of_window(window);
-    if let Err(err) = replace_state_with_url(history, &[scroll.x, scroll.y], None) {
+fn update_scroll(window: &Window, history: &History, container: Option<&str>) {
+    let scroll = ScrollPosition::of_container(window, container);
+    // Keep the state payload of the entry the user is leaving
+    let payload = get_current_payload(history);
+    if let Err(err) =
+        replace_state_with_url(history, &[scroll.x, scroll.y], payload.as_deref(), None)
+    {
         web_sys::console::error_1(&err);
     }
 }
@@ -34,6 +39,8 @@ fn update_scroll(window: &Window, history: &History) {
 /// in the URL. Otherwise, if a router navigation is triggered, the prefix will be added.
 pub struct WebHistory {
     do_scroll_restoration: bool,
+    /// A selector for the element to restore the scroll position of instead of the window
+    scroll_container: Rc<RefCell<Option<String>>>,
     history: History,
     prefix: Option<String>,
     window: Window,
@@ -58,7 +65,14 @@ impl WebHistory {
         let prefix_str = myself.prefix.as_deref().unwrap_or("");
         let current_url = format!("{prefix_str}{current_route_str}");
         let state = myself.create_state();
-        let _ = replace_state_with_url(&myself.history, &state, Some(&current_url));
+        // The page may be reloaded on an entry that has a state payload
+        let payload = get_current_payload(&myself.history);
+        let _ = replace_state_with_url(
+            &myself.history,
+            &state,
+            payload.as_deref(),
+            Some(&current_url),
+        );
 
         myself
     }
@@ -86,6 +100,7 @@ impl WebHistory {
 
         Self {
             do_scroll_restoration,
+            scroll_container: Default::default(),
             history,
             prefix,
             window,
@@ -94,7 +109,12 @@ impl WebHistory {
 
     fn scroll_pos(&self) -> ScrollPosition {
         self.do_scroll_restoration
-            .then(|| ScrollPosition::of_window(&self.window))
+            .then(|| {
+                ScrollPosition::of_container(
+                    &self.window,
+                    self.scroll_container.borrow().as_deref(),
+                )
+            })
             .unwrap_or_default()
     }
 
@@ -132,7 +152,11 @@ impl WebHistory {
         match result {
             Ok(_) => {
                 if self.do_scroll_restoration {
-                    self.window.scroll_to_with_x_and_y(0.0, 0.0)
+                    match self.scroll_container.borrow().clone() {
+                        Some(container) => ScrollPosition::default()
+                            .scroll_to(self.window.clone(), Some(container)),
+                        None => self.window.scroll_to_with_x_and_y(0.0, 0.0),
+                    }
                 }
             }
             Err(e) => {
@@ -141,6 +165,38 @@ impl WebHistory {
         }
     }
 
+    fn push_entry(&self, state: String, payload: Option<String>) {
+        let w = window().expect("access to `window`");
+        let h = w.history().expect("`window` has access to `history`");
+
+        // update the scroll position before pushing the new state
+        update_scroll(&w, &h, self.scroll_container.borrow().as_deref());
+
+        let path = self.full_path(&state);
+
+        crate::performance::mark_navigation(&state);
+        let state: [f64; 2] = self.create_state();
+        self.handle_nav(push_state_and_url(
+            &self.history,
+            &state,
+            payload.as_deref(),
+            path,
+        ));
+    }
+
+    fn replace_entry(&self, state: String, payload: Option<String>) {
+        crate::performance::mark_navigation(&state);
+        let path = self.full_path(&state);
+
+        let state = self.create_state();
+        self.handle_nav(replace_state_with_url(
+            &self.history,
+            &state,
+            payload.as_deref(),
+            Some(&path),
+        ));
+    }
+
     fn navigate_external(&self, url: String) -> bool {
         match self.window.location().set_href(&url) {
             Ok(_) => true,
@@ -166,6 +222,10 @@ impl dioxus_history::History for WebHistory {
         self.prefix.clone()
     }
 
+    fn current_host(&self) -> Option<String> {
+        self.window.location().host().ok()
+    }
+
     fn go_back(&self) {
         if let Err(e) = self.history.back() {
             web_sys::console::error_2(&JsValue::from_str("failed to go back: "), &e);
@@ -183,40 +243,61 @@ impl dioxus_history::History for WebHistory {
             // don't push the same state twice
             return;
         }
+        self.push_entry(state, None);
+    }
 
-        let w = window().expect("access to `window`");
-        let h = w.history().expect("`window` has access to `history`");
-
-        // update the scroll position before pushing the new state
-        update_scroll(&w, &h);
-
-        let path = self.full_path(&state);
+    fn replace(&self, state: String) {
+        self.replace_entry(state, None);
+    }
 
-        let state: [f64; 2] = self.create_state();
-        self.handle_nav(push_state_and_url(&self.history, &state, path));
+    fn push_with_state(&self, route: String, state: String) {
+        // Pushing the current route again only updates its state
+        if route == self.current_route() {
+            self.replace_entry(route, Some(state));
+        } else {
+            self.push_entry(route, Some(state));
+        }
     }
 
-    fn replace(&self, state: String) {
-        let path = self.full_path(&state);
+    fn replace_with_state(&self, route: String, state: String) {
+        self.replace_entry(route, Some(state));
+    }
 
-        let state = self.create_state();
-        self.handle_nav(replace_state_with_url(&self.history, &state, Some(&path)));
+    fn current_state(&self) -> Option<String> {
+        get_current_payload(&self.history)
     }
 
     fn external(&self, url: String) -> bool {
         self.navigate_external(url)
     }
 
+    fn set_scroll_container(&self, selector: Option<String>) {
+        *self.scroll_container.borrow_mut() = selector;
+    }
+
+    fn scroll_position(&self) -> Option<(f64, f64)> {
+        let scroll =
+            ScrollPosition::of_container(&self.window, self.scroll_container.borrow().as_deref());
+        Some((scroll.x, scroll.y))
+    }
+
+    fn scroll_to(&self, x: f64, y: f64) {
+        ScrollPosition { x, y }
+            .scroll_to(self.window.clone(), self.scroll_container.borrow().clone());
+    }
+
     fn updater(&self, callback: std::sync::Arc<dyn Fn() + Send + Sync>) {
         let w = self.window.clone();
         let h = self.history.clone();
         let d = self.do_scroll_restoration;
+        let container = self.scroll_container.clone();
 
         let function = Closure::wrap(Box::new(move |_| {
+            crate::performance::mark_navigation(&crate::performance::current_route());
             (*callback)();
             if d {
                 if let Some([x, y]) = get_current(&h) {
-                    ScrollPosition { x, y }.scroll_to(w.clone())
+                    ScrollPosition { x, y }.scroll_to(w.clone(), container.borrow().clone())
                 }
             }
         }) as Box<dyn FnMut(Event)>);
@@ -229,41 +310,53 @@ impl dioxus_history::History for WebHistory {
     }
 }
 
+/// The state of a history entry is an array with the scroll position and the state payload of the entry, if any
+fn entry_state(value: &[f64; 2], payload: Option<&str>) -> js_sys::Array {
+    let state = js_sys::Array::new();
+    state.push(&JsValue::from(value[0]));
+    state.push(&JsValue::from(value[1]));
+    if let Some(payload) = payload {
+        state.push(&JsValue::from_str(payload));
+    }
+    state
+}
+
 pub(crate) fn replace_state_with_url(
     history: &History,
     value: &[f64; 2],
+    payload: Option<&str>,
     url: Option<&str>,
 ) -> Result<(), JsValue> {
-    let position = js_sys::Array::new();
-    position.push(&JsValue::from(value[0]));
-    position.push(&JsValue::from(value[1]));
-
-    history.replace_state_with_url(&position, "", url)
+    history.replace_state_with_url(&entry_state(value, payload), "", url)
 }
 
 pub(crate) fn push_state_and_url(
     history: &History,
     value: &[f64; 2],
+    payload: Option<&str>,
     url: String,
 ) -> Result<(), JsValue> {
-    let position = js_sys::Array::new();
-    position.push(&JsValue::from(value[0]));
-    position.push(&JsValue::from(value[1]));
-
-    history.push_state_with_url(&position, "", Some(&url))
+    history.push_state_with_url(&entry_state(value, payload), "", Some(&url))
 }
 
-pub(crate) fn get_current(history: &History) -> Option<[f64; 2]> {
+fn current_entry_state(history: &History) -> Option<js_sys::Array> {
     use wasm_bindgen::JsCast;
 
     let state = history.state();
     if let Err(err) = &state {
         web_sys::console::error_1(err);
     }
-    state.ok().and_then(|state| {
-        let state = state.dyn_into::<js_sys::Array>().ok()?;
-        let x = state.get(0).as_f64()?;
-        let y = state.get(1).as_f64()?;
-        Some([x, y])
-    })
+    state.ok()?.dyn_into::<js_sys::Array>().ok()
+}
+
+pub(crate) fn get_current(history: &History) -> Option<[f64; 2]> {
+    let state = current_entry_state(history)?;
+    let x = state.get(0).as_f64()?;
+    let y = state.get(1).as_f64()?;
+    Some([x, y])
+}
+
+/// Get the state payload saved with the current history entry
+pub(crate) fn get_current_payload(history: &History) -> Option<String> {
+    current_entry_state(history)?.get(2).as_string()
 }
@@ -15,14 +15,41 @@ impl ScrollPosition {
         }
     }
 
-    pub(crate) fn scroll_to(&self, window: Window) {
+    /// Get the scroll position of the scroll container, or the window if there is no container
+    pub(crate) fn of_container(window: &Window, container: Option<&str>) -> Self {
+        match find_container(window, container) {
+            Some(element) => Self {
+                x: element.scroll_left() as f64,
+                y: element.scroll_top() as f64,
+            },
+            None => Self::of_window(window),
+        }
+    }
+
+    pub(crate) fn scroll_to(&self, window: Window, container: Option<String>) {
         let Self { x, y } = *self;
-        let f = Closure::wrap(
-            Box::new(move || window.scroll_to_with_x_and_y(x, y)) as Box<dyn FnMut()>
-        );
+        let f = Closure::wrap(Box::new(move || {
+            // Look up the container once the frame renders, since the route that owns it may have just been created
+            match find_container(&window, container.as_deref()) {
+                Some(element) => element.scroll_to_with_x_and_y(x, y),
+                None => window.scroll_to_with_x_and_y(x, y),
+            }
+        }) as Box<dyn FnMut()>);
         web_sys::window()
             .expect("should be run in a context with a `Window` object (dioxus cannot be run from a web worker)")
             .request_animation_frame(&f.into_js_value().unchecked_into())
             .expect("should register `requestAnimationFrame` OK");
     }
 }
+
+/// Find the element that matches the scroll container selector
+pub(crate) fn find_container(window: &Window, container: Option<&str>) -> Option<web_sys::Element> {
+    let container = container?;
+    let element = window.document()?.query_selector(container).ok().flatten();
+    if element.is_none() {
+        tracing::warn!(
+            "The scroll container {container:?} was not found. Falling back to the window."
+        );
+    }
+    element
+}
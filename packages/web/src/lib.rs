@@ -26,13 +26,19 @@ use dioxus_core::VirtualDom;
 use dom::WebsysDom;
 use futures_util::{pin_mut, select, FutureExt, StreamExt};
 
+#[cfg(debug_assertions)]
+mod a11y;
 mod cfg;
 mod dom;
 
 mod events;
+mod focus;
 pub mod launch;
 mod mutations;
+mod performance;
+mod scheduler;
 pub use events::*;
+pub use scheduler::wait_for_idle;
 
 #[cfg(feature = "document")]
 mod document;
@@ -48,6 +54,8 @@ pub use file_engine::*;
 #[cfg(all(feature = "devtools", debug_assertions))]
 mod devtools;
 
+mod portal;
+
 mod hydration;
 #[allow(unused)]
 pub use hydration::*;
@@ -68,10 +76,18 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
 
     let runtime = virtual_dom.runtime();
 
+    // Lower priority tasks wait for the browser so they can't starve input handling and painting
+    virtual_dom.set_deferred_pause(|priority| match priority {
+        dioxus_core::Priority::Animation => Box::pin(scheduler::animation_frame()),
+        _ => Box::pin(scheduler::wait_for_idle()),
+    });
+
     #[cfg(all(feature = "devtools", debug_assertions))]
     let mut hotreload_rx = devtools::init(runtime.clone());
 
     let should_hydrate = web_config.hydrate;
+    let batch_frames = web_config.batch_frames;
+    performance::set_enabled(web_config.performance_marks);
 
     let mut websys_dom = WebsysDom::new(web_config, runtime);
 
@@ -125,6 +141,7 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
 
             let rx = websys_dom.rehydrate(&virtual_dom).unwrap();
             hydration_receiver = Some(rx);
+            websys_dom.flush_portals();
 
             #[cfg(feature = "mounted")]
             {
@@ -207,26 +224,19 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
 
         #[cfg(feature = "hydrate")]
         if let Some(hydration_data) = hydration_work {
+            if batch_frames {
+                scheduler::wait_for_idle().await;
+            }
             websys_dom.rehydrate_streaming(hydration_data, &mut virtual_dom);
         }
 
-        // Todo: This is currently disabled because it has a negative impact on response times for events but it could be re-enabled for tasks
-        // Jank free rendering
-        //
-        // 1. wait for the browser to give us "idle" time
-        // 2. During idle time, diff the dom
-        // 3. Stop diffing if the deadline is exceeded
-        // 4. Wait for the animation frame to patch the dom
-
-        // wait for the mainthread to schedule us in
-        // let deadline = work_loop.wait_for_idle_time().await;
-
-        // run the virtualdom work phase until the frame deadline is reached
-        virtual_dom.render_immediate(&mut websys_dom);
+        // Wait for the next frame so every update that happens before it is diffed and written to the dom in one pass
+        if batch_frames {
+            scheduler::animation_frame().await;
+        }
 
-        // wait for the animation frame to fire so we can apply our changes
-        // work_loop.wait_for_raf().await;
+        performance::measure("diff", || virtual_dom.render_immediate(&mut websys_dom));
 
-        websys_dom.flush_edits();
+        performance::measure("commit", || websys_dom.flush_edits());
     }
 }
@@ -51,7 +51,16 @@ impl WebsysDom {
     }
 
     pub fn flush_edits(&mut self) {
+        // Replacing attributes on the focused element can move the caret or drop focus, so restore both after the edits
+        let focus = crate::focus::FocusSnapshot::take(self);
         self.interpreter.flush();
+        self.flush_portals();
+        if let Some(focus) = focus {
+            focus.restore(self);
+        }
+        self.flush_autofocus();
+        #[cfg(debug_assertions)]
+        self.flush_a11y_checks();
 
         // Now that we've flushed the edits and the dom nodes exist, we can send the mounted events.
         #[cfg(feature = "mounted")]
@@ -134,9 +143,15 @@ impl WriteMutations for WebsysDom {
             }
             let id = self.templates.len() as u16;
             self.templates.insert(template, id);
+            if crate::focus::template_has_autofocus(template.roots) {
+                self.autofocus_templates.insert(id);
+            }
             self.interpreter.base().save_template(roots, id);
             id
         });
+        if self.autofocus_templates.contains(&tmpl_id) {
+            self.pending_autofocus = true;
+        }
 
         self.interpreter
             .load_template(tmpl_id, index as u16, id.0 as u32)
@@ -178,9 +193,16 @@ impl WriteMutations for WebsysDom {
         value: &AttributeValue,
         id: ElementId,
     ) {
+        // Portals that were hydrated still need to be moved into their targets
+        if name == dioxus_core::PORTAL_ATTRIBUTE {
+            self.portals.queue(id, value);
+        }
         if self.skip_mutations() {
             return;
         }
+        if name == "autofocus" {
+            self.pending_autofocus = true;
+        }
         match value {
             AttributeValue::Text(txt) => {
                 self.interpreter
@@ -208,6 +230,8 @@ impl WriteMutations for WebsysDom {
                 self.interpreter
                     .remove_attribute(id.0 as u32, name, ns.unwrap_or_default())
             }
+            // Element handles of portals are only read by the renderer
+            AttributeValue::Any(_) if name == dioxus_core::PORTAL_ATTRIBUTE => {}
             _ => unreachable!(),
         }
     }
@@ -220,6 +244,9 @@ impl WriteMutations for WebsysDom {
     }
 
     fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        if event_bubbles(name) {
+            self.portals.listen(name);
+        }
         if self.skip_mutations() {
             return;
         }
@@ -229,6 +256,10 @@ impl WriteMutations for WebsysDom {
             self.send_mount_event(id);
             return;
         }
+        #[cfg(debug_assertions)]
+        if name == "click" {
+            self.queue_a11y_click_check(id);
+        }
 
         self.interpreter
             .new_event_listener(name, id.0 as u32, event_bubbles(name) as u8);
@@ -0,0 +1,60 @@
+//! Entries on the browser performance timeline for navigations, diffs, and commits.
+//!
+//! Every entry is named after the current route so real user monitoring tools can attribute slow work to the route
+//! that caused it. Entries are only added if [`Config::performance_marks`](crate::Config::performance_marks) is
+//! enabled.
+
+use std::cell::Cell;
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+}
+
+pub(crate) fn set_enabled(enabled: bool) {
+    ENABLED.with(|cell| cell.set(enabled));
+}
+
+fn performance() -> Option<web_sys::Performance> {
+    if !ENABLED.with(|cell| cell.get()) {
+        return None;
+    }
+    web_sys::window()?.performance()
+}
+
+pub(crate) fn current_route() -> String {
+    web_sys::window()
+        .and_then(|window| {
+            let location = window.location();
+            Some(location.pathname().ok()? + &location.search().ok()?)
+        })
+        .unwrap_or_default()
+}
+
+/// Mark a navigation to a new route
+#[allow(unused)]
+pub(crate) fn mark_navigation(route: &str) {
+    if let Some(performance) = performance() {
+        _ = performance.mark(&format!("dioxus:navigation {route}"));
+    }
+}
+
+/// Measure how long a phase of rendering takes
+pub(crate) fn measure<O>(phase: &str, f: impl FnOnce() -> O) -> O {
+    let Some(performance) = performance() else {
+        return f();
+    };
+
+    let start = format!("dioxus:{phase}-start");
+    _ = performance.mark(&start);
+    let output = f();
+    let end = format!("dioxus:{phase}-end");
+    _ = performance.mark(&end);
+
+    let name = format!("dioxus:{phase} {}", current_route());
+    _ = performance.measure_with_start_mark_and_end_mark(&name, &start, &end);
+    // Only keep the measure. The marks would collect on the timeline with one pair per render
+    performance.clear_marks_with_mark_name(&start);
+    performance.clear_marks_with_mark_name(&end);
+
+    output
+}
@@ -0,0 +1,114 @@
+//! Move [`Portal`](dioxus_core::prelude::Portal)s into their target containers.
+//!
+//! The children of a portal are created and diffed in place like any other nodes, so they keep their contexts and
+//! virtual event bubbling through their parents. After the edits are flushed, the renderer moves the wrapper of every
+//! portal whose target changed into the target container. Because the interpreter tracks nodes by id instead of by
+//! position, later edits still find the moved nodes.
+
+use dioxus_core::{AttributeValue, ElementId};
+use rustc_hash::FxHashSet;
+use wasm_bindgen::JsCast;
+
+use crate::dom::WebsysDom;
+
+/// The portals of the app and the events they forward
+#[derive(Default)]
+pub(crate) struct Portals {
+    /// Portals whose target was set since the last flush
+    queued: Vec<(ElementId, AttributeValue)>,
+    /// Portals that were moved outside of the root element. Events are delegated to the root element, so these
+    /// forward events to the dioxus event handler themselves
+    outside_root: Vec<web_sys::Element>,
+    /// Every bubbling event the app listens to
+    events: FxHashSet<&'static str>,
+    /// The handler that dispatches dom events into the virtual dom
+    pub(crate) handler: Option<js_sys::Function>,
+}
+
+impl Portals {
+    /// Create the portals for an app that dispatches dom events with the handler
+    pub(crate) fn new(handler: js_sys::Function) -> Self {
+        Self {
+            handler: Some(handler),
+            ..Default::default()
+        }
+    }
+
+    /// Move the portal to its target after the next flush
+    pub(crate) fn queue(&mut self, id: ElementId, target: &AttributeValue) {
+        self.queued.push((id, target.clone()));
+    }
+
+    /// Remember a bubbling event the app listens to and forward it from the portals outside of the root
+    pub(crate) fn listen(&mut self, name: &'static str) {
+        if !self.events.insert(name) {
+            return;
+        }
+        let Some(handler) = &self.handler else {
+            return;
+        };
+        self.outside_root.retain(|portal| portal.is_connected());
+        for portal in &self.outside_root {
+            _ = portal.add_event_listener_with_callback(name, handler.unchecked_ref());
+        }
+    }
+}
+
+impl WebsysDom {
+    /// Move every portal whose target changed since the last flush into its target
+    pub(crate) fn flush_portals(&mut self) {
+        if self.portals.queued.is_empty() {
+            return;
+        }
+        self.portals
+            .outside_root
+            .retain(|portal| portal.is_connected());
+
+        for (id, target) in std::mem::take(&mut self.portals.queued) {
+            let node = self.interpreter.base().get_node(id.0 as u32);
+            let Some(portal) = node.dyn_ref::<web_sys::Element>() else {
+                continue;
+            };
+            let container = match &target {
+                AttributeValue::Text(id) => self.document.get_element_by_id(id),
+                AttributeValue::Any(element) => {
+                    element.as_any().downcast_ref::<web_sys::Element>().cloned()
+                }
+                _ => continue,
+            };
+            let Some(container) = container else {
+                tracing::error!("Unable to find the portal target. Rendering the portal in place.");
+                continue;
+            };
+
+            if let Err(err) = container.append_child(portal) {
+                tracing::error!("Failed to move the portal into its target: {err:?}");
+                continue;
+            }
+
+            // If the container is inside of the root, the root element already sees every event from the portal
+            let outside_root = !self.root.contains(Some(&container));
+            let forwarding = self.portals.outside_root.iter().position(|p| p == portal);
+            let Some(handler) = self.portals.handler.clone() else {
+                continue;
+            };
+            match (outside_root, forwarding) {
+                (true, None) => {
+                    for event in &self.portals.events {
+                        _ = portal.add_event_listener_with_callback(event, handler.unchecked_ref());
+                    }
+                    self.portals.outside_root.push(portal.clone());
+                }
+                // The portal moved back into the root, which dispatches its events again
+                (false, Some(index)) => {
+                    for event in &self.portals.events {
+                        _ = portal
+                            .remove_event_listener_with_callback(event, handler.unchecked_ref());
+                    }
+                    self.portals.outside_root.remove(index);
+                }
+                _ => {}
+            }
+        }
+    }
+}
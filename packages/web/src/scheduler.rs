@@ -0,0 +1,58 @@
+//! Waiting for the browser to be ready for more work.
+//!
+//! With [`Config::batch_frames`](crate::Config::batch_frames) enabled, the renderer waits for the next animation frame
+//! before it diffs and writes to the DOM, so every state update that happens before the frame is coalesced into a single
+//! pass. Low priority work, like hydrating streamed suspense boundaries, waits until the browser is idle.
+
+use wasm_bindgen::{closure::Closure, JsCast};
+
+/// Wait for the next animation frame
+pub(crate) async fn animation_frame() {
+    let (tx, rx) = futures_channel::oneshot::channel();
+    let callback = Closure::once_into_js(move || {
+        _ = tx.send(());
+    });
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    if window
+        .request_animation_frame(callback.unchecked_ref())
+        .is_err()
+    {
+        return;
+    }
+    _ = rx.await;
+}
+
+/// Wait until the browser is idle with `requestIdleCallback`. Browsers that don't support idle callbacks wait for the
+/// next task instead.
+///
+/// Use this for low priority work that should not delay user input or rendering:
+///
+/// ```rust, ignore
+/// spawn(async move {
+///     dioxus_web::wait_for_idle().await;
+///     prefetch_next_page().await;
+/// });
+/// ```
+pub async fn wait_for_idle() {
+    let (tx, rx) = futures_channel::oneshot::channel();
+    let callback = Closure::once_into_js(move || {
+        _ = tx.send(());
+    });
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let scheduled = window
+        .request_idle_callback(callback.unchecked_ref())
+        .map(|_| ())
+        .or_else(|_| {
+            window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(callback.unchecked_ref(), 0)
+                .map(|_| ())
+        });
+    if scheduled.is_err() {
+        return;
+    }
+    _ = rx.await;
+}